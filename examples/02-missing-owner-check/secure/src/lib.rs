@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token::{spl_token, Token, TokenAccount};
 
 declare_id!("Secur22222222222222222222222222222222222222");
 
@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+
+declare_id!("Secur13213213213213213213213213213213213213");
+
+#[program]
+pub mod secure_supply_tracking {
+    use super::*;
+
+    /// SECURE: Share Math Reads The Mint's Supply
+    ///
+    /// The pool no longer keeps its own supply counter. Every share calculation
+    /// uses `lp_mint.supply`, which the SPL Token program updates on every mint
+    /// and burn regardless of which program initiated it.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Denominator comes from `ctx.accounts.lp_mint.supply`
+    /// 2. `has_one = lp_mint` ties the mint to this pool
+    /// 3. No shadow state exists that could drift from the mint
+    ///
+    /// WHY EXTERNAL BURNS ARE NOW HARMLESS:
+    /// - A direct burn lowers `lp_mint.supply` immediately
+    /// - The next redemption divides by the lower supply
+    /// - Reserve backing the burned tokens is shared by the remaining holders
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, lp_amount: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+
+        // ✅ Source of truth: the mint itself
+        let lp_supply = ctx.accounts.lp_mint.supply;
+        require!(lp_supply > 0, ErrorCode::EmptyPool);
+
+        let payout = (lp_amount as u128)
+            .checked_mul(ctx.accounts.reserve_vault.amount as u128)
+            .and_then(|v| v.checked_div(lp_supply as u128))
+            .ok_or(ErrorCode::ArithmeticOverflow)? as u64;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    from: ctx.accounts.user_lp.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            lp_amount,
+        )?;
+
+        let seeds = &[b"pool".as_ref(), pool.lp_mint.as_ref(), &[pool.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reserve_vault.to_account_info(),
+                    to: ctx.accounts.user_reserve.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            payout,
+        )?;
+
+        msg!("Securely redeemed {} LP for {} reserve tokens", lp_amount, payout);
+        Ok(())
+    }
+
+    /// Deposit reserve tokens and mint LP against the live mint supply
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let reserve = ctx.accounts.reserve_vault.amount;
+        let lp_supply = ctx.accounts.lp_mint.supply;
+
+        let lp_out = if lp_supply == 0 {
+            amount
+        } else {
+            (amount as u128)
+                .checked_mul(lp_supply as u128)
+                .and_then(|v| v.checked_div(reserve as u128))
+                .ok_or(ErrorCode::ArithmeticOverflow)? as u64
+        };
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_reserve.to_account_info(),
+                    to: ctx.accounts.reserve_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let seeds = &[b"pool".as_ref(), pool.lp_mint.as_ref(), &[pool.bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.user_lp.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            lp_out,
+        )?;
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(
+        seeds = [b"pool", lp_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = lp_mint,
+        has_one = reserve_vault,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// ✅ Read for its supply on every call
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub reserve_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = lp_mint, token::authority = user)]
+    pub user_lp: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user_reserve: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(
+        seeds = [b"pool", lp_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = lp_mint,
+        has_one = reserve_vault,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub reserve_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = lp_mint)]
+    pub user_lp: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user_reserve: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub lp_mint: Pubkey,
+    pub reserve_vault: Pubkey,
+    // ✅ No lp_supply field - the mint is the only record of supply
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+
+    #[msg("Pool has no outstanding LP tokens")]
+    EmptyPool,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * Never cache a value another program owns. The SPL mint is the authority
+ * on supply; any copy you keep can be invalidated by an instruction you
+ * never see. Read the mint directly, every time.
+ */
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln13213213213213213213213213213213213213");
+
+#[program]
+pub mod vulnerable_supply_tracking {
+    use super::*;
+
+    /// VULNERABILITY: LP Supply Tracked Separately From The Mint
+    ///
+    /// The pool keeps its own copy of the LP supply in `pool.lp_supply` and
+    /// uses it as the denominator for every share calculation. The real supply
+    /// lives on the SPL mint, and nothing keeps the two in sync.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Pool holds 1,000 reserve tokens backed by 1,000 LP tokens
+    /// 2. Mallory burns LP tokens by calling the SPL Token program directly
+    /// 3. The mint's supply drops, `pool.lp_supply` does not
+    /// 4. Every later deposit and withdrawal is priced against the stale number
+    /// 5. The reserve backing the burned LP is never redistributed, and the
+    ///    share each remaining holder is credited no longer matches reality
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Anyone holding a token can burn it; no pool instruction is involved
+    /// - A cached copy of on-chain state is only as good as the paths that update it
+    /// - Integrations that price LP from `pool.lp_supply` inherit the error
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, lp_amount: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+
+        // ❌ Stale denominator: ignores burns made outside this program
+        let payout = (lp_amount as u128)
+            .checked_mul(ctx.accounts.reserve_vault.amount as u128)
+            .and_then(|v| v.checked_div(pool.lp_supply as u128))
+            .ok_or(ErrorCode::ArithmeticOverflow)? as u64;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    from: ctx.accounts.user_lp.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            lp_amount,
+        )?;
+
+        let seeds = &[b"pool".as_ref(), pool.lp_mint.as_ref(), &[pool.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reserve_vault.to_account_info(),
+                    to: ctx.accounts.user_reserve.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            payout,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.lp_supply = pool
+            .lp_supply
+            .checked_sub(lp_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Redeemed {} LP for {} reserve tokens", lp_amount, payout);
+        Ok(())
+    }
+
+    /// Deposit reserve tokens and mint LP at the pool's tracked ratio
+    /// Shares the same stale denominator as `remove_liquidity`
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let reserve = ctx.accounts.reserve_vault.amount;
+
+        let lp_out = if pool.lp_supply == 0 {
+            amount
+        } else {
+            (amount as u128)
+                .checked_mul(pool.lp_supply as u128)
+                .and_then(|v| v.checked_div(reserve as u128))
+                .ok_or(ErrorCode::ArithmeticOverflow)? as u64
+        };
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_reserve.to_account_info(),
+                    to: ctx.accounts.reserve_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let seeds = &[b"pool".as_ref(), pool.lp_mint.as_ref(), &[pool.bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.user_lp.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            lp_out,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.lp_supply = pool
+            .lp_supply
+            .checked_add(lp_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", lp_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = lp_mint,
+        has_one = reserve_vault,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// The real supply lives here, but this instruction never reads it
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub reserve_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = lp_mint, token::authority = user)]
+    pub user_lp: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user_reserve: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", lp_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = lp_mint,
+        has_one = reserve_vault,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub reserve_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = lp_mint)]
+    pub user_lp: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user_reserve: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub lp_mint: Pubkey,
+    pub reserve_vault: Pubkey,
+    /// ❌ Shadow copy of the mint supply - drifts on external burns
+    pub lp_supply: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
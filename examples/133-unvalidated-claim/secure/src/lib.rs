@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur13313313313313313313313313313313313313");
+
+#[program]
+pub mod secure_claim {
+    use super::*;
+
+    /// SECURE: Loss Certified By An Oracle Attestation
+    ///
+    /// The payout is no longer an instruction argument. It is read from a
+    /// `LossAttestation` written by the oracle program that observed the
+    /// liquidation, and signed off by the oracle's attester key.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Attestation must be owned by `insurance_fund.oracle_program`
+    /// 2. Attestation must name `insurance_fund.oracle_attester` as its signer
+    /// 3. Attestation must reference the exact position this policy covers
+    /// 4. Payout is `min(certified_loss, policy.coverage_limit)`
+    /// 5. Claim PDA is seeded by the attestation, so it can be consumed once
+    ///
+    /// WHY THE OWNER CHECK MATTERS:
+    /// - Anyone can create an account whose bytes look like an attestation
+    /// - Only the oracle program can write to accounts it owns
+    pub fn file_claim(ctx: Context<FileClaim>) -> Result<()> {
+        let policy = &ctx.accounts.policy;
+
+        // ✅ Deserialize only after Anchor verified the owner
+        let attestation = LossAttestation::try_deserialize(
+            &mut &ctx.accounts.attestation.data.borrow()[..],
+        )?;
+
+        require!(
+            attestation.attester == ctx.accounts.insurance_fund.oracle_attester,
+            ErrorCode::UntrustedAttester
+        );
+        require!(
+            attestation.position == policy.covered_position,
+            ErrorCode::PositionMismatch
+        );
+        require!(attestation.liquidated, ErrorCode::NoLossEvent);
+
+        // ✅ Pay what was certified, never more than the policy covers
+        let payout = attestation.certified_loss.min(policy.coverage_limit);
+
+        let insurance_fund = &mut ctx.accounts.insurance_fund;
+        insurance_fund.balance = insurance_fund
+            .balance
+            .checked_sub(payout)
+            .ok_or(ErrorCode::InsufficientFund)?;
+
+        let claim = &mut ctx.accounts.claim;
+        claim.policy = policy.key();
+        claim.claimant = ctx.accounts.claimant.key();
+        claim.attestation = ctx.accounts.attestation.key();
+        claim.amount = payout;
+
+        msg!("Paid certified claim of {}", payout);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FileClaim<'info> {
+    #[account(mut)]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(has_one = holder @ ErrorCode::NotPolicyHolder)]
+    pub policy: Account<'info, Policy>,
+
+    /// ✅ Must be written by the configured oracle program
+    /// CHECK: Owner constrained below, contents deserialized and validated in handler
+    #[account(owner = insurance_fund.oracle_program @ ErrorCode::UntrustedAttester)]
+    pub attestation: AccountInfo<'info>,
+
+    /// ✅ One claim per attestation
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + Claim::LEN,
+        seeds = [b"claim", attestation.key().as_ref()],
+        bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    /// CHECK: Only compared against policy.holder
+    pub holder: AccountInfo<'info>,
+
+    #[account(mut, address = holder.key() @ ErrorCode::NotPolicyHolder)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct InsuranceFund {
+    pub authority: Pubkey,
+    pub balance: u64,
+    /// Program allowed to write loss attestations
+    pub oracle_program: Pubkey,
+    /// Key the oracle program records as the certifying signer
+    pub oracle_attester: Pubkey,
+}
+
+#[account]
+pub struct Policy {
+    pub holder: Pubkey,
+    pub covered_position: Pubkey,
+    pub coverage_limit: u64,
+}
+
+/// Layout written by the oracle program when it observes a liquidation
+#[account]
+pub struct LossAttestation {
+    pub attester: Pubkey,
+    pub position: Pubkey,
+    pub liquidated: bool,
+    pub certified_loss: u64,
+    pub observed_slot: u64,
+}
+
+#[account]
+pub struct Claim {
+    pub policy: Pubkey,
+    pub claimant: Pubkey,
+    pub attestation: Pubkey,
+    pub amount: u64,
+}
+
+impl Claim {
+    pub const LEN: usize = 32 + // policy
+                           32 + // claimant
+                           32 + // attestation
+                           8;   // amount
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insurance fund cannot cover this claim")]
+    InsufficientFund,
+
+    #[msg("Signer is not the policy holder")]
+    NotPolicyHolder,
+
+    #[msg("Attestation was not produced by the trusted oracle")]
+    UntrustedAttester,
+
+    #[msg("Attestation does not cover this policy's position")]
+    PositionMismatch,
+
+    #[msg("Attestation does not record a liquidation")]
+    NoLossEvent,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * A payout amount is a claim about the world. Require evidence from
+ * something that observed the world - an oracle, a position account,
+ * a liquidation record - not from the person who gets paid.
+ */
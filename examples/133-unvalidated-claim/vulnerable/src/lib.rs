@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln13313313313313313313313313313313313313");
+
+#[program]
+pub mod vulnerable_claim {
+    use super::*;
+
+    /// VULNERABILITY: Self-Reported Loss Amount
+    ///
+    /// The claimant tells the protocol how much they lost, and the protocol
+    /// believes them. The only bound on the payout is what the fund can afford.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory buys a policy covering a small lending position
+    /// 2. Mallory calls `file_claim` with `loss_amount = u64::MAX`
+    /// 3. The check `claim.amount <= insurance_fund.balance` caps the payout
+    ///    at the fund balance, but nothing caps it at the real loss
+    /// 4. Mallory resubmits with `loss_amount = insurance_fund.balance`
+    /// 5. The entire fund is paid out for a loss that never happened
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Instruction arguments are attacker-controlled input, not evidence
+    /// - "Can we afford it?" is not the same question as "Is it owed?"
+    pub fn file_claim(ctx: Context<FileClaim>, loss_amount: u64) -> Result<()> {
+        let insurance_fund = &mut ctx.accounts.insurance_fund;
+        let claim = &mut ctx.accounts.claim;
+
+        claim.policy = ctx.accounts.policy.key();
+        claim.claimant = ctx.accounts.claimant.key();
+        claim.amount = loss_amount;
+
+        // ❌ Only checks solvency - the loss itself is never verified
+        require!(
+            claim.amount <= insurance_fund.balance,
+            ErrorCode::InsufficientFund
+        );
+
+        insurance_fund.balance = insurance_fund
+            .balance
+            .checked_sub(claim.amount)
+            .ok_or(ErrorCode::InsufficientFund)?;
+
+        msg!("Paid claim of {}", claim.amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FileClaim<'info> {
+    #[account(mut)]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(has_one = holder @ ErrorCode::NotPolicyHolder)]
+    pub policy: Account<'info, Policy>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + Claim::LEN,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    /// CHECK: Only compared against policy.holder
+    pub holder: AccountInfo<'info>,
+
+    #[account(mut, address = holder.key() @ ErrorCode::NotPolicyHolder)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct InsuranceFund {
+    pub authority: Pubkey,
+    pub balance: u64,
+}
+
+#[account]
+pub struct Policy {
+    pub holder: Pubkey,
+    pub covered_position: Pubkey,
+    pub coverage_limit: u64,
+}
+
+#[account]
+pub struct Claim {
+    pub policy: Pubkey,
+    pub claimant: Pubkey,
+    /// ❌ Whatever the claimant said it was
+    pub amount: u64,
+}
+
+impl Claim {
+    pub const LEN: usize = 32 + // policy
+                           32 + // claimant
+                           8;   // amount
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insurance fund cannot cover this claim")]
+    InsufficientFund,
+
+    #[msg("Signer is not the policy holder")]
+    NotPolicyHolder,
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur13413413413413413413413413413413413413");
+
+#[program]
+pub mod secure_order_book {
+    use super::*;
+
+    /// Cancel an open order
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        let order = &mut ctx.accounts.order;
+
+        // Only open orders can be cancelled
+        require!(order.status == OrderStatus::Open, ErrorCode::OrderNotOpen);
+
+        order.status = OrderStatus::Cancelled;
+        msg!("Order {} cancelled", order.key());
+        Ok(())
+    }
+
+    /// SECURE: Fill Requires An Open Order
+    ///
+    /// The status written by `cancel_order` is enforced on the fill path, so
+    /// a cancellation takes effect the moment it lands.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `require!(order.status == OrderStatus::Open)` before any state change
+    /// 2. Checks for `Open` rather than `!= Cancelled`, so `Filled` orders and
+    ///    any status added later are rejected by default
+    ///
+    /// ORDERING GUARANTEE:
+    /// - Both instructions write the same order account
+    /// - The runtime serializes them; whichever lands first wins
+    /// - If the cancel lands first, the fill fails
+    pub fn fill_order(ctx: Context<FillOrder>, fill_amount: u64) -> Result<()> {
+        let order = &mut ctx.accounts.order;
+
+        // ✅ Cancelled (and already filled) orders cannot be filled
+        require!(order.status == OrderStatus::Open, ErrorCode::OrderNotOpen);
+
+        let remaining = order
+            .amount
+            .checked_sub(order.filled)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(fill_amount <= remaining, ErrorCode::FillExceedsOrder);
+
+        order.filled = order
+            .filled
+            .checked_add(fill_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        if order.filled == order.amount {
+            order.status = OrderStatus::Filled;
+        }
+
+        msg!("Securely filled {} at price {}", fill_amount, order.price);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(mut, has_one = maker)]
+    pub order: Account<'info, Order>,
+    pub maker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FillOrder<'info> {
+    #[account(mut)]
+    pub order: Account<'info, Order>,
+    pub taker: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum OrderStatus {
+    Open,
+    Filled,
+    Cancelled,
+}
+
+#[account]
+pub struct Order {
+    pub maker: Pubkey,
+    pub price: u64,
+    pub amount: u64,
+    pub filled: u64,
+    pub status: OrderStatus,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Order is not open")]
+    OrderNotOpen,
+
+    #[msg("Fill amount exceeds remaining order size")]
+    FillExceedsOrder,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln13413413413413413413413413413413413413");
+
+#[program]
+pub mod vulnerable_order_book {
+    use super::*;
+
+    /// Cancel an open order
+    /// This function is correct - it records the cancellation
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        let order = &mut ctx.accounts.order;
+        order.status = OrderStatus::Cancelled;
+        msg!("Order {} cancelled", order.key());
+        Ok(())
+    }
+
+    /// VULNERABILITY: Fill Ignores Order Status
+    ///
+    /// `cancel_order` writes `OrderStatus::Cancelled`, but `fill_order` never
+    /// reads the field. A cancellation is recorded and then disregarded.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice places a sell order at a price that was fair an hour ago
+    /// 2. The market moves; Alice cancels to avoid selling below value
+    /// 3. Mallory watches the order account and sees the cancellation land
+    /// 4. Mallory calls `fill_order` against the cancelled order
+    /// 5. The fill succeeds at Alice's stale price
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Makers cancel precisely when the price has become unfavourable to them
+    /// - A status flag is only a guarantee if every consumer enforces it
+    pub fn fill_order(ctx: Context<FillOrder>, fill_amount: u64) -> Result<()> {
+        let order = &mut ctx.accounts.order;
+
+        // ❌ No check on order.status - Cancelled and Filled orders still fill
+        let remaining = order
+            .amount
+            .checked_sub(order.filled)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(fill_amount <= remaining, ErrorCode::FillExceedsOrder);
+
+        order.filled = order
+            .filled
+            .checked_add(fill_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        if order.filled == order.amount {
+            order.status = OrderStatus::Filled;
+        }
+
+        msg!("Filled {} at price {}", fill_amount, order.price);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(mut, has_one = maker)]
+    pub order: Account<'info, Order>,
+    pub maker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FillOrder<'info> {
+    #[account(mut)]
+    pub order: Account<'info, Order>,
+    pub taker: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum OrderStatus {
+    Open,
+    Filled,
+    Cancelled,
+}
+
+#[account]
+pub struct Order {
+    pub maker: Pubkey,
+    pub price: u64,
+    pub amount: u64,
+    pub filled: u64,
+    /// ❌ Written by cancel_order, never read by fill_order
+    pub status: OrderStatus,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fill amount exceeds remaining order size")]
+    FillExceedsOrder,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
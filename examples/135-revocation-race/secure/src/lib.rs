@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur13513513513513513513513513513513513513");
+
+/// Slots that must pass between initiating and executing a revocation
+pub const REVOCATION_DELAY_SLOTS: u64 = 1;
+
+#[program]
+pub mod secure_vesting {
+    use super::*;
+
+    /// Claim whatever has vested so far - blocked once revocation starts
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        let vesting = &mut ctx.accounts.vesting;
+
+        // ✅ Any pending or completed revocation freezes claims
+        require!(vesting.state == VestingState::Active, ErrorCode::NotActive);
+
+        let now = Clock::get()?.unix_timestamp;
+        let claimable = vesting
+            .vested_amount(now)?
+            .checked_sub(vesting.claimed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        vesting.claimed = vesting
+            .claimed
+            .checked_add(claimable)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Beneficiary claimed {}", claimable);
+        Ok(())
+    }
+
+    /// SECURE: Two-Phase Revocation
+    ///
+    /// Revocation is split into `initiate_revocation` and `execute_revocation`.
+    /// The first call snapshots what has vested and moves the schedule into
+    /// `PendingRevocation`, which `claim` refuses to touch.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `claim` requires `VestingState::Active`
+    /// 2. Vested amount is frozen at initiation, so the beneficiary keeps
+    ///    what they had earned and nothing more
+    /// 3. `execute_revocation` only runs `REVOCATION_DELAY_SLOTS` later,
+    ///    after the pending state is confirmed on-chain
+    ///
+    /// WHY THIS CLOSES THE RACE:
+    /// - A claim that lands before initiation only takes already-vested tokens
+    /// - A claim that lands after initiation fails
+    /// - Nothing the beneficiary sends can change the snapshot
+    pub fn initiate_revocation(ctx: Context<Revoke>) -> Result<()> {
+        let vesting = &mut ctx.accounts.vesting;
+        require!(vesting.state == VestingState::Active, ErrorCode::NotActive);
+
+        let clock = Clock::get()?;
+        let vested = vesting.vested_amount(clock.unix_timestamp)?;
+
+        vesting.state = VestingState::PendingRevocation {
+            initiated_slot: clock.slot,
+            vested_at_initiation: vested,
+        };
+
+        msg!("Revocation initiated at slot {}", clock.slot);
+        Ok(())
+    }
+
+    /// Finalize a revocation after the delay has passed
+    pub fn execute_revocation(ctx: Context<Revoke>) -> Result<()> {
+        let vesting = &mut ctx.accounts.vesting;
+
+        let (initiated_slot, vested_at_initiation) = match vesting.state {
+            VestingState::PendingRevocation {
+                initiated_slot,
+                vested_at_initiation,
+            } => (initiated_slot, vested_at_initiation),
+            _ => return err!(ErrorCode::NoPendingRevocation),
+        };
+
+        let current_slot = Clock::get()?.slot;
+        require!(
+            current_slot >= initiated_slot + REVOCATION_DELAY_SLOTS,
+            ErrorCode::RevocationDelayNotElapsed
+        );
+
+        // ✅ Beneficiary keeps what vested before initiation
+        let owed_to_beneficiary = vested_at_initiation
+            .checked_sub(vesting.claimed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let returned_to_admin = vesting
+            .total
+            .checked_sub(vested_at_initiation)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        vesting.claimed = vested_at_initiation;
+        vesting.state = VestingState::Revoked;
+
+        msg!(
+            "Revoked: {} to beneficiary, {} returned to admin",
+            owed_to_beneficiary,
+            returned_to_admin
+        );
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut, has_one = beneficiary)]
+    pub vesting: Account<'info, Vesting>,
+    pub beneficiary: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    #[account(mut, has_one = admin)]
+    pub vesting: Account<'info, Vesting>,
+    pub admin: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum VestingState {
+    Active,
+    /// ✅ Claims are blocked while in this state
+    PendingRevocation {
+        initiated_slot: u64,
+        vested_at_initiation: u64,
+    },
+    Revoked,
+}
+
+#[account]
+pub struct Vesting {
+    pub admin: Pubkey,
+    pub beneficiary: Pubkey,
+    pub total: u64,
+    pub claimed: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub state: VestingState,
+}
+
+impl Vesting {
+    /// Linear vesting between start_ts and end_ts
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        if now <= self.start_ts {
+            return Ok(0);
+        }
+        if now >= self.end_ts {
+            return Ok(self.total);
+        }
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        Ok((self.total as u128 * elapsed / duration) as u64)
+    }
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Vesting schedule is not active")]
+    NotActive,
+
+    #[msg("No revocation is pending")]
+    NoPendingRevocation,
+
+    #[msg("Revocation delay has not elapsed")]
+    RevocationDelayNotElapsed,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
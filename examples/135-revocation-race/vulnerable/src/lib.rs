@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln13513513513513513513513513513513513513");
+
+#[program]
+pub mod vulnerable_vesting {
+    use super::*;
+
+    /// Claim whatever has vested so far
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        let vesting = &mut ctx.accounts.vesting;
+        require!(!vesting.revoked, ErrorCode::Revoked);
+
+        let now = Clock::get()?.unix_timestamp;
+        let claimable = vesting
+            .vested_amount(now)?
+            .checked_sub(vesting.claimed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        vesting.claimed = vesting
+            .claimed
+            .checked_add(claimable)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Beneficiary claimed {}", claimable);
+        Ok(())
+    }
+
+    /// VULNERABILITY: Single-Step Revocation Races The Beneficiary
+    ///
+    /// `revoke` and `claim` are independent instructions that both touch the
+    /// vesting account. Whichever the leader schedules first wins, and the
+    /// beneficiary can see the admin's transaction before it lands.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Admin decides to revoke Bob's grant and submits `revoke`
+    /// 2. Bob, watching for transactions that touch his vesting account,
+    ///    submits `claim` with a higher priority fee
+    /// 3. Bob's claim executes first in the same slot
+    /// 4. `revoke` executes second and finds nothing left to recover
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Revocation is an adversarial action; the target has every incentive
+    ///   to race it
+    /// - A one-transaction revoke gives no window in which the outcome is fixed
+    pub fn revoke(ctx: Context<Revoke>) -> Result<()> {
+        let vesting = &mut ctx.accounts.vesting;
+
+        // ❌ Takes effect only when it lands - claim can land first
+        let unclaimed = vesting
+            .total
+            .checked_sub(vesting.claimed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        vesting.revoked = true;
+        msg!("Revoked; {} returned to admin", unclaimed);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut, has_one = beneficiary)]
+    pub vesting: Account<'info, Vesting>,
+    pub beneficiary: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    #[account(mut, has_one = admin)]
+    pub vesting: Account<'info, Vesting>,
+    pub admin: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vesting {
+    pub admin: Pubkey,
+    pub beneficiary: Pubkey,
+    pub total: u64,
+    pub claimed: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub revoked: bool,
+}
+
+impl Vesting {
+    /// Linear vesting between start_ts and end_ts
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        if now <= self.start_ts {
+            return Ok(0);
+        }
+        if now >= self.end_ts {
+            return Ok(self.total);
+        }
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        Ok((self.total as u128 * elapsed / duration) as u64)
+    }
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Vesting schedule has been revoked")]
+    Revoked,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
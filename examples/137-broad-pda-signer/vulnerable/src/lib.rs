@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln13713713713713713713713713713713713713");
+
+#[program]
+pub mod vulnerable_pda_signer {
+    use super::*;
+
+    /// VULNERABILITY: One PDA Signs For Every Pool
+    ///
+    /// Every pool's vault is owned by the same PDA, derived from `[b"signer"]`.
+    /// When this program signs with that PDA it can move tokens out of ANY
+    /// vault, so a single slip in account validation exposes all pools at once.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Pool A holds 10 tokens; pool B holds 1,000,000 tokens
+    /// 2. Mallory deposits into pool A and gets a position worth 10
+    /// 3. Mallory calls `withdraw` with pool A and her position, but passes
+    ///    pool B's vault as `vault`
+    /// 4. The PDA signature is valid for pool B's vault too
+    /// 5. Tokens leave pool B, debited against Mallory's pool A position
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A PDA signature means "this program authorizes it" - nothing narrower
+    /// - Seeds decide what a PDA can authorize; `[b"signer"]` authorizes everything
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.amount = position
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+
+        // ❌ Global signer - valid for every vault this program has ever created
+        let seeds: &[&[u8]] = &[b"signer", &[ctx.bumps.pool_signer]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.pool_signer.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+
+        msg!("Withdrew {} from pool {}", amount, ctx.accounts.pool.key());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, Position>,
+
+    /// ❌ Any vault owned by the global signer is accepted
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA used only as a signing authority
+    #[account(seeds = [b"signer"], bump)]
+    pub pool_signer: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[account]
+pub struct Position {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient funds in position")]
+    InsufficientFunds,
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+declare_id!("Vuln13813813813813813813813813813813813813");
+
+/// Oracle program that owns the price feed
+pub const ORACLE_PROGRAM_ID: Pubkey = pubkey!("orac1e1111111111111111111111111111111111111");
+
+/// Instruction data for the oracle's `refresh_price` entrypoint
+pub const REFRESH_PRICE_IX: [u8; 8] = [0x52, 0x45, 0x46, 0x52, 0x45, 0x53, 0x48, 0x00];
+
+#[program]
+pub mod vulnerable_health_check {
+    use super::*;
+
+    /// VULNERABILITY: Health Check Runs Before The Oracle Refresh
+    ///
+    /// The borrow is approved using whatever price was sitting in the feed,
+    /// and only afterwards does the instruction refresh it. The check and the
+    /// state it protects are evaluated against two different prices.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Collateral price on the feed is 100, last refreshed several slots ago
+    /// 2. The true market price has fallen to 60
+    /// 3. Mallory borrows the maximum allowed at a price of 100
+    /// 4. The refresh CPI then writes 60 to the feed
+    /// 5. Mallory's position is insolvent the moment the instruction ends
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A check is only meaningful against the state that will be committed
+    /// - Anything that can change its inputs must run before it, not after
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        let new_debt = position
+            .debt
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // ❌ Reads the stale price
+        let price = read_price(&ctx.accounts.price_feed)?;
+        require!(
+            is_healthy(position.collateral, price, new_debt)?,
+            ErrorCode::Unhealthy
+        );
+
+        // ❌ Refresh happens after the decision has been made
+        refresh_oracle(&ctx.accounts.oracle_program, &ctx.accounts.price_feed)?;
+
+        position.debt = new_debt;
+        msg!("Borrowed {} at price {}", amount, price);
+        Ok(())
+    }
+}
+
+/// Invoke the oracle program to pull the latest price into the feed
+pub fn refresh_oracle<'info>(
+    oracle_program: &AccountInfo<'info>,
+    price_feed: &AccountInfo<'info>,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: oracle_program.key(),
+        accounts: vec![AccountMeta::new(price_feed.key(), false)],
+        data: REFRESH_PRICE_IX.to_vec(),
+    };
+    invoke(&ix, &[price_feed.clone(), oracle_program.clone()])?;
+    Ok(())
+}
+
+/// Price is stored after the 8-byte discriminator
+pub fn read_price(price_feed: &AccountInfo) -> Result<u64> {
+    let data = price_feed.try_borrow_data()?;
+    let feed = PriceFeed::deserialize(&mut &data[8..])?;
+    Ok(feed.price)
+}
+
+/// Collateral value must be at least 150% of debt
+pub fn is_healthy(collateral: u64, price: u64, debt: u64) -> Result<bool> {
+    let value = (collateral as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let required = (debt as u128)
+        .checked_mul(150)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(value * 100 >= required)
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(mut, has_one = owner)]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: Owner verified; contents read via read_price
+    #[account(mut, owner = ORACLE_PROGRAM_ID)]
+    pub price_feed: AccountInfo<'info>,
+
+    /// CHECK: Pinned to the oracle program ID
+    #[account(address = ORACLE_PROGRAM_ID)]
+    pub oracle_program: AccountInfo<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub collateral: u64,
+    pub debt: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceFeed {
+    pub price: u64,
+    pub last_update_slot: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Position would be undercollateralized")]
+    Unhealthy,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
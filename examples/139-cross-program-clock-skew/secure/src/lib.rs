@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+declare_id!("Secur13913913913913913913913913913913913913");
+
+/// External lending market the aggregator deposits into
+pub const MARKET_PROGRAM_ID: Pubkey = pubkey!("market1111111111111111111111111111111111111");
+
+/// Instruction discriminator for the market's `accrue_interest_at` entrypoint
+pub const ACCRUE_INTEREST_AT_IX: [u8; 8] = [0x41, 0x43, 0x43, 0x52, 0x55, 0x45, 0x41, 0x54];
+
+#[program]
+pub mod secure_yield_aggregator {
+    use super::*;
+
+    /// SECURE: One Clock Reading, Shared And Verified
+    ///
+    /// The aggregator reads the `Clock` sysvar once, passes the same account
+    /// into the market CPI along with an explicit `reference_slot`, and credits
+    /// only the yield the market actually recorded.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `clock: Sysvar<'info, Clock>` is the single time source for both
+    ///    programs in this instruction
+    /// 2. The market rejects `accrue_interest_at` if `reference_slot` differs
+    ///    from its own clock reading
+    /// 3. After the CPI, `market_position.last_accrual_slot` must equal
+    ///    `reference_slot`
+    /// 4. Credited yield is the change in the market position since the last
+    ///    harvest, not an estimate from the aggregator's own bookkeeping
+    ///
+    /// WHY THIS IS ROBUST TO THIRD-PARTY ACCRUALS:
+    /// - Interest from a third-party accrual lands in the market position
+    ///   whenever it happens
+    /// - `strategy.last_position_amount` remembers what was already credited,
+    ///   so everything accrued between two harvests is counted exactly once
+    pub fn harvest(ctx: Context<Harvest>) -> Result<()> {
+        let reference_slot = ctx.accounts.clock.slot;
+
+        // ✅ Explicit slot plus the clock account itself
+        let mut data = ACCRUE_INTEREST_AT_IX.to_vec();
+        data.extend_from_slice(&reference_slot.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: ctx.accounts.market_program.key(),
+            accounts: vec![
+                AccountMeta::new(ctx.accounts.market.key(), false),
+                AccountMeta::new(ctx.accounts.market_position.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.clock.key(), false),
+            ],
+            data,
+        };
+        invoke(
+            &ix,
+            &[
+                ctx.accounts.market.to_account_info(),
+                ctx.accounts.market_position.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.market_program.to_account_info(),
+            ],
+        )?;
+
+        let after = read_market_position(&ctx.accounts.market_position)?;
+
+        // ✅ Both programs agree on when accrual happened
+        require!(
+            after.last_accrual_slot == reference_slot,
+            ErrorCode::SlotMismatch
+        );
+
+        // ✅ Credit what the market paid since the last harvest, including
+        //    accruals triggered by anyone else in between
+        let strategy = &mut ctx.accounts.strategy;
+        let realized_yield = after
+            .amount
+            .checked_sub(strategy.last_position_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        strategy.total_assets = strategy
+            .total_assets
+            .checked_add(realized_yield)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        strategy.last_position_amount = after.amount;
+        strategy.last_harvest_slot = reference_slot;
+
+        msg!("Harvested {} at slot {}", realized_yield, reference_slot);
+        Ok(())
+    }
+}
+
+/// Position layout owned by the market program (after the 8-byte discriminator)
+pub fn read_market_position(account: &AccountInfo) -> Result<MarketPosition> {
+    let data = account.try_borrow_data()?;
+    Ok(MarketPosition::deserialize(&mut &data[8..])?)
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Harvest<'info> {
+    #[account(mut, has_one = market, has_one = market_position)]
+    pub strategy: Account<'info, Strategy>,
+
+    /// CHECK: Owned by the market program, mutated only via CPI
+    #[account(mut, owner = MARKET_PROGRAM_ID)]
+    pub market: AccountInfo<'info>,
+
+    /// CHECK: Owned by the market program; read after the CPI
+    #[account(mut, owner = MARKET_PROGRAM_ID)]
+    pub market_position: AccountInfo<'info>,
+
+    /// CHECK: Pinned to the market program ID
+    #[account(address = MARKET_PROGRAM_ID)]
+    pub market_program: AccountInfo<'info>,
+
+    /// ✅ Single clock reading shared with the market
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Strategy {
+    pub market: Pubkey,
+    pub market_position: Pubkey,
+    pub total_assets: u64,
+    /// Market position amount already credited to `total_assets`
+    pub last_position_amount: u64,
+    pub last_harvest_slot: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketPosition {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub last_accrual_slot: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Market accrued at a different slot than the aggregator")]
+    SlotMismatch,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
+
+// ============================================================================
+// MARKET SIDE OF THE CONTRACT
+// ============================================================================
+
+/*
+ * The market program must accept the reference slot and refuse to
+ * accrue against any other reading:
+ *
+ * pub fn accrue_interest_at(ctx: Context<Accrue>, reference_slot: u64) -> Result<()> {
+ *     let clock = Clock::from_account_info(&ctx.accounts.clock)?;
+ *     require!(reference_slot == clock.slot, MarketError::SlotMismatch);
+ *
+ *     let position = &mut ctx.accounts.position;
+ *     let elapsed = reference_slot - position.last_accrual_slot;
+ *     position.amount += interest_for(position.amount, elapsed);
+ *     position.last_accrual_slot = reference_slot;
+ *     Ok(())
+ * }
+ */
@@ -0,0 +1,55 @@
+#[tokio::test]
+async fn test_double_counted_yield_exploit() {
+    println!("\n=== EXPLOIT: Cross-Program Clock Skew ===\n");
+
+    let mallory = Keypair::new();
+    let strategy = create_strategy(1_000_000).await;
+
+    warp_to_slot(1_900).await;
+    println!("1. Mallory calls market.accrue_interest directly at slot 1900");
+    accrue_market_directly(&strategy).await.unwrap();
+
+    warp_to_slot(2_000).await;
+    println!("\n2. Mallory calls aggregator.harvest at slot 2000");
+    harvest(&strategy, &mallory).await.unwrap();
+
+    let credited = get_total_assets(&strategy).await - 1_000_000;
+    let realized = get_market_position_amount(&strategy).await - 1_000_000;
+    println!("   Aggregator credited: {}", credited);
+    println!("   Market actually paid: {}", realized);
+
+    // Vulnerable: 1000 slots credited, only 100 slots realized
+    assert!(credited > realized);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Share price inflated by phantom yield");
+}
+
+#[tokio::test]
+async fn test_shared_reference_slot() {
+    println!("\n=== SECURITY: Shared Reference Slot ===\n");
+
+    let mallory = Keypair::new();
+    let strategy = create_strategy(1_000_000).await;
+
+    warp_to_slot(1_900).await;
+    accrue_market_directly(&strategy).await.unwrap();
+
+    warp_to_slot(2_000).await;
+    println!("1. Harvest at slot 2000 after third-party accrual");
+    harvest(&strategy, &mallory).await.unwrap();
+
+    let credited = get_total_assets(&strategy).await - 1_000_000;
+    let realized = get_market_position_amount(&strategy).await - 1_000_000;
+
+    // Secure: credit measured from the market position
+    assert_eq!(credited, realized);
+
+    println!("\n   YIELD MATCHES MARKET!");
+    println!("   ✓ Market accrued at the aggregator's reference slot");
+
+    println!("\n2. Market built against a different slot");
+    let result = harvest_with_skewed_market(&strategy, &mallory, 1_999).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Market accrued at a different slot");
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+declare_id!("Vuln13913913913913913913913913913913913913");
+
+/// External lending market the aggregator deposits into
+pub const MARKET_PROGRAM_ID: Pubkey = pubkey!("market1111111111111111111111111111111111111");
+
+/// Instruction data for the market's `accrue_interest` entrypoint
+pub const ACCRUE_INTEREST_IX: [u8; 8] = [0x41, 0x43, 0x43, 0x52, 0x55, 0x45, 0x00, 0x00];
+
+#[program]
+pub mod vulnerable_yield_aggregator {
+    use super::*;
+
+    /// VULNERABILITY: Aggregator And Market Disagree On Elapsed Time
+    ///
+    /// The aggregator decides how much yield to credit from its own record of
+    /// when it last harvested. The market accrues interest from its own record
+    /// of when it last accrued. Nothing ties the two readings together, so the
+    /// aggregator credits yield for slots the market has already paid out.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Aggregator last harvested at slot 1,000; market last accrued at 1,000
+    /// 2. At slot 1,900 Mallory calls the market's public `accrue_interest`
+    ///    directly - the market now considers slots 1,000-1,900 settled
+    /// 3. At slot 2,000 Mallory calls `harvest` on the aggregator
+    /// 4. The aggregator credits 1,000 slots of yield; the market pays 100
+    /// 5. Share price is inflated by 900 slots of yield that does not exist,
+    ///    and Mallory withdraws at the inflated price
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Each program's "last updated" marker is its own private clock
+    /// - Two programs computing elapsed time independently will drift
+    ///   whenever a third party touches either one
+    pub fn harvest(ctx: Context<Harvest>) -> Result<()> {
+        let clock = Clock::get()?;
+        let strategy = &mut ctx.accounts.strategy;
+
+        // ❌ Elapsed time measured against the aggregator's own marker
+        let elapsed = clock
+            .slot
+            .checked_sub(strategy.last_harvest_slot)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let expected_yield = strategy
+            .deposited
+            .checked_mul(strategy.rate_per_slot_bps)
+            .and_then(|v| v.checked_mul(elapsed))
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Market computes its own elapsed time internally
+        let ix = Instruction {
+            program_id: ctx.accounts.market_program.key(),
+            accounts: vec![AccountMeta::new(ctx.accounts.market.key(), false)],
+            data: ACCRUE_INTEREST_IX.to_vec(),
+        };
+        invoke(
+            &ix,
+            &[
+                ctx.accounts.market.to_account_info(),
+                ctx.accounts.market_program.to_account_info(),
+            ],
+        )?;
+
+        // ❌ Credits the estimate, not what the market actually paid
+        strategy.total_assets = strategy
+            .total_assets
+            .checked_add(expected_yield)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        strategy.last_harvest_slot = clock.slot;
+
+        msg!("Harvested {} over {} slots", expected_yield, elapsed);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Harvest<'info> {
+    #[account(mut, has_one = market)]
+    pub strategy: Account<'info, Strategy>,
+
+    /// CHECK: Owned by the market program, mutated only via CPI
+    #[account(mut, owner = MARKET_PROGRAM_ID)]
+    pub market: AccountInfo<'info>,
+
+    /// CHECK: Pinned to the market program ID
+    #[account(address = MARKET_PROGRAM_ID)]
+    pub market_program: AccountInfo<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Strategy {
+    pub market: Pubkey,
+    pub deposited: u64,
+    pub total_assets: u64,
+    pub rate_per_slot_bps: u64,
+    /// ❌ Aggregator's private notion of "last accrual"
+    pub last_harvest_slot: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur14o14o14o14o14o14o14o14o14o14o14o14o14");
+
+#[program]
+pub mod secure_funding {
+    use super::*;
+
+    /// Recompute the funding rate from open interest
+    /// Negative means shorts pay longs - this is expected
+    pub fn update_funding_rate(ctx: Context<UpdateFunding>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        market.funding_rate = (market.long_oi as i64)
+            .checked_sub(market.short_oi as i64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// SECURE: Signed Arithmetic End To End
+    ///
+    /// The funding rate stays `i64` through the whole calculation. The sign
+    /// decides direction; the magnitude decides size.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Position size is converted with `i64::try_from`, not `as`
+    /// 2. `i64::checked_mul` / `checked_div` for the payment
+    /// 3. Longs pay `+payment`, shorts pay `-payment` - one signed formula
+    /// 4. Collateral is updated with `checked_add_signed`, which fails on
+    ///    underflow instead of wrapping
+    pub fn settle_funding(ctx: Context<SettleFunding>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let position = &mut ctx.accounts.position;
+
+        let size = i64::try_from(position.size).map_err(|_| ErrorCode::ArithmeticOverflow)?;
+
+        // ✅ Signed throughout: positive = this position pays
+        let payment = size
+            .checked_mul(market.funding_rate)
+            .and_then(|v| v.checked_div(FUNDING_PRECISION))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let owed = if position.is_long {
+            payment
+        } else {
+            payment.checked_neg().ok_or(ErrorCode::ArithmeticOverflow)?
+        };
+
+        // ✅ Debit or credit without ever reinterpreting the sign
+        let delta = owed.checked_neg().ok_or(ErrorCode::ArithmeticOverflow)?;
+        position.collateral = position
+            .collateral
+            .checked_add_signed(delta)
+            .ok_or(ErrorCode::InsufficientCollateral)?;
+
+        msg!("Settled signed funding payment of {}", owed);
+        Ok(())
+    }
+}
+
+pub const FUNDING_PRECISION: i64 = 1_000_000;
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateFunding<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+}
+
+#[derive(Accounts)]
+pub struct SettleFunding<'info> {
+    pub market: Account<'info, Market>,
+    #[account(mut, has_one = market)]
+    pub position: Account<'info, Position>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Market {
+    pub long_oi: u64,
+    pub short_oi: u64,
+    /// Positive: longs pay shorts. Negative: shorts pay longs.
+    pub funding_rate: i64,
+}
+
+#[account]
+pub struct Position {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub is_long: bool,
+    pub size: u64,
+    pub collateral: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+
+    #[msg("Funding payment exceeds position collateral")]
+    InsufficientCollateral,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * Treat every `as` between signed and unsigned types as a red flag in
+ * review. Use `try_from` to convert and keep signed quantities signed
+ * until the very last step, where `checked_add_signed` applies them.
+ */
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln14014014014014014014014014014014014014");
+
+#[program]
+pub mod vulnerable_funding {
+    use super::*;
+
+    /// Recompute the funding rate from open interest
+    /// Negative means shorts pay longs - this is expected
+    pub fn update_funding_rate(ctx: Context<UpdateFunding>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        market.funding_rate = (market.long_oi as i64)
+            .checked_sub(market.short_oi as i64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Signed Rate Cast To Unsigned
+    ///
+    /// The funding rate is correctly stored as `i64`, but settlement casts it
+    /// with `as u64`. A rate of -1 becomes 18,446,744,073,709,551,615.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory opens a large short, pushing `short_oi` above `long_oi`
+    /// 2. `update_funding_rate` stores a small negative rate
+    /// 3. A long position is settled: `rate as u64` is a huge positive number
+    /// 4. The wrapping multiplication charges the long an arbitrary amount
+    /// 5. Longs are liquidated; Mallory's shorts collect the proceeds
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - `as` never fails - it silently reinterprets the bits
+    /// - The sign is the whole point of a funding rate; dropping it inverts
+    ///   who pays whom, and by how much
+    pub fn settle_funding(ctx: Context<SettleFunding>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let position = &mut ctx.accounts.position;
+
+        // ❌ -1i64 as u64 == u64::MAX
+        let rate = market.funding_rate as u64;
+        let payment = position.size.wrapping_mul(rate) / FUNDING_PRECISION;
+
+        if position.is_long {
+            position.collateral = position.collateral.saturating_sub(payment);
+        } else {
+            position.collateral = position.collateral.saturating_add(payment);
+        }
+
+        msg!("Settled funding payment of {}", payment);
+        Ok(())
+    }
+}
+
+pub const FUNDING_PRECISION: u64 = 1_000_000;
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateFunding<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+}
+
+#[derive(Accounts)]
+pub struct SettleFunding<'info> {
+    pub market: Account<'info, Market>,
+    #[account(mut, has_one = market)]
+    pub position: Account<'info, Position>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Market {
+    pub long_oi: u64,
+    pub short_oi: u64,
+    /// Positive: longs pay shorts. Negative: shorts pay longs.
+    pub funding_rate: i64,
+}
+
+#[account]
+pub struct Position {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub is_long: bool,
+    pub size: u64,
+    pub collateral: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur14114114114114114114114114114114114114");
+
+/// Longest exemption the admin may grant in one approval (~30 days)
+pub const MAX_EXEMPTION_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+#[program]
+pub mod secure_liquidation {
+    use super::*;
+
+    /// SECURE: Admin-Approved, Expiring Exemption
+    ///
+    /// Exemptions require both the position owner and the protocol admin to
+    /// sign, and they carry an expiry timestamp stored in the position.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `admin: Signer` constrained to `config.admin` - the borrower cannot
+    ///    approve their own exemption
+    /// 2. Position must be healthy at the time of locking
+    /// 3. `exempt_until` is bounded by `MAX_EXEMPTION_SECONDS`
+    /// 4. `liquidate` ignores the exemption once it has expired
+    pub fn lock_position(ctx: Context<LockPosition>, duration: i64) -> Result<()> {
+        require!(
+            duration > 0 && duration <= MAX_EXEMPTION_SECONDS,
+            ErrorCode::InvalidDuration
+        );
+
+        let position = &mut ctx.accounts.position;
+
+        // ✅ Cannot be used to rescue a position that is already underwater
+        require!(
+            position.collateral_value >= position.debt_value,
+            ErrorCode::PositionUnhealthy
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        position.exempt_until = now
+            .checked_add(duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Position exempt until {}", position.exempt_until);
+        Ok(())
+    }
+
+    /// Liquidate an undercollateralized position
+    pub fn liquidate(ctx: Context<Liquidate>) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        let now = Clock::get()?.unix_timestamp;
+
+        // ✅ Exemption lapses on its own
+        require!(now >= position.exempt_until, ErrorCode::PositionExempt);
+        require!(
+            position.collateral_value < position.debt_value,
+            ErrorCode::PositionHealthy
+        );
+
+        position.debt_value = 0;
+        position.collateral_value = 0;
+        msg!("Position liquidated");
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct LockPosition<'info> {
+    #[account(has_one = admin @ ErrorCode::Unauthorized)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, has_one = owner)]
+    pub position: Account<'info, Position>,
+
+    pub owner: Signer<'info>,
+
+    /// ✅ Protocol admin must co-sign every exemption
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Liquidate<'info> {
+    #[account(mut)]
+    pub position: Account<'info, Position>,
+    pub liquidator: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub collateral_value: u64,
+    pub debt_value: u64,
+    /// ✅ Unix timestamp after which liquidation is allowed again (0 = none)
+    pub exempt_until: i64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Position is exempt from liquidation")]
+    PositionExempt,
+
+    #[msg("Position is healthy")]
+    PositionHealthy,
+
+    #[msg("Position is undercollateralized")]
+    PositionUnhealthy,
+
+    #[msg("Exemption duration out of range")]
+    InvalidDuration,
+
+    #[msg("Admin signature required")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln14114114114114114114114114114114114114");
+
+#[program]
+pub mod vulnerable_liquidation {
+    use super::*;
+
+    /// VULNERABILITY: Self-Granted, Permanent Liquidation Exemption
+    ///
+    /// `lock_position` was meant for long-term strategies approved by the
+    /// protocol. In practice any position owner can call it, at any time,
+    /// and the flag never expires.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory borrows close to her maximum
+    /// 2. Collateral price drops; the position is about to be liquidated
+    /// 3. Mallory calls `lock_position` on her own position
+    /// 4. The liquidator's call fails with `PositionExempt`
+    /// 5. Bad debt accumulates with no way for the protocol to recover it
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Liquidation protects lenders, not borrowers; the borrower should
+    ///   never control whether it can happen
+    /// - An irreversible flag turns a temporary mistake into a permanent one
+    pub fn lock_position(ctx: Context<LockPosition>) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+
+        // ❌ Owner alone can exempt themselves, with no expiry
+        position.liquidation_exempt = true;
+
+        msg!("Position {} locked from liquidation", position.key());
+        Ok(())
+    }
+
+    /// Liquidate an undercollateralized position
+    pub fn liquidate(ctx: Context<Liquidate>) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+
+        require!(!position.liquidation_exempt, ErrorCode::PositionExempt);
+        require!(
+            position.collateral_value < position.debt_value,
+            ErrorCode::PositionHealthy
+        );
+
+        position.debt_value = 0;
+        position.collateral_value = 0;
+        msg!("Position liquidated");
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct LockPosition<'info> {
+    #[account(mut, has_one = owner)]
+    pub position: Account<'info, Position>,
+
+    /// ❌ The only authorization required
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Liquidate<'info> {
+    #[account(mut)]
+    pub position: Account<'info, Position>,
+    pub liquidator: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub collateral_value: u64,
+    pub debt_value: u64,
+    /// ❌ Set by the owner, never cleared
+    pub liquidation_exempt: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Position is exempt from liquidation")]
+    PositionExempt,
+
+    #[msg("Position is healthy")]
+    PositionHealthy,
+}
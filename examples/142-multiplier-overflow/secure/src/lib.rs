@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur14214214214214214214214214214214214214");
+
+#[program]
+pub mod secure_boost {
+    use super::*;
+
+    /// SECURE: Widen Before Multiplying
+    ///
+    /// The product is computed in `u128`, which can hold any `u64 * u64`
+    /// without overflow. Only the final, divided result is narrowed back to
+    /// `u64`, and that narrowing is checked.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `as u128` on both operands before multiplication
+    /// 2. `checked_mul` / `checked_div` on the wide values
+    /// 3. `u64::try_from` maps an out-of-range result to `RewardOverflow`
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        let stake = &mut ctx.accounts.stake;
+
+        // ✅ u128 intermediate, checked narrowing
+        let rewards = boosted_rewards(stake.base_rewards, stake.boost_multiplier)?;
+
+        stake.base_rewards = 0;
+        stake.claimed = stake
+            .claimed
+            .checked_add(rewards)
+            .ok_or(ErrorCode::RewardOverflow)?;
+
+        msg!("Claimed {} boosted rewards", rewards);
+        Ok(())
+    }
+}
+
+/// `base_rewards * boost_multiplier / 100`, safe for every u64 input
+pub fn boosted_rewards(base_rewards: u64, boost_multiplier: u64) -> Result<u64> {
+    let wide = (base_rewards as u128)
+        .checked_mul(boost_multiplier as u128)
+        .and_then(|v| v.checked_div(100))
+        .ok_or(ErrorCode::RewardOverflow)?;
+
+    u64::try_from(wide).map_err(|_| ErrorCode::RewardOverflow.into())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut, has_one = owner)]
+    pub stake: Account<'info, Stake>,
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Stake {
+    pub owner: Pubkey,
+    pub base_rewards: u64,
+    /// Percentage: 100 = 1x, 200 = 2x
+    pub boost_multiplier: u64,
+    pub claimed: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Boosted reward does not fit in u64")]
+    RewardOverflow,
+}
@@ -0,0 +1,50 @@
+#[tokio::test]
+async fn test_boost_overflow_exploit() {
+    println!("\n=== EXPLOIT: Boost Multiplier Overflow ===\n");
+
+    let whale = Keypair::new();
+    let base = u64::MAX / 100;
+    let stake = create_stake(&whale, base, 200).await;
+
+    println!("1. base_rewards = {}", base);
+    println!("   boost_multiplier = 200 (2x)");
+    println!("   Expected payout: {}", (base as u128) * 2);
+
+    println!("\n2. Whale claims rewards");
+    let result = claim_rewards(&stake, &whale).await;
+
+    // Vulnerable: either wraps to a tiny value or panics
+    match result {
+        Ok(()) => {
+            let claimed = get_claimed(&stake).await;
+            println!("   Claimed: {}", claimed);
+            assert!((claimed as u128) < (base as u128) * 2);
+        }
+        Err(_) => println!("   Instruction panicked on overflow"),
+    }
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ base * multiplier overflowed u64 before / 100");
+}
+
+#[tokio::test]
+async fn test_u128_intermediate() {
+    println!("\n=== SECURITY: Widened Intermediate ===\n");
+
+    let whale = Keypair::new();
+    let base = u64::MAX / 100;
+    let stake = create_stake(&whale, base, 200).await;
+
+    println!("1. Claiming 2x boost on base = u64::MAX / 100");
+    claim_rewards(&stake, &whale).await.unwrap();
+
+    // Secure: product computed in u128, result fits in u64
+    assert_eq!(get_claimed(&stake).await, base * 2);
+    println!("   ✓ Exact payout, no overflow");
+
+    println!("\n2. Claiming 2x boost on base = u64::MAX");
+    let stake = create_stake(&whale, u64::MAX, 200).await;
+    let result = claim_rewards(&stake, &whale).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Boosted reward does not fit in u64");
+}
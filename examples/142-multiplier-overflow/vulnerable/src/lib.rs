@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln14214214214214214214214214214214214214");
+
+#[program]
+pub mod vulnerable_boost {
+    use super::*;
+
+    /// VULNERABILITY: Multiply-Then-Divide In u64
+    ///
+    /// Boosted rewards are `base_rewards * boost_multiplier / 100`. The
+    /// intermediate product is computed in `u64` before the division brings
+    /// it back down, so large balances overflow even though the final result
+    /// would have fit.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A whale accrues `base_rewards` just above `u64::MAX / 200`
+    /// 2. The whale equips an NFT with `boost_multiplier = 200` (2x)
+    /// 3. `base_rewards * 200` exceeds `u64::MAX`
+    /// 4. Without overflow checks the product wraps and the payout is a
+    ///    fraction of what was earned; with overflow checks the instruction
+    ///    panics on every call and the rewards can never be claimed
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The bug only appears for the largest, most valuable accounts
+    /// - Tests with realistic-looking small numbers never trigger it
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        let stake = &mut ctx.accounts.stake;
+
+        // ❌ Intermediate product overflows before the division
+        let rewards = stake.base_rewards * stake.boost_multiplier / 100;
+
+        stake.base_rewards = 0;
+        stake.claimed = stake.claimed.wrapping_add(rewards);
+
+        msg!("Claimed {} boosted rewards", rewards);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut, has_one = owner)]
+    pub stake: Account<'info, Stake>,
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Stake {
+    pub owner: Pubkey,
+    pub base_rewards: u64,
+    /// Percentage: 100 = 1x, 200 = 2x
+    pub boost_multiplier: u64,
+    pub claimed: u64,
+}
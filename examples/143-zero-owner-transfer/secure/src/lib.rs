@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur14314314314314314314314314314314314314");
+
+#[program]
+pub mod secure_ownership {
+    use super::*;
+
+    /// SECURE: Validated, Co-Signed Ownership Transfer
+    ///
+    /// The new owner is no longer an instruction argument. It is an account
+    /// that must sign the same transaction, which proves a private key for it
+    /// exists and is held by someone willing to accept ownership.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `require!(new_owner != Pubkey::default())` rejects the zero key
+    /// 2. `new_owner: Signer<'info>` - the recipient must co-sign
+    /// 3. Transferring to the current owner is rejected as a no-op mistake
+    ///
+    /// WHY THE CO-SIGNATURE MATTERS:
+    /// - The zero-key check only catches one bad address
+    /// - A signature catches all of them: typos, truncated keys, PDAs with
+    ///   no signing path, keys whose owner lost the seed phrase
+    pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
+        let new_owner = ctx.accounts.new_owner.key();
+
+        // ✅ Explicit guard against the unspendable default key
+        require!(new_owner != Pubkey::default(), ErrorCode::InvalidNewOwner);
+        require!(
+            new_owner != ctx.accounts.owner.key(),
+            ErrorCode::InvalidNewOwner
+        );
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.owner = new_owner;
+
+        msg!("Ownership transferred to {}", new_owner);
+        Ok(())
+    }
+
+    /// Owner-gated withdrawal
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct TransferOwnership<'info> {
+    #[account(mut, has_one = owner)]
+    pub treasury: Account<'info, Treasury>,
+
+    pub owner: Signer<'info>,
+
+    /// ✅ Recipient proves control by signing
+    pub new_owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = owner)]
+    pub treasury: Account<'info, Treasury>,
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Treasury {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient funds in treasury")]
+    InsufficientFunds,
+
+    #[msg("New owner is invalid")]
+    InvalidNewOwner,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln14314314314314314314314314314314314314");
+
+#[program]
+pub mod vulnerable_ownership {
+    use super::*;
+
+    /// VULNERABILITY: Ownership Can Be Sent To Pubkey::default()
+    ///
+    /// `transfer_ownership` accepts any `Pubkey` as the new owner. The all-zero
+    /// key (`11111111111111111111111111111111`) has no private key, so once
+    /// ownership lands there, no one can ever sign as owner again.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A client library serializes an unset field as `Pubkey::default()`
+    /// 2. The treasury owner submits `transfer_ownership` through that client
+    /// 3. The program writes the zero key as the new owner
+    /// 4. Every owner-gated instruction now requires a signature that cannot
+    ///    be produced - the treasury is frozen permanently
+    ///
+    /// The same outcome follows from a typo'd or truncated address: the
+    /// program never asks the new owner to prove they exist.
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Ownership transfer is irreversible by design
+    /// - A one-way operation with no validation turns mistakes into losses
+    pub fn transfer_ownership(ctx: Context<TransferOwnership>, new_owner: Pubkey) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+
+        // ❌ No check against Pubkey::default(), no proof new owner can sign
+        treasury.owner = new_owner;
+
+        msg!("Ownership transferred to {}", new_owner);
+        Ok(())
+    }
+
+    /// Owner-gated withdrawal - unreachable once owner is the zero key
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct TransferOwnership<'info> {
+    #[account(mut, has_one = owner)]
+    pub treasury: Account<'info, Treasury>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = owner)]
+    pub treasury: Account<'info, Treasury>,
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Treasury {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient funds in treasury")]
+    InsufficientFunds,
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur14414414414414414414414414414414414414");
+
+/// Flash loan fee in basis points
+pub const FLASH_FEE_BPS: u64 = 9;
+
+/// AMM program operated by the same protocol - never a valid callback target
+pub const PROTOCOL_AMM_PROGRAM_ID: Pubkey = pubkey!("amm1111111111111111111111111111111111111111");
+
+/// Audited integrator programs allowed to receive flash loan callbacks
+pub const CALLBACK_ALLOWLIST: [Pubkey; 2] = [
+    pubkey!("9LMzpLU8Ye6n2ApE4xYZN8BwKAkCTJ2Dn6yYS2ybzmeU"),
+    pubkey!("4w8XWvTKnR6BbNc8EcBGxbaDpiAGLhSq2VUPh6yzhkpK"),
+];
+
+#[program]
+pub mod secure_flash_loan {
+    use super::*;
+
+    /// SECURE: Allowlisted Callback Programs
+    ///
+    /// The callback target must be on `CALLBACK_ALLOWLIST`, and neither the
+    /// callback nor the accounts it receives may reach back into the
+    /// protocol's own programs.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `callback_program` must appear in `CALLBACK_ALLOWLIST`
+    /// 2. `callback_program` may not be this program or the protocol's AMM
+    /// 3. No remaining account may be the protocol's AMM program, so an
+    ///    allowlisted callback cannot be handed a path into it either
+    /// 4. Repayment is still enforced after the callback returns
+    ///
+    /// WHY AN ALLOWLIST:
+    /// - The lender cannot inspect what an arbitrary program will do
+    /// - Restricting callers to audited integrators bounds that behavior
+    pub fn flash_loan(ctx: Context<FlashLoan>, amount: u64, callback_data: Vec<u8>) -> Result<()> {
+        let vault_before = ctx.accounts.vault.amount;
+
+        let seeds: &[&[u8]] = &[b"lender", &[ctx.bumps.lender]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.borrower_token.to_account_info(),
+                    authority: ctx.accounts.lender.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+
+        // ✅ Only audited integrators, never the protocol's own programs
+        let callback = ctx.accounts.callback_program.key();
+        require!(
+            CALLBACK_ALLOWLIST.contains(&callback),
+            ErrorCode::CallbackNotAllowed
+        );
+        require!(
+            callback != crate::ID && callback != PROTOCOL_AMM_PROGRAM_ID,
+            ErrorCode::CallbackNotAllowed
+        );
+        require!(
+            ctx.remaining_accounts
+                .iter()
+                .all(|a| a.key() != PROTOCOL_AMM_PROGRAM_ID && a.key() != crate::ID),
+            ErrorCode::CallbackNotAllowed
+        );
+
+        let metas = ctx
+            .remaining_accounts
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: a.key(),
+                is_signer: a.is_signer,
+                is_writable: a.is_writable,
+            })
+            .collect();
+        let ix = Instruction {
+            program_id: ctx.accounts.callback_program.key(),
+            accounts: metas,
+            data: callback_data,
+        };
+        invoke(&ix, ctx.remaining_accounts)?;
+
+        ctx.accounts.vault.reload()?;
+        let fee = amount
+            .checked_mul(FLASH_FEE_BPS)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            ctx.accounts.vault.amount >= vault_before + fee,
+            ErrorCode::NotRepaid
+        );
+
+        msg!("Flash loan of {} repaid", amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FlashLoan<'info> {
+    #[account(mut, token::authority = lender)]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA signing authority over the vault
+    #[account(seeds = [b"lender"], bump)]
+    pub lender: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub borrower_token: Account<'info, TokenAccount>,
+
+    /// ✅ Must be executable and on CALLBACK_ALLOWLIST
+    /// CHECK: Validated against the allowlist in the handler
+    #[account(executable)]
+    pub callback_program: AccountInfo<'info>,
+
+    pub borrower: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Flash loan was not repaid with fee")]
+    NotRepaid,
+
+    #[msg("Callback program is not allowlisted")]
+    CallbackNotAllowed,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,54 @@
+#[tokio::test]
+async fn test_self_funded_mev_exploit() {
+    println!("\n=== EXPLOIT: Flash Loan Funds MEV Against Protocol ===\n");
+
+    let mallory = Keypair::new();
+    let lender = create_lender(10_000_000).await;
+    let amm_pool = create_protocol_amm_pool(5_000_000, 5_000_000).await;
+    let attack_program = deploy_sandwich_callback(&amm_pool).await;
+
+    println!("1. Mallory borrows 1,000,000 with her own callback");
+    let result = flash_loan(&lender, &mallory, 1_000_000, attack_program).await;
+
+    // Vulnerable: any callback accepted
+    assert!(result.is_ok());
+
+    let profit = get_token_balance(&mallory).await;
+    println!("   Mallory's profit after fee: {}", profit);
+    assert!(profit > 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Borrowed funds drove arbitrage through the protocol's AMM");
+    println!("   ✗ Protocol LPs paid for the attack");
+}
+
+#[tokio::test]
+async fn test_callback_allowlist() {
+    println!("\n=== SECURITY: Callback Allowlist ===\n");
+
+    let mallory = Keypair::new();
+    let lender = create_lender(10_000_000).await;
+    let amm_pool = create_protocol_amm_pool(5_000_000, 5_000_000).await;
+    let attack_program = deploy_sandwich_callback(&amm_pool).await;
+
+    println!("1. Callback to an unlisted program");
+    let result = flash_loan(&lender, &mallory, 1_000_000, attack_program).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Callback program is not allowlisted");
+
+    println!("\n2. Allowlisted callback handed the protocol AMM as an account");
+    let result = flash_loan_with_accounts(
+        &lender,
+        &mallory,
+        1_000_000,
+        CALLBACK_ALLOWLIST[0],
+        vec![PROTOCOL_AMM_PROGRAM_ID],
+    )
+    .await;
+    assert!(result.is_err());
+    println!("   ✓ Rejected: no path back into the protocol");
+
+    println!("\n3. Plain allowlisted callback");
+    let result = flash_loan(&lender, &mallory, 1_000_000, CALLBACK_ALLOWLIST[0]).await;
+    assert!(result.is_ok());
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln14414414414414414414414414414414414414");
+
+/// Flash loan fee in basis points
+pub const FLASH_FEE_BPS: u64 = 9;
+
+#[program]
+pub mod vulnerable_flash_loan {
+    use super::*;
+
+    /// VULNERABILITY: Unrestricted Flash Loan Callback
+    ///
+    /// The loan is sent out, an arbitrary caller-chosen program is invoked
+    /// with arbitrary accounts and data, and the only condition is that the
+    /// vault is repaid by the end of the instruction.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The protocol also runs an AMM program whose pools price against the
+    ///    same vault this lender draws from
+    /// 2. Mallory borrows 1,000,000 and names her own program as the callback
+    /// 3. Her callback swaps the borrowed funds through the protocol's AMM,
+    ///    moves the price, and back-runs it in the same call
+    /// 4. She repays principal plus a 0.09% fee out of the arbitrage profit
+    /// 5. LPs of the protocol's own pools absorbed the loss; the protocol's
+    ///    own capital financed the attack against it
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Solana forbids A -> B -> A reentry, but not A -> B -> C where C is
+    ///   another program in the same protocol
+    /// - "Repaid by the end" says nothing about what the money did meanwhile
+    pub fn flash_loan(ctx: Context<FlashLoan>, amount: u64, callback_data: Vec<u8>) -> Result<()> {
+        let vault_before = ctx.accounts.vault.amount;
+
+        let seeds: &[&[u8]] = &[b"lender", &[ctx.bumps.lender]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.borrower_token.to_account_info(),
+                    authority: ctx.accounts.lender.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+
+        // ❌ Any program, any accounts, any data
+        let metas = ctx
+            .remaining_accounts
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: a.key(),
+                is_signer: a.is_signer,
+                is_writable: a.is_writable,
+            })
+            .collect();
+        let ix = Instruction {
+            program_id: ctx.accounts.callback_program.key(),
+            accounts: metas,
+            data: callback_data,
+        };
+        invoke(&ix, ctx.remaining_accounts)?;
+
+        ctx.accounts.vault.reload()?;
+        let fee = amount
+            .checked_mul(FLASH_FEE_BPS)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            ctx.accounts.vault.amount >= vault_before + fee,
+            ErrorCode::NotRepaid
+        );
+
+        msg!("Flash loan of {} repaid", amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FlashLoan<'info> {
+    #[account(mut, token::authority = lender)]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA signing authority over the vault
+    #[account(seeds = [b"lender"], bump)]
+    pub lender: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub borrower_token: Account<'info, TokenAccount>,
+
+    /// CHECK: ❌ Arbitrary program - never validated
+    pub callback_program: AccountInfo<'info>,
+
+    pub borrower: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Flash loan was not repaid with fee")]
+    NotRepaid,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur14514514514514514514514514514514514514");
+
+/// Delay between proposing and applying a config change (~1 day of slots)
+pub const TIMELOCK_SLOTS: u64 = 216_000;
+
+/// Upper bound on any fee the admin may ever propose
+pub const MAX_FEE_BPS: u16 = 500;
+
+#[program]
+pub mod secure_config {
+    use super::*;
+
+    /// SECURE: Two-Step Timelocked Config Update
+    ///
+    /// Changes are recorded in a `PendingConfig` account with an
+    /// `effective_slot` in the future. Anyone can read the pending change
+    /// on-chain and exit before it applies.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `propose_config_update` stores the new values and
+    ///    `effective_slot = clock.slot + TIMELOCK_SLOTS`
+    /// 2. `apply_config_update` requires `clock.slot >= effective_slot`
+    /// 3. Proposed fees are bounded by `MAX_FEE_BPS`
+    /// 4. The pending account is closed on apply, so a proposal runs once
+    pub fn propose_config_update(
+        ctx: Context<ProposeConfigUpdate>,
+        fee_bps: u16,
+        max_slippage_bps: u16,
+    ) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, ErrorCode::FeeTooHigh);
+
+        let clock = Clock::get()?;
+        let pending = &mut ctx.accounts.pending_config;
+        pending.config = ctx.accounts.config.key();
+        pending.fee_bps = fee_bps;
+        pending.max_slippage_bps = max_slippage_bps;
+
+        // ✅ Publicly visible for TIMELOCK_SLOTS before it can apply
+        pending.effective_slot = clock
+            .slot
+            .checked_add(TIMELOCK_SLOTS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Config change proposed, effective at slot {}", pending.effective_slot);
+        Ok(())
+    }
+
+    /// Apply a proposed change once its timelock has elapsed
+    pub fn apply_config_update(ctx: Context<ApplyConfigUpdate>) -> Result<()> {
+        let pending = &ctx.accounts.pending_config;
+
+        // ✅ Too early is an error, not a no-op
+        require!(
+            Clock::get()?.slot >= pending.effective_slot,
+            ErrorCode::TimelockNotElapsed
+        );
+
+        let config = &mut ctx.accounts.config;
+        config.fee_bps = pending.fee_bps;
+        config.max_slippage_bps = pending.max_slippage_bps;
+
+        msg!("Config updated: fee={} slippage={}", config.fee_bps, config.max_slippage_bps);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ProposeConfigUpdate<'info> {
+    #[account(has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    /// One outstanding proposal per config
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PendingConfig::LEN,
+        seeds = [b"pending_config", config.key().as_ref()],
+        bump,
+    )]
+    pub pending_config: Account<'info, PendingConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyConfigUpdate<'info> {
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_config", config.key().as_ref()],
+        bump,
+        has_one = config,
+        close = admin,
+    )]
+    pub pending_config: Account<'info, PendingConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub max_slippage_bps: u16,
+}
+
+#[account]
+pub struct PendingConfig {
+    pub config: Pubkey,
+    pub fee_bps: u16,
+    pub max_slippage_bps: u16,
+    pub effective_slot: u64,
+}
+
+impl PendingConfig {
+    pub const LEN: usize = 32 + // config
+                           2 +  // fee_bps
+                           2 +  // max_slippage_bps
+                           8;   // effective_slot
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Proposed fee exceeds the protocol maximum")]
+    FeeTooHigh,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
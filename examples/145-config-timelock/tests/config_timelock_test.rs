@@ -0,0 +1,52 @@
+#[tokio::test]
+async fn test_admin_front_runs_trade_exploit() {
+    println!("\n=== EXPLOIT: Instant Config Change ===\n");
+
+    let admin = Keypair::new();
+    let alice = Keypair::new();
+    let config = create_config(&admin, 30, 100).await;
+    println!("1. Advertised fee: 30 bps");
+
+    println!("\n2. Admin front-runs Alice's swap with fee = 1000 bps");
+    let (update_result, swap_result) = send_in_same_slot(
+        update_config_ix(&config, &admin, 1_000, 100),
+        swap_ix(&config, &alice, 1_000_000),
+    )
+    .await;
+
+    // Vulnerable: both succeed, swap pays the new fee
+    assert!(update_result.is_ok());
+    assert!(swap_result.is_ok());
+    assert_eq!(get_fee_paid(&alice).await, 100_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Alice paid 10% instead of 0.3%");
+}
+
+#[tokio::test]
+async fn test_timelocked_config() {
+    println!("\n=== SECURITY: Timelocked Config Update ===\n");
+
+    let admin = Keypair::new();
+    let config = create_config(&admin, 30, 100).await;
+
+    println!("1. Admin proposes fee = 100 bps");
+    propose_config_update(&config, &admin, 100, 100).await.unwrap();
+
+    println!("\n2. Admin applies immediately");
+    let result = apply_config_update(&config, &admin).await;
+    assert!(result.is_err());
+    assert_eq!(get_fee_bps(&config).await, 30);
+    println!("   ✓ Error: Timelock has not elapsed");
+
+    warp_slots(TIMELOCK_SLOTS).await;
+
+    println!("\n3. Admin applies after TIMELOCK_SLOTS");
+    apply_config_update(&config, &admin).await.unwrap();
+    assert_eq!(get_fee_bps(&config).await, 100);
+
+    println!("\n4. Admin proposes fee above MAX_FEE_BPS");
+    let result = propose_config_update(&config, &admin, 1_000, 100).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Proposed fee exceeds the protocol maximum");
+}
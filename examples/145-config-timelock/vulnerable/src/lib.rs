@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln14514514514514514514514514514514514514");
+
+#[program]
+pub mod vulnerable_config {
+    use super::*;
+
+    /// VULNERABILITY: Config Changes Take Effect Immediately
+    ///
+    /// The admin is authenticated correctly, but a new fee or slippage bound
+    /// applies to the very next instruction. Users sign trades against the
+    /// config they saw; they execute against whatever the admin set since.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice submits a large swap expecting the advertised 0.3% fee
+    /// 2. The admin sees it pending and sends `update_config` with
+    ///    `fee_bps = 1000` (10%) and a higher priority fee
+    /// 3. The config update lands first; Alice's swap pays 10%
+    /// 4. The admin restores the old fee in the next slot
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Users cannot react to a change that lands in the same slot
+    /// - "Trusted admin" becomes "admin who can tax any trade they see"
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        fee_bps: u16,
+        max_slippage_bps: u16,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // ❌ No delay between decision and effect
+        config.fee_bps = fee_bps;
+        config.max_slippage_bps = max_slippage_bps;
+
+        msg!("Config updated: fee={} slippage={}", fee_bps, max_slippage_bps);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub max_slippage_bps: u16,
+}
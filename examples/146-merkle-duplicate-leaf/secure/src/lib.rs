@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+declare_id!("Secur14614614614614614614614614614614614614");
+
+#[program]
+pub mod secure_airdrop {
+    use super::*;
+
+    /// SECURE: One Receipt Per Leaf Hash
+    ///
+    /// The claim receipt PDA is derived from the leaf hash itself. However
+    /// many times a leaf appears in the tree, it maps to exactly one receipt
+    /// address, and `init` fails if that receipt already exists.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Leaf is recomputed on-chain from the signer's key and `amount`
+    /// 2. Proof is verified against the stored root
+    /// 3. Receipt PDA seeds are `[b"claimed", distributor, leaf_hash]`
+    /// 4. `init` on an existing receipt aborts the transaction
+    pub fn claim(
+        ctx: Context<Claim>,
+        leaf_hash: [u8; 32],
+        index: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let leaf = keccak::hashv(&[
+            ctx.accounts.claimant.key().as_ref(),
+            &amount.to_le_bytes(),
+        ])
+        .0;
+
+        // ✅ The seed must be the real leaf, not a caller-chosen value
+        require!(leaf == leaf_hash, ErrorCode::LeafMismatch);
+
+        require!(
+            verify_proof(&proof, ctx.accounts.distributor.root, leaf, index),
+            ErrorCode::InvalidProof
+        );
+
+        // ✅ Receipt seeded by leaf - a duplicate leaf hits the same PDA
+        let receipt = &mut ctx.accounts.claim_receipt;
+        receipt.claimant = ctx.accounts.claimant.key();
+        receipt.amount = amount;
+
+        let distributor = &mut ctx.accounts.distributor;
+        distributor.total_claimed = distributor
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Claimed {} at index {}", amount, index);
+        Ok(())
+    }
+}
+
+/// Walk the proof from leaf to root; each bit of `index` picks the side
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32], index: u64) -> bool {
+    let mut computed = leaf;
+    let mut position = index;
+    for sibling in proof {
+        computed = if position & 1 == 0 {
+            keccak::hashv(&[&computed, sibling]).0
+        } else {
+            keccak::hashv(&[sibling, &computed]).0
+        };
+        position >>= 1;
+    }
+    computed == root
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(leaf_hash: [u8; 32])]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub distributor: Account<'info, Distributor>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + ClaimReceipt::LEN,
+        seeds = [b"claimed", distributor.key().as_ref(), leaf_hash.as_ref()],
+        bump,
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Distributor {
+    pub root: [u8; 32],
+    pub total_claimed: u64,
+}
+
+#[account]
+pub struct ClaimReceipt {
+    pub claimant: Pubkey,
+    pub amount: u64,
+}
+
+impl ClaimReceipt {
+    pub const LEN: usize = 32 + // claimant
+                           8;   // amount
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Merkle proof is invalid")]
+    InvalidProof,
+
+    #[msg("Leaf hash does not match claimant and amount")]
+    LeafMismatch,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,49 @@
+#[tokio::test]
+async fn test_duplicate_leaf_double_claim_exploit() {
+    println!("\n=== EXPLOIT: Duplicate Merkle Leaf ===\n");
+
+    let mallory = Keypair::new();
+    let leaf = (mallory.pubkey(), 50_000u64);
+
+    // Same leaf placed at two positions
+    let tree = build_tree_with_leaf_at(&[7, 12], leaf, 16);
+    let distributor = create_distributor(tree.root()).await;
+    println!("1. Tree root published with Mallory's leaf at 7 and 12");
+
+    println!("\n2. Mallory claims with index 7");
+    claim(&distributor, &mallory, 7, 50_000, tree.proof(7)).await.unwrap();
+
+    println!("\n3. Mallory claims with index 12");
+    let result = claim(&distributor, &mallory, 12, 50_000, tree.proof(12)).await;
+
+    // Vulnerable: distinct receipt PDAs
+    assert!(result.is_ok());
+    assert_eq!(get_total_claimed(&distributor).await, 100_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Both proofs valid, both receipts new");
+    println!("   ✗ Mallory paid twice for one allocation");
+}
+
+#[tokio::test]
+async fn test_leaf_keyed_receipt() {
+    println!("\n=== SECURITY: Leaf-Keyed Claim Receipt ===\n");
+
+    let mallory = Keypair::new();
+    let leaf = (mallory.pubkey(), 50_000u64);
+    let tree = build_tree_with_leaf_at(&[7, 12], leaf, 16);
+    let distributor = create_distributor(tree.root()).await;
+
+    println!("1. Mallory claims with index 7");
+    claim(&distributor, &mallory, 7, 50_000, tree.proof(7)).await.unwrap();
+
+    println!("\n2. Mallory claims with index 12");
+    let result = claim(&distributor, &mallory, 12, 50_000, tree.proof(12)).await;
+
+    // Secure: same leaf hash -> same receipt PDA -> init fails
+    assert!(result.is_err());
+    assert_eq!(get_total_claimed(&distributor).await, 50_000);
+
+    println!("\n   DOUBLE CLAIM BLOCKED!");
+    println!("   ✓ Receipt derived from leaf hash already exists");
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+declare_id!("Vuln14614614614614614614614614614614614614");
+
+#[program]
+pub mod vulnerable_airdrop {
+    use super::*;
+
+    /// VULNERABILITY: Claims Keyed By Tree Position, Not By Leaf
+    ///
+    /// The claim receipt PDA is derived from the leaf's `index` in the tree.
+    /// A proof for index 7 and a proof for index 12 are both valid if the
+    /// same `(claimant, amount)` leaf sits at both positions - and nothing
+    /// stops the tree builder from putting it there.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory operates (or compromises) the off-chain tree builder
+    /// 2. She inserts her leaf `(mallory, 50_000)` at indices 7 and 12
+    /// 3. The published root is valid; a casual audit of the root reveals nothing
+    /// 4. Mallory claims with index 7, then again with index 12
+    /// 5. Two distinct receipt PDAs are created; she is paid twice
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A Merkle proof shows "this leaf is in the tree", not "only once"
+    /// - Replay protection must key on what is being claimed, not where it sits
+    pub fn claim(ctx: Context<Claim>, index: u64, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        let leaf = keccak::hashv(&[
+            ctx.accounts.claimant.key().as_ref(),
+            &amount.to_le_bytes(),
+        ])
+        .0;
+
+        require!(
+            verify_proof(&proof, ctx.accounts.distributor.root, leaf, index),
+            ErrorCode::InvalidProof
+        );
+
+        // ❌ Receipt seeded by index - duplicate leaves get distinct receipts
+        let receipt = &mut ctx.accounts.claim_receipt;
+        receipt.claimant = ctx.accounts.claimant.key();
+        receipt.amount = amount;
+
+        let distributor = &mut ctx.accounts.distributor;
+        distributor.total_claimed = distributor
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Claimed {} at index {}", amount, index);
+        Ok(())
+    }
+}
+
+/// Walk the proof from leaf to root; each bit of `index` picks the side
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32], index: u64) -> bool {
+    let mut computed = leaf;
+    let mut position = index;
+    for sibling in proof {
+        computed = if position & 1 == 0 {
+            keccak::hashv(&[&computed, sibling]).0
+        } else {
+            keccak::hashv(&[sibling, &computed]).0
+        };
+        position >>= 1;
+    }
+    computed == root
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub distributor: Account<'info, Distributor>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + ClaimReceipt::LEN,
+        seeds = [b"claimed", distributor.key().as_ref(), &index.to_le_bytes()],
+        bump,
+    )]
+    pub claim_receipt: Account<'info, ClaimReceipt>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Distributor {
+    pub root: [u8; 32],
+    pub total_claimed: u64,
+}
+
+#[account]
+pub struct ClaimReceipt {
+    pub claimant: Pubkey,
+    pub amount: u64,
+}
+
+impl ClaimReceipt {
+    pub const LEN: usize = 32 + // claimant
+                           8;   // amount
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Merkle proof is invalid")]
+    InvalidProof,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
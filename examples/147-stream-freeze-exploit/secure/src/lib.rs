@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur14714714714714714714714714714714714714");
+
+#[program]
+pub mod secure_stream {
+    use super::*;
+
+    /// Recipient withdraws what has streamed so far
+    /// After a freeze, this pays out the earned amount snapshotted at freeze
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        let stream = &mut ctx.accounts.stream;
+
+        let available = if stream.frozen {
+            stream.earned_unclaimed
+        } else {
+            let now = Clock::get()?.unix_timestamp;
+            stream
+                .streamed_until(now)?
+                .checked_sub(stream.withdrawn)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+        };
+
+        stream.earned_unclaimed = 0;
+        stream.withdrawn = stream
+            .withdrawn
+            .checked_add(available)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// SECURE: Freeze Snapshots What The Recipient Has Earned
+    ///
+    /// At the moment the stream stops, `earned = rate * elapsed` is computed
+    /// and everything earned but not yet withdrawn is set aside in
+    /// `earned_unclaimed`. That amount belongs to the recipient.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `freeze_stream` records `earned_unclaimed` before stopping accrual
+    /// 2. `reclaim_unfunded_amount` excludes `earned_unclaimed`
+    /// 3. The recipient can still withdraw `earned_unclaimed` after a freeze
+    pub fn freeze_stream(ctx: Context<PayerOnly>) -> Result<()> {
+        let stream = &mut ctx.accounts.stream;
+        require!(!stream.frozen, ErrorCode::StreamFrozen);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        // ✅ earned = rate * elapsed, minus what was already withdrawn
+        let earned = stream.streamed_until(now)?;
+        stream.earned_unclaimed = earned
+            .checked_sub(stream.withdrawn)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        stream.frozen = true;
+        stream.frozen_at = now;
+        Ok(())
+    }
+
+    /// Return only the portion of the deposit that never streamed
+    pub fn reclaim_unfunded_amount(ctx: Context<PayerOnly>) -> Result<()> {
+        let stream = &mut ctx.accounts.stream;
+        require!(stream.frozen, ErrorCode::StreamNotFrozen);
+
+        // ✅ Recipient's earned tokens stay in the stream
+        let reclaimable = stream
+            .total_deposited
+            .checked_sub(stream.withdrawn)
+            .and_then(|v| v.checked_sub(stream.earned_unclaimed))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        stream.total_deposited = stream
+            .total_deposited
+            .checked_sub(reclaimable)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Payer reclaimed {}", reclaimable);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = recipient)]
+    pub stream: Account<'info, Stream>,
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PayerOnly<'info> {
+    #[account(mut, has_one = payer)]
+    pub stream: Account<'info, Stream>,
+    pub payer: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Stream {
+    pub payer: Pubkey,
+    pub recipient: Pubkey,
+    pub rate_per_second: u64,
+    pub start_ts: i64,
+    pub total_deposited: u64,
+    pub withdrawn: u64,
+    pub frozen: bool,
+    pub frozen_at: i64,
+    /// ✅ Earned before the freeze, still owed to the recipient
+    pub earned_unclaimed: u64,
+}
+
+impl Stream {
+    /// Total streamed to the recipient by `now`, capped at the deposit
+    pub fn streamed_until(&self, now: i64) -> Result<u64> {
+        let elapsed = now.saturating_sub(self.start_ts).max(0) as u64;
+        let streamed = self
+            .rate_per_second
+            .checked_mul(elapsed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(streamed.min(self.total_deposited))
+    }
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Stream is frozen")]
+    StreamFrozen,
+
+    #[msg("Stream must be frozen first")]
+    StreamNotFrozen,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
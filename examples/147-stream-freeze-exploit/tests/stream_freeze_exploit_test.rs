@@ -0,0 +1,53 @@
+const DAY: i64 = 86_400;
+
+#[tokio::test]
+async fn test_freeze_and_reclaim_exploit() {
+    println!("\n=== EXPLOIT: Freeze Then Reclaim Earned Tokens ===\n");
+
+    let payer = Keypair::new();
+    let bob = Keypair::new();
+    // 30,000 over 30 days
+    let stream = create_stream(&payer, &bob, 30_000, 30 * DAY).await;
+
+    warp_seconds(29 * DAY).await;
+    println!("1. 29 days elapsed - Bob has earned 29,000, withdrawn 0");
+
+    println!("\n2. Payer freezes and reclaims");
+    freeze_stream(&stream, &payer).await.unwrap();
+    let reclaimed = reclaim_unfunded_amount(&stream, &payer).await.unwrap();
+
+    // Vulnerable: payer takes everything
+    assert_eq!(reclaimed, 30_000);
+
+    println!("\n3. Bob tries to withdraw");
+    let result = withdraw(&stream, &bob).await;
+    assert!(result.is_err());
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Payer reclaimed 29,000 Bob had already earned");
+}
+
+#[tokio::test]
+async fn test_earned_amount_protected() {
+    println!("\n=== SECURITY: Earned Amount Snapshot ===\n");
+
+    let payer = Keypair::new();
+    let bob = Keypair::new();
+    let stream = create_stream(&payer, &bob, 30_000, 30 * DAY).await;
+
+    warp_seconds(29 * DAY).await;
+
+    println!("1. Payer freezes and reclaims");
+    freeze_stream(&stream, &payer).await.unwrap();
+    let reclaimed = reclaim_unfunded_amount(&stream, &payer).await.unwrap();
+
+    // Secure: only the unstreamed day comes back
+    assert_eq!(reclaimed, 1_000);
+
+    println!("\n2. Bob withdraws after the freeze");
+    withdraw(&stream, &bob).await.unwrap();
+    assert_eq!(get_withdrawn(&stream).await, 29_000);
+
+    println!("\n   EARNINGS PRESERVED!");
+    println!("   ✓ earned_unclaimed set aside at freeze");
+}
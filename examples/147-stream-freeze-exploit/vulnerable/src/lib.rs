@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln14714714714714714714714714714714714714");
+
+#[program]
+pub mod vulnerable_stream {
+    use super::*;
+
+    /// Recipient withdraws what has streamed so far
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        let stream = &mut ctx.accounts.stream;
+        require!(!stream.frozen, ErrorCode::StreamFrozen);
+
+        let now = Clock::get()?.unix_timestamp;
+        let available = stream
+            .streamed_until(now)?
+            .checked_sub(stream.withdrawn)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        stream.withdrawn = stream
+            .withdrawn
+            .checked_add(available)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Payer pauses the stream
+    pub fn freeze_stream(ctx: Context<PayerOnly>) -> Result<()> {
+        let stream = &mut ctx.accounts.stream;
+        stream.frozen = true;
+        stream.frozen_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Reclaim Ignores Earned-But-Unwithdrawn Tokens
+    ///
+    /// Once frozen, the payer reclaims everything the recipient has not yet
+    /// withdrawn - which includes tokens that already streamed to them.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Payer opens a 30-day stream of 30,000 tokens to Bob
+    /// 2. Bob works 29 days without withdrawing (29,000 earned)
+    /// 3. Payer calls `freeze_stream`, then `reclaim_unfunded_amount`
+    /// 4. Reclaim returns `total_deposited - withdrawn` = 30,000 to the payer
+    /// 5. Bob's 29,000 earned tokens are gone, and frozen streams block
+    ///    `withdraw`, so he cannot get them back
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - "Not yet withdrawn" is not the same as "not yet earned"
+    /// - Recipients are penalized for trusting the stream and batching claims
+    pub fn reclaim_unfunded_amount(ctx: Context<PayerOnly>) -> Result<()> {
+        let stream = &mut ctx.accounts.stream;
+        require!(stream.frozen, ErrorCode::StreamNotFrozen);
+
+        // ❌ Treats every unwithdrawn token as unfunded
+        let reclaimable = stream
+            .total_deposited
+            .checked_sub(stream.withdrawn)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        stream.total_deposited = stream.withdrawn;
+        msg!("Payer reclaimed {}", reclaimable);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = recipient)]
+    pub stream: Account<'info, Stream>,
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PayerOnly<'info> {
+    #[account(mut, has_one = payer)]
+    pub stream: Account<'info, Stream>,
+    pub payer: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Stream {
+    pub payer: Pubkey,
+    pub recipient: Pubkey,
+    pub rate_per_second: u64,
+    pub start_ts: i64,
+    pub total_deposited: u64,
+    pub withdrawn: u64,
+    pub frozen: bool,
+    pub frozen_at: i64,
+}
+
+impl Stream {
+    /// Total streamed to the recipient by `now`, capped at the deposit
+    pub fn streamed_until(&self, now: i64) -> Result<u64> {
+        let elapsed = now.saturating_sub(self.start_ts).max(0) as u64;
+        let streamed = self
+            .rate_per_second
+            .checked_mul(elapsed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(streamed.min(self.total_deposited))
+    }
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Stream is frozen")]
+    StreamFrozen,
+
+    #[msg("Stream must be frozen first")]
+    StreamNotFrozen,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur14814814814814814814814814814814814814");
+
+#[program]
+pub mod secure_signed_fee {
+    use super::*;
+
+    /// SECURE: Reject Non-Positive Fees Before Any Cast
+    ///
+    /// The sign is checked while the value is still `i64`. Only once it is
+    /// known to be positive is it converted, and the arithmetic that uses it
+    /// is checked rather than wrapping.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `require!(fee > 0)` runs before the cast
+    /// 2. `fee as u64` is only reached for values in `1..=i64::MAX`, all of
+    ///    which are representable as `u64`
+    /// 3. `checked_sub` fails if the vault cannot cover the fee
+    pub fn collect_fee(ctx: Context<CollectFee>, fee: i64) -> Result<()> {
+        // ✅ Sign checked while the type can still express it
+        require!(fee > 0, ErrorCode::InvalidFee);
+        let fee = fee as u64;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.balance = vault
+            .balance
+            .checked_sub(fee)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+
+        let fee_account = &mut ctx.accounts.fee_account;
+        fee_account.collected = fee_account
+            .collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Collected fee {}", fee);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CollectFee<'info> {
+    #[account(mut, has_one = owner)]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub fee_account: Account<'info, FeeAccount>,
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+#[account]
+pub struct FeeAccount {
+    pub collected: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fee must be positive")]
+    InvalidFee,
+
+    #[msg("Insufficient funds in vault")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln14814814814814814814814814814814814814");
+
+#[program]
+pub mod vulnerable_signed_fee {
+    use super::*;
+
+    /// VULNERABILITY: Signed Fee Cast To Unsigned
+    ///
+    /// The fee arrives as `i64` so integrators can express rebates, but the
+    /// collector casts it with `as u64` and subtracts with wrapping math. A
+    /// negative fee reinterprets as an enormous unsigned value, and the
+    /// wrapping subtraction turns "take a fee" into "add a balance".
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory's vault holds 100
+    /// 2. Mallory calls `collect_fee` with `fee = -1_000_000`
+    /// 3. `-1_000_000i64 as u64` = 18_446_744_073_708_551_616
+    /// 4. `100.wrapping_sub(that)` = 1_000_100
+    /// 5. Mallory withdraws a million tokens she never deposited
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - `as` between signed and unsigned types is a bit reinterpretation,
+    ///   not a conversion
+    /// - Wrapping arithmetic then makes the nonsense value look reasonable
+    pub fn collect_fee(ctx: Context<CollectFee>, fee: i64) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+
+        // ❌ Negative fee becomes a huge u64, and wrapping_sub adds it back
+        vault.balance = vault.balance.wrapping_sub(fee as u64);
+
+        let fee_account = &mut ctx.accounts.fee_account;
+        fee_account.collected = fee_account.collected.wrapping_add(fee as u64);
+
+        msg!("Collected fee {}", fee);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CollectFee<'info> {
+    #[account(mut, has_one = owner)]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub fee_account: Account<'info, FeeAccount>,
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+#[account]
+pub struct FeeAccount {
+    pub collected: u64,
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use std::collections::BTreeSet;
+
+declare_id!("Secur14914914914914914914914914914914914914");
+
+#[program]
+pub mod secure_emergency {
+    use super::*;
+
+    /// Normal withdrawal path - fully validated
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        transfer_above_rent_floor(
+            &ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.destination,
+            amount,
+        )
+    }
+
+    /// SECURE: Emergency Path Uses The Full Validation Stack Plus Multisig
+    ///
+    /// The emergency instruction is held to a higher bar than the normal one,
+    /// not a lower one. Every check from `withdraw` still applies, and the
+    /// action additionally needs M-of-N guardian signatures.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `vault: Account<Vault>` - owner and discriminator verified
+    /// 2. Funds go only to `config.recovery_destination`, fixed in advance
+    /// 3. Rent floor enforced by the same helper the normal path uses
+    /// 4. At least `config.threshold` distinct guardians from
+    ///    `config.guardians` must sign, passed as remaining accounts
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+        let config = &ctx.accounts.config;
+
+        // ✅ Count distinct guardian signatures
+        let mut signed = BTreeSet::new();
+        for account in ctx.remaining_accounts.iter() {
+            if account.is_signer && config.guardians.contains(account.key) {
+                signed.insert(account.key());
+            }
+        }
+        require!(
+            signed.len() >= config.threshold as usize,
+            ErrorCode::InsufficientSignatures
+        );
+
+        // ✅ Same rent floor as the normal path
+        let vault_info = ctx.accounts.vault.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(vault_info.data_len());
+        let amount = vault_info.lamports().saturating_sub(rent_floor);
+        transfer_above_rent_floor(&vault_info, &ctx.accounts.recovery_destination, amount)?;
+
+        msg!(
+            "EMERGENCY: {} guardians moved {} lamports to recovery",
+            signed.len(),
+            amount
+        );
+        Ok(())
+    }
+}
+
+/// Move lamports out of a program-owned account without dropping below rent
+pub fn transfer_above_rent_floor(
+    from: &AccountInfo,
+    to: &AccountInfo,
+    amount: u64,
+) -> Result<()> {
+    let rent_floor = Rent::get()?.minimum_balance(from.data_len());
+    require!(
+        from.lamports().saturating_sub(amount) >= rent_floor,
+        ErrorCode::BelowRentExempt
+    );
+
+    **from.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = authority)]
+    pub vault: Account<'info, Vault>,
+    pub authority: Signer<'info>,
+    /// CHECK: Destination for lamports
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    /// ✅ Single program-wide config PDA
+    #[account(seeds = [b"emergency_config"], bump, has_one = recovery_destination)]
+    pub config: Account<'info, EmergencyConfig>,
+
+    /// ✅ Typed account - owner and discriminator checked
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: ✅ Pinned by has_one on config
+    #[account(mut)]
+    pub recovery_destination: AccountInfo<'info>,
+    // Guardian signers are passed as remaining accounts
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub authority: Pubkey,
+}
+
+#[account]
+pub struct EmergencyConfig {
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub recovery_destination: Pubkey,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Not enough guardian signatures")]
+    InsufficientSignatures,
+
+    #[msg("Withdrawal would leave vault below rent exemption")]
+    BelowRentExempt,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * An emergency is exactly when an attacker is most likely to be active.
+ * Emergency instructions should reuse every check of the normal path and
+ * add more: multiple signers, fixed destinations, and no caller-chosen
+ * accounts.
+ */
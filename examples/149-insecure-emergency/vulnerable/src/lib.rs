@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln14914914914914914914914914914914914914");
+
+/// Hardcoded protocol admin
+pub const ADMIN_PUBKEY: Pubkey = pubkey!("9LMzpLU8Ye6n2ApE4xYZN8BwKAkCTJ2Dn6yYS2ybzmeU");
+
+#[program]
+pub mod vulnerable_emergency {
+    use super::*;
+
+    /// Normal withdrawal path - fully validated
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let vault_info = ctx.accounts.vault.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(vault_info.data_len());
+        require!(
+            vault_info.lamports().saturating_sub(amount) >= rent_floor,
+            ErrorCode::BelowRentExempt
+        );
+
+        **vault_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Emergency Path Skips The Security Stack
+    ///
+    /// The normal `withdraw` uses `Signer`, `Account<Vault>` and a rent-floor
+    /// check. The emergency path was written in a hurry "because it will
+    /// never be used" and keeps only a pubkey comparison.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. `ADMIN_PUBKEY` is public - it is in the program binary
+    /// 2. Mallory builds a transaction passing `ADMIN_PUBKEY` as `admin`
+    ///    without its signature
+    /// 3. `admin.key() == ADMIN_PUBKEY` passes
+    /// 4. `vault` is an unchecked `AccountInfo`, so Mallory can point it at any
+    ///    account this program owns, and every lamport is moved out - the
+    ///    account drops below rent exemption and is garbage-collected
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Emergency paths are the highest-privilege code in the program
+    /// - Attackers read the rarely-used instructions first
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+        // ❌ Key comparison without a signature proves nothing
+        require!(
+            ctx.accounts.admin.key() == ADMIN_PUBKEY,
+            ErrorCode::Unauthorized
+        );
+
+        // ❌ No owner check, no rent floor - drain everything
+        let vault = &ctx.accounts.vault;
+        let amount = vault.lamports();
+        **vault.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+
+        msg!("EMERGENCY: withdrew {} lamports", amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = authority)]
+    pub vault: Account<'info, Vault>,
+    pub authority: Signer<'info>,
+    /// CHECK: Destination for lamports
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    /// CHECK: ❌ Any account - owner and type never validated
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: ❌ Compared to ADMIN_PUBKEY but never required to sign
+    pub admin: AccountInfo<'info>,
+
+    /// CHECK: ❌ Caller-chosen destination
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub authority: Pubkey,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Unauthorized")]
+    Unauthorized,
+
+    #[msg("Withdrawal would leave vault below rent exemption")]
+    BelowRentExempt,
+}
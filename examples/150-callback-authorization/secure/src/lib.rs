@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur15o15o15o15o15o15o15o15o15o15o15o15o15");
+
+#[program]
+pub mod secure_callback_authorization {
+    use super::*;
+
+    /// SECURE: Callback Pinned To The Configured Program
+    ///
+    /// The reward pool records which program is allowed to report task
+    /// completion. The callback signer must be that program's PDA, so a
+    /// look-alike program cannot forge the call.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `callback_program.key() == reward_pool.authorized_callback_program`
+    /// 2. `callback_authority` seeds are derived under the authorized program
+    /// 3. `user_rewards` is a PDA of the pool and user, so credits cannot be
+    ///    routed into an unrelated account
+    pub fn on_task_completed(ctx: Context<OnTaskCompleted>, tasks: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.reward_pool;
+
+        // ✅ Only the configured program may report completions
+        require!(
+            ctx.accounts.callback_program.key() == pool.authorized_callback_program,
+            ErrorCode::UnauthorizedCallback
+        );
+
+        let reward = tasks
+            .checked_mul(pool.reward_per_task)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let user_rewards = &mut ctx.accounts.user_rewards;
+        user_rewards.pending = user_rewards
+            .pending
+            .checked_add(reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_distributed = pool
+            .total_distributed
+            .checked_add(reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Credited {} rewards to {}", reward, user_rewards.user);
+        Ok(())
+    }
+
+    /// Admin-only update of the trusted callback program
+    pub fn set_callback_program(
+        ctx: Context<SetCallbackProgram>,
+        authorized_callback_program: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.reward_pool.authorized_callback_program = authorized_callback_program;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OnTaskCompleted<'info> {
+    #[account(mut)]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [b"user_rewards", reward_pool.key().as_ref(), user_rewards.user.as_ref()],
+        bump,
+    )]
+    pub user_rewards: Account<'info, UserRewards>,
+
+    /// CHECK: ✅ Compared against reward_pool.authorized_callback_program
+    #[account(executable)]
+    pub callback_program: AccountInfo<'info>,
+
+    /// ✅ PDA of the authorized program - only it can sign
+    #[account(
+        seeds = [b"callback_authority"],
+        bump,
+        seeds::program = reward_pool.authorized_callback_program,
+    )]
+    pub callback_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCallbackProgram<'info> {
+    #[account(mut, has_one = admin)]
+    pub reward_pool: Account<'info, RewardPool>,
+    pub admin: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct RewardPool {
+    pub admin: Pubkey,
+    pub authorized_callback_program: Pubkey,
+    pub reward_per_task: u64,
+    pub total_distributed: u64,
+}
+
+#[account]
+pub struct UserRewards {
+    pub user: Pubkey,
+    pub pending: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Callback program is not authorized")]
+    UnauthorizedCallback,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln15015015015015015015015015015015015015");
+
+#[program]
+pub mod vulnerable_callback_authorization {
+    use super::*;
+
+    /// VULNERABILITY: Callback Program Never Validated
+    ///
+    /// Program B (a quest program) is supposed to call this instruction via
+    /// CPI once a user has completed a task, signing with its
+    /// `[b"callback_authority"]` PDA. The PDA check is derived from whatever
+    /// `callback_program` the caller supplies, so any program can produce a
+    /// valid signer.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory deploys her own program M
+    /// 2. M derives `[b"callback_authority"]` under its own program id and
+    ///    signs for it with `invoke_signed`
+    /// 3. M calls `on_task_completed`, passing itself as `callback_program`
+    /// 4. The seeds check passes because it is computed against M's id
+    /// 5. Mallory is credited rewards without completing anything in B
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A PDA signature only proves which program signed, not that it is
+    ///   the program you trust
+    /// - Rewards are paid for work that never happened
+    pub fn on_task_completed(ctx: Context<OnTaskCompleted>, tasks: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.reward_pool;
+        let reward = tasks
+            .checked_mul(pool.reward_per_task)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // ❌ Credited on the word of an unverified program
+        let user_rewards = &mut ctx.accounts.user_rewards;
+        user_rewards.pending = user_rewards
+            .pending
+            .checked_add(reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_distributed = pool
+            .total_distributed
+            .checked_add(reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Credited {} rewards to {}", reward, user_rewards.user);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OnTaskCompleted<'info> {
+    #[account(mut)]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(mut)]
+    pub user_rewards: Account<'info, UserRewards>,
+
+    /// CHECK: ❌ Any executable account is accepted
+    pub callback_program: AccountInfo<'info>,
+
+    /// ❌ PDA of the caller-supplied program, not of program B
+    #[account(
+        seeds = [b"callback_authority"],
+        bump,
+        seeds::program = callback_program.key(),
+    )]
+    pub callback_authority: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct RewardPool {
+    pub admin: Pubkey,
+    pub reward_per_task: u64,
+    pub total_distributed: u64,
+}
+
+#[account]
+pub struct UserRewards {
+    pub user: Pubkey,
+    pub pending: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
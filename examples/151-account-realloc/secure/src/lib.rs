@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+declare_id!("Secur15115115115115115115115115115115115115");
+
+pub const DEFAULT_FEE_RATE_BPS: u16 = 30;
+
+#[program]
+pub mod secure_account_realloc {
+    use super::*;
+
+    /// Owner-only fee update on a migrated vault
+    pub fn set_fee_rate(ctx: Context<SetFeeRate>, fee_rate: u16) -> Result<()> {
+        ctx.accounts.vault.fee_rate = fee_rate;
+        Ok(())
+    }
+
+    /// SECURE: One-Way, Owner-Authorized Account Migration
+    ///
+    /// `grow_account` tops up rent from the authority and reallocs the
+    /// account in place. The existing bytes are left alone, so the V1
+    /// fields are still readable at the same offsets; only the
+    /// discriminator and the new trailing fields are rewritten.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `VaultV1::try_deserialize` checks the V1 discriminator, so an
+    ///    already-migrated vault is rejected and migration runs exactly once
+    /// 2. The signer must be the vault's recorded authority
+    /// 3. `authority` and `balance` are copied unchanged into V2
+    /// 4. `fee_rate` starts at `DEFAULT_FEE_RATE_BPS` and `created_at` at
+    ///    the migration timestamp
+    ///
+    /// WHY UncheckedAccount:
+    /// `Account<VaultV1>` would re-serialize itself as V1 on exit and undo
+    /// the discriminator change, so the account is typed manually here.
+    pub fn migrate_v1_to_v2(ctx: Context<MigrateV1ToV2>) -> Result<()> {
+        let vault_info = ctx.accounts.vault.to_account_info();
+
+        // ✅ Must currently be a V1 vault
+        let v1 = {
+            let data = vault_info.try_borrow_data()?;
+            VaultV1::try_deserialize(&mut &data[..])?
+        };
+
+        // ✅ Only the owner can migrate
+        require_keys_eq!(
+            v1.authority,
+            ctx.accounts.authority.key(),
+            ErrorCode::Unauthorized
+        );
+
+        // ✅ V1 data preserved, new fields initialized
+        let v2 = VaultV2 {
+            authority: v1.authority,
+            balance: v1.balance,
+            fee_rate: DEFAULT_FEE_RATE_BPS,
+            created_at: Clock::get()?.unix_timestamp,
+        };
+
+        grow_account(
+            &vault_info,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + VaultV2::LEN,
+        )?;
+        let mut data = vault_info.try_borrow_mut_data()?;
+        v2.try_serialize(&mut &mut data[..])?;
+
+        msg!("Migrated vault {} to V2", vault_info.key());
+        Ok(())
+    }
+}
+
+/// Grow `account` to `new_len` bytes, keeping its data, with `payer`
+/// covering the extra rent
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.realloc(new_len, false)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetFeeRate<'info> {
+    #[account(mut, has_one = authority)]
+    pub vault: Account<'info, VaultV2>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateV1ToV2<'info> {
+    /// CHECK: ✅ Owner checked here, V1 discriminator and authority checked
+    /// in the handler
+    #[account(mut, owner = crate::ID)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct VaultV1 {
+    pub authority: Pubkey,
+    pub balance: u64,
+}
+
+impl VaultV1 {
+    pub const LEN: usize = 32 + // authority
+        8; // balance
+}
+
+#[account]
+pub struct VaultV2 {
+    pub authority: Pubkey,
+    pub balance: u64,
+    pub fee_rate: u16,
+    pub created_at: i64,
+}
+
+impl VaultV2 {
+    pub const LEN: usize = 32 + // authority
+        8 + // balance
+        2 + // fee_rate
+        8; // created_at
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer is not the vault authority")]
+    Unauthorized,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * Only append fields in a new version so old offsets stay valid, check the
+ * old discriminator before migrating, and require the same authority a
+ * normal write would need.
+ */
@@ -0,0 +1,56 @@
+#[tokio::test]
+async fn test_remigration_resets_fee_exploit() {
+    println!("\n=== EXPLOIT: Re-Running Account Migration ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let vault = create_vault_v1(&alice, 1_000).await;
+
+    println!("1. Alice migrates and sets fee_rate = 0");
+    migrate_v1_to_v2(&vault, &alice).await.unwrap();
+    set_fee_rate(&vault, &alice, 0).await.unwrap();
+
+    println!("\n2. Mallory migrates Alice's V2 vault again");
+    let result = migrate_v1_to_v2(&vault, &mallory).await;
+
+    // Vulnerable: discriminator and authority ignored
+    assert!(result.is_ok());
+    let v2: VaultV2 = get_account(&vault).await;
+    assert_eq!(v2.fee_rate, DEFAULT_FEE_RATE_BPS);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Alice's fee_rate reset to {} bps", v2.fee_rate);
+}
+
+#[tokio::test]
+async fn test_migration_preserves_v1_data() {
+    println!("\n=== SECURITY: Safe V1 -> V2 Migration ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let vault = create_vault_v1(&alice, 1_000).await;
+    let before: VaultV1 = get_account(&vault).await;
+
+    println!("1. Mallory tries to migrate Alice's vault");
+    let result = migrate_v1_to_v2(&vault, &mallory).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Signer is not the vault authority");
+
+    println!("\n2. Alice migrates");
+    migrate_v1_to_v2(&vault, &alice).await.unwrap();
+
+    let after: VaultV2 = get_account(&vault).await;
+    assert_eq!(after.authority, before.authority);
+    assert_eq!(after.balance, before.balance);
+    assert_eq!(after.fee_rate, DEFAULT_FEE_RATE_BPS);
+    assert!(after.created_at > 0);
+    assert_eq!(get_data_len(&vault).await, 8 + VaultV2::LEN);
+    println!("   ✓ authority and balance unchanged");
+
+    println!("\n3. Alice tries to migrate again");
+    set_fee_rate(&vault, &alice, 0).await.unwrap();
+    let result = migrate_v1_to_v2(&vault, &alice).await;
+    assert!(result.is_err());
+    assert_eq!(get_account::<VaultV2>(&vault).await.fee_rate, 0);
+    println!("   ✓ Error: Account discriminator mismatch");
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+declare_id!("Vuln15115115115115115115115115115115115115");
+
+pub const DEFAULT_FEE_RATE_BPS: u16 = 30;
+
+#[program]
+pub mod vulnerable_account_realloc {
+    use super::*;
+
+    /// Owner-only fee update on a migrated vault
+    pub fn set_fee_rate(ctx: Context<SetFeeRate>, fee_rate: u16) -> Result<()> {
+        ctx.accounts.vault.fee_rate = fee_rate;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Migration Without Version Or Authority Checks
+    ///
+    /// The account is grown with `realloc` and the V2 fields are filled with
+    /// defaults. The old layout is read with `try_deserialize_unchecked`,
+    /// which ignores the discriminator, and nobody has to prove they own
+    /// the vault.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice migrates her vault and calls `set_fee_rate(0)` - she runs a
+    ///    fee-free vault for her community
+    /// 2. Mallory calls `migrate_v1_to_v2` on Alice's already-migrated vault,
+    ///    paying the (zero) realloc difference herself
+    /// 3. The V2 account is read as if it were V1 - the shared prefix
+    ///    `authority, balance` parses fine
+    /// 4. `fee_rate` and `created_at` are overwritten with defaults
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Migrations are state writes and need the same authorization as any
+    ///   other write
+    /// - A migration that can run twice silently resets upgraded state
+    pub fn migrate_v1_to_v2(ctx: Context<MigrateV1ToV2>) -> Result<()> {
+        let vault_info = ctx.accounts.vault.to_account_info();
+
+        // ❌ Discriminator ignored - V2 accounts are accepted as V1
+        let v1 = {
+            let data = vault_info.try_borrow_data()?;
+            VaultV1::try_deserialize_unchecked(&mut &data[..])?
+        };
+
+        // ❌ No check that the payer is v1.authority
+        let v2 = VaultV2 {
+            authority: v1.authority,
+            balance: v1.balance,
+            fee_rate: DEFAULT_FEE_RATE_BPS,
+            created_at: Clock::get()?.unix_timestamp,
+        };
+
+        grow_account(
+            &vault_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + VaultV2::LEN,
+        )?;
+        let mut data = vault_info.try_borrow_mut_data()?;
+        v2.try_serialize(&mut &mut data[..])?;
+
+        msg!("Migrated vault {} to V2", vault_info.key());
+        Ok(())
+    }
+}
+
+/// Grow `account` to `new_len` bytes, keeping its data, with `payer`
+/// covering the extra rent
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.realloc(new_len, false)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetFeeRate<'info> {
+    #[account(mut, has_one = authority)]
+    pub vault: Account<'info, VaultV2>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateV1ToV2<'info> {
+    /// CHECK: Layout is read manually in the handler
+    #[account(mut, owner = crate::ID)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// ❌ Anyone can pay for and trigger the migration
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct VaultV1 {
+    pub authority: Pubkey,
+    pub balance: u64,
+}
+
+impl VaultV1 {
+    pub const LEN: usize = 32 + // authority
+        8; // balance
+}
+
+#[account]
+pub struct VaultV2 {
+    pub authority: Pubkey,
+    pub balance: u64,
+    pub fee_rate: u16,
+    pub created_at: i64,
+}
+
+impl VaultV2 {
+    pub const LEN: usize = 32 + // authority
+        8 + // balance
+        2 + // fee_rate
+        8; // created_at
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+declare_id!("Secur15215215215215215215215215215215215215");
+
+/// Runtime builtin that sets compute limits; has no effect when invoked
+const COMPUTE_BUDGET_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+#[program]
+pub mod secure_compute_budget_filter {
+    use super::*;
+
+    /// SECURE: Routed Programs Filtered Before Forwarding
+    ///
+    /// Only programs the admin has allowlisted can be routed to, and the
+    /// ComputeBudget program is rejected outright so that a misconfigured
+    /// allowlist still cannot turn its no-op CPIs into rewards.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `program_id != COMPUTE_BUDGET_ID` for every call
+    /// 2. `program_id` must be in `router.allowed_programs`
+    /// 3. The target account must be executable
+    /// 4. Keeper rewards are only credited after all checks pass
+    pub fn route(ctx: Context<Route>, calls: Vec<RoutedIx>) -> Result<()> {
+        for call in calls.iter() {
+            let program = ctx
+                .remaining_accounts
+                .get(call.program_index as usize)
+                .ok_or(ErrorCode::InvalidAccountIndex)?;
+            let program_id = program.key();
+
+            // ✅ Runtime builtin - never forward
+            require!(
+                program_id != COMPUTE_BUDGET_ID,
+                ErrorCode::ForbiddenProgram
+            );
+            // ✅ Explicit allowlist
+            require!(
+                ctx.accounts.router.allowed_programs.contains(&program_id),
+                ErrorCode::ForbiddenProgram
+            );
+            require!(program.executable, ErrorCode::ForbiddenProgram);
+
+            let mut metas = Vec::with_capacity(call.account_indices.len());
+            let mut infos = vec![program.clone()];
+            for index in call.account_indices.iter() {
+                let account = ctx
+                    .remaining_accounts
+                    .get(*index as usize)
+                    .ok_or(ErrorCode::InvalidAccountIndex)?;
+                metas.push(AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                });
+                infos.push(account.clone());
+            }
+
+            let ix = Instruction {
+                program_id,
+                accounts: metas,
+                data: call.data.clone(),
+            };
+            invoke(&ix, &infos)?;
+
+            credit_keeper(&mut ctx.accounts.router, &mut ctx.accounts.keeper)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn credit_keeper(router: &mut Router, keeper: &mut Keeper) -> Result<()> {
+    router.reward_pool = router
+        .reward_pool
+        .checked_sub(router.reward_per_call)
+        .ok_or(ErrorCode::RewardPoolExhausted)?;
+    keeper.routed_calls = keeper
+        .routed_calls
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    keeper.earned = keeper
+        .earned
+        .checked_add(router.reward_per_call)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Route<'info> {
+    #[account(mut)]
+    pub router: Account<'info, Router>,
+
+    #[account(mut, has_one = authority)]
+    pub keeper: Account<'info, Keeper>,
+
+    pub authority: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoutedIx {
+    pub program_index: u8,
+    pub account_indices: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+#[account]
+pub struct Router {
+    pub admin: Pubkey,
+    pub allowed_programs: Vec<Pubkey>,
+    pub reward_per_call: u64,
+    pub reward_pool: u64,
+}
+
+#[account]
+pub struct Keeper {
+    pub authority: Pubkey,
+    pub routed_calls: u64,
+    pub earned: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Program may not be routed to")]
+    ForbiddenProgram,
+
+    #[msg("Account index out of range")]
+    InvalidAccountIndex,
+
+    #[msg("Reward pool exhausted")]
+    RewardPoolExhausted,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
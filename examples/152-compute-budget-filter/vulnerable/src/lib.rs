@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+declare_id!("Vuln15215215215215215215215215215215215215");
+
+#[program]
+pub mod vulnerable_compute_budget_filter {
+    use super::*;
+
+    /// VULNERABILITY: Router Pays For Any Forwarded Instruction
+    ///
+    /// Keepers earn `reward_per_call` for every instruction the router
+    /// forwards. The target program is read from remaining accounts and
+    /// never filtered. The ComputeBudget program is a builtin whose
+    /// instructions only matter at transaction level - invoked through CPI
+    /// they do nothing and succeed.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory registers as a keeper
+    /// 2. She calls `route` with 50 calls to the ComputeBudget program, each
+    ///    carrying a `SetComputeUnitPrice` payload
+    /// 3. Every CPI succeeds as a no-op
+    /// 4. The router counts 50 "executed" calls and credits her 50 rewards
+    ///    without any useful work being done
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Runtime builtins do not behave like regular programs under CPI
+    /// - Per-call accounting turns free no-ops into a reward faucet
+    pub fn route(ctx: Context<Route>, calls: Vec<RoutedIx>) -> Result<()> {
+        for call in calls.iter() {
+            let program = ctx
+                .remaining_accounts
+                .get(call.program_index as usize)
+                .ok_or(ErrorCode::InvalidAccountIndex)?;
+
+            // ❌ Any program id is forwarded, including ComputeBudget
+            let mut metas = Vec::with_capacity(call.account_indices.len());
+            let mut infos = vec![program.clone()];
+            for index in call.account_indices.iter() {
+                let account = ctx
+                    .remaining_accounts
+                    .get(*index as usize)
+                    .ok_or(ErrorCode::InvalidAccountIndex)?;
+                metas.push(AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                });
+                infos.push(account.clone());
+            }
+
+            let ix = Instruction {
+                program_id: program.key(),
+                accounts: metas,
+                data: call.data.clone(),
+            };
+            invoke(&ix, &infos)?;
+
+            // ❌ A no-op counts the same as real work
+            credit_keeper(&mut ctx.accounts.router, &mut ctx.accounts.keeper)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn credit_keeper(router: &mut Router, keeper: &mut Keeper) -> Result<()> {
+    router.reward_pool = router
+        .reward_pool
+        .checked_sub(router.reward_per_call)
+        .ok_or(ErrorCode::RewardPoolExhausted)?;
+    keeper.routed_calls = keeper
+        .routed_calls
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    keeper.earned = keeper
+        .earned
+        .checked_add(router.reward_per_call)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Route<'info> {
+    #[account(mut)]
+    pub router: Account<'info, Router>,
+
+    #[account(mut, has_one = authority)]
+    pub keeper: Account<'info, Keeper>,
+
+    pub authority: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoutedIx {
+    pub program_index: u8,
+    pub account_indices: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+#[account]
+pub struct Router {
+    pub admin: Pubkey,
+    pub reward_per_call: u64,
+    pub reward_pool: u64,
+}
+
+#[account]
+pub struct Keeper {
+    pub authority: Pubkey,
+    pub routed_calls: u64,
+    pub earned: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Account index out of range")]
+    InvalidAccountIndex,
+
+    #[msg("Reward pool exhausted")]
+    RewardPoolExhausted,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln15315315315315315315315315315315315315");
+
+#[program]
+pub mod vulnerable_pda_collision {
+    use super::*;
+
+    /// VULNERABILITY: Two Position Accounts, No Seeds, No Distinctness Check
+    ///
+    /// A user's long position lives at `[b"long", user]` and the short
+    /// position at `[b"short", user]`. The instruction expects one of each
+    /// but only checks `has_one = user`, so nothing stops both slots from
+    /// resolving to the same address.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory has a long position with 1,000 collateral
+    /// 2. She calls `rebalance(1_000)` passing the long account as both
+    ///    `long_position` and `short_position`
+    /// 3. Anchor deserializes two independent copies of the same data
+    /// 4. The long copy is debited to 0, the short copy is credited to 2,000
+    /// 5. On exit both copies are written back in field order - the short
+    ///    copy is written last and wins
+    /// 6. The account now holds 2,000 collateral
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Same-address aliasing turns a transfer into a mint
+    /// - `has_one` proves ownership, not which PDA an account is
+    pub fn rebalance(ctx: Context<Rebalance>, amount: u64) -> Result<()> {
+        // ❌ long_position and short_position may be the same account
+        let long = &mut ctx.accounts.long_position;
+        long.collateral = long
+            .collateral
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientCollateral)?;
+
+        let short = &mut ctx.accounts.short_position;
+        short.collateral = short
+            .collateral
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Moved {} collateral from long to short", amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Rebalance<'info> {
+    /// ❌ No seeds - any position owned by `user`
+    #[account(mut, has_one = user)]
+    pub long_position: Account<'info, Position>,
+
+    /// ❌ No seeds - may alias long_position
+    #[account(mut, has_one = user)]
+    pub short_position: Account<'info, Position>,
+
+    pub user: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Position {
+    pub user: Pubkey,
+    pub collateral: u64,
+    pub size: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient collateral")]
+    InsufficientCollateral,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
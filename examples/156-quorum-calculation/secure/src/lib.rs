@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+declare_id!("Secur15615615615615615615615615615615615615");
+
+pub const QUORUM_PERCENT: u64 = 10;
+
+/// ~1 hour at 400ms slots
+pub const MAX_SUPPLY_STALENESS_SLOTS: u64 = 9_000;
+
+#[program]
+pub mod secure_quorum_calculation {
+    use super::*;
+
+    /// Permissionless crank: recompute circulating supply from the mint and
+    /// the registered vesting escrows
+    pub fn update_circulating_supply<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateCirculatingSupply<'info>>,
+    ) -> Result<()> {
+        let oracle = &mut ctx.accounts.circulating_supply;
+        require!(
+            ctx.remaining_accounts.len() == oracle.vesting_escrows.len(),
+            ErrorCode::EscrowMismatch
+        );
+
+        let mut locked: u64 = 0;
+        for (info, expected) in ctx
+            .remaining_accounts
+            .iter()
+            .zip(oracle.vesting_escrows.iter())
+        {
+            // ✅ Only the registered escrows, in registration order
+            require_keys_eq!(info.key(), *expected, ErrorCode::EscrowMismatch);
+            let escrow = Account::<TokenAccount>::try_from(info)?;
+            require_keys_eq!(
+                escrow.mint,
+                ctx.accounts.governance_mint.key(),
+                ErrorCode::EscrowMismatch
+            );
+            locked = locked
+                .checked_add(escrow.amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        let total = ctx.accounts.governance_mint.supply;
+        oracle.total_supply = total;
+        oracle.locked_in_vesting = locked;
+        oracle.circulating = total
+            .checked_sub(locked)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        oracle.last_update_slot = Clock::get()?.slot;
+        Ok(())
+    }
+
+    /// SECURE: Quorum Measured Against Circulating Supply
+    ///
+    /// Only tokens that can vote are used as the denominator. A
+    /// `CirculatingSupply` account tracks mint supply minus everything held
+    /// in vesting escrows and is refreshed by a crank.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `quorum = circulating * QUORUM_PERCENT / 100`
+    /// 2. The supply reading must be fresher than `MAX_SUPPLY_STALENESS_SLOTS`
+    /// 3. Escrow list is fixed in the oracle account, so the crank cannot
+    ///    inflate `locked_in_vesting` with arbitrary token accounts
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        let clock = Clock::get()?;
+        let supply = &ctx.accounts.circulating_supply;
+
+        // ✅ Stale readings rejected
+        require!(
+            clock.slot.saturating_sub(supply.last_update_slot) <= MAX_SUPPLY_STALENESS_SLOTS,
+            ErrorCode::StaleSupply
+        );
+
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.finalized, ErrorCode::AlreadyFinalized);
+        require!(
+            clock.unix_timestamp >= proposal.voting_ends_at,
+            ErrorCode::VotingActive
+        );
+
+        // ✅ Only voting-capable tokens in the denominator
+        let quorum = supply
+            .circulating
+            .checked_mul(QUORUM_PERCENT)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 100;
+
+        let turnout = proposal
+            .votes_for
+            .checked_add(proposal.votes_against)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        proposal.passed = turnout >= quorum && proposal.votes_for > proposal.votes_against;
+        proposal.finalized = true;
+
+        msg!("Turnout {} / quorum {}: passed = {}", turnout, quorum, proposal.passed);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateCirculatingSupply<'info> {
+    #[account(has_one = governance_mint, has_one = circulating_supply)]
+    pub dao: Account<'info, Dao>,
+
+    #[account(mut)]
+    pub circulating_supply: Account<'info, CirculatingSupply>,
+
+    pub governance_mint: Account<'info, Mint>,
+    // Vesting escrow token accounts are passed as remaining accounts
+}
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(has_one = circulating_supply)]
+    pub dao: Account<'info, Dao>,
+
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+
+    pub circulating_supply: Account<'info, CirculatingSupply>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Dao {
+    pub governance_mint: Pubkey,
+    pub circulating_supply: Pubkey,
+}
+
+#[account]
+pub struct CirculatingSupply {
+    pub vesting_escrows: Vec<Pubkey>,
+    pub total_supply: u64,
+    pub locked_in_vesting: u64,
+    pub circulating: u64,
+    pub last_update_slot: u64,
+}
+
+#[account]
+pub struct Proposal {
+    pub dao: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub voting_ends_at: i64,
+    pub finalized: bool,
+    pub passed: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Circulating supply reading is stale")]
+    StaleSupply,
+
+    #[msg("Escrow accounts do not match the registered list")]
+    EscrowMismatch,
+
+    #[msg("Proposal already finalized")]
+    AlreadyFinalized,
+
+    #[msg("Voting period still active")]
+    VotingActive,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * A quorum is a fraction of the votes that could be cast. Tokens that are
+ * locked, burned, or held by the treasury cannot vote and must not sit in
+ * the denominator.
+ */
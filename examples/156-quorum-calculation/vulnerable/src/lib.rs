@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+declare_id!("Vuln15615615615615615615615615615615615615");
+
+pub const QUORUM_PERCENT: u64 = 10;
+
+#[program]
+pub mod vulnerable_quorum_calculation {
+    use super::*;
+
+    /// VULNERABILITY: Quorum Measured Against Total Supply
+    ///
+    /// `quorum = total_supply * 10 / 100`. Tokens sitting in vesting
+    /// escrows count towards the denominator but can never vote. With half
+    /// the supply locked, the real bar is 20% of the tokens that can vote.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. 1,000,000 total supply, 500,000 locked in team/investor vesting
+    /// 2. Quorum is 100,000 - 20% of the 500,000 circulating
+    /// 3. Typical honest turnout is ~8% of circulating (40,000), so ordinary
+    ///    proposals - including security fixes - fail quorum and expire
+    /// 4. Mallory accumulates 100,000 tokens and becomes the only party
+    ///    that can pass anything on her own
+    /// 5. She blocks the fix by abstaining and passes her own proposals
+    ///    whenever the honest minority does not show up
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Governance is paralysed for honest voters
+    /// - A single bloc of "only" 10% of supply gains agenda control
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.finalized, ErrorCode::AlreadyFinalized);
+        require!(
+            Clock::get()?.unix_timestamp >= proposal.voting_ends_at,
+            ErrorCode::VotingActive
+        );
+
+        // ❌ Includes tokens that cannot vote
+        let quorum = ctx
+            .accounts
+            .governance_mint
+            .supply
+            .checked_mul(QUORUM_PERCENT)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 100;
+
+        let turnout = proposal
+            .votes_for
+            .checked_add(proposal.votes_against)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        proposal.passed = turnout >= quorum && proposal.votes_for > proposal.votes_against;
+        proposal.finalized = true;
+
+        msg!("Turnout {} / quorum {}: passed = {}", turnout, quorum, proposal.passed);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(has_one = governance_mint)]
+    pub dao: Account<'info, Dao>,
+
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+
+    pub governance_mint: Account<'info, Mint>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Dao {
+    pub governance_mint: Pubkey,
+}
+
+#[account]
+pub struct Proposal {
+    pub dao: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub voting_ends_at: i64,
+    pub finalized: bool,
+    pub passed: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Proposal already finalized")]
+    AlreadyFinalized,
+
+    #[msg("Voting period still active")]
+    VotingActive,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+declare_id!("Secur15715715715715715715715715715715715715");
+
+#[program]
+pub mod secure_program_data_validation {
+    use super::*;
+
+    /// SECURE: ProgramData Address Derived From The Target Program
+    ///
+    /// A program's ProgramData lives at a PDA of the upgradeable loader
+    /// seeded by the program id. Deriving that address and comparing it
+    /// means the upgrade authority read belongs to the program being
+    /// approved and to no other.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `target_program` must be owned by the upgradeable loader
+    /// 2. `program_data` typed as `Account<ProgramData>` - owner is the
+    ///    loader, so fake ProgramData bytes are rejected
+    /// 3. `program_data.key()` must equal the loader PDA `[target_program]`
+    /// 4. Upgrade authority must be the DAO
+    pub fn register_plugin(ctx: Context<RegisterPlugin>) -> Result<()> {
+        // ✅ Expected ProgramData address for this exact program
+        let (expected_program_data, _) = Pubkey::find_program_address(
+            &[ctx.accounts.target_program.key().as_ref()],
+            &bpf_loader_upgradeable::id(),
+        );
+        require_keys_eq!(
+            ctx.accounts.program_data.key(),
+            expected_program_data,
+            ErrorCode::ProgramDataMismatch
+        );
+
+        require!(
+            ctx.accounts.program_data.upgrade_authority_address
+                == Some(ctx.accounts.registry.dao_authority),
+            ErrorCode::NotDaoControlled
+        );
+
+        let plugin = &mut ctx.accounts.plugin;
+        plugin.program_id = ctx.accounts.target_program.key();
+        plugin.approved = true;
+
+        msg!("Approved plugin {}", plugin.program_id);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RegisterPlugin<'info> {
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Plugin::LEN,
+        seeds = [b"plugin", registry.key().as_ref(), target_program.key().as_ref()],
+        bump,
+    )]
+    pub plugin: Account<'info, Plugin>,
+
+    /// CHECK: ✅ Must be an upgradeable program
+    #[account(executable, owner = bpf_loader_upgradeable::id())]
+    pub target_program: AccountInfo<'info>,
+
+    /// ✅ Owned by the loader; address checked in the handler
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Registry {
+    pub dao_authority: Pubkey,
+}
+
+#[account]
+pub struct Plugin {
+    pub program_id: Pubkey,
+    pub approved: bool,
+}
+
+impl Plugin {
+    pub const LEN: usize = 32 + // program_id
+        1; // approved
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("ProgramData does not belong to the target program")]
+    ProgramDataMismatch,
+
+    #[msg("Program upgrade authority is not the DAO")]
+    NotDaoControlled,
+}
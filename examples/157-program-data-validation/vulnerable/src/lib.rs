@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln15715715715715715715715715715715715715");
+
+#[program]
+pub mod vulnerable_program_data_validation {
+    use super::*;
+
+    /// VULNERABILITY: ProgramData Not Tied To The Target Program
+    ///
+    /// The registry approves plugin programs whose upgrade authority is the
+    /// DAO, so that a plugin's code can never change without a vote.
+    /// Approved plugins are later invoked with the registry's vault PDA as
+    /// signer. The upgrade authority is read from a caller-supplied
+    /// `program_data` account that is never linked to `target_program`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory deploys plugin M with herself as upgrade authority
+    /// 2. She calls `register_plugin` with `target_program = M` but passes
+    ///    the ProgramData of an existing DAO-controlled plugin - or an
+    ///    account her own program owns, filled with fake ProgramData bytes
+    /// 3. The deserialized `upgrade_authority_address` is the DAO
+    /// 4. M is approved
+    /// 5. Mallory upgrades M to code that drains the vault on its next CPI
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The check passes on data describing a different program
+    /// - Approval of upgradeable code is approval of every future version
+    pub fn register_plugin(ctx: Context<RegisterPlugin>) -> Result<()> {
+        // ❌ No owner check and no address check on program_data
+        let program_data = {
+            let data = ctx.accounts.program_data.try_borrow_data()?;
+            ProgramData::try_deserialize(&mut &data[..])?
+        };
+        let upgrade_authority = program_data.upgrade_authority_address;
+
+        require!(
+            upgrade_authority == Some(ctx.accounts.registry.dao_authority),
+            ErrorCode::NotDaoControlled
+        );
+
+        let plugin = &mut ctx.accounts.plugin;
+        plugin.program_id = ctx.accounts.target_program.key();
+        plugin.approved = true;
+
+        msg!("Approved plugin {}", plugin.program_id);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RegisterPlugin<'info> {
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Plugin::LEN,
+        seeds = [b"plugin", registry.key().as_ref(), target_program.key().as_ref()],
+        bump,
+    )]
+    pub plugin: Account<'info, Plugin>,
+
+    /// CHECK: Program being registered
+    #[account(executable)]
+    pub target_program: AccountInfo<'info>,
+
+    /// CHECK: ❌ Trusted to be target_program's ProgramData
+    pub program_data: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Registry {
+    pub dao_authority: Pubkey,
+}
+
+#[account]
+pub struct Plugin {
+    pub program_id: Pubkey,
+    pub approved: bool,
+}
+
+impl Plugin {
+    pub const LEN: usize = 32 + // program_id
+        1; // approved
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Program upgrade authority is not the DAO")]
+    NotDaoControlled,
+}
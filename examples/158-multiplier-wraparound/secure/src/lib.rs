@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur15815815815815815815815815815815815815");
+
+/// Lamports paid to the pool per boost
+pub const BOOST_PRICE: u64 = 1_000_000;
+
+/// Highest multiplier a stake can reach
+pub const MAX_MULTIPLIER: u16 = 10;
+
+#[program]
+pub mod secure_multiplier_wraparound {
+    use super::*;
+
+    /// SECURE: Bounded Multiplier With Checked Arithmetic
+    ///
+    /// The multiplier is widened to `u16`, increased with `checked_add`,
+    /// and capped well below the type's limit, so it can never wrap and
+    /// never exceed the protocol's intended range.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `u16` storage
+    /// 2. `checked_add(delta)` - overflow is an error, not a wrap
+    /// 3. `new_multiplier <= MAX_MULTIPLIER`
+    /// 4. Boost cost scales with `delta`
+    pub fn increase_multiplier(ctx: Context<IncreaseMultiplier>, delta: u16) -> Result<()> {
+        require!(delta > 0, ErrorCode::InvalidDelta);
+
+        let stake = &mut ctx.accounts.stake;
+
+        // ✅ Overflow is an error
+        let new_multiplier = stake
+            .multiplier
+            .checked_add(delta)
+            .ok_or(ErrorCode::MultiplierOverflow)?;
+        // ✅ Protocol cap
+        require!(
+            new_multiplier <= MAX_MULTIPLIER,
+            ErrorCode::MultiplierTooHigh
+        );
+        stake.multiplier = new_multiplier;
+
+        let cost = BOOST_PRICE
+            .checked_mul(delta as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let pool = &mut ctx.accounts.pool;
+        pool.boost_revenue = pool
+            .boost_revenue
+            .checked_add(cost)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Stake {} multiplier now {}", stake.key(), stake.multiplier);
+        Ok(())
+    }
+
+    /// Reward weight used by the distributor
+    pub fn refresh_weight(ctx: Context<RefreshWeight>) -> Result<()> {
+        let stake = &mut ctx.accounts.stake;
+        stake.weight = stake
+            .amount
+            .checked_mul(stake.multiplier as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct IncreaseMultiplier<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool)]
+    pub stake: Account<'info, Stake>,
+
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshWeight<'info> {
+    #[account(mut)]
+    pub stake: Account<'info, Stake>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub boost_revenue: u64,
+}
+
+#[account]
+pub struct Stake {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub multiplier: u16, // ✅ Widened and capped
+    pub weight: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Boost delta must be positive")]
+    InvalidDelta,
+
+    #[msg("Multiplier overflow")]
+    MultiplierOverflow,
+
+    #[msg("Multiplier exceeds maximum")]
+    MultiplierTooHigh,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln15815815815815815815815815815815815815");
+
+/// Lamports paid to the pool per boost
+pub const BOOST_PRICE: u64 = 1_000_000;
+
+#[program]
+pub mod vulnerable_multiplier_wraparound {
+    use super::*;
+
+    /// VULNERABILITY: u8 Multiplier Wraps From 255 To 0
+    ///
+    /// Anyone can gift a boost to any stake; each boost raises the reward
+    /// multiplier by one. The multiplier is a `u8` incremented with `+=`.
+    /// Programs built without `overflow-checks = true` (the release
+    /// default) wrap silently instead of panicking.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice holds the largest stake, multiplier 1
+    /// 2. Mallory gifts Alice 255 boosts (cheap relative to the pool)
+    /// 3. 1 + 255 = 256 wraps to 0
+    /// 4. Alice's reward weight is `amount * 0 = 0`
+    /// 5. Her share of every distribution is redistributed to the other
+    ///    stakers, including Mallory
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - "More boost" ends up meaning "no rewards"
+    /// - Narrow integer types make the wrap point reachable
+    pub fn increase_multiplier(ctx: Context<IncreaseMultiplier>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.boost_revenue = pool
+            .boost_revenue
+            .checked_add(BOOST_PRICE)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // ❌ u8 with no upper bound - wraps at 256
+        let stake = &mut ctx.accounts.stake;
+        stake.multiplier += 1;
+
+        msg!("Stake {} multiplier now {}", stake.key(), stake.multiplier);
+        Ok(())
+    }
+
+    /// Reward weight used by the distributor
+    pub fn refresh_weight(ctx: Context<RefreshWeight>) -> Result<()> {
+        let stake = &mut ctx.accounts.stake;
+        stake.weight = stake
+            .amount
+            .checked_mul(stake.multiplier as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct IncreaseMultiplier<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+
+    /// Any stake in the pool can receive a boost
+    #[account(mut, has_one = pool)]
+    pub stake: Account<'info, Stake>,
+
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshWeight<'info> {
+    #[account(mut)]
+    pub stake: Account<'info, Stake>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub boost_revenue: u64,
+}
+
+#[account]
+pub struct Stake {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub multiplier: u8, // ❌ Wraps at 256
+    pub weight: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
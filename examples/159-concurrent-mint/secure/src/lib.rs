@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur15915915915915915915915915915915915915");
+
+#[program]
+pub mod secure_concurrent_mint {
+    use super::*;
+
+    /// SECURE: Check And Increment The Same Write-Locked Counter
+    ///
+    /// The Solana runtime never executes two transactions that write the
+    /// same account at the same time. Marking `collection` as `mut` puts
+    /// every mint in a single queue, and because the cap check and the
+    /// increment happen in the same instruction, each mint sees the result
+    /// of the one before it.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `collection` is writable - the runtime serializes all mints
+    /// 2. `minted < max_supply` and `minted += 1` in one instruction
+    /// 3. No secondary counters to drift from the cap
+    ///
+    /// WHY NO EXTRA LOCK:
+    /// Instructions inside one transaction already run sequentially and see
+    /// each other's writes, so several mints packed into one transaction are
+    /// counted one after another like any others. A slot-based or
+    /// realloc-based lock would add state without closing any gap.
+    pub fn mint_nft(ctx: Context<MintNft>) -> Result<()> {
+        let collection = &mut ctx.accounts.collection;
+
+        // ✅ Checked against the live total
+        require!(
+            collection.minted < collection.max_supply,
+            ErrorCode::SoldOut
+        );
+
+        // ✅ Incremented under the same write lock
+        collection.minted = collection
+            .minted
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Minted {} of {}", collection.minted, collection.max_supply);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct MintNft<'info> {
+    /// ✅ Writable - the runtime serializes every mint on this account
+    #[account(mut)]
+    pub collection: Account<'info, Collection>,
+
+    pub minter: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Collection {
+    pub authority: Pubkey,
+    pub max_supply: u64,
+    pub minted: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Collection is sold out")]
+    SoldOut,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * Any invariant that must hold across transactions - a cap, a total, a
+ * balance - has to live in an account that each of those transactions
+ * writes. Read-only accounts buy parallelism by giving up ordering.
+ */
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln15915915915915915915915915915915915915");
+
+#[program]
+pub mod vulnerable_concurrent_mint {
+    use super::*;
+
+    /// VULNERABILITY: Supply Cap Checked Against A Counter Nobody Locks
+    ///
+    /// To let mints run in parallel, the collection account is passed
+    /// read-only and each mint only writes to one of several shard
+    /// counters. `collection.minted` is refreshed later by a `sync_supply`
+    /// crank. Transactions that do not write the same account are scheduled
+    /// concurrently, so every mint between two syncs checks the same stale
+    /// total.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. `max_supply = 10_000`, last sync recorded `minted = 9_990`
+    /// 2. Mallory sends 200 mint transactions spread across all shards in
+    ///    the same few slots
+    /// 3. Each one reads `collection.minted = 9_990 < 10_000` and succeeds
+    /// 4. The next sync records 10_190 - 190 NFTs beyond the cap
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A read-only account gives no ordering guarantee between readers
+    /// - "Limited" supply is diluted, devaluing every holder's NFT
+    pub fn mint_nft(ctx: Context<MintNft>) -> Result<()> {
+        let collection = &ctx.accounts.collection;
+
+        // ❌ Stale, unlocked total
+        require!(
+            collection.minted < collection.max_supply,
+            ErrorCode::SoldOut
+        );
+
+        // ❌ Only the shard is write-locked
+        let shard = &mut ctx.accounts.shard;
+        shard.minted = shard
+            .minted
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Minted from shard {} ({} so far)", shard.index, shard.minted);
+        Ok(())
+    }
+
+    /// Crank: fold shard counters into the collection total
+    pub fn sync_supply<'info>(ctx: Context<'_, '_, 'info, 'info, SyncSupply<'info>>) -> Result<()> {
+        let collection = &mut ctx.accounts.collection;
+        let mut total: u64 = 0;
+        for info in ctx.remaining_accounts.iter() {
+            let shard = Account::<MintShard>::try_from(info)?;
+            require_keys_eq!(shard.collection, collection.key(), ErrorCode::InvalidShard);
+            total = total
+                .checked_add(shard.minted)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        collection.minted = total;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct MintNft<'info> {
+    /// ❌ Read-only: no write lock, no serialization between mints
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        mut,
+        seeds = [b"shard", collection.key().as_ref(), &[shard.index]],
+        bump = shard.bump,
+    )]
+    pub shard: Account<'info, MintShard>,
+
+    pub minter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SyncSupply<'info> {
+    #[account(mut)]
+    pub collection: Account<'info, Collection>,
+    // Shard accounts are passed as remaining accounts
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Collection {
+    pub authority: Pubkey,
+    pub max_supply: u64,
+    pub minted: u64,
+}
+
+#[account]
+pub struct MintShard {
+    pub collection: Pubkey,
+    pub index: u8,
+    pub minted: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Collection is sold out")]
+    SoldOut,
+
+    #[msg("Shard does not belong to this collection")]
+    InvalidShard,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
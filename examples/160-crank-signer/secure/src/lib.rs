@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur16o16o16o16o16o16o16o16o16o16o16o16o16");
+
+#[program]
+pub mod secure_crank_signer {
+    use super::*;
+
+    /// SECURE: Authorized Crank Must Also Sign
+    ///
+    /// Allowlist membership answers "is this key trusted?"; the signature
+    /// answers "is the key holder actually here?". Both are required.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `crank: Signer<'info>` - Anchor rejects the transaction unless the
+    ///    crank signed it
+    /// 2. `config.authorized_cranks.contains(&crank.key())` enforced as an
+    ///    account constraint, before the handler runs
+    pub fn settle_position(ctx: Context<SettlePosition>, settlement_price: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        require!(!position.settled, ErrorCode::AlreadySettled);
+        require!(
+            Clock::get()?.unix_timestamp >= position.expiry,
+            ErrorCode::NotExpired
+        );
+
+        let price_delta = (settlement_price as i128)
+            .checked_sub(position.entry_price as i128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let pnl = price_delta
+            .checked_mul(position.size as i128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        position.realized_pnl = i64::try_from(pnl).map_err(|_| ErrorCode::ArithmeticOverflow)?;
+        position.settled = true;
+
+        msg!("Settled at {} with PnL {}", settlement_price, position.realized_pnl);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SettlePosition<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut, has_one = config)]
+    pub position: Account<'info, Position>,
+
+    /// ✅ Must sign and be on the allowlist
+    #[account(
+        constraint = config.authorized_cranks.contains(&crank.key())
+            @ ErrorCode::UnauthorizedCrank
+    )]
+    pub crank: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub authorized_cranks: Vec<Pubkey>,
+}
+
+#[account]
+pub struct Position {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub entry_price: u64,
+    pub size: u64,
+    pub expiry: i64,
+    pub realized_pnl: i64,
+    pub settled: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Crank is not authorized")]
+    UnauthorizedCrank,
+
+    #[msg("Position already settled")]
+    AlreadySettled,
+
+    #[msg("Position has not expired")]
+    NotExpired,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,47 @@
+#[tokio::test]
+async fn test_unsigned_crank_exploit() {
+    println!("\n=== EXPLOIT: Impersonating An Authorized Crank ===\n");
+
+    let crank = Keypair::new();
+    let mallory = Keypair::new();
+    let config = create_config(&[crank.pubkey()]).await;
+    let position = open_long(&config, &mallory, 100, 1_000).await;
+    warp_past_expiry(&position).await;
+    println!("1. Mallory's long expired (entry 100, real price 95)");
+
+    println!("\n2. Mallory settles at 200 using the crank's pubkey, unsigned");
+    let result = settle_position_unsigned(&config, &position, &crank.pubkey(), &mallory, 200).await;
+
+    // Vulnerable: allowlist check passes without a signature
+    assert!(result.is_ok());
+    assert_eq!(get_position(&position).await.realized_pnl, 100_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Settled at an attacker-chosen price");
+}
+
+#[tokio::test]
+async fn test_crank_must_sign() {
+    println!("\n=== SECURITY: Signed Crank ===\n");
+
+    let crank = Keypair::new();
+    let mallory = Keypair::new();
+    let config = create_config(&[crank.pubkey()]).await;
+    let position = open_long(&config, &mallory, 100, 1_000).await;
+    warp_past_expiry(&position).await;
+
+    println!("1. Crank pubkey without its signature");
+    let result = settle_position_unsigned(&config, &position, &crank.pubkey(), &mallory, 200).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: missing required signature");
+
+    println!("\n2. Mallory signs as the crank herself");
+    let result = settle_position(&config, &position, &mallory, 200).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Crank is not authorized");
+
+    println!("\n3. Authorized crank settles at 95");
+    settle_position(&config, &position, &crank, 95).await.unwrap();
+    assert_eq!(get_position(&position).await.realized_pnl, -5_000);
+    println!("   ✓ Settled at the real price");
+}
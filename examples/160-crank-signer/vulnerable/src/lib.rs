@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln16016016016016016016016016016016016016");
+
+#[program]
+pub mod vulnerable_crank_signer {
+    use super::*;
+
+    /// VULNERABILITY: Crank Allowlist Without A Signature
+    ///
+    /// Expired positions are settled by keeper bots listed in
+    /// `config.authorized_cranks`, which supply the settlement price. The
+    /// instruction checks that `crank` is on the list but takes it as an
+    /// `AccountInfo`, so the crank never has to sign.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Crank pubkeys are public - they are stored in `config`
+    /// 2. Mallory's long position expires with the real price at 95
+    ///    (entry 100, a loss)
+    /// 3. She calls `settle_position` with an authorized crank's pubkey as
+    ///    `crank` and `settlement_price = 200`
+    /// 4. The allowlist check passes; no signature is required
+    /// 5. Her position is settled at 200 for a large profit
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Whoever controls the settlement price controls every payout
+    /// - An allowlist of keys is only meaningful if the key holder signs
+    pub fn settle_position(ctx: Context<SettlePosition>, settlement_price: u64) -> Result<()> {
+        // ❌ Membership checked, signature never required
+        require!(
+            ctx.accounts
+                .config
+                .authorized_cranks
+                .contains(&ctx.accounts.crank.key()),
+            ErrorCode::UnauthorizedCrank
+        );
+
+        let position = &mut ctx.accounts.position;
+        require!(!position.settled, ErrorCode::AlreadySettled);
+        require!(
+            Clock::get()?.unix_timestamp >= position.expiry,
+            ErrorCode::NotExpired
+        );
+
+        let price_delta = (settlement_price as i128)
+            .checked_sub(position.entry_price as i128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let pnl = price_delta
+            .checked_mul(position.size as i128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        position.realized_pnl = i64::try_from(pnl).map_err(|_| ErrorCode::ArithmeticOverflow)?;
+        position.settled = true;
+
+        msg!("Settled at {} with PnL {}", settlement_price, position.realized_pnl);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SettlePosition<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut, has_one = config)]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: ❌ Only compared against the allowlist
+    pub crank: AccountInfo<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub authorized_cranks: Vec<Pubkey>,
+}
+
+#[account]
+pub struct Position {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub entry_price: u64,
+    pub size: u64,
+    pub expiry: i64,
+    pub realized_pnl: i64,
+    pub settled: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Crank is not authorized")]
+    UnauthorizedCrank,
+
+    #[msg("Position already settled")]
+    AlreadySettled,
+
+    #[msg("Position has not expired")]
+    NotExpired,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+    state::Account as Token2022Account,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+declare_id!("Secur16116116116116116116116116116116116116");
+
+#[program]
+pub mod secure_royalty_enforcer {
+    use super::*;
+
+    /// Creator registers the royalty and the hook's extra accounts.
+    ///
+    /// The sale price is negotiated off-chain and cannot be trusted, so the
+    /// hook enforces a fixed per-transfer royalty set by the creator - the
+    /// same trade-off pNFT rule sets make.
+    pub fn initialize_royalty(
+        ctx: Context<InitializeRoyalty>,
+        royalty_lamports: u64,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.royalty_config;
+        config.mint = ctx.accounts.mint.key();
+        config.creator = ctx.accounts.creator.key();
+        config.royalty_lamports = royalty_lamports;
+        config.bump = ctx.bumps.royalty_config;
+
+        // Accounts the token program must pass to `transfer_hook`:
+        // royalty_config = [b"royalty_config", mint]
+        // receipt        = [b"receipt", mint, destination_token]
+        let extra_metas = [
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"royalty_config".to_vec() },
+                    Seed::AccountKey { index: 1 },
+                ],
+                false,
+                false,
+            )?,
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"receipt".to_vec() },
+                    Seed::AccountKey { index: 1 },
+                    Seed::AccountKey { index: 2 },
+                ],
+                false,
+                true,
+            )?,
+        ];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_metas,
+        )?;
+        Ok(())
+    }
+
+    /// Pay the creator ahead of a transfer to `destination_token`
+    pub fn pay_royalty(ctx: Context<PayRoyalty>) -> Result<()> {
+        let config = &ctx.accounts.royalty_config;
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+            ),
+            config.royalty_lamports,
+        )?;
+
+        let receipt = &mut ctx.accounts.receipt;
+        receipt.paid = true;
+        receipt.bump = ctx.bumps.receipt;
+        Ok(())
+    }
+
+    /// SECURE: Transfer Hook Rejects Transfers Without A Royalty Receipt
+    ///
+    /// Token-2022 calls this hook on every transfer of the mint, whichever
+    /// program or wallet initiated it. There is no path around the
+    /// marketplace because the check lives in the token itself.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Hook runs for every transfer - no alternative transfer path
+    /// 2. Source account's `transferring` flag must be set, so the hook
+    ///    cannot be invoked directly to consume receipts
+    /// 3. A paid receipt for `[mint, destination]` must exist
+    /// 4. The receipt is consumed - one payment covers one transfer
+    ///
+    /// WHY NOT PRICE-BASED:
+    /// The chain cannot see an off-chain price. Creator-set flat royalties
+    /// (or pNFT rule sets restricting transfers to compliant marketplaces)
+    /// are the enforceable alternatives.
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+        // ✅ Only during a real Token-2022 transfer
+        let source_info = ctx.accounts.source_token.to_account_info();
+        let data = source_info.try_borrow_data()?;
+        let source = StateWithExtensions::<Token2022Account>::unpack(&data)?;
+        let hook_state = source.get_extension::<TransferHookAccount>()?;
+        require!(
+            bool::from(hook_state.transferring),
+            ErrorCode::NotTransferring
+        );
+
+        // ✅ Royalty paid for this destination
+        let receipt = &mut ctx.accounts.receipt;
+        require!(receipt.paid, ErrorCode::RoyaltyNotPaid);
+
+        // ✅ Single use
+        receipt.paid = false;
+
+        msg!("Royalty verified for transfer to {}", ctx.accounts.destination_token.key());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeRoyalty<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + RoyaltyConfig::LEN,
+        seeds = [b"royalty_config", mint.key().as_ref()],
+        bump,
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+
+    /// CHECK: Initialized as an ExtraAccountMetaList in the handler
+    #[account(
+        init,
+        payer = creator,
+        space = ExtraAccountMetaList::size_of(2)?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(mint::authority = creator)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayRoyalty<'info> {
+    #[account(
+        seeds = [b"royalty_config", royalty_config.mint.as_ref()],
+        bump = royalty_config.bump,
+        has_one = creator,
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RoyaltyReceipt::LEN,
+        seeds = [b"receipt", royalty_config.mint.as_ref(), destination_token.key().as_ref()],
+        bump,
+    )]
+    pub receipt: Account<'info, RoyaltyReceipt>,
+
+    #[account(token::mint = royalty_config.mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Royalty recipient, pinned by has_one
+    #[account(mut)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account order fixed by the transfer hook interface
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint, token::authority = owner)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Source owner or delegate
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by its seeds
+    #[account(seeds = [b"extra-account-metas", mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"royalty_config", mint.key().as_ref()],
+        bump = royalty_config.bump,
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"receipt", mint.key().as_ref(), destination_token.key().as_ref()],
+        bump = receipt.bump,
+    )]
+    pub receipt: Account<'info, RoyaltyReceipt>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct RoyaltyConfig {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub royalty_lamports: u64,
+    pub bump: u8,
+}
+
+impl RoyaltyConfig {
+    pub const LEN: usize = 32 + // mint
+        32 + // creator
+        8 + // royalty_lamports
+        1; // bump
+}
+
+#[account]
+pub struct RoyaltyReceipt {
+    pub paid: bool,
+    pub bump: u8,
+}
+
+impl RoyaltyReceipt {
+    pub const LEN: usize = 1 + // paid
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Hook called outside of a token transfer")]
+    NotTransferring,
+
+    #[msg("Royalty has not been paid for this transfer")]
+    RoyaltyNotPaid,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * Royalties enforced by a marketplace only bind people who use that
+ * marketplace. Enforcement has to live in the token: a Token-2022 transfer
+ * hook or a pNFT rule set runs on every transfer, whoever initiates it.
+ */
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln16116116116116116116116116116116116116");
+
+#[program]
+pub mod vulnerable_royalty_bypass {
+    use super::*;
+
+    /// VULNERABILITY: Royalty Computed From A Caller-Declared Price
+    ///
+    /// The marketplace charges `price * royalty_bps / 10_000` to the
+    /// creator when a sale goes through `sell`. The price is whatever the
+    /// two parties say it is, and the NFT itself is a classic SPL token that
+    /// can move without the marketplace at all.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Buyer and seller agree on 100 SOL in a chat
+    /// 2. The buyer sends the seller 100 SOL with a plain system transfer
+    /// 3. They call `sell(price = 0)` - royalty is `0 * bps = 0`
+    /// 4. The NFT moves; the creator receives nothing
+    /// 5. Equivalently they can skip `sell` and call `spl_token::transfer`
+    ///    directly
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Royalties depend on the honesty of the two parties who pay them
+    /// - Any enforcement that lives only in the marketplace can be routed
+    ///   around by not using the marketplace
+    pub fn sell(ctx: Context<Sell>, price: u64) -> Result<()> {
+        // ❌ Price is self-reported; 0 is accepted
+        let royalty = price
+            .checked_mul(ctx.accounts.collection.royalty_bps as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+            ),
+            royalty,
+        )?;
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            price - royalty,
+        )?;
+
+        // ❌ The NFT could have been moved like this without `sell`
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.seller_nft.to_account_info(),
+                    to: ctx.accounts.buyer_nft.to_account_info(),
+                    authority: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        msg!("Sold for {} (royalty {})", price, royalty);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Sell<'info> {
+    #[account(has_one = creator)]
+    pub collection: Account<'info, Collection>,
+
+    /// CHECK: Royalty recipient, pinned by has_one
+    #[account(mut)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut, token::authority = seller)]
+    pub seller_nft: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = seller_nft.mint, token::authority = buyer)]
+    pub buyer_nft: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Collection {
+    pub creator: Pubkey,
+    pub royalty_bps: u16,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur16216216216216216216216216216216216216");
+
+#[program]
+pub mod secure_close_then_reuse {
+    use super::*;
+
+    /// SECURE: Zero, Drain And Reassign On Close
+    ///
+    /// The receipt is wiped before its lamports leave. A later instruction
+    /// in the same transaction that is handed the same account finds no
+    /// discriminator and a System Program owner, and fails to deserialize.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `data.fill(0)` before lamports are moved - discriminator gone
+    /// 2. Ownership handed back to the System Program and data shrunk to 0,
+    ///    so Anchor's exit does not write the struct back
+    /// 3. Payout computed before closing, from data read once
+    pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
+        let amount = ctx.accounts.receipt.amount;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.escrowed = vault
+            .escrowed
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientEscrow)?;
+
+        let ledger = &mut ctx.accounts.user_ledger;
+        ledger.balance = ledger
+            .balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        close_account(
+            &ctx.accounts.receipt.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+        )?;
+
+        msg!("Redeemed {}", amount);
+        Ok(())
+    }
+}
+
+/// ✅ Wipe first, then move lamports, then give the account back
+pub fn close_account(account: &AccountInfo, destination: &AccountInfo) -> Result<()> {
+    account.data.borrow_mut().fill(0);
+
+    let lamports = account.lamports();
+    **account.try_borrow_mut_lamports()? -= lamports;
+    **destination.try_borrow_mut_lamports()? += lamports;
+
+    account.assign(&System::id());
+    account.realloc(0, false)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, has_one = owner, has_one = vault)]
+    pub receipt: Account<'info, Receipt>,
+
+    #[account(mut, has_one = owner)]
+    pub user_ledger: Account<'info, Ledger>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub escrowed: u64,
+}
+
+#[account]
+pub struct Receipt {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+}
+
+#[account]
+pub struct Ledger {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient escrow")]
+    InsufficientEscrow,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * Draining lamports marks an account for deletion at the end of the
+ * transaction, not now. Prefer Anchor's `close = destination`, which does
+ * the same wipe-and-reassign; when closing by hand, zero the data first.
+ */
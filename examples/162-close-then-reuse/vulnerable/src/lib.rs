@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln16216216216216216216216216216216216216");
+
+#[program]
+pub mod vulnerable_close_then_reuse {
+    use super::*;
+
+    /// VULNERABILITY: Close By Draining Lamports Only
+    ///
+    /// After paying out, the receipt is "closed" by moving its lamports to
+    /// the owner. The runtime only removes zero-lamport accounts at the end
+    /// of the transaction, so until then the account keeps its owner, its
+    /// discriminator and its data.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory holds a receipt for 1,000 escrowed tokens
+    /// 2. She builds one transaction with two `redeem` instructions, both
+    ///    pointing at the same receipt
+    /// 3. The first pays 1,000 and drains the receipt's lamports
+    /// 4. The second still deserializes a valid `Receipt { amount: 1000 }`
+    ///    and pays 1,000 again
+    /// 5. The receipt is garbage-collected only after both have run
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - "Closed" state is still live state for the rest of the transaction
+    /// - Any instruction that trusts the account can be replayed in-tx
+    pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
+        let amount = ctx.accounts.receipt.amount;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.escrowed = vault
+            .escrowed
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientEscrow)?;
+
+        let ledger = &mut ctx.accounts.user_ledger;
+        ledger.balance = ledger
+            .balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // ❌ Lamports moved, data left intact
+        let receipt_info = ctx.accounts.receipt.to_account_info();
+        let owner_info = ctx.accounts.owner.to_account_info();
+        let lamports = receipt_info.lamports();
+        **receipt_info.try_borrow_mut_lamports()? -= lamports;
+        **owner_info.try_borrow_mut_lamports()? += lamports;
+
+        msg!("Redeemed {}", amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, has_one = owner, has_one = vault)]
+    pub receipt: Account<'info, Receipt>,
+
+    #[account(mut, has_one = owner)]
+    pub user_ledger: Account<'info, Ledger>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub escrowed: u64,
+}
+
+#[account]
+pub struct Receipt {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+}
+
+#[account]
+pub struct Ledger {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient escrow")]
+    InsufficientEscrow,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
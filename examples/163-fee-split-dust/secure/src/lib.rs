@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur16316316316316316316316316316316316316");
+
+#[program]
+pub mod secure_fee_split_dust {
+    use super::*;
+
+    /// SECURE: Dust Carried Per Recipient Until It Is Payable
+    ///
+    /// Each recipient accrues its exact share in units of 1/10_000 lamport.
+    /// Whole lamports are paid out and the fractional remainder stays on the
+    /// recipient's record for the next round. A recipient whose payout is
+    /// still 0 is skipped - not marked paid - and a `DustSkipped` event
+    /// shows how much is being held for it.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. No rounding loss: `accrued_fraction` keeps what division drops
+    /// 2. Zero payouts are skipped and logged via `DustSkipped`
+    /// 3. Unpaid lamports stay in the splitter, owed via `accrued_fraction`,
+    ///    rather than being re-split next round
+    /// 4. Every recipient must be supplied, in order
+    pub fn distribute(ctx: Context<Distribute>) -> Result<()> {
+        let splitter = &mut ctx.accounts.splitter;
+        require!(
+            ctx.remaining_accounts.len() == splitter.recipients.len(),
+            ErrorCode::RecipientMismatch
+        );
+
+        let amount = splitter.pending_fees;
+        let splitter_info = splitter.to_account_info();
+
+        for (recipient, wallet) in splitter
+            .recipients
+            .iter_mut()
+            .zip(ctx.remaining_accounts.iter())
+        {
+            require_keys_eq!(recipient.wallet, wallet.key(), ErrorCode::RecipientMismatch);
+
+            // ✅ Exact share, including last round's remainder
+            let owed = (amount as u128)
+                .checked_mul(recipient.share_bps as u128)
+                .and_then(|v| v.checked_add(recipient.accrued_fraction as u128))
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            let share = u64::try_from(owed / 10_000).map_err(|_| ErrorCode::ArithmeticOverflow)?;
+            recipient.accrued_fraction = (owed % 10_000) as u64;
+
+            if share == 0 {
+                // ✅ Not paid, not marked paid
+                emit!(DustSkipped {
+                    recipient: recipient.wallet,
+                    retained_fraction: recipient.accrued_fraction,
+                });
+                continue;
+            }
+
+            **splitter_info.try_borrow_mut_lamports()? -= share;
+            **wallet.try_borrow_mut_lamports()? += share;
+
+            recipient.rounds_paid += 1;
+            recipient.total_received = recipient
+                .total_received
+                .checked_add(share)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        // ✅ Everything is either paid or held in accrued_fraction
+        splitter.pending_fees = 0;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Distribute<'info> {
+    #[account(mut)]
+    pub splitter: Account<'info, FeeSplitter>,
+    // Recipient wallets are passed as remaining accounts, in order
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Recipient {
+    pub wallet: Pubkey,
+    pub share_bps: u16,
+    pub rounds_paid: u64,
+    pub total_received: u64,
+    /// Owed but unpaid, in 1/10_000 lamport
+    pub accrued_fraction: u64,
+}
+
+#[account]
+pub struct FeeSplitter {
+    pub recipients: Vec<Recipient>,
+    pub pending_fees: u64,
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct DustSkipped {
+    pub recipient: Pubkey,
+    /// Amount held for the next distribution, in 1/10_000 lamport
+    pub retained_fraction: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Recipient account does not match")]
+    RecipientMismatch,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
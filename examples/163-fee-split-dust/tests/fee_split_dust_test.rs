@@ -0,0 +1,59 @@
+#[tokio::test]
+async fn test_dust_starvation_exploit() {
+    println!("\n=== EXPLOIT: Small Recipient Starved By Rounding ===\n");
+
+    let mallory = Keypair::new();
+    let grants = Keypair::new();
+    let others = Keypair::new();
+    let splitter = create_splitter(&[
+        (mallory.pubkey(), 9_000),
+        (grants.pubkey(), 100),
+        (others.pubkey(), 900),
+    ])
+    .await;
+
+    println!("1. Mallory cranks after every 50-lamport fee, 1,000 times");
+    for _ in 0..1_000 {
+        accrue_fees(&splitter, 50).await;
+        distribute(&splitter).await.unwrap();
+    }
+
+    // Vulnerable: 50 * 100 / 10_000 == 0 each round
+    let grants_state = get_recipient(&splitter, &grants.pubkey()).await;
+    assert_eq!(grants_state.total_received, 0);
+    assert_eq!(grants_state.rounds_paid, 1_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Grants program marked paid 1,000 times, received 0 of 500 owed");
+}
+
+#[tokio::test]
+async fn test_dust_retained_and_paid_later() {
+    println!("\n=== SECURITY: Dust Carried Per Recipient ===\n");
+
+    let mallory = Keypair::new();
+    let grants = Keypair::new();
+    let others = Keypair::new();
+    let splitter = create_splitter(&[
+        (mallory.pubkey(), 9_000),
+        (grants.pubkey(), 100),
+        (others.pubkey(), 900),
+    ])
+    .await;
+
+    println!("1. First 50-lamport round");
+    accrue_fees(&splitter, 50).await;
+    let events = distribute_with_events(&splitter).await.unwrap();
+    assert!(events.iter().any(|e| e.recipient == grants.pubkey()));
+    assert_eq!(get_recipient(&splitter, &grants.pubkey()).await.rounds_paid, 0);
+    println!("   ✓ DustSkipped emitted; grants not marked paid");
+
+    println!("\n2. 1,000 rounds of 50 lamports");
+    for _ in 1..1_000 {
+        accrue_fees(&splitter, 50).await;
+        distribute(&splitter).await.unwrap();
+    }
+    let grants_state = get_recipient(&splitter, &grants.pubkey()).await;
+    assert_eq!(grants_state.total_received, 500);
+    println!("   ✓ Grants received its full 1% (500 lamports)");
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln16316316316316316316316316316316316316");
+
+#[program]
+pub mod vulnerable_fee_split_dust {
+    use super::*;
+
+    /// VULNERABILITY: Zero Shares Recorded As Paid
+    ///
+    /// Pending fees are split by basis points and sent to each recipient.
+    /// Integer division gives small recipients 0 when the pending amount
+    /// is small, but every recipient is still marked paid for the round and
+    /// the unpaid remainder is forgotten when `pending_fees` is reset.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Recipients: Mallory 90%, a grants program 1%, others 9%
+    /// 2. `distribute` is a permissionless crank
+    /// 3. Mallory cranks after every trade, when ~50 lamports are pending
+    /// 4. The grants program's share is `50 * 100 / 10_000 = 0` every time,
+    ///    and it is recorded as paid
+    /// 5. Over thousands of rounds it receives nothing; the lost remainders
+    ///    sit in the splitter where no counter tracks them
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Small recipients can be starved by whoever controls call timing
+    /// - Accounting says "paid" while lamports are stranded
+    pub fn distribute(ctx: Context<Distribute>) -> Result<()> {
+        let splitter = &mut ctx.accounts.splitter;
+        let amount = splitter.pending_fees;
+        let splitter_info = splitter.to_account_info();
+
+        for (recipient, wallet) in splitter
+            .recipients
+            .iter_mut()
+            .zip(ctx.remaining_accounts.iter())
+        {
+            require_keys_eq!(recipient.wallet, wallet.key(), ErrorCode::RecipientMismatch);
+
+            // ❌ Rounds to 0 for small amounts
+            let share = amount
+                .checked_mul(recipient.share_bps as u64)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                / 10_000;
+
+            **splitter_info.try_borrow_mut_lamports()? -= share;
+            **wallet.try_borrow_mut_lamports()? += share;
+
+            // ❌ Marked paid even when share == 0
+            recipient.rounds_paid += 1;
+            recipient.total_received += share;
+        }
+
+        // ❌ Unpaid remainder forgotten
+        splitter.pending_fees = 0;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Distribute<'info> {
+    #[account(mut)]
+    pub splitter: Account<'info, FeeSplitter>,
+    // Recipient wallets are passed as remaining accounts, in order
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Recipient {
+    pub wallet: Pubkey,
+    pub share_bps: u16,
+    pub rounds_paid: u64,
+    pub total_received: u64,
+}
+
+#[account]
+pub struct FeeSplitter {
+    pub recipients: Vec<Recipient>,
+    pub pending_fees: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Recipient account does not match")]
+    RecipientMismatch,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur16416416416416416416416416416416416416");
+
+#[program]
+pub mod secure_blacklist_dos {
+    use super::*;
+
+    /// Authority bans an address by creating its marker PDA
+    pub fn ban_address(ctx: Context<BanAddress>, address: Pubkey) -> Result<()> {
+        let record = &mut ctx.accounts.ban_record;
+        record.address = address;
+        record.bump = ctx.bumps.ban_record;
+        msg!("Banned {}", address);
+        Ok(())
+    }
+
+    /// Authority lifts a ban by closing the marker PDA
+    pub fn unban_address(_ctx: Context<UnbanAddress>, address: Pubkey) -> Result<()> {
+        msg!("Unbanned {}", address);
+        Ok(())
+    }
+
+    /// SECURE: One PDA Per Banned Address
+    ///
+    /// Whether an address is banned is answered by whether
+    /// `[b"blacklisted", address]` exists. The transfer touches exactly two
+    /// marker addresses no matter how many bans there are.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. O(1) lookup - cost independent of blacklist size
+    /// 2. Marker addresses are re-derived via `seeds`, so a caller cannot
+    ///    substitute an unrelated empty account
+    /// 3. A marker only counts if this program owns it - lamports sent to
+    ///    the address by a third party do not create a ban
+    /// 4. Only the authority can ban
+    pub fn transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
+        // ✅ Constant-time existence checks
+        require!(
+            !is_banned(&ctx.accounts.from_ban),
+            ErrorCode::Blacklisted
+        );
+        require!(!is_banned(&ctx.accounts.to_ban), ErrorCode::Blacklisted);
+
+        let from = &mut ctx.accounts.from;
+        from.balance = from
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+        let to = &mut ctx.accounts.to;
+        to.balance = to
+            .balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// A ban exists iff the marker PDA is an initialized account of this program
+pub fn is_banned(marker: &AccountInfo) -> bool {
+    marker.owner == &crate::ID && !marker.data_is_empty()
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct BanAddress<'info> {
+    #[account(has_one = authority)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BanRecord::LEN,
+        seeds = [b"blacklisted", address.as_ref()],
+        bump,
+    )]
+    pub ban_record: Account<'info, BanRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct UnbanAddress<'info> {
+    #[account(has_one = authority)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"blacklisted", address.as_ref()],
+        bump = ban_record.bump,
+    )]
+    pub ban_record: Account<'info, BanRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Transfer<'info> {
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Balance>,
+
+    #[account(mut)]
+    pub to: Account<'info, Balance>,
+
+    /// CHECK: ✅ Marker PDA for the sender; may not exist
+    #[account(seeds = [b"blacklisted", from.owner.as_ref()], bump)]
+    pub from_ban: UncheckedAccount<'info>,
+
+    /// CHECK: ✅ Marker PDA for the recipient; may not exist
+    #[account(seeds = [b"blacklisted", to.owner.as_ref()], bump)]
+    pub to_ban: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub authority: Pubkey,
+}
+
+#[account]
+pub struct BanRecord {
+    pub address: Pubkey,
+    pub bump: u8,
+}
+
+impl BanRecord {
+    pub const LEN: usize = 32 + // address
+        1; // bump
+}
+
+#[account]
+pub struct Balance {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Address is blacklisted")]
+    Blacklisted,
+
+    #[msg("Insufficient funds")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
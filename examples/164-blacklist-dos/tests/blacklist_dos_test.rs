@@ -0,0 +1,54 @@
+#[tokio::test]
+async fn test_blacklist_flood_exploit() {
+    println!("\n=== EXPLOIT: Blacklist Flood ===\n");
+
+    let mallory = Keypair::new();
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let blacklist = create_blacklist().await;
+    let from = create_balance(&alice, 1_000).await;
+    let to = create_balance(&bob, 0).await;
+
+    println!("1. Mallory flags 10,000 random addresses");
+    for _ in 0..10_000 {
+        report_address(&blacklist, &mallory, Pubkey::new_unique()).await.unwrap();
+    }
+
+    println!("\n2. Alice sends Bob 100");
+    let result = transfer(&blacklist, &from, &to, &alice, 100).await;
+
+    // Vulnerable: list no longer fits in heap / compute budget
+    assert!(result.is_err());
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ All transfers fail - token frozen for everyone");
+}
+
+#[tokio::test]
+async fn test_pda_blacklist_constant_cost() {
+    println!("\n=== SECURITY: PDA Per Banned Address ===\n");
+
+    let authority = Keypair::new();
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let config = create_config(&authority).await;
+    let from = create_balance(&alice, 1_000).await;
+    let to = create_balance(&bob, 0).await;
+
+    println!("1. 10,000 bans exist");
+    for _ in 0..10_000 {
+        ban_address(&config, &authority, Pubkey::new_unique()).await.unwrap();
+    }
+
+    println!("\n2. Alice sends Bob 100");
+    let units = transfer_with_compute_report(&from, &to, &alice, 100).await.unwrap();
+    println!("   Compute units: {}", units);
+    assert!(units < 20_000);
+    println!("   ✓ Cost independent of blacklist size");
+
+    println!("\n3. Bob is banned");
+    ban_address(&config, &authority, bob.pubkey()).await.unwrap();
+    let result = transfer(&from, &to, &alice, 100).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Address is blacklisted");
+}
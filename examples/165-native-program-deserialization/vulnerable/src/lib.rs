@@ -0,0 +1,93 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+solana_program::declare_id!("Vuln16516516516516516516516516516516516516");
+
+entrypoint!(process_instruction);
+
+// ============================================================================
+// INSTRUCTIONS
+// ============================================================================
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum TreasuryInstruction {
+    /// Accounts:
+    /// 0. `[]` config
+    /// 1. `[signer]` admin
+    /// 2. `[writable]` treasury
+    /// 3. `[writable]` destination
+    Withdraw { amount: u64 },
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let instruction = TreasuryInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    match instruction {
+        TreasuryInstruction::Withdraw { amount } => withdraw(program_id, accounts, amount),
+    }
+}
+
+/// VULNERABILITY: Native Program Trusts Raw Account Data
+///
+/// Without Anchor, nothing checks an account before the program reads it.
+/// `accounts[0].data` is deserialized as `Config` with no owner check, no
+/// type tag, and no address check; the treasury is never verified either.
+///
+/// ATTACK SCENARIO:
+/// 1. Mallory deploys her own program and creates an account it owns
+/// 2. She writes `Config { admin: mallory }` into it - the same Borsh layout
+/// 3. She calls `Withdraw` with her fake config at index 0 and the real
+///    treasury (owned by this program) at index 2
+/// 4. `config.admin == admin.key` and `admin.is_signer` both pass
+/// 5. The treasury is drained to her wallet
+///
+/// WHY THIS IS DANGEROUS:
+/// - Every check Anchor's `Account<T>` does implicitly is absent here
+/// - Data layout alone says nothing about who wrote the data
+fn withdraw(_program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let config_info = next_account_info(account_iter)?;
+    let admin_info = next_account_info(account_iter)?;
+    let treasury_info = next_account_info(account_iter)?;
+    let destination_info = next_account_info(account_iter)?;
+
+    // ❌ No owner check, no type check, no address check
+    let config = Config::try_from_slice(&config_info.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if config.admin != *admin_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // ❌ Treasury identity, writability and rent floor never checked
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **destination_info.try_borrow_mut_lamports()? += amount;
+
+    msg!("Withdrew {} lamports", amount);
+    Ok(())
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub treasury_bump: u8,
+}
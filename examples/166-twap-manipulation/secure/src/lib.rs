@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur16616616616616616616616616616616616616");
+
+/// Minimum averaging window (~2 minutes at 400ms slots)
+pub const TWAP_MIN_SLOTS: u64 = 300;
+
+/// Largest move the oracle accepts per elapsed slot (5%)
+pub const MAX_PRICE_CHANGE_BPS: u64 = 500;
+
+/// Spacing between stored checkpoints
+pub const OBSERVATION_INTERVAL_SLOTS: u64 = 30;
+
+/// 32 checkpoints * 30 slots covers ~3x TWAP_MIN_SLOTS
+pub const MAX_OBSERVATIONS: usize = 32;
+
+/// Fixed-point scale for prices
+pub const PRICE_SCALE: u128 = 1_000_000;
+
+#[program]
+pub mod secure_twap_manipulation {
+    use super::*;
+
+    /// Seed the oracle from the pool's current price
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let oracle = &mut ctx.accounts.oracle;
+        oracle.pool = ctx.accounts.pool.key();
+        oracle.last_price = spot_price(&ctx.accounts.pool)?;
+        oracle.last_slot = slot;
+        oracle.observations[0] = Observation {
+            slot,
+            cumulative_price: 0,
+        };
+        oracle.head = 1;
+        oracle.count = 1;
+        Ok(())
+    }
+
+    /// SECURE: Slot-Weighted TWAP With A Minimum Window And Rate Limit
+    ///
+    /// The oracle keeps a running sum of `price * slots_elapsed`, so each
+    /// price counts for as long as it was in effect - not for how many
+    /// times it was recorded. Several updates in one slot add no weight.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Cumulative price weighted by slots, updates within a slot ignored
+    /// 2. `consult` averages over at least `TWAP_MIN_SLOTS`
+    /// 3. Accepted price moves at most `MAX_PRICE_CHANGE_BPS` per elapsed
+    ///    slot, so one manipulated slot cannot drag the average far
+    ///
+    /// WHY THIS HOLDS:
+    /// A flash loan lasts one transaction. Holding a manipulated price for
+    /// 300 slots means keeping the pool imbalanced - and open to arbitrage -
+    /// for 300 slots.
+    pub fn update(ctx: Context<Update>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let spot = spot_price(&ctx.accounts.pool)?;
+        let oracle = &mut ctx.accounts.oracle;
+
+        // ✅ Same slot - no additional weight
+        let elapsed = slot.saturating_sub(oracle.last_slot);
+        if elapsed == 0 {
+            return Ok(());
+        }
+
+        // ✅ Previous price accrues for the time it was in effect
+        oracle.cumulative_price = oracle
+            .cumulative_price
+            .checked_add(oracle.last_price as u128 * elapsed as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // ✅ Rate-limit the accepted price
+        let last = oracle.last_price as u128;
+        let max_move = last
+            .checked_mul(MAX_PRICE_CHANGE_BPS as u128 * elapsed as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+        let lower = last.saturating_sub(max_move);
+        let upper = last.saturating_add(max_move);
+        let accepted = (spot as u128).clamp(lower, upper);
+        oracle.last_price =
+            u64::try_from(accepted).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+        oracle.last_slot = slot;
+
+        // Checkpoint for windowed reads
+        let latest = oracle.latest_observation();
+        if slot - latest.slot >= OBSERVATION_INTERVAL_SLOTS {
+            let head = oracle.head as usize;
+            oracle.observations[head] = Observation {
+                slot,
+                cumulative_price: oracle.cumulative_price,
+            };
+            oracle.head = ((head + 1) % MAX_OBSERVATIONS) as u8;
+            oracle.count = oracle.count.saturating_add(1).min(MAX_OBSERVATIONS as u8);
+        }
+        Ok(())
+    }
+
+    /// Average price over at least `TWAP_MIN_SLOTS`
+    pub fn consult(ctx: Context<Consult>) -> Result<u64> {
+        let slot = Clock::get()?.slot;
+        let oracle = &ctx.accounts.oracle;
+
+        let cumulative_now = oracle
+            .cumulative_price
+            .checked_add(oracle.last_price as u128 * slot.saturating_sub(oracle.last_slot) as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // ✅ Newest checkpoint at least TWAP_MIN_SLOTS old
+        let cutoff = slot
+            .checked_sub(TWAP_MIN_SLOTS)
+            .ok_or(ErrorCode::InsufficientHistory)?;
+        let start = oracle.observations[..oracle.count as usize]
+            .iter()
+            .filter(|o| o.slot <= cutoff)
+            .max_by_key(|o| o.slot)
+            .ok_or(ErrorCode::InsufficientHistory)?;
+
+        let window = (slot - start.slot) as u128;
+        let twap = (cumulative_now - start.cumulative_price) / window;
+        u64::try_from(twap).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+    }
+}
+
+pub fn spot_price(pool: &Pool) -> Result<u64> {
+    require!(pool.reserve_a > 0, ErrorCode::EmptyPool);
+    let price = (pool.reserve_b as u128)
+        .checked_mul(PRICE_SCALE)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / pool.reserve_a as u128;
+    u64::try_from(price).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TwapOracle::LEN,
+        seeds = [b"twap", pool.key().as_ref()],
+        bump,
+    )]
+    pub oracle: Account<'info, TwapOracle>,
+    pub pool: Account<'info, Pool>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Update<'info> {
+    #[account(mut, has_one = pool)]
+    pub oracle: Account<'info, TwapOracle>,
+    pub pool: Account<'info, Pool>,
+}
+
+#[derive(Accounts)]
+pub struct Consult<'info> {
+    pub oracle: Account<'info, TwapOracle>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct Observation {
+    pub slot: u64,
+    pub cumulative_price: u128,
+}
+
+#[account]
+pub struct TwapOracle {
+    pub pool: Pubkey,
+    pub last_price: u64,
+    pub last_slot: u64,
+    pub cumulative_price: u128,
+    pub observations: [Observation; MAX_OBSERVATIONS],
+    pub head: u8,
+    pub count: u8,
+}
+
+impl TwapOracle {
+    pub const LEN: usize = 32 + // pool
+        8 + // last_price
+        8 + // last_slot
+        16 + // cumulative_price
+        (8 + 16) * MAX_OBSERVATIONS + // observations
+        1 + // head
+        1; // count
+
+    pub fn latest_observation(&self) -> Observation {
+        let index = (self.head as usize + MAX_OBSERVATIONS - 1) % MAX_OBSERVATIONS;
+        self.observations[index]
+    }
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Not enough history for the minimum TWAP window")]
+    InsufficientHistory,
+
+    #[msg("Pool is empty")]
+    EmptyPool,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
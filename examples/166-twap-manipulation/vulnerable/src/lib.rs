@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln16616616616616616616616616616616616616");
+
+/// Number of observations averaged
+pub const TWAP_WINDOW: usize = 10;
+
+/// Fixed-point scale for prices
+pub const PRICE_SCALE: u128 = 1_000_000;
+
+#[program]
+pub mod vulnerable_twap_manipulation {
+    use super::*;
+
+    /// VULNERABILITY: "TWAP" Over The Last N Updates, Not Over Time
+    ///
+    /// Every call records the pool's spot price, and the oracle reports the
+    /// mean of the last 10 records. Nothing ties a record to elapsed time,
+    /// so ten records can all come from the same transaction.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory flash-borrows a large amount of token B
+    /// 2. She swaps it into the pool, pushing the spot price of A up 5x
+    /// 3. She calls `update` ten times in the same transaction
+    /// 4. All ten observations are the manipulated price - the "TWAP" is 5x
+    /// 5. She borrows against A at the inflated price from a lending
+    ///    protocol that consults this oracle, swaps back and repays the loan
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Averaging over transactions averages over attacker-chosen samples
+    /// - A flash loan makes the cost of the manipulation just the swap fee
+    pub fn update(ctx: Context<Update>) -> Result<()> {
+        let price = spot_price(&ctx.accounts.pool)?;
+        let oracle = &mut ctx.accounts.oracle;
+
+        // ❌ No slot check - one transaction can fill the window
+        let head = oracle.head as usize;
+        oracle.prices[head] = price;
+        oracle.head = ((head + 1) % TWAP_WINDOW) as u8;
+        oracle.count = oracle.count.saturating_add(1).min(TWAP_WINDOW as u8);
+        Ok(())
+    }
+
+    /// Average of the recorded observations
+    pub fn consult(ctx: Context<Consult>) -> Result<u64> {
+        let oracle = &ctx.accounts.oracle;
+        require!(oracle.count > 0, ErrorCode::NoObservations);
+
+        let sum: u128 = oracle.prices[..oracle.count as usize]
+            .iter()
+            .map(|p| *p as u128)
+            .sum();
+        Ok((sum / oracle.count as u128) as u64)
+    }
+}
+
+pub fn spot_price(pool: &Pool) -> Result<u64> {
+    require!(pool.reserve_a > 0, ErrorCode::EmptyPool);
+    let price = (pool.reserve_b as u128)
+        .checked_mul(PRICE_SCALE)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / pool.reserve_a as u128;
+    u64::try_from(price).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Update<'info> {
+    #[account(mut, has_one = pool)]
+    pub oracle: Account<'info, TwapOracle>,
+    pub pool: Account<'info, Pool>,
+}
+
+#[derive(Accounts)]
+pub struct Consult<'info> {
+    pub oracle: Account<'info, TwapOracle>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+}
+
+#[account]
+pub struct TwapOracle {
+    pub pool: Pubkey,
+    pub prices: [u64; TWAP_WINDOW],
+    pub head: u8,
+    pub count: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No observations recorded")]
+    NoObservations,
+
+    #[msg("Pool is empty")]
+    EmptyPool,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
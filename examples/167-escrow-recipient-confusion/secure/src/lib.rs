@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("Secur16716716716716716716716716716716716716");
+
+#[program]
+pub mod secure_escrow_recipient_confusion {
+    use super::*;
+
+    /// SECURE: Payout Destination Derived From The Seller
+    ///
+    /// The destination is not chosen by the caller. It is the seller's
+    /// associated token account for the payment mint, re-derived from
+    /// `escrow.seller`, and the escrow's rent goes back to the seller's
+    /// wallet, which must be a plain system account.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `seller` pinned by `has_one` and typed as a `SystemAccount`
+    /// 2. `seller_token` must be the ATA of (`seller`, `payment_mint`)
+    /// 3. `payment_mint` is pinned by `has_one` on the escrow
+    /// 4. `payment_vault` is pinned by `has_one` and owned by the escrow
+    /// 5. Escrow closed to the seller, so it cannot be settled twice
+    pub fn pay_seller(ctx: Context<PaySeller>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        require!(escrow.nft_delivered, ErrorCode::NftNotDelivered);
+
+        let seeds = &[b"escrow".as_ref(), escrow.nft_mint.as_ref(), &[escrow.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payment_vault.to_account_info(),
+                    // ✅ Seller's own ATA
+                    to: ctx.accounts.seller_token.to_account_info(),
+                    authority: ctx.accounts.escrow.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            escrow.price,
+        )?;
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PaySeller<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.nft_mint.as_ref()],
+        bump = escrow.bump,
+        has_one = payment_vault,
+        has_one = payment_mint,
+        has_one = seller,
+        close = seller,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(mut, token::mint = payment_mint, token::authority = escrow)]
+    pub payment_vault: Account<'info, TokenAccount>,
+
+    pub payment_mint: Account<'info, Mint>,
+
+    /// ✅ Seller's wallet - a system account, never a token account
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+
+    /// ✅ Derived from seller + mint; cannot be substituted
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Escrow {
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub nft_mint: Pubkey,
+    pub payment_mint: Pubkey,
+    pub payment_vault: Pubkey,
+    pub price: u64,
+    pub nft_delivered: bool,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("NFT has not been delivered")]
+    NftNotDelivered,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln16716716716716716716716716716716716716");
+
+#[program]
+pub mod vulnerable_escrow_recipient_confusion {
+    use super::*;
+
+    /// VULNERABILITY: Recipient Key Checked, Destination Account Not
+    ///
+    /// Once the NFT has been delivered, anyone can crank `pay_seller` to
+    /// release the buyer's payment. The instruction checks that
+    /// `recipient` is the seller, but the tokens go to `recipient_token`,
+    /// which is never linked to `recipient`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Escrow holds 1,000 USDC for Alice (seller); NFT delivered
+    /// 2. Mallory calls `pay_seller` before Alice does
+    /// 3. `recipient` = Alice's pubkey - no signature required, check passes
+    /// 4. `recipient_token` = Mallory's USDC account
+    /// 5. The 1,000 USDC go to Mallory; the escrow is marked settled
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A correct key on one account says nothing about its neighbours
+    /// - Permissionless settlement lets anyone pick the accounts
+    pub fn pay_seller(ctx: Context<PaySeller>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        require!(escrow.nft_delivered, ErrorCode::NftNotDelivered);
+        require!(!escrow.settled, ErrorCode::AlreadySettled);
+
+        // ❌ Only the loose `recipient` account is checked
+        require_keys_eq!(
+            ctx.accounts.recipient.key(),
+            escrow.seller,
+            ErrorCode::WrongRecipient
+        );
+
+        let seeds = &[b"escrow".as_ref(), escrow.nft_mint.as_ref(), &[escrow.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payment_vault.to_account_info(),
+                    // ❌ Any token account of the right mint
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: ctx.accounts.escrow.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            escrow.price,
+        )?;
+
+        ctx.accounts.escrow.settled = true;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PaySeller<'info> {
+    #[account(mut, has_one = payment_vault)]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(mut)]
+    pub payment_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: ❌ Compared to escrow.seller, then unused
+    pub recipient: AccountInfo<'info>,
+
+    /// ❌ Not tied to recipient
+    #[account(mut, token::mint = payment_vault.mint)]
+    pub recipient_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Escrow {
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub nft_mint: Pubkey,
+    pub payment_vault: Pubkey,
+    pub price: u64,
+    pub nft_delivered: bool,
+    pub settled: bool,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("NFT has not been delivered")]
+    NftNotDelivered,
+
+    #[msg("Escrow already settled")]
+    AlreadySettled,
+
+    #[msg("Recipient is not the seller")]
+    WrongRecipient,
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+declare_id!("Secur16816816816816816816816816816816816816");
+
+#[program]
+pub mod secure_fractional_nft_cap {
+    use super::*;
+
+    /// SECURE: Share Minting Bounded By The Configured Maximum
+    ///
+    /// The account constraint rejects any mint that would take
+    /// `minted_shares` past `config.max_shares`. The sum is computed with
+    /// `checked_add`, so a huge `amount` cannot wrap around and slip under
+    /// the cap.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `minted_shares + amount <= max_shares` enforced before the handler
+    /// 2. `checked_add` in the comparison - overflow fails the constraint
+    /// 3. `config` bound to the vault via `has_one` and PDA seeds
+    /// 4. Counter updated with `checked_add` as well
+    pub fn mint_shares(ctx: Context<MintShares>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+
+        let vault = &ctx.accounts.vault;
+        let cost = amount
+            .checked_mul(vault.price_per_share)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            cost,
+        )?;
+
+        let seeds = &[
+            b"fraction_vault".as_ref(),
+            vault.config.as_ref(),
+            &[vault.bump],
+        ];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.share_mint.to_account_info(),
+                    to: ctx.accounts.buyer_shares.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        // ✅ Checked counter update
+        let vault = &mut ctx.accounts.vault;
+        vault.minted_shares = vault
+            .minted_shares
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct MintShares<'info> {
+    pub config: Account<'info, FractionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"fraction_vault", config.key().as_ref()],
+        bump = vault.bump,
+        has_one = config,
+        has_one = share_mint,
+        // ✅ Cap enforced, overflow treated as exceeding it
+        constraint = vault
+            .minted_shares
+            .checked_add(amount)
+            .is_some_and(|total| total <= config.max_shares)
+            @ ErrorCode::ShareCapExceeded,
+    )]
+    pub vault: Account<'info, FractionVault>,
+
+    #[account(mut)]
+    pub share_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = share_mint, token::authority = buyer)]
+    pub buyer_shares: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct FractionConfig {
+    pub curator: Pubkey,
+    pub nft_mint: Pubkey,
+    pub max_shares: u64,
+}
+
+#[account]
+pub struct FractionVault {
+    pub config: Pubkey,
+    pub share_mint: Pubkey,
+    pub minted_shares: u64,
+    pub price_per_share: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Minting would exceed the maximum share supply")]
+    ShareCapExceeded,
+
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
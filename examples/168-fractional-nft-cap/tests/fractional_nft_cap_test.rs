@@ -0,0 +1,51 @@
+#[tokio::test]
+async fn test_share_over_mint_exploit() {
+    println!("\n=== EXPLOIT: Minting Past The Share Cap ===\n");
+
+    let curator = Keypair::new();
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let (config, vault) = fractionalize_nft(&curator, 1_000, 100_000_000).await;
+
+    println!("1. Alice buys 1,000 shares (the full cap)");
+    mint_shares(&vault, &alice, 1_000).await.unwrap();
+
+    println!("\n2. Mallory mints 9,000 more");
+    let result = mint_shares(&vault, &mallory, 9_000).await;
+
+    // Vulnerable: max_shares never checked
+    assert!(result.is_ok());
+    assert_eq!(get_vault(&vault).await.minted_shares, 10_000);
+    assert_eq!(get_config(&config).await.max_shares, 1_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Supply is 10x the cap; Alice diluted from 100% to 10%");
+}
+
+#[tokio::test]
+async fn test_share_cap_enforced() {
+    println!("\n=== SECURITY: Share Cap Enforced ===\n");
+
+    let curator = Keypair::new();
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let (_config, vault) = fractionalize_nft(&curator, 1_000, 100_000_000).await;
+
+    println!("1. Alice buys 999 shares");
+    mint_shares(&vault, &alice, 999).await.unwrap();
+
+    println!("\n2. Mallory tries to mint 2");
+    let result = mint_shares(&vault, &mallory, 2).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ShareCapExceeded");
+
+    println!("\n3. Mallory tries u64::MAX to wrap the sum");
+    let result = mint_shares(&vault, &mallory, u64::MAX).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ShareCapExceeded (checked_add overflow)");
+
+    println!("\n4. The last share can still be minted");
+    mint_shares(&vault, &mallory, 1).await.unwrap();
+    assert_eq!(get_vault(&vault).await.minted_shares, 1_000);
+    println!("   ✓ Supply exactly at cap");
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+declare_id!("Vuln16816816816816816816816816816816816816");
+
+#[program]
+pub mod vulnerable_fractional_nft_cap {
+    use super::*;
+
+    /// VULNERABILITY: Share Cap Stored But Never Enforced
+    ///
+    /// A fractionalized NFT is split into `config.max_shares` fungible
+    /// shares. Buyers call `mint_shares` to purchase them at a fixed price.
+    /// The vault counts `minted_shares`, but nothing compares the count
+    /// against the cap.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A Punk is fractionalized into 1,000 shares at 0.1 SOL each
+    /// 2. Holders expect each share to be 1/1000 of the NFT
+    /// 3. Mallory mints 9,000 more shares for 900 SOL
+    /// 4. She now holds 90% of the supply and wins the buyout vote
+    /// 5. Existing holders' 1/1000 claims are diluted to 1/10000
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Share supply defines ownership; an uncapped supply means no ownership
+    /// - Governance and buyout payouts are pro-rata on the inflated supply
+    pub fn mint_shares(ctx: Context<MintShares>, amount: u64) -> Result<()> {
+        let vault = &ctx.accounts.vault;
+        let cost = amount
+            .checked_mul(vault.price_per_share)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            cost,
+        )?;
+
+        // ❌ No check against config.max_shares
+        let seeds = &[
+            b"fraction_vault".as_ref(),
+            vault.config.as_ref(),
+            &[vault.bump],
+        ];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.share_mint.to_account_info(),
+                    to: ctx.accounts.buyer_shares.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.minted_shares += amount;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct MintShares<'info> {
+    pub config: Account<'info, FractionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"fraction_vault", config.key().as_ref()],
+        bump = vault.bump,
+        has_one = config,
+        has_one = share_mint,
+    )]
+    pub vault: Account<'info, FractionVault>,
+
+    #[account(mut)]
+    pub share_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = share_mint, token::authority = buyer)]
+    pub buyer_shares: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct FractionConfig {
+    pub curator: Pubkey,
+    pub nft_mint: Pubkey,
+    pub max_shares: u64,
+}
+
+#[account]
+pub struct FractionVault {
+    pub config: Pubkey,
+    pub share_mint: Pubkey,
+    pub minted_shares: u64,
+    pub price_per_share: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,138 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+solana_program::declare_id!("Secur16916916916916916916916916916916916916");
+
+entrypoint!(process_instruction);
+
+// ============================================================================
+// INSTRUCTIONS
+// ============================================================================
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum DepositInstruction {
+    /// Accounts:
+    /// 0. `[writable]` user ledger (owned by this program)
+    /// 1. `[signer]` user
+    /// 2. `[writable]` user token account
+    /// 3. `[writable]` vault token account
+    /// 4. `[]` token program
+    Deposit { amount: u64 },
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let instruction = DepositInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    match instruction {
+        DepositInstruction::Deposit { amount } => deposit(program_id, accounts, amount),
+    }
+}
+
+/// SECURE: CPI Result Propagated Before Any State Change
+///
+/// The transfer happens first and its result is propagated with `?`. The
+/// ledger is only read and written after the CPI has returned `Ok`, so
+/// no borrow is held across the call and no credit exists without a
+/// completed transfer.
+///
+/// SECURITY MEASURES:
+/// 1. `invoke(...)?` - any error aborts the instruction
+/// 2. Token program ID checked, so the CPI reaches the real token program
+/// 3. Ledger account must differ from the token accounts
+/// 4. Ledger borrowed only after the CPI (interactions, then effects)
+fn deposit(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let ledger_info = next_account_info(account_iter)?;
+    let user_info = next_account_info(account_iter)?;
+    let source_info = next_account_info(account_iter)?;
+    let vault_info = next_account_info(account_iter)?;
+    let token_program_info = next_account_info(account_iter)?;
+
+    if ledger_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !user_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    // ✅ Real token program
+    if *token_program_info.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    // ✅ No aliasing between the ledger and the token accounts
+    if ledger_info.key == source_info.key || ledger_info.key == vault_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let owner = UserLedger::try_from_slice(&ledger_info.try_borrow_data()?)
+        .map_err(|_| ProgramError::InvalidAccountData)?
+        .owner;
+    if owner != *user_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let ix = spl_token::instruction::transfer(
+        token_program_info.key,
+        source_info.key,
+        vault_info.key,
+        user_info.key,
+        &[],
+        amount,
+    )?;
+
+    // ✅ Propagate the CPI result
+    invoke(
+        &ix,
+        &[
+            source_info.clone(),
+            vault_info.clone(),
+            user_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    // ✅ Credit only after a successful transfer
+    let mut data = ledger_info.try_borrow_mut_data()?;
+    let mut ledger = UserLedger::try_from_slice(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    ledger.credited = ledger
+        .credited
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    ledger.serialize(&mut &mut data[..])?;
+
+    msg!("Credited {}", amount);
+    Ok(())
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct UserLedger {
+    pub owner: Pubkey,
+    pub credited: u64,
+}
+
+// ============================================================================
+// KEY TAKEAWAYS
+// ============================================================================
+
+/*
+ * `invoke` returns a `ProgramResult` for a reason. Anything other than `?`
+ * on that result needs a written justification - and crediting state after
+ * an ignored CPI never has one.
+ */
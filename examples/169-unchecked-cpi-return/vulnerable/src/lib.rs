@@ -0,0 +1,130 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+solana_program::declare_id!("Vuln16916916916916916916916916916916916916");
+
+entrypoint!(process_instruction);
+
+// ============================================================================
+// INSTRUCTIONS
+// ============================================================================
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum DepositInstruction {
+    /// Accounts:
+    /// 0. `[writable]` user ledger (owned by this program)
+    /// 1. `[signer]` user
+    /// 2. `[writable]` user token account
+    /// 3. `[writable]` vault token account
+    /// 4. `[]` token program
+    Deposit { amount: u64 },
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let instruction = DepositInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    match instruction {
+        DepositInstruction::Deposit { amount } => deposit(program_id, accounts, amount),
+    }
+}
+
+/// VULNERABILITY: Result Of `invoke` Discarded
+///
+/// The deposit moves tokens into the vault with a CPI to the token
+/// program, then credits the user's ledger. The CPI's `ProgramResult` is
+/// thrown away with `let _ =`, so the ledger is credited whether or not
+/// any tokens moved.
+///
+/// Note that a callee that *runs and fails* aborts the whole transaction -
+/// the runtime does not hand that error back. What `invoke` does return
+/// are the errors raised before the syscall, such as the `RefCell` borrow
+/// checks on every account passed in. Those are the ones that get
+/// swallowed here.
+///
+/// ATTACK SCENARIO:
+/// 1. Mallory calls `deposit(1_000_000)` with her ledger account passed
+///    both as account 0 and as the "user token account" (account 2)
+/// 2. The handler holds a mutable borrow on the ledger's data
+/// 3. `invoke` sees the same account already borrowed and returns
+///    `AccountBorrowFailed` without ever calling the token program
+/// 4. The error is ignored and her ledger is credited 1,000,000
+/// 5. She withdraws 1,000,000 real tokens from the vault
+///
+/// WHY THIS IS DANGEROUS:
+/// - Effects are recorded for an action that never happened
+/// - Any pre-flight CPI failure becomes a free credit
+fn deposit(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let ledger_info = next_account_info(account_iter)?;
+    let user_info = next_account_info(account_iter)?;
+    let source_info = next_account_info(account_iter)?;
+    let vault_info = next_account_info(account_iter)?;
+    let token_program_info = next_account_info(account_iter)?;
+
+    if ledger_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !user_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut data = ledger_info.try_borrow_mut_data()?;
+    let mut ledger = UserLedger::try_from_slice(&data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if ledger.owner != *user_info.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let ix = spl_token::instruction::transfer(
+        token_program_info.key,
+        source_info.key,
+        vault_info.key,
+        user_info.key,
+        &[],
+        amount,
+    )?;
+
+    // ❌ Failure ignored - the ledger borrow above is still alive
+    let _ = invoke(
+        &ix,
+        &[
+            source_info.clone(),
+            vault_info.clone(),
+            user_info.clone(),
+            token_program_info.clone(),
+        ],
+    );
+
+    // ❌ Credited regardless of the transfer
+    ledger.credited = ledger
+        .credited
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    ledger.serialize(&mut &mut data[..])?;
+
+    msg!("Credited {}", amount);
+    Ok(())
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct UserLedger {
+    pub owner: Pubkey,
+    pub credited: u64,
+}
@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur17o17o17o17o17o17o17o17o17o17o17o17o17");
+
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Largest single-sided deposit, relative to the A reserve (10%)
+pub const MAX_DEVIATION_BPS: u128 = 1_000;
+
+#[program]
+pub mod secure_single_asset_deposit {
+    use super::*;
+
+    /// SECURE: Single-Sided Deposits Pay An Imbalance Penalty
+    ///
+    /// A deposit of token A alone is first valued against the whole pool,
+    /// not just the A side: at the current ratio the pool is worth
+    /// `2 * reserve_a` in A terms. It then pays a penalty that grows with
+    /// how far the deposit pushes the pool off its ratio,
+    /// `amount_a / (2 * reserve_a + amount_a)`. Together these mint exactly
+    /// the shares the deposit is worth at the post-deposit price, so an
+    /// immediate withdrawal returns no more value than was put in.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Base shares priced against total pool value, not one reserve
+    /// 2. Imbalance penalty proportional to the deviation caused
+    /// 3. Deposits above `MAX_DEVIATION_BPS` of the A reserve rejected
+    /// 4. Caller-supplied `min_lp_out` bounds slippage
+    /// 5. All intermediate math in u128 with checked operations
+    pub fn deposit_single(
+        ctx: Context<DepositSingle>,
+        amount_a: u64,
+        min_lp_out: u64,
+    ) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        require!(pool.reserve_a > 0, ErrorCode::EmptyPool);
+        require!(amount_a > 0, ErrorCode::ZeroAmount);
+
+        let reserve_a = pool.reserve_a as u128;
+        let amount = amount_a as u128;
+
+        // ✅ Bound how far one deposit can move the ratio
+        let deviation_bps = amount
+            .checked_mul(BPS_DENOMINATOR)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / reserve_a;
+        require!(
+            deviation_bps <= MAX_DEVIATION_BPS,
+            ErrorCode::DeviationTooLarge
+        );
+
+        // ✅ Penalty grows with the imbalance introduced
+        let twice_reserve = reserve_a
+            .checked_mul(2)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        // Rounded up so truncation never favours the depositor
+        let penalty_bps = amount
+            .checked_mul(BPS_DENOMINATOR)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .div_ceil(
+                twice_reserve
+                    .checked_add(amount)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?,
+            );
+
+        // ✅ lp = supply * amount / (2 * reserve_a) * (1 - penalty)
+        let lp_out = amount
+            .checked_mul(pool.lp_supply as u128)
+            .and_then(|v| v.checked_mul(BPS_DENOMINATOR - penalty_bps))
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / twice_reserve
+                .checked_mul(BPS_DENOMINATOR)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let lp_out = u64::try_from(lp_out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+        require!(lp_out > 0, ErrorCode::ZeroAmount);
+        require!(lp_out >= min_lp_out, ErrorCode::SlippageExceeded);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_a.to_account_info(),
+                    to: ctx.accounts.vault_a.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount_a,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.reserve_a = pool
+            .reserve_a
+            .checked_add(amount_a)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.lp_supply = pool
+            .lp_supply
+            .checked_add(lp_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let position = &mut ctx.accounts.position;
+        position.lp_balance = position
+            .lp_balance
+            .checked_add(lp_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Burn LP shares for a pro-rata amount of both reserves
+    pub fn withdraw(ctx: Context<Withdraw>, lp_amount: u64) -> Result<()> {
+        require!(
+            ctx.accounts.position.lp_balance >= lp_amount,
+            ErrorCode::InsufficientLp
+        );
+        let pool = &ctx.accounts.pool;
+        let out_a = pro_rata(pool.reserve_a, lp_amount, pool.lp_supply)?;
+        let out_b = pro_rata(pool.reserve_b, lp_amount, pool.lp_supply)?;
+
+        let seeds = &[b"pool".as_ref(), pool.mint_a.as_ref(), pool.mint_b.as_ref(), &[pool.bump]];
+        for (vault, user_token, amount) in [
+            (&ctx.accounts.vault_a, &ctx.accounts.user_token_a, out_a),
+            (&ctx.accounts.vault_b, &ctx.accounts.user_token_b, out_b),
+        ] {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: user_token.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    &[&seeds[..]],
+                ),
+                amount,
+            )?;
+        }
+
+        let pool = &mut ctx.accounts.pool;
+        pool.reserve_a -= out_a;
+        pool.reserve_b -= out_b;
+        pool.lp_supply -= lp_amount;
+        ctx.accounts.position.lp_balance -= lp_amount;
+        Ok(())
+    }
+}
+
+fn pro_rata(reserve: u64, lp_amount: u64, lp_supply: u64) -> Result<u64> {
+    let out = (reserve as u128)
+        .checked_mul(lp_amount as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / lp_supply as u128;
+    u64::try_from(out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct DepositSingle<'info> {
+    #[account(mut, has_one = vault_a)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_a, token::authority = owner)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_a)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_b)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub lp_supply: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct LpPosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub lp_balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Pool is empty")]
+    EmptyPool,
+
+    #[msg("Insufficient LP balance")]
+    InsufficientLp,
+
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Deposit moves the pool ratio too far")]
+    DeviationTooLarge,
+
+    #[msg("LP output below minimum")]
+    SlippageExceeded,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,58 @@
+#[tokio::test]
+async fn test_single_sided_deposit_exploit() {
+    println!("\n=== EXPLOIT: Free Token B From A Single-Sided Deposit ===\n");
+
+    let lp = Keypair::new();
+    let mallory = Keypair::new();
+    let pool = create_pool(&lp, 1_000, 1_000).await;
+    println!("1. Pool: 1,000 A / 1,000 B, 1,000 LP");
+
+    println!("\n2. Mallory deposits 1,000 A only");
+    deposit_single(&pool, &mallory, 1_000).await.unwrap();
+    let lp_received = get_position(&pool, &mallory).await.lp_balance;
+    assert_eq!(lp_received, 1_000);
+
+    println!("\n3. Mallory withdraws immediately");
+    withdraw(&pool, &mallory, lp_received).await.unwrap();
+
+    // Vulnerable: priced as if B had been deposited too
+    assert_eq!(get_token_a_balance(&mallory).await, 1_000);
+    assert_eq!(get_token_b_balance(&mallory).await, 500);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Round trip returned all 1,000 A plus 500 B");
+}
+
+#[tokio::test]
+async fn test_imbalance_penalty_applied() {
+    println!("\n=== SECURITY: Imbalance Penalty On Single-Sided Deposits ===\n");
+
+    let lp = Keypair::new();
+    let mallory = Keypair::new();
+    let pool = create_pool(&lp, 1_000, 1_000).await;
+
+    println!("1. Deposit of 1,000 A (100% of the reserve)");
+    let result = deposit_single(&pool, &mallory, 1_000, 0).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: DeviationTooLarge");
+
+    println!("\n2. Deposit of 100 A");
+    deposit_single(&pool, &mallory, 100, 0).await.unwrap();
+    let lp_received = get_position(&pool, &mallory).await.lp_balance;
+    // 100 * 1,000 / 2,000 = 50 base, minus a 4.77% penalty
+    assert_eq!(lp_received, 47);
+    println!("   ✓ 47 LP minted instead of 100");
+
+    println!("\n3. Immediate withdrawal");
+    withdraw(&pool, &mallory, lp_received).await.unwrap();
+    let a = get_token_a_balance(&mallory).await;
+    let b = get_token_b_balance(&mallory).await;
+    assert_eq!((a, b), (49, 44));
+    assert!(a + b < 100);
+    println!("   ✓ Got back {} A + {} B - less than the 100 A deposited", a, b);
+
+    println!("\n4. min_lp_out protects honest depositors");
+    let result = deposit_single(&pool, &lp, 100, 100).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: SlippageExceeded");
+}
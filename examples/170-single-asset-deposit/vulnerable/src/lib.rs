@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln17017017017017017017017017017017017017");
+
+#[program]
+pub mod vulnerable_single_asset_deposit {
+    use super::*;
+
+    /// VULNERABILITY: Single-Sided Deposit Priced As A Balanced One
+    ///
+    /// `deposit_single` accepts only token A and mints LP shares as
+    /// `amount_a * lp_supply / reserve_a` - the formula for a balanced
+    /// deposit. It credits the depositor as if they had also brought the
+    /// matching amount of token B. `withdraw` then pays out both tokens
+    /// pro-rata.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Pool: 1,000 A / 1,000 B, 1,000 LP outstanding
+    /// 2. Mallory deposits 1,000 A alone and receives 1,000 LP
+    /// 3. Pool: 2,000 A / 1,000 B, 2,000 LP - she owns half
+    /// 4. She withdraws 1,000 LP: 1,000 A and 500 B
+    /// 5. She is back to her 1,000 A plus 500 B taken from other LPs
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Each deposit/withdraw round trip takes B at no cost
+    /// - Repeating it drains the B side of the pool
+    pub fn deposit_single(ctx: Context<DepositSingle>, amount_a: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        require!(pool.reserve_a > 0, ErrorCode::EmptyPool);
+
+        // ❌ Ignores that no token B was contributed
+        let lp_out = (amount_a as u128)
+            .checked_mul(pool.lp_supply as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / pool.reserve_a as u128;
+        let lp_out = u64::try_from(lp_out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_a.to_account_info(),
+                    to: ctx.accounts.vault_a.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount_a,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.reserve_a = pool
+            .reserve_a
+            .checked_add(amount_a)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.lp_supply = pool
+            .lp_supply
+            .checked_add(lp_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let position = &mut ctx.accounts.position;
+        position.lp_balance = position
+            .lp_balance
+            .checked_add(lp_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Burn LP shares for a pro-rata amount of both reserves
+    pub fn withdraw(ctx: Context<Withdraw>, lp_amount: u64) -> Result<()> {
+        require!(
+            ctx.accounts.position.lp_balance >= lp_amount,
+            ErrorCode::InsufficientLp
+        );
+        let pool = &ctx.accounts.pool;
+        let out_a = pro_rata(pool.reserve_a, lp_amount, pool.lp_supply)?;
+        let out_b = pro_rata(pool.reserve_b, lp_amount, pool.lp_supply)?;
+
+        let seeds = &[b"pool".as_ref(), pool.mint_a.as_ref(), pool.mint_b.as_ref(), &[pool.bump]];
+        for (vault, user_token, amount) in [
+            (&ctx.accounts.vault_a, &ctx.accounts.user_token_a, out_a),
+            (&ctx.accounts.vault_b, &ctx.accounts.user_token_b, out_b),
+        ] {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: user_token.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    &[&seeds[..]],
+                ),
+                amount,
+            )?;
+        }
+
+        let pool = &mut ctx.accounts.pool;
+        pool.reserve_a -= out_a;
+        pool.reserve_b -= out_b;
+        pool.lp_supply -= lp_amount;
+        ctx.accounts.position.lp_balance -= lp_amount;
+        Ok(())
+    }
+}
+
+fn pro_rata(reserve: u64, lp_amount: u64, lp_supply: u64) -> Result<u64> {
+    let out = (reserve as u128)
+        .checked_mul(lp_amount as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / lp_supply as u128;
+    u64::try_from(out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct DepositSingle<'info> {
+    #[account(mut, has_one = vault_a)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_a, token::authority = owner)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_a)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_b)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub lp_supply: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct LpPosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub lp_balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Pool is empty")]
+    EmptyPool,
+
+    #[msg("Insufficient LP balance")]
+    InsufficientLp,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use std::collections::BTreeSet;
+
+declare_id!("Secur17117117117117117117117117117117117117");
+
+/// Upper bound on tickets per batch
+pub const MAX_BATCH_SIZE: usize = 20;
+
+#[program]
+pub mod secure_remaining_accounts_dedup {
+    use super::*;
+
+    /// SECURE: Batch Rejected Unless Every Account Is Distinct
+    ///
+    /// Before any ticket is read, the keys of `remaining_accounts` are
+    /// collected into a `BTreeSet`. If the set is smaller than the list,
+    /// some account appears twice and the whole batch fails.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `remaining_accounts.len() == unique_keys.len()` checked first
+    /// 2. Batch size bounded by `MAX_BATCH_SIZE`
+    /// 3. Each ticket deserialized as `Account<Ticket>` (owner + discriminator)
+    /// 4. Ownership and `claimed` checked per ticket
+    pub fn claim_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimBatch<'info>>,
+    ) -> Result<()> {
+        require!(
+            !ctx.remaining_accounts.is_empty()
+                && ctx.remaining_accounts.len() <= MAX_BATCH_SIZE,
+            ErrorCode::InvalidBatchSize
+        );
+
+        // ✅ Every account must appear exactly once
+        let unique_keys: BTreeSet<Pubkey> =
+            ctx.remaining_accounts.iter().map(|info| info.key()).collect();
+        require!(
+            ctx.remaining_accounts.len() == unique_keys.len(),
+            ErrorCode::DuplicateAccount
+        );
+
+        let claimer = ctx.accounts.claimer.key();
+        let mut tickets = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut total: u64 = 0;
+        for info in ctx.remaining_accounts.iter() {
+            let ticket = Account::<Ticket>::try_from(info)?;
+            require_keys_eq!(ticket.owner, claimer, ErrorCode::NotTicketOwner);
+            require!(!ticket.claimed, ErrorCode::AlreadyClaimed);
+
+            total = total
+                .checked_add(ticket.amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            tickets.push(ticket);
+        }
+
+        let seeds = &[b"reward_pool".as_ref(), &[ctx.accounts.reward_pool.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.claimer_token.to_account_info(),
+                    authority: ctx.accounts.reward_pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            total,
+        )?;
+
+        for ticket in tickets.iter_mut() {
+            ticket.claimed = true;
+            ticket.exit(&crate::ID)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimBatch<'info> {
+    #[account(seeds = [b"reward_pool"], bump = reward_pool.bump, has_one = reward_vault)]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = claimer)]
+    pub claimer_token: Account<'info, TokenAccount>,
+
+    pub claimer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: [writable] Ticket, ... (all distinct)
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct RewardPool {
+    pub reward_vault: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Ticket {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub claimed: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Duplicate account in batch")]
+    DuplicateAccount,
+
+    #[msg("Batch must contain between 1 and MAX_BATCH_SIZE tickets")]
+    InvalidBatchSize,
+
+    #[msg("Ticket belongs to another user")]
+    NotTicketOwner,
+
+    #[msg("Ticket already claimed")]
+    AlreadyClaimed,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
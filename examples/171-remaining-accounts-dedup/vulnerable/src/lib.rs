@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln17117117117117117117117117117117117117");
+
+#[program]
+pub mod vulnerable_remaining_accounts_dedup {
+    use super::*;
+
+    /// VULNERABILITY: Duplicate Accounts In A Batch Are Processed Twice
+    ///
+    /// `claim_batch` lets a user redeem many reward tickets in one
+    /// transaction by passing them as `remaining_accounts`. Every ticket is
+    /// loaded up front, summed, and only then marked claimed. Nothing stops
+    /// the same ticket from appearing several times in the list.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory holds one unclaimed ticket worth 100 tokens
+    /// 2. She calls `claim_batch` with that ticket repeated 20 times
+    /// 3. Each copy is deserialized before any is written back, so all 20
+    ///    see `claimed == false`
+    /// 4. The payout is 20 x 100 = 2,000 tokens
+    /// 5. The ticket ends up marked claimed once
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The runtime happily passes one account at several indices
+    /// - Per-entry checks cannot see the other entries
+    pub fn claim_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimBatch<'info>>,
+    ) -> Result<()> {
+        let claimer = ctx.accounts.claimer.key();
+
+        // ❌ No uniqueness check across remaining_accounts
+        let mut tickets = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut total: u64 = 0;
+        for info in ctx.remaining_accounts.iter() {
+            let ticket = Account::<Ticket>::try_from(info)?;
+            require_keys_eq!(ticket.owner, claimer, ErrorCode::NotTicketOwner);
+            require!(!ticket.claimed, ErrorCode::AlreadyClaimed);
+
+            total = total
+                .checked_add(ticket.amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            tickets.push(ticket);
+        }
+
+        let seeds = &[b"reward_pool".as_ref(), &[ctx.accounts.reward_pool.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.claimer_token.to_account_info(),
+                    authority: ctx.accounts.reward_pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            total,
+        )?;
+
+        for ticket in tickets.iter_mut() {
+            ticket.claimed = true;
+            ticket.exit(&crate::ID)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimBatch<'info> {
+    #[account(seeds = [b"reward_pool"], bump = reward_pool.bump, has_one = reward_vault)]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = claimer)]
+    pub claimer_token: Account<'info, TokenAccount>,
+
+    pub claimer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: [writable] Ticket, ...
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct RewardPool {
+    pub reward_vault: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Ticket {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub claimed: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Ticket belongs to another user")]
+    NotTicketOwner,
+
+    #[msg("Ticket already claimed")]
+    AlreadyClaimed,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
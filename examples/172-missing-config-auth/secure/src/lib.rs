@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur17217217217217217217217217217217217217");
+
+/// Highest fee the config will accept (10%)
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+#[program]
+pub mod secure_missing_config_auth {
+    use super::*;
+
+    /// SECURE: Config Updates Restricted To The Stored Admin
+    ///
+    /// The account struct ties `config.admin` to an `admin` account that
+    /// must sign. Anchor rejects the instruction before the handler runs
+    /// if either part is missing.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `has_one = admin @ ErrorCode::Unauthorized` on the config
+    /// 2. `admin: Signer<'info>` - the key alone is not enough
+    /// 3. Fee bounded by `MAX_FEE_BPS` even for the admin
+    /// 4. Update emitted as an event for off-chain monitoring
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        // ✅ Sanity bound on the new value
+        require!(params.fee_bps <= MAX_FEE_BPS, ErrorCode::FeeTooHigh);
+
+        let config = &mut ctx.accounts.config;
+        config.fee_bps = params.fee_bps;
+        config.treasury = params.treasury;
+        config.paused = params.paused;
+
+        emit!(ConfigUpdated {
+            admin: config.admin,
+            fee_bps: config.fee_bps,
+            treasury: config.treasury,
+            paused: config.paused,
+        });
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    // ✅ Stored admin must match the signer
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct GlobalConfig {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+    pub paused: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    pub fee_bps: u16,
+    pub treasury: Pubkey,
+    pub paused: bool,
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub treasury: Pubkey,
+    pub paused: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Caller is not the config admin")]
+    Unauthorized,
+
+    #[msg("Fee exceeds the maximum")]
+    FeeTooHigh,
+}
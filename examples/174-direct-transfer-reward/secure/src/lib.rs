@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur17417417417417417417417417417417417417");
+
+/// Fixed-point scale for `reward_rate`
+pub const RATE_PRECISION: u128 = 1_000_000_000_000;
+
+#[program]
+pub mod secure_direct_transfer_reward {
+    use super::*;
+
+    /// SECURE: Stake Tracked In Program State, Not Read From The Vault
+    ///
+    /// `position.staked_amount` changes only in `stake` and `unstake`.
+    /// Rewards are settled before every change, so each amount earns only
+    /// for the slots it was actually staked. Tokens sent straight to the
+    /// vault earn nothing and cannot be withdrawn as stake.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Rewards computed from `staked_amount`, never `stake_vault.amount`
+    /// 2. `settle` accrues rewards at the old stake before it changes
+    /// 3. `unstake` bounded by `staked_amount`
+    /// 4. Checked arithmetic throughout
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        let now = Clock::get()?.slot;
+        settle(&mut ctx.accounts.position, &ctx.accounts.farm, now)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    to: ctx.accounts.stake_vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        // ✅ Only path that increases the stake
+        let position = &mut ctx.accounts.position;
+        position.staked_amount = position
+            .staked_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Pay out everything accrued so far
+    pub fn claim_rewards(ctx: Context<Claim>) -> Result<()> {
+        let now = Clock::get()?.slot;
+        settle(&mut ctx.accounts.position, &ctx.accounts.farm, now)?;
+
+        let reward = ctx.accounts.position.pending_rewards;
+        ctx.accounts.position.pending_rewards = 0;
+
+        let seeds = &[b"farm".as_ref(), ctx.accounts.farm.stake_mint.as_ref(), &[ctx.accounts.farm.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.user_reward_token.to_account_info(),
+                    authority: ctx.accounts.farm.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            reward,
+        )?;
+        Ok(())
+    }
+
+    /// Withdraw previously staked tokens
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        let now = Clock::get()?.slot;
+        settle(&mut ctx.accounts.position, &ctx.accounts.farm, now)?;
+
+        // ✅ Bounded by recorded stake
+        let position = &mut ctx.accounts.position;
+        position.staked_amount = position
+            .staked_amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStake)?;
+
+        let owner = ctx.accounts.owner.key();
+        let farm = ctx.accounts.farm.key();
+        let seeds = &[
+            b"position".as_ref(),
+            farm.as_ref(),
+            owner.as_ref(),
+            &[ctx.accounts.position.bump],
+        ];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_vault.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.position.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+/// Accrue rewards on the recorded stake up to `now`
+fn settle(position: &mut Position, farm: &Farm, now: u64) -> Result<()> {
+    let elapsed = now.saturating_sub(position.last_update_slot);
+    let accrued = (position.staked_amount as u128)
+        .checked_mul(farm.reward_rate as u128)
+        .and_then(|v| v.checked_mul(elapsed as u128))
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / RATE_PRECISION;
+    let accrued = u64::try_from(accrued).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+    position.pending_rewards = position
+        .pending_rewards
+        .checked_add(accrued)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    position.last_update_slot = now;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    pub farm: Account<'info, Farm>,
+
+    #[account(mut, has_one = farm, has_one = owner, has_one = stake_vault)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = farm.stake_mint, token::authority = owner)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(has_one = reward_vault)]
+    pub farm: Account<'info, Farm>,
+
+    #[account(mut, has_one = farm, has_one = owner)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = owner)]
+    pub user_reward_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub farm: Account<'info, Farm>,
+
+    #[account(mut, has_one = farm, has_one = owner, has_one = stake_vault)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = farm.stake_mint, token::authority = owner)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Farm {
+    pub stake_mint: Pubkey,
+    pub reward_vault: Pubkey,
+    /// Reward per staked token per slot, scaled by `RATE_PRECISION`
+    pub reward_rate: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub farm: Pubkey,
+    pub owner: Pubkey,
+    pub stake_vault: Pubkey,
+    /// Changed only by `stake` and `unstake`
+    pub staked_amount: u64,
+    pub pending_rewards: u64,
+    pub last_update_slot: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Insufficient staked balance")]
+    InsufficientStake,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
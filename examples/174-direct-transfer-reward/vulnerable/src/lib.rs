@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln17417417417417417417417417417417417417");
+
+/// Fixed-point scale for `reward_rate`
+pub const RATE_PRECISION: u128 = 1_000_000_000_000;
+
+#[program]
+pub mod vulnerable_direct_transfer_reward {
+    use super::*;
+
+    /// Move tokens into the position's stake vault
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    to: ctx.accounts.stake_vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Rewards Paid On The Live Token Balance
+    ///
+    /// Rewards for the period since the last claim are computed from
+    /// `stake_vault.amount`, the SPL balance right now. Anyone can send
+    /// tokens straight to the vault without calling `stake`, and the
+    /// balance at claim time is applied to the whole elapsed period.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory stakes 10 tokens and waits 30 days
+    /// 2. She flash-borrows 1,000,000 tokens
+    /// 3. She transfers them directly into her stake vault
+    /// 4. `claim_rewards` pays 30 days of rewards on 1,000,010 tokens
+    /// 5. `unstake` returns the full vault balance; she repays the loan
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Balance is an instantaneous value; rewards accrue over time
+    /// - Direct transfers bypass every accounting hook
+    pub fn claim_rewards(ctx: Context<Claim>) -> Result<()> {
+        let now = Clock::get()?.slot;
+        let position = &ctx.accounts.position;
+        let elapsed = now.saturating_sub(position.last_claim_slot);
+
+        // ❌ Current SPL balance used as the staked amount
+        let staked = ctx.accounts.stake_vault.amount;
+        let reward = (staked as u128)
+            .checked_mul(ctx.accounts.farm.reward_rate as u128)
+            .and_then(|v| v.checked_mul(elapsed as u128))
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / RATE_PRECISION;
+        let reward = u64::try_from(reward).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        let seeds = &[b"farm".as_ref(), ctx.accounts.farm.stake_mint.as_ref(), &[ctx.accounts.farm.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.user_reward_token.to_account_info(),
+                    authority: ctx.accounts.farm.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            reward,
+        )?;
+
+        ctx.accounts.position.last_claim_slot = now;
+        Ok(())
+    }
+
+    /// Withdraw from the stake vault
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        // ❌ Bounded by the vault balance, not by what was staked
+        require!(
+            ctx.accounts.stake_vault.amount >= amount,
+            ErrorCode::InsufficientStake
+        );
+
+        let owner = ctx.accounts.owner.key();
+        let farm = ctx.accounts.farm.key();
+        let seeds = &[
+            b"position".as_ref(),
+            farm.as_ref(),
+            owner.as_ref(),
+            &[ctx.accounts.position.bump],
+        ];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_vault.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.position.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    pub farm: Account<'info, Farm>,
+
+    #[account(has_one = farm, has_one = owner, has_one = stake_vault)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = farm.stake_mint, token::authority = owner)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(has_one = reward_vault)]
+    pub farm: Account<'info, Farm>,
+
+    #[account(mut, has_one = farm, has_one = owner, has_one = stake_vault)]
+    pub position: Account<'info, Position>,
+
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = owner)]
+    pub user_reward_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub farm: Account<'info, Farm>,
+
+    #[account(has_one = farm, has_one = owner, has_one = stake_vault)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = farm.stake_mint, token::authority = owner)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Farm {
+    pub stake_mint: Pubkey,
+    pub reward_vault: Pubkey,
+    /// Reward per staked token per slot, scaled by `RATE_PRECISION`
+    pub reward_rate: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub farm: Pubkey,
+    pub owner: Pubkey,
+    pub stake_vault: Pubkey,
+    pub last_claim_slot: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient staked balance")]
+    InsufficientStake,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur17517517517517517517517517517517517517");
+
+/// Leading seed of every PDA this program derives
+pub const PREFIX: &[u8] = b"my_protocol_v1_";
+
+pub const RECORD_TAG: &[u8] = b"record";
+pub const FEE_EXEMPT_TAG: &[u8] = b"fee_exempt";
+
+#[program]
+pub mod secure_user_controlled_seeds {
+    use super::*;
+
+    /// SECURE: Seeds Built Only From Program-Defined Prefixes
+    ///
+    /// Every PDA starts with `PREFIX` followed by a tag naming the account
+    /// type. The caller's namespace still selects a record, but only as
+    /// the last seed, after the fixed tag - so it can never reproduce the
+    /// seeds of an exemption or any other account type.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `PREFIX` (`b"my_protocol_v1_"`) leads every derivation
+    /// 2. Distinct per-type tags: `RECORD_TAG`, `FEE_EXEMPT_TAG`
+    /// 3. User input appears only after the fixed seeds
+    /// 4. Namespace length bounded by `MAX_NAMESPACE_LEN`
+    pub fn create_record(
+        ctx: Context<CreateRecord>,
+        namespace: Vec<u8>,
+        label: String,
+    ) -> Result<()> {
+        require!(label.len() <= UserRecord::MAX_LABEL_LEN, ErrorCode::LabelTooLong);
+        require!(
+            namespace.len() <= UserRecord::MAX_NAMESPACE_LEN,
+            ErrorCode::NamespaceTooLong
+        );
+
+        let record = &mut ctx.accounts.record;
+        record.owner = ctx.accounts.user.key();
+        record.namespace = namespace;
+        record.label = label;
+        Ok(())
+    }
+
+    /// Admin grants a fee exemption
+    pub fn grant_fee_exemption(ctx: Context<GrantFeeExemption>) -> Result<()> {
+        ctx.accounts.exemption.user = ctx.accounts.user.key();
+        Ok(())
+    }
+
+    /// Move balance between wallets, charging the protocol fee
+    pub fn transfer(ctx: Context<TransferFunds>, amount: u64) -> Result<()> {
+        let fee = if is_fee_exempt(&ctx.accounts.exemption) {
+            0
+        } else {
+            amount
+                .checked_mul(ctx.accounts.fee_config.fee_bps as u64)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                / 10_000
+        };
+
+        let from = &mut ctx.accounts.from;
+        from.balance = from
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+        let to = &mut ctx.accounts.to;
+        to.balance = to
+            .balance
+            .checked_add(amount - fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury
+            .balance
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Exemption exists if this program owns a non-empty account at the PDA
+pub fn is_fee_exempt(marker: &AccountInfo) -> bool {
+    marker.owner == &crate::ID && !marker.data_is_empty()
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(namespace: Vec<u8>)]
+pub struct CreateRecord<'info> {
+    // ✅ Fixed prefix and tag first; caller input last
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UserRecord::LEN,
+        seeds = [PREFIX, RECORD_TAG, user.key().as_ref(), namespace.as_slice()],
+        bump,
+    )]
+    pub record: Account<'info, UserRecord>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GrantFeeExemption<'info> {
+    #[account(has_one = admin)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + FeeExemption::LEN,
+        seeds = [PREFIX, FEE_EXEMPT_TAG, user.key().as_ref()],
+        bump,
+    )]
+    pub exemption: Account<'info, FeeExemption>,
+
+    /// CHECK: Recipient of the exemption
+    pub user: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferFunds<'info> {
+    #[account(has_one = treasury)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Wallet>,
+
+    #[account(mut)]
+    pub to: Account<'info, Wallet>,
+
+    #[account(mut)]
+    pub treasury: Account<'info, Wallet>,
+
+    /// CHECK: May or may not exist
+    #[account(seeds = [PREFIX, FEE_EXEMPT_TAG, owner.key().as_ref()], bump)]
+    pub exemption: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct FeeConfig {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[account]
+pub struct FeeExemption {
+    pub user: Pubkey,
+}
+
+impl FeeExemption {
+    pub const LEN: usize = 32; // user
+}
+
+#[account]
+pub struct UserRecord {
+    pub owner: Pubkey,
+    pub namespace: Vec<u8>,
+    pub label: String,
+}
+
+impl UserRecord {
+    pub const MAX_NAMESPACE_LEN: usize = 32;
+    pub const MAX_LABEL_LEN: usize = 64;
+    pub const LEN: usize = 32 + // owner
+        4 + Self::MAX_NAMESPACE_LEN + // namespace
+        4 + Self::MAX_LABEL_LEN; // label
+}
+
+#[account]
+pub struct Wallet {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Label too long")]
+    LabelTooLong,
+
+    #[msg("Namespace too long")]
+    NamespaceTooLong,
+
+    #[msg("Insufficient funds")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
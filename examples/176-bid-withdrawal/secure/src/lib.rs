@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur17617617617617617617617617617617617617");
+
+/// Final stretch in which the leading bid cannot be withdrawn (~10 minutes)
+pub const LOCKOUT_SLOTS: u64 = 1_500;
+
+#[program]
+pub mod secure_bid_withdrawal {
+    use super::*;
+
+    /// Escrow lamports toward a bid; the escrowed total is the bid
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        require!(slot < ctx.accounts.auction.end_slot, ErrorCode::AuctionEnded);
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.bidder.to_account_info(),
+                    to: ctx.accounts.bid.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let bid = &mut ctx.accounts.bid;
+        bid.auction = ctx.accounts.auction.key();
+        bid.bidder = ctx.accounts.bidder.key();
+        bid.amount = bid
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        bid.bump = ctx.bumps.bid;
+
+        let auction = &mut ctx.accounts.auction;
+        require!(bid.amount > auction.highest_bid, ErrorCode::BidTooLow);
+        auction.highest_bidder = bid.bidder;
+        auction.highest_bid = bid.amount;
+        Ok(())
+    }
+
+    /// SECURE: Leading Bid Frozen Near The Close
+    ///
+    /// Outbid escrows can always be reclaimed. The leading bid can only be
+    /// retracted while more than `LOCKOUT_SLOTS` remain, and retracting it
+    /// clears the lead instead of promoting anyone - other bidders still
+    /// have the whole lockout window to bid again. Whoever leads when the
+    /// lockout begins either stays the winner or is outbid.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Leading bid cannot be withdrawn in the final `LOCKOUT_SLOTS`
+    /// 2. Bids below the leader withdrawable at any time
+    /// 3. No runner-up promotion - a winner must hold a live, escrowed bid
+    /// 4. An auction with no standing bid settles by returning the NFT
+    pub fn withdraw_bid(ctx: Context<WithdrawBid>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let auction = &mut ctx.accounts.auction;
+
+        if auction.highest_bidder == ctx.accounts.bidder.key() {
+            // ✅ Leader frozen during the lockout window
+            require!(
+                slot < auction.end_slot.saturating_sub(LOCKOUT_SLOTS),
+                ErrorCode::BidLocked
+            );
+            // ✅ Lead cleared, not handed to a possibly empty escrow
+            auction.highest_bidder = Pubkey::default();
+            auction.highest_bid = 0;
+        }
+        Ok(())
+    }
+
+    /// Pay the seller from the winning escrow and deliver the NFT, or
+    /// return the NFT if no bid is standing
+    pub fn settle(ctx: Context<Settle>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let auction = &ctx.accounts.auction;
+        require!(slot >= auction.end_slot, ErrorCode::AuctionNotEnded);
+        require!(!auction.settled, ErrorCode::AlreadySettled);
+
+        if let Some(winning_bid) = ctx.accounts.winning_bid.as_mut() {
+            let price = winning_bid.amount;
+            winning_bid.sub_lamports(price)?;
+            ctx.accounts.seller.add_lamports(price)?;
+        } else {
+            require!(auction.highest_bid == 0, ErrorCode::MissingWinningBid);
+        }
+
+        let seeds = &[b"auction".as_ref(), auction.nft_mint.as_ref(), &[auction.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.nft_vault.to_account_info(),
+                    to: ctx.accounts.nft_destination.to_account_info(),
+                    authority: ctx.accounts.auction.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            1,
+        )?;
+
+        ctx.accounts.auction.settled = true;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + Bid::LEN,
+        seeds = [b"bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawBid<'info> {
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [b"bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        has_one = bidder,
+        close = bidder,
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    #[account(mut, has_one = seller, has_one = nft_vault)]
+    pub auction: Account<'info, Auction>,
+
+    /// Present only when a bid is standing
+    #[account(
+        mut,
+        seeds = [b"bid", auction.key().as_ref(), auction.highest_bidder.as_ref()],
+        bump = winning_bid.bump,
+    )]
+    pub winning_bid: Option<Account<'info, Bid>>,
+
+    /// CHECK: Pinned by has_one
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub nft_vault: Account<'info, TokenAccount>,
+
+    /// Winner's NFT account, or the seller's if unsold
+    #[account(
+        mut,
+        token::mint = auction.nft_mint,
+        constraint = nft_destination.owner == auction.nft_recipient() @ ErrorCode::WrongRecipient,
+    )]
+    pub nft_destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Auction {
+    pub seller: Pubkey,
+    pub nft_mint: Pubkey,
+    pub nft_vault: Pubkey,
+    pub end_slot: u64,
+    pub highest_bidder: Pubkey,
+    pub highest_bid: u64,
+    pub settled: bool,
+    pub bump: u8,
+}
+
+impl Auction {
+    pub fn nft_recipient(&self) -> Pubkey {
+        if self.highest_bid > 0 {
+            self.highest_bidder
+        } else {
+            self.seller
+        }
+    }
+}
+
+#[account]
+pub struct Bid {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl Bid {
+    pub const LEN: usize = 32 + // auction
+        32 + // bidder
+        8 + // amount
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Auction has ended")]
+    AuctionEnded,
+
+    #[msg("Auction has not ended")]
+    AuctionNotEnded,
+
+    #[msg("Auction already settled")]
+    AlreadySettled,
+
+    #[msg("Bid must exceed the current highest bid")]
+    BidTooLow,
+
+    #[msg("Leading bid is locked near the auction end")]
+    BidLocked,
+
+    #[msg("Winning bid account missing")]
+    MissingWinningBid,
+
+    #[msg("NFT destination does not belong to the recipient")]
+    WrongRecipient,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
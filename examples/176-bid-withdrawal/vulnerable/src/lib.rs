@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln17617617617617617617617617617617617617");
+
+#[program]
+pub mod vulnerable_bid_withdrawal {
+    use super::*;
+
+    /// Escrow lamports toward a bid; the escrowed total is the bid
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        require!(slot < ctx.accounts.auction.end_slot, ErrorCode::AuctionEnded);
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.bidder.to_account_info(),
+                    to: ctx.accounts.bid.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let bid = &mut ctx.accounts.bid;
+        bid.auction = ctx.accounts.auction.key();
+        bid.bidder = ctx.accounts.bidder.key();
+        bid.amount = bid
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        bid.bump = ctx.bumps.bid;
+
+        let auction = &mut ctx.accounts.auction;
+        require!(bid.amount > auction.highest_bid, ErrorCode::BidTooLow);
+        if auction.highest_bidder != bid.bidder {
+            auction.runner_up_bidder = auction.highest_bidder;
+            auction.runner_up_bid = auction.highest_bid;
+        }
+        auction.highest_bidder = bid.bidder;
+        auction.highest_bid = bid.amount;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Bids Withdrawable At Any Time
+    ///
+    /// Any bid, including the leading one, can be pulled right up to the
+    /// last slot. When the leader withdraws, the recorded runner-up is
+    /// promoted - without checking that the runner-up's escrow still
+    /// exists.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice bids 10 SOL and is outbid by Mallory at 100 SOL
+    /// 2. Bob, seeing 100 SOL, decides not to bid 60
+    /// 3. Alice, outbid, withdraws her 10 SOL; she is still recorded as
+    ///    runner-up
+    /// 4. One slot before `end_slot`, Mallory withdraws her 100 SOL
+    /// 5. Alice is promoted to winner with no escrowed funds
+    /// 6. `settle` cannot load her bid; the NFT is stuck in the auction and
+    ///    the seller never sees Bob's 60 SOL
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A fake top bid scares off real bidders at no cost
+    /// - The "winner" may never have agreed to win, or may have no funds
+    pub fn withdraw_bid(ctx: Context<WithdrawBid>) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
+        let bidder = ctx.accounts.bidder.key();
+
+        // ❌ No lockout, no restriction on the leading bid
+        if auction.highest_bidder == bidder {
+            // ❌ Runner-up may have withdrawn already
+            auction.highest_bidder = auction.runner_up_bidder;
+            auction.highest_bid = auction.runner_up_bid;
+            auction.runner_up_bidder = Pubkey::default();
+            auction.runner_up_bid = 0;
+        }
+        Ok(())
+    }
+
+    /// Pay the seller from the winning escrow and deliver the NFT
+    pub fn settle(ctx: Context<Settle>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let auction = &ctx.accounts.auction;
+        require!(slot >= auction.end_slot, ErrorCode::AuctionNotEnded);
+        require!(!auction.settled, ErrorCode::AlreadySettled);
+
+        let price = ctx.accounts.winning_bid.amount;
+        ctx.accounts.winning_bid.sub_lamports(price)?;
+        ctx.accounts.seller.add_lamports(price)?;
+
+        let seeds = &[b"auction".as_ref(), auction.nft_mint.as_ref(), &[auction.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.nft_vault.to_account_info(),
+                    to: ctx.accounts.winner_nft_account.to_account_info(),
+                    authority: ctx.accounts.auction.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            1,
+        )?;
+
+        ctx.accounts.auction.settled = true;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + Bid::LEN,
+        seeds = [b"bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawBid<'info> {
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [b"bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        has_one = bidder,
+        close = bidder,
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    #[account(mut, has_one = seller, has_one = nft_vault)]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [b"bid", auction.key().as_ref(), auction.highest_bidder.as_ref()],
+        bump = winning_bid.bump,
+    )]
+    pub winning_bid: Account<'info, Bid>,
+
+    /// CHECK: Pinned by has_one
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub nft_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = auction.nft_mint,
+        token::authority = auction.highest_bidder,
+    )]
+    pub winner_nft_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Auction {
+    pub seller: Pubkey,
+    pub nft_mint: Pubkey,
+    pub nft_vault: Pubkey,
+    pub end_slot: u64,
+    pub highest_bidder: Pubkey,
+    pub highest_bid: u64,
+    pub runner_up_bidder: Pubkey,
+    pub runner_up_bid: u64,
+    pub settled: bool,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Bid {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl Bid {
+    pub const LEN: usize = 32 + // auction
+        32 + // bidder
+        8 + // amount
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Auction has ended")]
+    AuctionEnded,
+
+    #[msg("Auction has not ended")]
+    AuctionNotEnded,
+
+    #[msg("Auction already settled")]
+    AlreadySettled,
+
+    #[msg("Bid must exceed the current highest bid")]
+    BidTooLow,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
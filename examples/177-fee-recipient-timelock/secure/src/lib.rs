@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur17717717717717717717717717717717717717");
+
+/// Epochs between proposing a new recipient and it receiving fees
+pub const RECIPIENT_TIMELOCK_EPOCHS: u64 = 2;
+
+#[program]
+pub mod secure_fee_recipient_timelock {
+    use super::*;
+
+    /// SECURE: Recipient Changes Only Apply To Future Epochs
+    ///
+    /// A new recipient is queued with `effective_epoch = current_epoch + 2`.
+    /// The crank switches to the new recipient only once every lamport in
+    /// the vault was collected after the change took effect, so a change
+    /// can never reach fees that have already accrued, and it sits
+    /// on-chain for a full epoch before it applies.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `propose_fee_recipient` only queues the change
+    /// 2. `effective_epoch = current_epoch + RECIPIENT_TIMELOCK_EPOCHS`
+    /// 3. `distribute_fees` promotes the new recipient only after a
+    ///    distribution has run in the effective epoch
+    /// 4. Change event emitted for monitoring
+    pub fn propose_fee_recipient(
+        ctx: Context<ProposeFeeRecipient>,
+        new_recipient: Pubkey,
+    ) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+        let config = &mut ctx.accounts.config;
+
+        // ✅ Queued, not applied
+        config.pending_fee_recipient = new_recipient;
+        config.pending_effective_epoch = epoch
+            .checked_add(RECIPIENT_TIMELOCK_EPOCHS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        emit!(FeeRecipientProposed {
+            current: config.fee_recipient,
+            proposed: new_recipient,
+            effective_epoch: config.pending_effective_epoch,
+        });
+        Ok(())
+    }
+
+    /// Pay accrued fees to the recipient that was in force while they accrued
+    pub fn distribute_fees(ctx: Context<DistributeFees>) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+        let config = &mut ctx.accounts.config;
+        require!(epoch > config.last_distributed_epoch, ErrorCode::AlreadyDistributed);
+
+        // ✅ Promote the queued recipient only when everything left in the
+        // vault was collected at or after its effective epoch, i.e. the
+        // previous distribution already happened in that epoch
+        if config.pending_effective_epoch != 0
+            && config.last_distributed_epoch >= config.pending_effective_epoch
+        {
+            config.fee_recipient = config.pending_fee_recipient;
+            config.pending_fee_recipient = Pubkey::default();
+            config.pending_effective_epoch = 0;
+        }
+        require_keys_eq!(
+            ctx.accounts.fee_recipient.key(),
+            config.fee_recipient,
+            ErrorCode::WrongRecipient
+        );
+
+        let vault = &ctx.accounts.fee_vault;
+        let rent_floor = Rent::get()?.minimum_balance(vault.to_account_info().data_len());
+        let amount = vault.get_lamports().saturating_sub(rent_floor);
+
+        ctx.accounts.fee_vault.sub_lamports(amount)?;
+        ctx.accounts.fee_recipient.add_lamports(amount)?;
+
+        ctx.accounts.config.last_distributed_epoch = epoch;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ProposeFeeRecipient<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, FeeConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, FeeConfig>,
+
+    #[account(mut, seeds = [b"fee_vault"], bump = fee_vault.bump)]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    /// CHECK: Checked against the recipient for the epoch being paid
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct FeeConfig {
+    pub admin: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub pending_fee_recipient: Pubkey,
+    /// 0 when nothing is queued
+    pub pending_effective_epoch: u64,
+    pub last_distributed_epoch: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct FeeVault {
+    pub bump: u8,
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct FeeRecipientProposed {
+    pub current: Pubkey,
+    pub proposed: Pubkey,
+    pub effective_epoch: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fees for this epoch already distributed")]
+    AlreadyDistributed,
+
+    #[msg("Recipient does not match the recipient for this epoch")]
+    WrongRecipient,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,68 @@
+#[tokio::test]
+async fn test_fee_redirect_exploit() {
+    println!("\n=== EXPLOIT: Epoch Fees Redirected Before Distribution ===\n");
+
+    let admin = Keypair::new();
+    let dao_treasury = Pubkey::new_unique();
+    let config = initialize_fee_config(&admin, &dao_treasury).await;
+
+    println!("1. 5,000 SOL of fees accrue during epoch N");
+    collect_fees(5_000 * LAMPORTS_PER_SOL).await;
+
+    println!("\n2. Last slot of epoch N: admin sets itself as recipient");
+    warp_to_last_slot_of_epoch(N).await;
+    set_fee_recipient(&config, &admin, &admin.pubkey()).await.unwrap();
+
+    println!("\n3. Epoch N+1: crank distributes");
+    warp_to_epoch(N + 1).await;
+    distribute_fees(&config, &admin.pubkey()).await.unwrap();
+
+    // Vulnerable: change applied to fees already earned
+    assert_eq!(get_balance(&dao_treasury).await, 0);
+    assert_eq!(
+        get_balance(&admin.pubkey()).await,
+        ADMIN_START_BALANCE + 5_000 * LAMPORTS_PER_SOL
+    );
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ DAO's epoch fees paid to the admin");
+}
+
+#[tokio::test]
+async fn test_recipient_change_timelocked() {
+    println!("\n=== SECURITY: Recipient Change Effective At Epoch + 2 ===\n");
+
+    let admin = Keypair::new();
+    let dao_treasury = Pubkey::new_unique();
+    let config = initialize_fee_config(&admin, &dao_treasury).await;
+
+    collect_fees(5_000 * LAMPORTS_PER_SOL).await;
+    warp_to_last_slot_of_epoch(N).await;
+    propose_fee_recipient(&config, &admin, &admin.pubkey())
+        .await
+        .unwrap();
+    println!("1. Change proposed in epoch N, effective N+2");
+
+    println!("\n2. Epoch N+1: distribution to the admin");
+    warp_to_epoch(N + 1).await;
+    let result = distribute_fees(&config, &admin.pubkey()).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: WrongRecipient");
+
+    println!("\n3. Epoch N+1: distribution to the DAO");
+    distribute_fees(&config, &dao_treasury).await.unwrap();
+    assert_eq!(get_balance(&dao_treasury).await, 5_000 * LAMPORTS_PER_SOL);
+    println!("   ✓ Epoch N fees reach the DAO");
+
+    println!("\n4. Epoch N+2: fees from N+1 still go to the DAO");
+    collect_fees_in_epoch(N + 1, 200 * LAMPORTS_PER_SOL).await;
+    warp_to_epoch(N + 2).await;
+    distribute_fees(&config, &dao_treasury).await.unwrap();
+    assert_eq!(get_balance(&dao_treasury).await, 5_200 * LAMPORTS_PER_SOL);
+
+    println!("\n5. Epoch N+3: fees from N+2 go to the new recipient");
+    collect_fees_in_epoch(N + 2, 100 * LAMPORTS_PER_SOL).await;
+    warp_to_epoch(N + 3).await;
+    distribute_fees(&config, &admin.pubkey()).await.unwrap();
+    println!("   ✓ New recipient only receives fees accrued after the timelock");
+}
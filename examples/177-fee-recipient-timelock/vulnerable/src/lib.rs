@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln17717717717717717717717717717717717717");
+
+#[program]
+pub mod vulnerable_fee_recipient_timelock {
+    use super::*;
+
+    /// VULNERABILITY: Fee Recipient Changed With Immediate Effect
+    ///
+    /// Protocol fees accumulate in the `fee_vault` PDA during an epoch and
+    /// are paid to `config.fee_recipient` by a permissionless crank once
+    /// the epoch is over. The admin can swap the recipient at any moment,
+    /// and the crank pays whoever is set at distribution time.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Fees are promised to the DAO treasury; 5,000 SOL accrue in epoch N
+    /// 2. At the last slot of epoch N the admin key (rogue or compromised)
+    ///    sets `fee_recipient` to its own wallet
+    /// 3. The first transaction of epoch N+1 calls `distribute_fees`
+    /// 4. All 5,000 SOL - earned while the DAO was the recipient - go to
+    ///    the admin
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A parameter change applies retroactively to value already accrued
+    /// - Nobody gets a chance to react before the funds move
+    pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>, new_recipient: Pubkey) -> Result<()> {
+        // ❌ Takes effect immediately, including for fees already collected
+        ctx.accounts.config.fee_recipient = new_recipient;
+        Ok(())
+    }
+
+    /// Pay everything in the fee vault to the current recipient
+    pub fn distribute_fees(ctx: Context<DistributeFees>) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+        let config = &mut ctx.accounts.config;
+        require!(epoch > config.last_distributed_epoch, ErrorCode::AlreadyDistributed);
+
+        let vault = &ctx.accounts.fee_vault;
+        let rent_floor = Rent::get()?.minimum_balance(vault.to_account_info().data_len());
+        let amount = vault.get_lamports().saturating_sub(rent_floor);
+
+        ctx.accounts.fee_vault.sub_lamports(amount)?;
+        // ❌ Whoever is configured right now
+        ctx.accounts.fee_recipient.add_lamports(amount)?;
+
+        ctx.accounts.config.last_distributed_epoch = epoch;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetFeeRecipient<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, FeeConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump, has_one = fee_recipient)]
+    pub config: Account<'info, FeeConfig>,
+
+    #[account(mut, seeds = [b"fee_vault"], bump = fee_vault.bump)]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    /// CHECK: Pinned by has_one
+    #[account(mut)]
+    pub fee_recipient: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct FeeConfig {
+    pub admin: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub last_distributed_epoch: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct FeeVault {
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fees for this epoch already distributed")]
+    AlreadyDistributed,
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::epoch_schedule::EpochSchedule;
+use anchor_lang::system_program;
+
+declare_id!("Secur17817817817817817817817817817817817817");
+
+/// Fixed-point scale for epoch weights
+pub const WEIGHT_PRECISION: u128 = 1_000_000;
+
+#[program]
+pub mod secure_epoch_weighting {
+    use super::*;
+
+    /// SECURE: First Epoch Prorated By The Slots Actually Staked
+    ///
+    /// At deposit time the program records what fraction of the current
+    /// epoch is still ahead: `remaining_slots_in_epoch / slots_in_epoch`.
+    /// That fraction is the weight of the deposit epoch. Later epochs
+    /// count as 1, and an epoch that has not finished counts as 0.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Slot bounds of the epoch come from the `EpochSchedule` sysvar
+    /// 2. Deposit epoch weight stored as a fixed-point fraction
+    /// 3. A deposit in the last slot earns ~1/432,000 of an epoch
+    /// 4. u128 intermediate math with checked operations
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let clock = Clock::get()?;
+        let schedule = EpochSchedule::get()?;
+        let first_slot = schedule.get_first_slot_in_epoch(clock.epoch);
+        let slots_in_epoch = schedule.get_slots_in_epoch(clock.epoch);
+        let remaining_slots = (first_slot + slots_in_epoch).saturating_sub(clock.slot);
+
+        // ✅ remaining_slots_in_epoch / total_slots_in_epoch
+        let first_epoch_weight = (remaining_slots as u128)
+            .checked_mul(WEIGHT_PRECISION)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / slots_in_epoch as u128;
+
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.amount = amount;
+        position.deposit_epoch = clock.epoch;
+        position.first_epoch_weight = first_epoch_weight as u64;
+        Ok(())
+    }
+
+    /// Withdraw principal plus rewards for completed epochs
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+        let position = &ctx.accounts.position;
+
+        // ✅ Deposit epoch prorated; only completed epochs earn
+        let weight = if epoch > position.deposit_epoch {
+            let full_epochs = (epoch - position.deposit_epoch - 1) as u128;
+            full_epochs
+                .checked_mul(WEIGHT_PRECISION)
+                .and_then(|v| v.checked_add(position.first_epoch_weight as u128))
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+        } else {
+            0
+        };
+
+        let reward = (position.amount as u128)
+            .checked_mul(ctx.accounts.pool.reward_rate_bps as u128)
+            .and_then(|v| v.checked_mul(weight))
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / (10_000 * WEIGHT_PRECISION);
+        let reward = u64::try_from(reward).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        let payout = position
+            .amount
+            .checked_add(reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        ctx.accounts.pool.sub_lamports(payout)?;
+        ctx.accounts.owner.add_lamports(payout)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Position::LEN,
+        seeds = [b"position", owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"position", owner.key().as_ref()],
+        bump,
+        has_one = owner,
+        close = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct StakePool {
+    /// Reward per full epoch, in basis points of the stake
+    pub reward_rate_bps: u16,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub deposit_epoch: u64,
+    /// Share of the deposit epoch that was staked, scaled by `WEIGHT_PRECISION`
+    pub first_epoch_weight: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        8 + // amount
+        8 + // deposit_epoch
+        8; // first_epoch_weight
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
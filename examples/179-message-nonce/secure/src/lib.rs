@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur17917917917917917917917917917917917917");
+
+#[program]
+pub mod secure_message_nonce {
+    use super::*;
+
+    /// Guardian posts a message attested on the source chain
+    pub fn post_message(
+        ctx: Context<PostMessage>,
+        nonce: u64,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        let message = &mut ctx.accounts.message;
+        message.nonce = nonce;
+        message.recipient = recipient;
+        message.amount = amount;
+        Ok(())
+    }
+
+    /// SECURE: Strictly Sequential Nonces
+    ///
+    /// The relay keeps `expected_nonce`. A message runs only if its nonce
+    /// equals that value, and the counter is bumped in the same
+    /// instruction. Once a nonce has been consumed it can never match
+    /// again, and no message can skip ahead of an earlier one.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `message.nonce == relay_state.expected_nonce` required
+    /// 2. `expected_nonce` incremented before funds move
+    /// 3. Both happen in one instruction - they commit or fail together
+    /// 4. Executed message account closed, refunding the guardian's rent
+    pub fn execute_message(ctx: Context<ExecuteMessage>) -> Result<()> {
+        let relay_state = &mut ctx.accounts.relay_state;
+        let message = &ctx.accounts.message;
+
+        // ✅ Exactly the next message in sequence
+        require!(
+            message.nonce == relay_state.expected_nonce,
+            ErrorCode::InvalidNonce
+        );
+        relay_state.expected_nonce = relay_state
+            .expected_nonce
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        ctx.accounts.vault.sub_lamports(message.amount)?;
+        ctx.accounts.recipient.add_lamports(message.amount)?;
+
+        msg!("Executed message {}", message.nonce);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct PostMessage<'info> {
+    #[account(seeds = [b"relay"], bump = relay_state.bump, has_one = guardian)]
+    pub relay_state: Account<'info, RelayState>,
+
+    #[account(
+        init,
+        payer = guardian,
+        space = 8 + PostedMessage::LEN,
+        seeds = [b"message", nonce.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub message: Account<'info, PostedMessage>,
+
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMessage<'info> {
+    #[account(mut, seeds = [b"relay"], bump = relay_state.bump, has_one = guardian)]
+    pub relay_state: Account<'info, RelayState>,
+
+    #[account(
+        mut,
+        seeds = [b"message", message.nonce.to_le_bytes().as_ref()],
+        bump,
+        has_one = recipient,
+        close = guardian,
+    )]
+    pub message: Account<'info, PostedMessage>,
+
+    #[account(mut, seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, BridgeVault>,
+
+    /// CHECK: Pinned by has_one
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Rent refund destination, pinned by has_one
+    #[account(mut)]
+    pub guardian: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct RelayState {
+    pub guardian: Pubkey,
+    /// Nonce of the next message allowed to execute
+    pub expected_nonce: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct PostedMessage {
+    pub nonce: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+impl PostedMessage {
+    pub const LEN: usize = 8 + // nonce
+        32 + // recipient
+        8; // amount
+}
+
+#[account]
+pub struct BridgeVault {
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Message nonce is not the next expected nonce")]
+    InvalidNonce,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln17917917917917917917917917917917917917");
+
+#[program]
+pub mod vulnerable_message_nonce {
+    use super::*;
+
+    /// Guardian posts a message attested on the source chain
+    pub fn post_message(
+        ctx: Context<PostMessage>,
+        nonce: u64,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        let message = &mut ctx.accounts.message;
+        message.nonce = nonce;
+        message.recipient = recipient;
+        message.amount = amount;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Messages Executed Without Nonce Ordering
+    ///
+    /// A posted message releases `amount` lamports from the bridge vault
+    /// to `recipient`. Anyone may execute a posted message, and nothing
+    /// records that it was executed or requires messages to run in
+    /// order.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory bridges 1,000 SOL; the guardian posts message #7
+    /// 2. Message #7 is executed and pays her 1,000 SOL
+    /// 3. She executes message #7 again, and again...
+    /// 4. Each run pays another 1,000 SOL until the vault is empty
+    /// 5. Ordering is not enforced either: a later "raise limit" message
+    ///    can run before the "lower limit" message that preceded it
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A valid attestation stays valid forever
+    /// - Bridge vaults hold the backing for every wrapped token
+    pub fn execute_message(ctx: Context<ExecuteMessage>) -> Result<()> {
+        let message = &ctx.accounts.message;
+
+        // ❌ No nonce check, no executed flag
+        ctx.accounts.vault.sub_lamports(message.amount)?;
+        ctx.accounts.recipient.add_lamports(message.amount)?;
+
+        msg!("Executed message {}", message.nonce);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct PostMessage<'info> {
+    #[account(seeds = [b"relay"], bump = relay_state.bump, has_one = guardian)]
+    pub relay_state: Account<'info, RelayState>,
+
+    #[account(
+        init,
+        payer = guardian,
+        space = 8 + PostedMessage::LEN,
+        seeds = [b"message", nonce.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub message: Account<'info, PostedMessage>,
+
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMessage<'info> {
+    #[account(seeds = [b"relay"], bump = relay_state.bump)]
+    pub relay_state: Account<'info, RelayState>,
+
+    #[account(has_one = recipient)]
+    pub message: Account<'info, PostedMessage>,
+
+    #[account(mut, seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, BridgeVault>,
+
+    /// CHECK: Pinned by has_one
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct RelayState {
+    pub guardian: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct PostedMessage {
+    pub nonce: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+impl PostedMessage {
+    pub const LEN: usize = 8 + // nonce
+        32 + // recipient
+        8; // amount
+}
+
+#[account]
+pub struct BridgeVault {
+    pub bump: u8,
+}
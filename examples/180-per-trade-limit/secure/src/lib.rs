@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur18o18o18o18o18o18o18o18o18o18o18o18o18");
+
+/// Largest price impact a single swap may have (1%)
+pub const MAX_IMPACT_BPS: u64 = 100;
+
+/// Largest input volume per direction within one slot, in basis points
+/// of the input reserve at the slot's first swap (3%)
+pub const MAX_BLOCK_VOLUME: u64 = 300;
+
+#[program]
+pub mod secure_per_trade_limit {
+    use super::*;
+
+    /// SECURE: Per-Trade and Per-Block Impact Limits
+    ///
+    /// The per-trade limit stays, and a `BlockVolumeTracker` PDA
+    /// accumulates the input volume of every swap in the current slot.
+    /// The accumulator is keyed by slot: the first swap of a new slot
+    /// resets it and snapshots the reserves. Splitting a trade no longer
+    /// helps - the pieces all count against the same budget.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `price_impact_bps <= MAX_IMPACT_BPS` for each swap
+    /// 2. Volume summed per direction across all swaps in a slot
+    /// 3. Budget measured against reserves at the start of the slot, so
+    ///    earlier pieces cannot enlarge it for later ones
+    /// 4. Cumulative volume capped at `MAX_BLOCK_VOLUME`
+    ///
+    /// WHY PER SLOT:
+    /// Lending markets and oracles read the pool within the same slot the
+    /// manipulation happens in. Bounding movement per slot bounds what
+    /// any one block can do to that price, however the trades are split.
+    pub fn swap(ctx: Context<Swap>, a_to_b: bool, amount_in: u64, min_out: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let (reserve_in, reserve_out) = if a_to_b {
+            (pool.reserve_a, pool.reserve_b)
+        } else {
+            (pool.reserve_b, pool.reserve_a)
+        };
+
+        let impact_bps = price_impact_bps(amount_in, reserve_in)?;
+        require!(impact_bps <= MAX_IMPACT_BPS, ErrorCode::PriceImpactTooHigh);
+
+        // ✅ Charge this swap against the slot's volume budget
+        let current_slot = Clock::get()?.slot;
+        ctx.accounts
+            .block_volume
+            .record(current_slot, pool, a_to_b, amount_in)?;
+
+        let amount_out = swap_output(amount_in, reserve_in, reserve_out)?;
+        require!(amount_out >= min_out, ErrorCode::SlippageExceeded);
+
+        execute_swap(ctx, a_to_b, amount_in, amount_out)
+    }
+}
+
+/// Impact of `amount_in` on a constant-product price, in basis points
+pub fn price_impact_bps(amount_in: u64, reserve_in: u64) -> Result<u64> {
+    let denominator = (reserve_in as u128)
+        .checked_add(amount_in as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(denominator > 0, ErrorCode::EmptyPool);
+    Ok(((amount_in as u128) * 10_000 / denominator) as u64)
+}
+
+pub fn swap_output(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+    let out = (reserve_out as u128)
+        .checked_mul(amount_in as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / (reserve_in as u128 + amount_in as u128);
+    u64::try_from(out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+fn execute_swap(ctx: Context<Swap>, a_to_b: bool, amount_in: u64, amount_out: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let (user_in, vault_in, vault_out, user_out) = if a_to_b {
+        (&accounts.user_token_a, &accounts.vault_a, &accounts.vault_b, &accounts.user_token_b)
+    } else {
+        (&accounts.user_token_b, &accounts.vault_b, &accounts.vault_a, &accounts.user_token_a)
+    };
+
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: user_in.to_account_info(),
+                to: vault_in.to_account_info(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        amount_in,
+    )?;
+
+    let seeds = &[b"pool".as_ref(), &[accounts.pool.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault_out.to_account_info(),
+                to: user_out.to_account_info(),
+                authority: accounts.pool.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount_out,
+    )?;
+
+    let pool = &mut accounts.pool;
+    if a_to_b {
+        pool.reserve_a += amount_in;
+        pool.reserve_b -= amount_out;
+    } else {
+        pool.reserve_b += amount_in;
+        pool.reserve_a -= amount_out;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    // ✅ One tracker per pool, shared by every trader
+    #[account(
+        mut,
+        seeds = [b"block_volume", pool.key().as_ref()],
+        bump = block_volume.bump,
+        has_one = pool,
+    )]
+    pub block_volume: Account<'info, BlockVolumeTracker>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_a.mint, token::authority = user)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_b.mint, token::authority = user)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct BlockVolumeTracker {
+    pub pool: Pubkey,
+    /// Slot the accumulators below belong to
+    pub slot: u64,
+    /// Reserves snapshotted at the slot's first swap
+    pub start_reserve_a: u64,
+    pub start_reserve_b: u64,
+    /// Input volume swapped in each direction during `slot`
+    pub volume_a_in: u64,
+    pub volume_b_in: u64,
+    pub bump: u8,
+}
+
+impl BlockVolumeTracker {
+    pub const LEN: usize = 32 + // pool
+        8 + // slot
+        8 + // start_reserve_a
+        8 + // start_reserve_b
+        8 + // volume_a_in
+        8 + // volume_b_in
+        1; // bump
+
+    pub fn record(&mut self, current_slot: u64, pool: &Pool, a_to_b: bool, amount_in: u64) -> Result<()> {
+        if current_slot != self.slot {
+            self.slot = current_slot;
+            self.start_reserve_a = pool.reserve_a;
+            self.start_reserve_b = pool.reserve_b;
+            self.volume_a_in = 0;
+            self.volume_b_in = 0;
+        }
+
+        let (volume, start_reserve) = if a_to_b {
+            (&mut self.volume_a_in, self.start_reserve_a)
+        } else {
+            (&mut self.volume_b_in, self.start_reserve_b)
+        };
+        *volume = volume
+            .checked_add(amount_in)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let limit = (start_reserve as u128) * (MAX_BLOCK_VOLUME as u128) / 10_000;
+        require!((*volume as u128) <= limit, ErrorCode::BlockVolumeExceeded);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Price impact exceeds the per-trade limit")]
+    PriceImpactTooHigh,
+
+    #[msg("Swap volume for this block exceeds the limit")]
+    BlockVolumeExceeded,
+
+    #[msg("Output below minimum")]
+    SlippageExceeded,
+
+    #[msg("Pool is empty")]
+    EmptyPool,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
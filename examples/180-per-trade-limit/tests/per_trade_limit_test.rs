@@ -0,0 +1,51 @@
+#[tokio::test]
+async fn test_split_trade_exploit() {
+    println!("\n=== EXPLOIT: Per-Trade Impact Limit Bypassed By Splitting ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_pool(1_000_000, 1_000_000).await;
+    let price_before = get_spot_price_b(&pool).await;
+
+    println!("1. One 1,700,000 A swap is rejected");
+    let result = swap(&pool, &mallory, true, 1_700_000, 0).await;
+    assert!(result.is_err());
+
+    println!("\n2. Mallory sends 100 swaps, each at the 1% impact limit");
+    for _ in 0..100 {
+        let reserve_a = get_pool(&pool).await.reserve_a;
+        // Vulnerable: each piece passes the per-trade check
+        swap(&pool, &mallory, true, reserve_a / 99, 0).await.unwrap();
+    }
+
+    let price_after = get_spot_price_b(&pool).await;
+    assert!(price_after > price_before * 7);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Price of B moved ~7x through 100 \"small\" trades");
+}
+
+#[tokio::test]
+async fn test_block_volume_limit() {
+    println!("\n=== SECURITY: Per-Block Volume Limit ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_pool(1_000_000, 1_000_000).await;
+
+    println!("1. Three 1% swaps in one slot");
+    for _ in 0..3 {
+        swap(&pool, &mallory, true, 10_000, 0).await.unwrap();
+    }
+    println!("   ✓ 30,000 A of a 30,000 A budget used");
+
+    println!("\n2. A fourth swap in the same slot");
+    let result = swap(&pool, &mallory, true, 10_000, 0).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: BlockVolumeExceeded");
+
+    println!("\n3. Next slot: budget resets");
+    warp_to_slot(get_slot().await + 1).await;
+    swap(&pool, &mallory, true, 10_000, 0).await.unwrap();
+    let tracker = get_block_volume(&pool).await;
+    assert_eq!(tracker.volume_a_in, 10_000);
+    println!("   ✓ volume_a_in = 10,000");
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln18018018018018018018018018018018018018");
+
+/// Largest price impact a single swap may have (1%)
+pub const MAX_IMPACT_BPS: u64 = 100;
+
+#[program]
+pub mod vulnerable_per_trade_limit {
+    use super::*;
+
+    /// VULNERABILITY: Price Impact Limited Per Trade Only
+    ///
+    /// Each swap is rejected if it moves the price more than 1%. The
+    /// limit looks at one trade in isolation, so a large trade split into
+    /// many small ones passes every check while moving the price just as
+    /// far.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A lending market values collateral at this pool's spot price
+    /// 2. In one transaction Mallory sends 100 swaps of A -> B, each ~1%
+    /// 3. Each swap passes `price_impact_bps <= MAX_IMPACT_BPS`
+    /// 4. Together they grow reserve A ~2.7x, pushing the price of B ~7x
+    /// 5. She borrows against B at the inflated price and swaps back
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A per-trade limit bounds trade size, not price movement
+    /// - Transactions can carry dozens of swap instructions, and a block
+    ///   can carry many transactions
+    pub fn swap(ctx: Context<Swap>, a_to_b: bool, amount_in: u64, min_out: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let (reserve_in, reserve_out) = if a_to_b {
+            (pool.reserve_a, pool.reserve_b)
+        } else {
+            (pool.reserve_b, pool.reserve_a)
+        };
+
+        // ❌ Only this trade's impact is bounded
+        let impact_bps = price_impact_bps(amount_in, reserve_in)?;
+        require!(impact_bps <= MAX_IMPACT_BPS, ErrorCode::PriceImpactTooHigh);
+
+        let amount_out = swap_output(amount_in, reserve_in, reserve_out)?;
+        require!(amount_out >= min_out, ErrorCode::SlippageExceeded);
+
+        execute_swap(ctx, a_to_b, amount_in, amount_out)
+    }
+}
+
+/// Impact of `amount_in` on a constant-product price, in basis points
+pub fn price_impact_bps(amount_in: u64, reserve_in: u64) -> Result<u64> {
+    let denominator = (reserve_in as u128)
+        .checked_add(amount_in as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(denominator > 0, ErrorCode::EmptyPool);
+    Ok(((amount_in as u128) * 10_000 / denominator) as u64)
+}
+
+pub fn swap_output(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+    let out = (reserve_out as u128)
+        .checked_mul(amount_in as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / (reserve_in as u128 + amount_in as u128);
+    u64::try_from(out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+fn execute_swap(ctx: Context<Swap>, a_to_b: bool, amount_in: u64, amount_out: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let (user_in, vault_in, vault_out, user_out) = if a_to_b {
+        (&accounts.user_token_a, &accounts.vault_a, &accounts.vault_b, &accounts.user_token_b)
+    } else {
+        (&accounts.user_token_b, &accounts.vault_b, &accounts.vault_a, &accounts.user_token_a)
+    };
+
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: user_in.to_account_info(),
+                to: vault_in.to_account_info(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        amount_in,
+    )?;
+
+    let seeds = &[b"pool".as_ref(), &[accounts.pool.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault_out.to_account_info(),
+                to: user_out.to_account_info(),
+                authority: accounts.pool.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount_out,
+    )?;
+
+    let pool = &mut accounts.pool;
+    if a_to_b {
+        pool.reserve_a += amount_in;
+        pool.reserve_b -= amount_out;
+    } else {
+        pool.reserve_b += amount_in;
+        pool.reserve_a -= amount_out;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_a.mint, token::authority = user)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_b.mint, token::authority = user)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Price impact exceeds the per-trade limit")]
+    PriceImpactTooHigh,
+
+    #[msg("Output below minimum")]
+    SlippageExceeded,
+
+    #[msg("Pool is empty")]
+    EmptyPool,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+declare_id!("Secur18118118118118118118118118118118118118");
+
+#[program]
+pub mod secure_expired_proposal_vote {
+    use super::*;
+
+    /// SECURE: Votes Bounded By Deadline And Finalization
+    ///
+    /// `cast_vote` rejects anything after `voting_deadline`, and
+    /// `finalize_vote` records the result exactly once. Late votes cannot
+    /// be added, and a finalized result cannot be recomputed.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `clock.unix_timestamp <= proposal.voting_deadline` in `cast_vote`
+    /// 2. `proposal.finalized` starts false and is set by `finalize_vote`
+    /// 3. Both instructions reject a finalized proposal
+    pub fn cast_vote(ctx: Context<CastVote>, support: bool) -> Result<()> {
+        let clock = Clock::get()?;
+        let weight = ctx.accounts.voter_token_account.amount;
+        let proposal = &mut ctx.accounts.proposal;
+
+        // ✅ Voting window enforced
+        require!(
+            clock.unix_timestamp <= proposal.voting_deadline,
+            ErrorCode::VotingClosed
+        );
+        require!(!proposal.finalized, ErrorCode::AlreadyFinalized);
+
+        if support {
+            proposal.votes_for = proposal
+                .votes_for
+                .checked_add(weight)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        } else {
+            proposal.votes_against = proposal
+                .votes_against
+                .checked_add(weight)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        ctx.accounts.vote_record.voter = ctx.accounts.voter.key();
+        msg!("Voted {} with weight {}", support, weight);
+        Ok(())
+    }
+
+    pub fn finalize_vote(ctx: Context<FinalizeVote>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            Clock::get()?.unix_timestamp > proposal.voting_deadline,
+            ErrorCode::VotingActive
+        );
+
+        // ✅ Result recorded once
+        require!(!proposal.finalized, ErrorCode::AlreadyFinalized);
+        proposal.passed = proposal.votes_for > proposal.votes_against;
+        proposal.finalized = true;
+
+        msg!("Proposal passed = {}", proposal.passed);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(token::mint = proposal.governance_mint, token::authority = voter)]
+    pub voter_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeVote<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Proposal {
+    pub governance_mint: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub voting_deadline: i64,
+    pub passed: bool,
+    /// Set by `finalize_vote`; no votes or re-finalization afterwards
+    pub finalized: bool,
+}
+
+#[account]
+pub struct VoteRecord {
+    pub voter: Pubkey,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 32; // voter
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Voting period has ended")]
+    VotingClosed,
+
+    #[msg("Proposal already finalized")]
+    AlreadyFinalized,
+
+    #[msg("Voting period has not ended")]
+    VotingActive,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
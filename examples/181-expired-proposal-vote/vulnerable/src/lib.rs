@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+declare_id!("Vuln18118118118118118118118118118118118118");
+
+#[program]
+pub mod vulnerable_expired_proposal_vote {
+    use super::*;
+
+    /// VULNERABILITY: Votes Accepted After The Deadline
+    ///
+    /// `voting_deadline` is stored on the proposal but `cast_vote` never
+    /// reads it, and `finalize_vote` can be called again and again. The
+    /// outcome is whatever the tally says the last time someone finalizes.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory's proposal to raise her own grant loses 40,000 to 60,000
+    /// 2. The deadline passes; honest voters stop watching
+    /// 3. A week later she buys 30,000 tokens and votes "for" from a
+    ///    fresh wallet
+    /// 4. The tally is now 70,000 to 60,000 and she calls `finalize_vote`
+    /// 5. `passed` flips to true and the grant is paid
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The losing side gets unlimited extra time
+    /// - Voters cannot respond to votes they did not know were still open
+    pub fn cast_vote(ctx: Context<CastVote>, support: bool) -> Result<()> {
+        let weight = ctx.accounts.voter_token_account.amount;
+        let proposal = &mut ctx.accounts.proposal;
+
+        // ❌ No deadline check, no finalized check
+        if support {
+            proposal.votes_for = proposal
+                .votes_for
+                .checked_add(weight)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        } else {
+            proposal.votes_against = proposal
+                .votes_against
+                .checked_add(weight)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+
+        ctx.accounts.vote_record.voter = ctx.accounts.voter.key();
+        msg!("Voted {} with weight {}", support, weight);
+        Ok(())
+    }
+
+    pub fn finalize_vote(ctx: Context<FinalizeVote>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            Clock::get()?.unix_timestamp > proposal.voting_deadline,
+            ErrorCode::VotingActive
+        );
+
+        // ❌ Re-finalizable: a later tally overwrites the earlier result
+        proposal.passed = proposal.votes_for > proposal.votes_against;
+        msg!("Proposal passed = {}", proposal.passed);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(token::mint = proposal.governance_mint, token::authority = voter)]
+    pub voter_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeVote<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Proposal {
+    pub governance_mint: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub voting_deadline: i64,
+    pub passed: bool,
+}
+
+#[account]
+pub struct VoteRecord {
+    pub voter: Pubkey,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 32; // voter
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Voting period has not ended")]
+    VotingActive,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+declare_id!("Secur18218218218218218218218218218218218218");
+
+/// Protocol's cut of every claim (10%)
+pub const PROTOCOL_FEE_BPS: u64 = 1_000;
+
+#[program]
+pub mod secure_tip_distribution {
+    use super::*;
+
+    /// Searchers tip for fast inclusion; tips pool in the jar
+    pub fn tip(ctx: Context<Tip>, amount: u64) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tipper.to_account_info(),
+                    to: ctx.accounts.tip_jar.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let tip_jar = &mut ctx.accounts.tip_jar;
+        tip_jar.pending_tips = tip_jar
+            .pending_tips
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// SECURE: Tips Split Between Validator And Protocol
+    ///
+    /// Each claim pays the validator `tip * (10000 - PROTOCOL_FEE_BPS) /
+    /// 10000` and sends the remainder to the protocol treasury recorded in
+    /// the jar. The protocol share is computed as the difference, so
+    /// rounding never leaves lamports behind or pays out more than the jar
+    /// holds.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `PROTOCOL_FEE_BPS` withheld on every claim
+    /// 2. `protocol_share = tip - validator_share` - the two always sum to
+    ///    the tip
+    /// 3. Treasury pinned by `has_one`, so the claimer cannot redirect it
+    pub fn claim_tips(ctx: Context<ClaimTips>) -> Result<()> {
+        let amount = ctx.accounts.tip_jar.pending_tips;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+
+        // ✅ Validator share rounds down; remainder goes to the protocol
+        let validator_share = (amount as u128)
+            .checked_mul((10_000 - PROTOCOL_FEE_BPS) as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+        let validator_share = validator_share as u64;
+        let protocol_share = amount - validator_share;
+
+        ctx.accounts.tip_jar.sub_lamports(amount)?;
+        ctx.accounts.winner.add_lamports(validator_share)?;
+        ctx.accounts.treasury.add_lamports(protocol_share)?;
+        ctx.accounts.tip_jar.pending_tips = 0;
+
+        msg!(
+            "Validator claimed {} lamports, protocol {}",
+            validator_share,
+            protocol_share
+        );
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Tip<'info> {
+    #[account(mut, seeds = [b"tip_jar"], bump = tip_jar.bump)]
+    pub tip_jar: Account<'info, TipJar>,
+
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimTips<'info> {
+    #[account(
+        mut,
+        seeds = [b"tip_jar"],
+        bump = tip_jar.bump,
+        has_one = winner,
+        has_one = treasury,
+    )]
+    pub tip_jar: Account<'info, TipJar>,
+
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    /// CHECK: Protocol treasury, pinned by has_one
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct TipJar {
+    /// Validator that won the current round
+    pub winner: Pubkey,
+    /// Receives `PROTOCOL_FEE_BPS` of every claim
+    pub treasury: Pubkey,
+    pub pending_tips: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No tips to claim")]
+    NothingToClaim,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
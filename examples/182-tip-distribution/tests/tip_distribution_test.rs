@@ -0,0 +1,48 @@
+#[tokio::test]
+async fn test_validator_takes_all_tips_exploit() {
+    println!("\n=== EXPLOIT: Validator Claims 100% Of Tips ===\n");
+
+    let mallory = Keypair::new();
+    let treasury = Keypair::new();
+    initialize_tip_jar(&treasury.pubkey()).await;
+
+    println!("1. Searchers tip 10,000 SOL; Mallory's validator wins the round");
+    tip(10_000 * LAMPORTS_PER_SOL).await.unwrap();
+    set_round_winner(&mallory.pubkey()).await;
+
+    println!("\n2. Mallory claims");
+    let before = get_balance(&mallory.pubkey()).await;
+    claim_tips(&mallory).await.unwrap();
+
+    // Vulnerable: no protocol split
+    assert_eq!(get_balance(&mallory.pubkey()).await - before, 10_000 * LAMPORTS_PER_SOL);
+    assert_eq!(get_balance(&treasury.pubkey()).await, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Treasury received nothing");
+}
+
+#[tokio::test]
+async fn test_tips_split_with_protocol() {
+    println!("\n=== SECURITY: Protocol Fee Withheld On Claim ===\n");
+
+    let mallory = Keypair::new();
+    let treasury = Keypair::new();
+    initialize_tip_jar(&treasury.pubkey()).await;
+
+    println!("1. 10,000 SOL tipped, then 7 lamports more");
+    tip(10_000 * LAMPORTS_PER_SOL).await.unwrap();
+    tip(7).await.unwrap();
+    set_round_winner(&mallory.pubkey()).await;
+
+    println!("\n2. Mallory claims");
+    let before = get_balance(&mallory.pubkey()).await;
+    claim_tips(&mallory).await.unwrap();
+
+    let validator_share = get_balance(&mallory.pubkey()).await - before;
+    let protocol_share = get_balance(&treasury.pubkey()).await;
+    assert_eq!(validator_share, 9_000 * LAMPORTS_PER_SOL + 6);
+    assert_eq!(protocol_share, 1_000 * LAMPORTS_PER_SOL + 1);
+    assert_eq!(get_tip_jar().await.pending_tips, 0);
+    println!("   ✓ 90% to validator, 10% (plus rounding dust) to treasury");
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+declare_id!("Vuln18218218218218218218218218218218218218");
+
+#[program]
+pub mod vulnerable_tip_distribution {
+    use super::*;
+
+    /// Searchers tip for fast inclusion; tips pool in the jar
+    pub fn tip(ctx: Context<Tip>, amount: u64) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tipper.to_account_info(),
+                    to: ctx.accounts.tip_jar.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let tip_jar = &mut ctx.accounts.tip_jar;
+        tip_jar.pending_tips = tip_jar
+            .pending_tips
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Tips Paid Out With No Protocol Split
+    ///
+    /// The round's winning validator ("fastest to include") claims every
+    /// lamport in the jar. The protocol is supposed to keep a cut to fund
+    /// the relayers and the insurance reserve that refunds failed bundles,
+    /// but no fee is ever taken.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory runs a validator with enough stake to win most rounds
+    /// 2. Searchers tip 10,000 SOL over a month
+    /// 3. She claims every round in full - 100% of tips
+    /// 4. The insurance reserve receives nothing; the first failed bundle
+    ///    that needs a refund finds it empty
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The protocol's own operating and insurance budget is zero
+    /// - Rewards concentrate entirely with the largest stakers
+    pub fn claim_tips(ctx: Context<ClaimTips>) -> Result<()> {
+        let amount = ctx.accounts.tip_jar.pending_tips;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+
+        // ❌ Entire jar to the validator
+        ctx.accounts.tip_jar.sub_lamports(amount)?;
+        ctx.accounts.winner.add_lamports(amount)?;
+        ctx.accounts.tip_jar.pending_tips = 0;
+
+        msg!("Validator claimed {} lamports", amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Tip<'info> {
+    #[account(mut, seeds = [b"tip_jar"], bump = tip_jar.bump)]
+    pub tip_jar: Account<'info, TipJar>,
+
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimTips<'info> {
+    #[account(mut, seeds = [b"tip_jar"], bump = tip_jar.bump, has_one = winner)]
+    pub tip_jar: Account<'info, TipJar>,
+
+    #[account(mut)]
+    pub winner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct TipJar {
+    /// Validator that won the current round
+    pub winner: Pubkey,
+    pub pending_tips: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No tips to claim")]
+    NothingToClaim,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur18318318318318318318318318318318318318");
+
+/// How long the counterparty has to accept a cancellation (24 hours)
+pub const CANCEL_WINDOW: i64 = 24 * 60 * 60;
+
+#[program]
+pub mod secure_policy_cancellation {
+    use super::*;
+
+    /// SECURE: Early Cancellation Needs Both Parties
+    ///
+    /// Either party may propose ending the policy early, but it only ends
+    /// when the other party accepts within `CANCEL_WINDOW`. Until then the
+    /// policy stays active and claims can still be filed. The insurer can
+    /// no longer walk away from a loss on its own.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `propose_cancel` signed by the insurer or the insured
+    /// 2. `accept_cancel` signed by the *other* party
+    /// 3. Acceptance only within 24 hours of the proposal
+    /// 4. Policy remains in force while a proposal is pending
+    pub fn propose_cancel(ctx: Context<ProposeCancel>) -> Result<()> {
+        let proposer = ctx.accounts.proposer.key();
+        let policy = &mut ctx.accounts.policy;
+
+        // ✅ Only the two parties to the policy
+        require!(
+            proposer == policy.insurer || proposer == policy.insured,
+            ErrorCode::NotAParty
+        );
+
+        policy.cancel_proposer = Some(proposer);
+        policy.cancel_proposed_at = Clock::get()?.unix_timestamp;
+
+        msg!("Cancellation proposed by {}", proposer);
+        Ok(())
+    }
+
+    /// Counterparty agrees; premium refunded, collateral returned
+    pub fn accept_cancel(ctx: Context<AcceptCancel>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let acceptor = ctx.accounts.acceptor.key();
+        let policy = &ctx.accounts.policy;
+
+        let proposer = policy.cancel_proposer.ok_or(ErrorCode::NoPendingCancel)?;
+
+        // ✅ Mutual consent: the acceptor is the party that did not propose
+        let counterparty = if proposer == policy.insurer {
+            policy.insured
+        } else {
+            policy.insurer
+        };
+        require_keys_eq!(acceptor, counterparty, ErrorCode::NotCounterparty);
+
+        // ✅ Stale proposals cannot be accepted later at a convenient moment
+        require!(
+            now <= policy.cancel_proposed_at + CANCEL_WINDOW,
+            ErrorCode::CancelWindowExpired
+        );
+
+        let refund = policy.unused_premium(now);
+        ctx.accounts.policy.sub_lamports(refund)?;
+        ctx.accounts.insured.add_lamports(refund)?;
+
+        msg!("Policy cancelled, {} lamports refunded", refund);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ProposeCancel<'info> {
+    #[account(mut)]
+    pub policy: Account<'info, Policy>,
+
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptCancel<'info> {
+    // Remaining collateral and rent go back to the insurer
+    #[account(mut, has_one = insurer, has_one = insured, close = insurer)]
+    pub policy: Account<'info, Policy>,
+
+    pub acceptor: Signer<'info>,
+
+    /// CHECK: Collateral destination, pinned by has_one
+    #[account(mut)]
+    pub insurer: UncheckedAccount<'info>,
+
+    /// CHECK: Premium refund destination, pinned by has_one
+    #[account(mut)]
+    pub insured: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Policy {
+    pub insurer: Pubkey,
+    pub insured: Pubkey,
+    pub coverage: u64,
+    pub premium: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    /// Party that proposed early cancellation, if any
+    pub cancel_proposer: Option<Pubkey>,
+    pub cancel_proposed_at: i64,
+}
+
+impl Policy {
+    /// Premium for the time the policy will no longer cover
+    pub fn unused_premium(&self, now: i64) -> u64 {
+        let term = self.end_ts.saturating_sub(self.start_ts).max(1) as u128;
+        let remaining = self.end_ts.saturating_sub(now).clamp(0, term as i64) as u128;
+        (self.premium as u128 * remaining / term) as u64
+    }
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer is not a party to this policy")]
+    NotAParty,
+
+    #[msg("No cancellation has been proposed")]
+    NoPendingCancel,
+
+    #[msg("Only the other party can accept the cancellation")]
+    NotCounterparty,
+
+    #[msg("Cancellation proposal has expired")]
+    CancelWindowExpired,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln18318318318318318318318318318318318318");
+
+#[program]
+pub mod vulnerable_policy_cancellation {
+    use super::*;
+
+    /// VULNERABILITY: Insurer Can Cancel A Policy At Any Time
+    ///
+    /// The policy PDA holds the insurer's coverage collateral. Cancelling
+    /// refunds the unused part of the premium to the insured and returns
+    /// the collateral to the insurer. Only the insurer signs, and nothing
+    /// stops them cancelling the moment a loss looks likely.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice pays 50 SOL for a one-year, 1,000 SOL hack cover on a
+    ///    lending protocol; the insurer locks 1,000 SOL of collateral
+    /// 2. Month seven: the protocol is exploited, Alice prepares a claim
+    /// 3. The insurer sees the exploit on-chain and calls `cancel_policy`
+    ///    before Alice's claim lands
+    /// 4. Alice gets ~21 SOL of premium back; the insurer keeps 1,000 SOL
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Coverage can be withdrawn exactly when it is needed
+    /// - The insured cannot buy replacement cover for a loss already
+    ///   under way
+    pub fn cancel_policy(ctx: Context<CancelPolicy>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let refund = ctx.accounts.policy.unused_premium(now);
+
+        // ❌ Insurer alone decides, at any moment
+        ctx.accounts.policy.sub_lamports(refund)?;
+        ctx.accounts.insured.add_lamports(refund)?;
+
+        msg!("Policy cancelled, {} lamports refunded", refund);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CancelPolicy<'info> {
+    // Remaining collateral and rent go back to the insurer
+    #[account(mut, has_one = insurer, has_one = insured, close = insurer)]
+    pub policy: Account<'info, Policy>,
+
+    #[account(mut)]
+    pub insurer: Signer<'info>,
+
+    /// CHECK: Premium refund destination, pinned by has_one
+    #[account(mut)]
+    pub insured: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Policy {
+    pub insurer: Pubkey,
+    pub insured: Pubkey,
+    pub coverage: u64,
+    pub premium: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+impl Policy {
+    /// Premium for the time the policy will no longer cover
+    pub fn unused_premium(&self, now: i64) -> u64 {
+        let term = self.end_ts.saturating_sub(self.start_ts).max(1) as u128;
+        let remaining = self.end_ts.saturating_sub(now).clamp(0, term as i64) as u128;
+        (self.premium as u128 * remaining / term) as u64
+    }
+}
@@ -0,0 +1,302 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::TokenAccount;
+
+declare_id!("Secur18418418418418418418418418418418418418");
+
+/// Minimum time a proposal is open for voting (3 days)
+pub const MIN_VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;
+
+/// Delay between a proposal passing and it becoming executable (2 days)
+pub const TIMELOCK_DELAY: i64 = 2 * 24 * 60 * 60;
+
+pub const MAX_IX_ACCOUNTS: usize = 16;
+pub const MAX_IX_DATA: usize = 512;
+
+#[program]
+pub mod secure_admin_arbitrary_cpi {
+    use super::*;
+
+    /// Record the exact instruction the treasury would sign
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        program_id: Pubkey,
+        accounts: Vec<ProposedAccount>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        require!(accounts.len() <= MAX_IX_ACCOUNTS, ErrorCode::InstructionTooLarge);
+        require!(data.len() <= MAX_IX_DATA, ErrorCode::InstructionTooLarge);
+
+        let now = Clock::get()?.unix_timestamp;
+        let dao = &mut ctx.accounts.dao;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.dao = dao.key();
+        proposal.id = dao.proposal_count;
+        proposal.program_id = program_id;
+        proposal.accounts = accounts;
+        proposal.data = data;
+        proposal.voting_ends_at = now + MIN_VOTING_PERIOD;
+
+        dao.proposal_count = dao
+            .proposal_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, support: bool) -> Result<()> {
+        let weight = ctx.accounts.voter_token_account.amount;
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            Clock::get()?.unix_timestamp <= proposal.voting_ends_at,
+            ErrorCode::VotingClosed
+        );
+
+        let tally = if support {
+            &mut proposal.votes_for
+        } else {
+            &mut proposal.votes_against
+        };
+        *tally = tally.checked_add(weight).ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        ctx.accounts.vote_record.voter = ctx.accounts.voter.key();
+        Ok(())
+    }
+
+    /// Start the timelock on a proposal that passed
+    pub fn queue_proposal(ctx: Context<QueueProposal>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+
+        require!(now > proposal.voting_ends_at, ErrorCode::VotingActive);
+        require!(proposal.eta == 0, ErrorCode::AlreadyQueued);
+        require!(
+            proposal.votes_for > proposal.votes_against,
+            ErrorCode::ProposalDefeated
+        );
+
+        proposal.eta = now + TIMELOCK_DELAY;
+        msg!("Proposal {} executable at {}", proposal.id, proposal.eta);
+        Ok(())
+    }
+
+    /// SECURE: Treasury CPIs Only Through Passed, Timelocked Proposals
+    ///
+    /// There is no admin execution path. The treasury PDA signs only the
+    /// instruction recorded in a proposal, and only after the proposal
+    /// won its vote and sat in the queue for `TIMELOCK_DELAY`. Holders see
+    /// the exact program, accounts and data for the whole voting period
+    /// and timelock.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. No `execute_instruction`: a single key cannot move treasury funds
+    /// 2. Voting stays open for at least `MIN_VOTING_PERIOD`
+    /// 3. `eta = queued_at + TIMELOCK_DELAY` gives holders time to exit
+    /// 4. Executed instruction must match the proposal account for account
+    /// 5. `executed` set before the CPI, so a proposal runs once
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let dao_key = ctx.accounts.dao.key();
+        let treasury_key = ctx.accounts.treasury.key();
+        let proposal = &mut ctx.accounts.proposal;
+
+        // ✅ Passed, queued, and past its timelock
+        require!(proposal.eta != 0, ErrorCode::NotQueued);
+        require!(now >= proposal.eta, ErrorCode::TimelockNotElapsed);
+        require!(!proposal.executed, ErrorCode::AlreadyExecuted);
+
+        // ✅ Exactly the accounts that were voted on
+        require!(
+            ctx.remaining_accounts.len() == proposal.accounts.len(),
+            ErrorCode::AccountMismatch
+        );
+        for (info, proposed) in ctx.remaining_accounts.iter().zip(&proposal.accounts) {
+            require_keys_eq!(info.key(), proposed.pubkey, ErrorCode::AccountMismatch);
+        }
+
+        proposal.executed = true;
+
+        let accounts = proposal
+            .accounts
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: a.pubkey,
+                is_signer: a.pubkey == treasury_key,
+                is_writable: a.is_writable,
+            })
+            .collect();
+        let ix = Instruction {
+            program_id: proposal.program_id,
+            accounts,
+            data: proposal.data.clone(),
+        };
+
+        let seeds: &[&[u8]] = &[b"treasury", dao_key.as_ref(), &[ctx.bumps.treasury]];
+        invoke_signed(&ix, ctx.remaining_accounts, &[seeds])?;
+
+        msg!("Executed proposal {}", proposal.id);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub dao: Account<'info, Dao>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::LEN,
+        seeds = [b"proposal", dao.key().as_ref(), dao.proposal_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(has_one = governance_mint)]
+    pub dao: Account<'info, Dao>,
+
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(token::mint = governance_mint, token::authority = voter)]
+    pub voter_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Pinned by has_one on dao
+    pub governance_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct QueueProposal<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    pub dao: Account<'info, Dao>,
+
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: PDA that owns the treasury's token accounts
+    #[account(seeds = [b"treasury", dao.key().as_ref()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Dao {
+    pub governance_mint: Pubkey,
+    pub proposal_count: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposedAccount {
+    pub pubkey: Pubkey,
+    pub is_writable: bool,
+}
+
+#[account]
+pub struct Proposal {
+    pub dao: Pubkey,
+    pub id: u64,
+    pub program_id: Pubkey,
+    pub accounts: Vec<ProposedAccount>,
+    pub data: Vec<u8>,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub voting_ends_at: i64,
+    /// Earliest execution time; 0 until queued
+    pub eta: i64,
+    pub executed: bool,
+}
+
+impl Proposal {
+    pub const LEN: usize = 32 + // dao
+        8 + // id
+        32 + // program_id
+        4 + MAX_IX_ACCOUNTS * (32 + 1) + // accounts
+        4 + MAX_IX_DATA + // data
+        8 + // votes_for
+        8 + // votes_against
+        8 + // voting_ends_at
+        8 + // eta
+        1; // executed
+}
+
+#[account]
+pub struct VoteRecord {
+    pub voter: Pubkey,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 32; // voter
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Proposed instruction exceeds size limits")]
+    InstructionTooLarge,
+
+    #[msg("Voting period has ended")]
+    VotingClosed,
+
+    #[msg("Voting period has not ended")]
+    VotingActive,
+
+    #[msg("Proposal did not pass")]
+    ProposalDefeated,
+
+    #[msg("Proposal already queued")]
+    AlreadyQueued,
+
+    #[msg("Proposal has not been queued")]
+    NotQueued,
+
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Proposal already executed")]
+    AlreadyExecuted,
+
+    #[msg("Accounts do not match the proposal")]
+    AccountMismatch,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("Vuln18418418418418418418418418418418418418");
+
+#[program]
+pub mod vulnerable_admin_arbitrary_cpi {
+    use super::*;
+
+    /// VULNERABILITY: Admin Executes Arbitrary CPIs As The Treasury
+    ///
+    /// `execute_instruction` builds an instruction from caller-supplied
+    /// program id, data and `remaining_accounts`, and signs it with the
+    /// treasury PDA. The only check is that the admin signed. Token holders
+    /// vote on proposals, but this path bypasses voting entirely.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The DAO treasury PDA owns 2,000,000 USDC
+    /// 2. The admin key is compromised (or the team decides to leave)
+    /// 3. It calls `execute_instruction` with an SPL Token `Transfer` from
+    ///    the treasury's token account to its own
+    /// 4. The treasury PDA signs; the funds are gone in one transaction
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - One key can do anything the treasury can do
+    /// - No vote, no delay: token holders learn about it afterwards
+    pub fn execute_instruction(
+        ctx: Context<ExecuteInstruction>,
+        program_id: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let dao_key = ctx.accounts.dao.key();
+        let treasury_key = ctx.accounts.treasury.key();
+
+        // ❌ Any program, any accounts, any data - signed by the treasury
+        let accounts = ctx
+            .remaining_accounts
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: a.key(),
+                is_signer: a.is_signer || a.key() == treasury_key,
+                is_writable: a.is_writable,
+            })
+            .collect();
+        let ix = Instruction { program_id, accounts, data };
+
+        let seeds: &[&[u8]] = &[b"treasury", dao_key.as_ref(), &[ctx.bumps.treasury]];
+        invoke_signed(&ix, ctx.remaining_accounts, &[seeds])?;
+
+        msg!("Admin executed CPI into {}", program_id);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ExecuteInstruction<'info> {
+    #[account(has_one = admin)]
+    pub dao: Account<'info, Dao>,
+
+    /// CHECK: PDA that owns the treasury's token accounts
+    #[account(seeds = [b"treasury", dao.key().as_ref()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Dao {
+    pub admin: Pubkey,
+    pub governance_mint: Pubkey,
+    pub proposal_count: u64,
+}
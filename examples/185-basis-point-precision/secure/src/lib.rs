@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur18518518518518518518518518518518518518");
+
+#[program]
+pub mod secure_basis_point_precision {
+    use super::*;
+
+    /// SECURE: Basis-Point Fee Rounded Up In u128
+    ///
+    /// The fee is computed with ceiling division,
+    /// `(amount * fee_rate_bps + 9999) / 10000`, on a `u128` product. Any
+    /// non-zero payment on a market with a non-zero rate pays at least one
+    /// unit, so splitting a payment can only raise the total fee.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Ceiling division: the protocol, not the payer, keeps the fraction
+    /// 2. `u128` intermediate - no overflow for any `u64` amount and rate
+    /// 3. Merchant credit uses `checked_sub`, so a misconfigured rate
+    ///    above 100% fails instead of underflowing
+    pub fn pay(ctx: Context<Pay>, amount: u64) -> Result<()> {
+        let fee = calculate_fee(amount, ctx.accounts.market.fee_rate_bps)?;
+        let net = amount
+            .checked_sub(fee)
+            .ok_or(ErrorCode::FeeExceedsAmount)?;
+
+        let payer = &mut ctx.accounts.payer_balance;
+        payer.amount = payer
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+
+        let merchant = &mut ctx.accounts.merchant_balance;
+        merchant.amount = merchant
+            .amount
+            .checked_add(net)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let market = &mut ctx.accounts.market;
+        market.fees_collected = market
+            .fees_collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Paid {} (fee {})", amount, fee);
+        Ok(())
+    }
+}
+
+/// ✅ Ceiling division on a u128 product
+pub fn calculate_fee(amount: u64, fee_rate_bps: u64) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_rate_bps as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .div_ceil(10_000);
+    u64::try_from(fee).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Pay<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+
+    #[account(mut, has_one = market, has_one = owner)]
+    pub payer_balance: Account<'info, Balance>,
+
+    #[account(mut, has_one = market)]
+    pub merchant_balance: Account<'info, Balance>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Market {
+    pub mint: Pubkey,
+    /// Fee in basis points; 30 = 0.30%
+    pub fee_rate_bps: u64,
+    pub fees_collected: u64,
+}
+
+#[account]
+pub struct Balance {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient funds")]
+    InsufficientFunds,
+
+    #[msg("Fee exceeds the payment amount")]
+    FeeExceedsAmount,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
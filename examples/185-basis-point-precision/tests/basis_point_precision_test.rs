@@ -0,0 +1,41 @@
+#[tokio::test]
+async fn test_fee_truncated_to_zero_exploit() {
+    println!("\n=== EXPLOIT: Fee-Free Payments Below 10000 / rate ===\n");
+
+    let mallory = Keypair::new();
+    let merchant = Keypair::new();
+    let market = create_market(30).await; // 0.30%, 0-decimal token
+    fund_balance(&market, &mallory, 99_900).await;
+
+    println!("1. Mallory pays 99,900 tickets in 300 chunks of 333");
+    for _ in 0..300 {
+        // Vulnerable: 333 * 30 / 10000 = 0
+        pay(&market, &mallory, &merchant.pubkey(), 333).await.unwrap();
+    }
+
+    assert_eq!(get_market(&market).await.fees_collected, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 99,900 tickets moved, 0 fee collected (expected ~300)");
+}
+
+#[tokio::test]
+async fn test_fee_rounded_up() {
+    println!("\n=== SECURITY: Ceiling Division On Basis-Point Fee ===\n");
+
+    let mallory = Keypair::new();
+    let merchant = Keypair::new();
+    let market = create_market(30).await;
+    fund_balance(&market, &mallory, 99_900).await;
+
+    println!("1. Same 300 chunks of 333");
+    for _ in 0..300 {
+        pay(&market, &mallory, &merchant.pubkey(), 333).await.unwrap();
+    }
+    assert_eq!(get_market(&market).await.fees_collected, 300);
+    println!("   ✓ 1 ticket per chunk: 300 collected");
+
+    println!("\n2. Large amounts do not overflow");
+    assert_eq!(calculate_fee(u64::MAX, 30).unwrap(), 55_340_232_221_128_655);
+    println!("   ✓ u128 intermediate");
+}
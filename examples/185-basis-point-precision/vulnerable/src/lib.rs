@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln18518518518518518518518518518518518518");
+
+#[program]
+pub mod vulnerable_basis_point_precision {
+    use super::*;
+
+    /// VULNERABILITY: Basis-Point Fee Truncated By Integer Division
+    ///
+    /// Each market stores its fee as `fee_rate_bps` (1 bps = 0.01%) and
+    /// charges `amount * fee_rate_bps / 10000`. Integer division drops the
+    /// fraction, so whenever `amount * fee_rate_bps < 10000` the fee is
+    /// zero. The product is also computed in `u64`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A market for a 0-decimal token (event tickets) charges 30 bps
+    /// 2. Mallory pays in chunks of `9999 / 30 = 333` tickets
+    /// 3. `333 * 30 / 10000 = 0` - every chunk is fee-free
+    /// 4. A bot packs dozens of chunks per transaction and moves any
+    ///    volume without paying the protocol
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The fee is optional for anyone willing to split payments
+    /// - Low-decimal tokens make the fee-free size economically large
+    pub fn pay(ctx: Context<Pay>, amount: u64) -> Result<()> {
+        // ❌ Rounds toward zero; u64 intermediate
+        let fee = amount
+            .checked_mul(ctx.accounts.market.fee_rate_bps)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+
+        let payer = &mut ctx.accounts.payer_balance;
+        payer.amount = payer
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+
+        let merchant = &mut ctx.accounts.merchant_balance;
+        merchant.amount = merchant
+            .amount
+            .checked_add(amount - fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let market = &mut ctx.accounts.market;
+        market.fees_collected = market
+            .fees_collected
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("Paid {} (fee {})", amount, fee);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Pay<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+
+    #[account(mut, has_one = market, has_one = owner)]
+    pub payer_balance: Account<'info, Balance>,
+
+    #[account(mut, has_one = market)]
+    pub merchant_balance: Account<'info, Balance>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Market {
+    pub mint: Pubkey,
+    /// Fee in basis points; 30 = 0.30%
+    pub fee_rate_bps: u64,
+    pub fees_collected: u64,
+}
+
+#[account]
+pub struct Balance {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient funds")]
+    InsufficientFunds,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use std::collections::BTreeSet;
+
+declare_id!("Secur18618618618618618618618618618618618618");
+
+pub const MAX_SIGNERS: usize = 10;
+
+#[program]
+pub mod secure_multisig_signer_replacement {
+    use super::*;
+
+    /// SECURE: Unanimous Approval For Signer Set Changes
+    ///
+    /// Payments still need `threshold` approvals, but replacing the signer
+    /// set needs every current signer. A colluding subset cannot remove
+    /// anyone without that person's signature. The new set is validated
+    /// before it is stored.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Every current signer must sign `update_signers`
+    /// 2. `Pubkey::default()` rejected as a signer
+    /// 3. Duplicate signers rejected
+    /// 4. `1 <= new_threshold <= new_signers.len()`
+    pub fn update_signers(
+        ctx: Context<UpdateSigners>,
+        new_signers: Vec<Pubkey>,
+        new_threshold: u8,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        require!(new_signers.len() <= MAX_SIGNERS, ErrorCode::TooManySigners);
+
+        let approvals: BTreeSet<Pubkey> = ctx
+            .remaining_accounts
+            .iter()
+            .filter(|info| info.is_signer && multisig.signers.contains(info.key))
+            .map(|info| info.key())
+            .collect();
+
+        // ✅ Everyone currently on the multisig agrees
+        require!(
+            approvals.len() == multisig.signers.len(),
+            ErrorCode::NotAllSignersApproved
+        );
+
+        // ✅ New set must be usable
+        require!(
+            !new_signers.contains(&Pubkey::default()),
+            ErrorCode::InvalidSigner
+        );
+        let unique: BTreeSet<&Pubkey> = new_signers.iter().collect();
+        require!(unique.len() == new_signers.len(), ErrorCode::DuplicateSigner);
+        require!(
+            new_threshold >= 1 && new_threshold as usize <= new_signers.len(),
+            ErrorCode::InvalidThreshold
+        );
+
+        multisig.signers = new_signers;
+        multisig.threshold = new_threshold;
+
+        msg!("Signer set replaced: {} of {}", multisig.threshold, multisig.signers.len());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateSigners<'info> {
+    #[account(mut)]
+    pub multisig: Account<'info, Multisig>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Multisig {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl Multisig {
+    pub const LEN: usize = 4 + 32 * MAX_SIGNERS + // signers
+        1; // threshold
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Too many signers")]
+    TooManySigners,
+
+    #[msg("Every current signer must approve a signer set change")]
+    NotAllSignersApproved,
+
+    #[msg("Pubkey::default() cannot be a signer")]
+    InvalidSigner,
+
+    #[msg("Duplicate signer")]
+    DuplicateSigner,
+
+    #[msg("Threshold must be between 1 and the number of signers")]
+    InvalidThreshold,
+}
@@ -0,0 +1,52 @@
+#[tokio::test]
+async fn test_threshold_coalition_takeover_exploit() {
+    println!("\n=== EXPLOIT: Two Of Five Replace The Other Three ===\n");
+
+    let [alice, bob, carol, mallory, trent] = keypairs::<5>();
+    let multisig = create_multisig(&[&alice, &bob, &carol, &mallory, &trent], 2).await;
+
+    println!("1. Mallory and Trent submit a new signer list");
+    let new_signers = vec![
+        mallory.pubkey(),
+        trent.pubkey(),
+        Pubkey::default(),
+        Pubkey::default(),
+        Pubkey::default(),
+    ];
+    // Vulnerable: 2 approvals meet the threshold
+    update_signers(&multisig, new_signers, &[&mallory, &trent]).await.unwrap();
+
+    println!("\n2. Alice tries to approve a payment");
+    let result = approve_payment(&multisig, &alice).await;
+    assert!(result.is_err());
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Three legitimate owners locked out permanently");
+}
+
+#[tokio::test]
+async fn test_signer_change_requires_everyone() {
+    println!("\n=== SECURITY: Unanimous Signer Set Changes ===\n");
+
+    let [alice, bob, carol, mallory, trent] = keypairs::<5>();
+    let all = [&alice, &bob, &carol, &mallory, &trent];
+    let multisig = create_multisig(&all, 2).await;
+
+    println!("1. Mallory and Trent alone");
+    let new_signers = vec![mallory.pubkey(), trent.pubkey()];
+    let result = update_signers(&multisig, new_signers, 2, &[&mallory, &trent]).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: NotAllSignersApproved");
+
+    println!("\n2. All five sign, but the list contains Pubkey::default()");
+    let new_signers = vec![alice.pubkey(), bob.pubkey(), Pubkey::default()];
+    let result = update_signers(&multisig, new_signers, 2, &all).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: InvalidSigner");
+
+    println!("\n3. All five sign a valid rotation");
+    let new_signers = vec![alice.pubkey(), bob.pubkey(), carol.pubkey()];
+    update_signers(&multisig, new_signers, 2, &all).await.unwrap();
+    assert_eq!(get_multisig(&multisig).await.signers.len(), 3);
+    println!("   ✓ Signer set updated to 2-of-3");
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use std::collections::BTreeSet;
+
+declare_id!("Vuln18618618618618618618618618618618618618");
+
+pub const MAX_SIGNERS: usize = 10;
+
+#[program]
+pub mod vulnerable_multisig_signer_replacement {
+    use super::*;
+
+    /// VULNERABILITY: Signer Set Replaced With Only `threshold` Approvals
+    ///
+    /// `update_signers` overwrites the whole `signers` list with a
+    /// caller-supplied one. It needs the same `threshold` approvals as a
+    /// routine payment, and the new list is not validated.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A 2-of-5 treasury multisig: Alice, Bob, Carol, Mallory, Trent
+    /// 2. Mallory and Trent collude - two approvals meet the threshold
+    /// 3. They submit `[Mallory, Trent, default, default, default]`
+    /// 4. Alice, Bob and Carol are removed; `Pubkey::default()` can never
+    ///    sign, so the slots cannot be used to re-add them
+    /// 5. The pair now controls the treasury permanently and unobserved
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Membership changes are far more powerful than spends, yet cost the
+    ///   same number of approvals
+    /// - Excluded signers have no way back in
+    pub fn update_signers(ctx: Context<UpdateSigners>, new_signers: Vec<Pubkey>) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        require!(new_signers.len() <= MAX_SIGNERS, ErrorCode::TooManySigners);
+
+        let approvals: BTreeSet<Pubkey> = ctx
+            .remaining_accounts
+            .iter()
+            .filter(|info| info.is_signer && multisig.signers.contains(info.key))
+            .map(|info| info.key())
+            .collect();
+
+        // ❌ Threshold, not unanimity, for a membership change
+        require!(
+            approvals.len() >= multisig.threshold as usize,
+            ErrorCode::NotEnoughApprovals
+        );
+
+        // ❌ No check for defaults or duplicates
+        multisig.signers = new_signers;
+
+        msg!("Signer set replaced with {} approvals", approvals.len());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateSigners<'info> {
+    #[account(mut)]
+    pub multisig: Account<'info, Multisig>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Multisig {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl Multisig {
+    pub const LEN: usize = 4 + 32 * MAX_SIGNERS + // signers
+        1; // threshold
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Too many signers")]
+    TooManySigners,
+
+    #[msg("Not enough signer approvals")]
+    NotEnoughApprovals,
+}
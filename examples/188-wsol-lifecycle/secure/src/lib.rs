@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, CloseAccount, Mint, SyncNative, Token, TokenAccount, Transfer};
+
+declare_id!("Secur18818818818818818818818818818818818818");
+
+#[program]
+pub mod secure_wsol_lifecycle {
+    use super::*;
+
+    /// SECURE: Complete WSOL Lifecycle Inside One Instruction
+    ///
+    /// The WSOL account is created, funded, used and closed by the same
+    /// instruction. If any step fails the whole transaction reverts, so a
+    /// PDA-owned WSOL account never exists between transactions and
+    /// nothing is left behind if the program is later closed. Each step
+    /// checks its own postcondition.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `create_wsol_account` - fresh account (`init`) funded with
+    ///    `wrap_amount`
+    /// 2. `sync_native` - token balance must equal `wrap_amount`
+    /// 3. `use_for_swap` - balance must drop by exactly `swap_amount`
+    /// 4. `sync_native` again - token balance must match lamports minus
+    ///    rent, so no lamports are invisible to the token program
+    /// 5. `close_wsol_account` - all remaining lamports, rent included,
+    ///    go back to the user
+    /// 6. User balance checked against the expected net cost, and the
+    ///    WSOL account must be empty
+    pub fn swap_sol(ctx: Context<SwapSol>, wrap_amount: u64, swap_amount: u64) -> Result<()> {
+        require!(swap_amount <= wrap_amount, ErrorCode::SwapExceedsWrap);
+
+        // Rent for the WSOL account was already paid by `init`
+        let user_lamports_before = ctx.accounts.user.lamports();
+        let rent = ctx.accounts.wsol_account.to_account_info().lamports();
+
+        create_wsol_account(&ctx, wrap_amount)?;
+        sync_native(&ctx)?;
+        ctx.accounts.wsol_account.reload()?;
+        require!(
+            ctx.accounts.wsol_account.amount == wrap_amount,
+            ErrorCode::WrapMismatch
+        );
+
+        use_for_swap(&ctx, swap_amount)?;
+        ctx.accounts.wsol_account.reload()?;
+        require!(
+            ctx.accounts.wsol_account.amount == wrap_amount - swap_amount,
+            ErrorCode::SwapMismatch
+        );
+
+        sync_native(&ctx)?;
+        ctx.accounts.wsol_account.reload()?;
+        let wsol_info = ctx.accounts.wsol_account.to_account_info();
+        require!(
+            ctx.accounts.wsol_account.amount == wsol_info.lamports() - rent,
+            ErrorCode::WrapMismatch
+        );
+        let returned = wsol_info.lamports();
+
+        close_wsol_account(&ctx)?;
+
+        // ✅ Everything not swapped came back, rent included
+        let expected = user_lamports_before
+            .checked_sub(wrap_amount)
+            .and_then(|v| v.checked_add(returned))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            ctx.accounts.user.lamports() == expected,
+            ErrorCode::LamportsNotReturned
+        );
+        require!(
+            ctx.accounts.wsol_account.to_account_info().lamports() == 0,
+            ErrorCode::LamportsNotReturned
+        );
+
+        msg!("Swapped {} SOL, {} lamports returned", swap_amount, returned);
+        Ok(())
+    }
+}
+
+fn create_wsol_account(ctx: &Context<SwapSol>, wrap_amount: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.wsol_account.to_account_info(),
+            },
+        ),
+        wrap_amount,
+    )
+}
+
+fn sync_native(ctx: &Context<SwapSol>) -> Result<()> {
+    token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SyncNative {
+            account: ctx.accounts.wsol_account.to_account_info(),
+        },
+    ))
+}
+
+fn use_for_swap(ctx: &Context<SwapSol>, swap_amount: u64) -> Result<()> {
+    let user_key = ctx.accounts.user.key();
+    let seeds: &[&[u8]] = &[b"wsol_authority", user_key.as_ref(), &[ctx.bumps.wsol_authority]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.wsol_account.to_account_info(),
+                to: ctx.accounts.pool_wsol_vault.to_account_info(),
+                authority: ctx.accounts.wsol_authority.to_account_info(),
+            },
+            &[seeds],
+        ),
+        swap_amount,
+    )
+}
+
+fn close_wsol_account(ctx: &Context<SwapSol>) -> Result<()> {
+    let user_key = ctx.accounts.user.key();
+    let seeds: &[&[u8]] = &[b"wsol_authority", user_key.as_ref(), &[ctx.bumps.wsol_authority]];
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.wsol_account.to_account_info(),
+            destination: ctx.accounts.user.to_account_info(),
+            authority: ctx.accounts.wsol_authority.to_account_info(),
+        },
+        &[seeds],
+    ))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SwapSol<'info> {
+    // ✅ `init`, not `init_if_needed`: a leftover account is an error
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"wsol", user.key().as_ref()],
+        bump,
+        token::mint = native_mint,
+        token::authority = wsol_authority,
+    )]
+    pub wsol_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority over the user's WSOL account
+    #[account(seeds = [b"wsol_authority", user.key().as_ref()], bump)]
+    pub wsol_authority: UncheckedAccount<'info>,
+
+    #[account(address = anchor_spl::token::spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = native_mint)]
+    pub pool_wsol_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Swap amount exceeds the wrapped amount")]
+    SwapExceedsWrap,
+
+    #[msg("WSOL token balance does not match its lamports")]
+    WrapMismatch,
+
+    #[msg("Swap moved an unexpected amount")]
+    SwapMismatch,
+
+    #[msg("Unswapped lamports were not returned to the user")]
+    LamportsNotReturned,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
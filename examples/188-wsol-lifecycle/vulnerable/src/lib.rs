@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, CloseAccount, Mint, SyncNative, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln18818818818818818818818818818818818818");
+
+#[program]
+pub mod vulnerable_wsol_lifecycle {
+    use super::*;
+
+    /// Step 1 (transaction 1): wrap SOL into a per-user WSOL account
+    pub fn wrap(ctx: Context<Wrap>, amount: u64) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.wsol_account.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        token::sync_native(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SyncNative {
+                account: ctx.accounts.wsol_account.to_account_info(),
+            },
+        ))?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: WSOL Account Outlives The Transaction
+    ///
+    /// Wrapping, swapping and unwrapping are three separate instructions,
+    /// sent in three transactions. Between them, the user's SOL sits in a
+    /// WSOL account whose only authority is this program's PDA. If
+    /// `unwrap` never runs - the user's transaction fails, or the program
+    /// is closed - nothing can ever sign to close that account.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Users wrap 10,000 SOL in total and swap part of it
+    /// 2. Leftover WSOL waits for each user to send `unwrap`
+    /// 3. The team runs `solana program close` to redeploy at a new
+    ///    address (or the upgrade key does so maliciously)
+    /// 4. Only `invoke_signed` from this program can sign for the PDA, and
+    ///    the program is gone: the leftover SOL is locked forever
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Funds depend on a later transaction that may never happen
+    /// - Closing a program strands every account its PDAs control
+    pub fn swap(ctx: Context<Swap>, amount: u64) -> Result<()> {
+        let user_key = ctx.accounts.user.key();
+        let seeds: &[&[u8]] = &[b"wsol_authority", user_key.as_ref(), &[ctx.bumps.wsol_authority]];
+
+        // ❌ Leftover WSOL stays behind, owned by the PDA, until `unwrap`
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.wsol_account.to_account_info(),
+                    to: ctx.accounts.pool_wsol_vault.to_account_info(),
+                    authority: ctx.accounts.wsol_authority.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+
+    /// Step 3 (transaction 3): close the WSOL account back to the user
+    pub fn unwrap(ctx: Context<Unwrap>) -> Result<()> {
+        let user_key = ctx.accounts.user.key();
+        let seeds: &[&[u8]] = &[b"wsol_authority", user_key.as_ref(), &[ctx.bumps.wsol_authority]];
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.wsol_account.to_account_info(),
+                destination: ctx.accounts.user.to_account_info(),
+                authority: ctx.accounts.wsol_authority.to_account_info(),
+            },
+            &[seeds],
+        ))
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Wrap<'info> {
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [b"wsol", user.key().as_ref()],
+        bump,
+        token::mint = native_mint,
+        token::authority = wsol_authority,
+    )]
+    pub wsol_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority over the user's WSOL account
+    #[account(seeds = [b"wsol_authority", user.key().as_ref()], bump)]
+    pub wsol_authority: UncheckedAccount<'info>,
+
+    #[account(address = anchor_spl::token::spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut, seeds = [b"wsol", user.key().as_ref()], bump)]
+    pub wsol_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority over the user's WSOL account
+    #[account(seeds = [b"wsol_authority", user.key().as_ref()], bump)]
+    pub wsol_authority: UncheckedAccount<'info>,
+
+    #[account(mut, token::mint = anchor_spl::token::spl_token::native_mint::ID)]
+    pub pool_wsol_vault: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Unwrap<'info> {
+    #[account(mut, seeds = [b"wsol", user.key().as_ref()], bump)]
+    pub wsol_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority over the user's WSOL account
+    #[account(seeds = [b"wsol_authority", user.key().as_ref()], bump)]
+    pub wsol_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur18918918918918918918918918918918918918");
+
+/// Upper bound on the guardian set; also keeps `initialize` far below the
+/// 10 KiB limit on accounts created through CPI
+pub const MAX_ENTRIES: usize = 64;
+
+#[program]
+pub mod secure_dynamic_account_size {
+    use super::*;
+
+    /// Create the guardian set with its initial members
+    pub fn initialize(ctx: Context<Initialize>, initial: Vec<Entry>) -> Result<()> {
+        require!(initial.len() <= MAX_ENTRIES, ErrorCode::GuardianSetFull);
+        let guardian_set = &mut ctx.accounts.guardian_set;
+        guardian_set.authority = ctx.accounts.authority.key();
+        guardian_set.entries = initial;
+        Ok(())
+    }
+
+    /// SECURE: Account Reallocated Before Each Push
+    ///
+    /// `add_entry` declares `realloc = 8 + GuardianSet::space(len + 1)`.
+    /// Anchor grows the account, and tops up rent from the authority,
+    /// before the handler runs, so the pushed entry always fits when the
+    /// account is serialized.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `realloc` sized from the current length plus one
+    /// 2. `realloc::payer = authority` funds the extra rent
+    /// 3. `realloc::zero = false` - the existing bytes are kept as they are
+    /// 4. `MAX_ENTRIES` bounds growth
+    pub fn add_entry(ctx: Context<AddEntry>, entry: Entry) -> Result<()> {
+        let guardian_set = &mut ctx.accounts.guardian_set;
+        require!(guardian_set.entries.len() < MAX_ENTRIES, ErrorCode::GuardianSetFull);
+
+        // ✅ Space for this entry was allocated by `realloc`
+        guardian_set.entries.push(entry);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(initial: Vec<Entry>)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GuardianSet::space(initial.len()),
+        seeds = [b"guardian_set"],
+        bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddEntry<'info> {
+    #[account(
+        mut,
+        seeds = [b"guardian_set"],
+        bump,
+        has_one = authority,
+        realloc = 8 + GuardianSet::space(guardian_set.entries.len() + 1),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Entry {
+    pub guardian: Pubkey,
+    pub weight: u64,
+}
+
+impl Entry {
+    pub const SIZE: usize = 32 + // guardian
+        8; // weight
+}
+
+#[account]
+pub struct GuardianSet {
+    pub authority: Pubkey,
+    pub entries: Vec<Entry>,
+}
+
+impl GuardianSet {
+    pub fn space(entries: usize) -> usize {
+        32 + // authority
+        4 + entries * Entry::SIZE // entries
+    }
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Guardian set is full")]
+    GuardianSetFull,
+}
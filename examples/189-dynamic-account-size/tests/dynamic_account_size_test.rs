@@ -0,0 +1,41 @@
+#[tokio::test]
+async fn test_push_past_allocation_exploit() {
+    println!("\n=== EXPLOIT: Vec Outgrows Its Fixed Allocation ===\n");
+
+    let authority = Keypair::new();
+    let guardians = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+    println!("1. Guardian set created with three entries");
+    initialize(&authority, entries_for(&guardians)).await.unwrap();
+    assert_eq!(get_account_size(&guardian_set_pda()).await, 8 + 32 + 4 + 3 * 40);
+
+    println!("\n2. Guardian #2 is compromised; the team adds a replacement");
+    let replacement = Keypair::new();
+    // Vulnerable: no realloc
+    let result = add_entry(&authority, entry(&replacement)).await;
+    assert!(result.is_err()); // AccountDidNotSerialize
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Guardian set frozen at its initial size");
+}
+
+#[tokio::test]
+async fn test_realloc_on_add() {
+    println!("\n=== SECURITY: Realloc Before Push ===\n");
+
+    let authority = Keypair::new();
+    let guardians = [Keypair::new(), Keypair::new(), Keypair::new()];
+    initialize(&authority, entries_for(&guardians)).await.unwrap();
+
+    println!("1. Add two replacements");
+    for _ in 0..2 {
+        add_entry(&authority, entry(&Keypair::new())).await.unwrap();
+    }
+    assert_eq!(get_account_size(&guardian_set_pda()).await, 8 + 32 + 4 + 5 * 40);
+    assert_eq!(get_guardian_set().await.entries.len(), 5);
+    println!("   ✓ Account grew to fit 5 entries");
+
+    println!("\n2. Rent stays covered");
+    assert!(is_rent_exempt(&guardian_set_pda()).await);
+    println!("   ✓ Authority paid the extra rent");
+}
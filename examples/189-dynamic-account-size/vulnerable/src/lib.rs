@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln18918918918918918918918918918918918918");
+
+#[program]
+pub mod vulnerable_dynamic_account_size {
+    use super::*;
+
+    /// Create the guardian set with its initial members
+    pub fn initialize(ctx: Context<Initialize>, initial: Vec<Entry>) -> Result<()> {
+        let guardian_set = &mut ctx.accounts.guardian_set;
+        guardian_set.authority = ctx.accounts.authority.key();
+        guardian_set.entries = initial;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Account Sized For The Vec's Initial Length
+    ///
+    /// `initialize` allocates `8 + 32 + 4 + initial.len() * Entry::SIZE`
+    /// bytes - exactly enough for the entries it was created with. The
+    /// account is never resized, so after `add_entry` pushes one more
+    /// element, Anchor cannot serialize it back and the instruction fails
+    /// with `AccountDidNotSerialize`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The guardian set is created with three guardians; tests pass
+    ///    because they never add a fourth
+    /// 2. Months later guardian #2's key leaks and is used to pause the
+    ///    protocol repeatedly
+    /// 3. The team adds a replacement before removing #2, so the set never
+    ///    drops below quorum - `add_entry` fails every time
+    /// 4. Incident response is stuck until a program upgrade ships
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The bug is invisible until the list grows in production
+    /// - Security-critical lists (guardians, allowlists) are exactly the
+    ///   ones that need to change under pressure
+    pub fn add_entry(ctx: Context<AddEntry>, entry: Entry) -> Result<()> {
+        // ❌ Pushes past the allocated space; fails at serialization
+        ctx.accounts.guardian_set.entries.push(entry);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(initial: Vec<Entry>)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        // ❌ Room for the current entries only
+        space = 8 + GuardianSet::space(initial.len()),
+        seeds = [b"guardian_set"],
+        bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddEntry<'info> {
+    #[account(mut, seeds = [b"guardian_set"], bump, has_one = authority)]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    pub authority: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Entry {
+    pub guardian: Pubkey,
+    pub weight: u64,
+}
+
+impl Entry {
+    pub const SIZE: usize = 32 + // guardian
+        8; // weight
+}
+
+#[account]
+pub struct GuardianSet {
+    pub authority: Pubkey,
+    pub entries: Vec<Entry>,
+}
+
+impl GuardianSet {
+    pub fn space(entries: usize) -> usize {
+        32 + // authority
+        4 + entries * Entry::SIZE // entries
+    }
+}
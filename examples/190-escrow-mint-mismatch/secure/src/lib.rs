@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("Secur19o19o19o19o19o19o19o19o19o19o19o19o19");
+
+#[program]
+pub mod secure_escrow_mint_mismatch {
+    use super::*;
+
+    /// Lock `amount` tokens until `release_ts`
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        amount: u64,
+        release_ts: i64,
+    ) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.maker_token.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.maker.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let escrow = &mut ctx.accounts.escrow;
+        escrow.maker = ctx.accounts.maker.key();
+        // ✅ One mint for deposit and payout
+        escrow.mint = ctx.accounts.mint.key();
+        escrow.amount = amount;
+        escrow.release_ts = release_ts;
+        Ok(())
+    }
+
+    /// SECURE: One Mint For Deposit And Payout
+    ///
+    /// The escrow stores a single `mint`. `release` is pinned to it with
+    /// `has_one = mint`, and the vault and the maker's token account are
+    /// both constrained to that mint. An escrow can only ever pay out the
+    /// token it took in.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `Escrow` has one `mint` field - there is no payout mint to choose
+    /// 2. `has_one = mint` ties the passed `mint` account to the escrow
+    /// 3. `vault` is the PDA vault of that mint (`token::mint = mint`)
+    /// 4. `maker_token` must hold the same mint in both instructions
+    pub fn release(ctx: Context<Release>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        require!(
+            Clock::get()?.unix_timestamp >= escrow.release_ts,
+            ErrorCode::EscrowLocked
+        );
+
+        let seeds: &[&[u8]] = &[b"pool", &[ctx.bumps.pool]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    // ✅ Same vault the deposit went into
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.maker_token.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[seeds],
+            ),
+            escrow.amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateEscrow<'info> {
+    #[account(init, payer = maker, space = 8 + Escrow::LEN)]
+    pub escrow: Account<'info, Escrow>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = mint, token::authority = maker)]
+    pub maker_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority over every vault
+    #[account(seeds = [b"pool"], bump)]
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Release<'info> {
+    // ✅ Escrow, vault and destination all agree on the mint
+    #[account(mut, has_one = maker, has_one = mint, close = maker)]
+    pub escrow: Account<'info, Escrow>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint, token::authority = maker)]
+    pub maker_token: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority over every vault
+    #[account(seeds = [b"pool"], bump)]
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Escrow {
+    pub maker: Pubkey,
+    /// Deposited and paid out in this mint only
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub release_ts: i64,
+}
+
+impl Escrow {
+    pub const LEN: usize = 32 + // maker
+        32 + // mint
+        8 + // amount
+        8; // release_ts
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Escrow is still locked")]
+    EscrowLocked,
+}
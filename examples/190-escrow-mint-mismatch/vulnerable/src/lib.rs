@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln19019019019019019019019019019019019019");
+
+#[program]
+pub mod vulnerable_escrow_mint_mismatch {
+    use super::*;
+
+    /// Lock `amount` tokens until `release_ts`
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        amount: u64,
+        release_ts: i64,
+        payout_mint: Pubkey,
+    ) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.maker_token.to_account_info(),
+                    to: ctx.accounts.deposit_vault.to_account_info(),
+                    authority: ctx.accounts.maker.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let escrow = &mut ctx.accounts.escrow;
+        escrow.maker = ctx.accounts.maker.key();
+        escrow.deposit_mint = ctx.accounts.deposit_mint.key();
+        // ❌ Chosen independently of what was deposited
+        escrow.payout_mint = payout_mint;
+        escrow.amount = amount;
+        escrow.release_ts = release_ts;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Payout Mint Not Tied To Deposit Mint
+    ///
+    /// All escrows share one vault per mint, owned by the `pool` PDA. An
+    /// escrow records a `deposit_mint` and a separate `payout_mint`, and
+    /// `release` pays `amount` from whichever vault holds `payout_mint`.
+    /// Nothing requires the two mints to be equal.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The USDC vault holds 2,000,000 USDC from honest escrows
+    /// 2. Mallory mints 1,000,000 of a worthless token and escrows it with
+    ///    `payout_mint = USDC` and `release_ts = now`
+    /// 3. She calls `release`; `payout_vault` is the USDC vault
+    /// 4. She receives 1,000,000 USDC for 1,000,000 worthless tokens
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A shared pool makes every depositor's funds reachable
+    /// - The exchange rate between any two mints is silently 1:1
+    pub fn release(ctx: Context<Release>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        require!(
+            Clock::get()?.unix_timestamp >= escrow.release_ts,
+            ErrorCode::EscrowLocked
+        );
+
+        let seeds: &[&[u8]] = &[b"pool", &[ctx.bumps.pool]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    // ❌ Paid from the payout mint's vault
+                    from: ctx.accounts.payout_vault.to_account_info(),
+                    to: ctx.accounts.maker_payout_token.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[seeds],
+            ),
+            escrow.amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateEscrow<'info> {
+    #[account(init, payer = maker, space = 8 + Escrow::LEN)]
+    pub escrow: Account<'info, Escrow>,
+
+    pub deposit_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = deposit_mint, token::authority = maker)]
+    pub maker_token: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", deposit_mint.key().as_ref()],
+        bump,
+        token::mint = deposit_mint,
+        token::authority = pool,
+    )]
+    pub deposit_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority over every vault
+    #[account(seeds = [b"pool"], bump)]
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Release<'info> {
+    #[account(mut, has_one = maker, has_one = payout_mint, close = maker)]
+    pub escrow: Account<'info, Escrow>,
+
+    pub payout_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", payout_mint.key().as_ref()],
+        bump,
+        token::mint = payout_mint,
+        token::authority = pool,
+    )]
+    pub payout_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = payout_mint, token::authority = maker)]
+    pub maker_payout_token: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority over every vault
+    #[account(seeds = [b"pool"], bump)]
+    pub pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Escrow {
+    pub maker: Pubkey,
+    pub deposit_mint: Pubkey,
+    pub payout_mint: Pubkey,
+    pub amount: u64,
+    pub release_ts: i64,
+}
+
+impl Escrow {
+    pub const LEN: usize = 32 + // maker
+        32 + // deposit_mint
+        32 + // payout_mint
+        8 + // amount
+        8; // release_ts
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Escrow is still locked")]
+    EscrowLocked,
+}
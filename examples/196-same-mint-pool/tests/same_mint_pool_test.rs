@@ -0,0 +1,47 @@
+#[tokio::test]
+async fn test_same_mint_pool_exploit() {
+    println!("\n=== EXPLOIT: Same-Mint Pool Double Counts Its Vault ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let mint_x = create_mint().await;
+
+    println!("1. Mallory creates an X/X pool and deposits 100 + 100");
+    // Vulnerable: both vaults resolve to the same ATA
+    let pool = create_pool(&mallory, &mint_x, &mint_x).await.unwrap();
+    deposit(&pool, &mallory, 100, 100).await.unwrap();
+    assert_eq!(get_vault_balance(&pool).await, 200);
+
+    println!("\n2. Alice deposits 200 + 200");
+    deposit(&pool, &alice, 200, 200).await.unwrap();
+    assert_eq!(get_position(&pool, &alice).await.lp_balance, 100);
+
+    println!("\n3. Mallory withdraws her 100 LP");
+    withdraw(&pool, &mallory, 100).await.unwrap();
+    assert_eq!(get_token_balance(&mallory, &mint_x).await, INITIAL_BALANCE + 400);
+    assert_eq!(get_vault_balance(&pool).await, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Mallory withdrew 600 for a 200 deposit");
+    println!("   ✗ Alice's 100 LP are backed by nothing");
+}
+
+#[tokio::test]
+async fn test_same_mint_pool_rejected() {
+    println!("\n=== SECURITY: Pool Mints Must Differ ===\n");
+
+    let mallory = Keypair::new();
+    let mint_x = create_mint().await;
+    let mint_y = create_mint().await;
+
+    println!("1. Create an X/X pool");
+    let result = create_pool(&mallory, &mint_x, &mint_x).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: SameMintPool");
+
+    println!("\n2. Create an X/Y pool");
+    let pool = create_pool(&mallory, &mint_x, &mint_y).await.unwrap();
+    let state = get_pool(&pool).await;
+    assert_ne!(state.vault_a, state.vault_b);
+    println!("   ✓ Separate vaults for each side");
+}
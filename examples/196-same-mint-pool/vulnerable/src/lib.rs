@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln19619619619619619619619619619619619619");
+
+#[program]
+pub mod vulnerable_same_mint_pool {
+    use super::*;
+
+    /// VULNERABILITY: Pool Created With The Same Mint On Both Sides
+    ///
+    /// Pools are permissionless and each vault is the pool's associated
+    /// token account for its mint. Nothing requires the two mints to
+    /// differ. With `token_a_mint == token_b_mint` both vaults resolve to
+    /// the same address, so one balance is read as reserve A and again as
+    /// reserve B. Withdrawals then pay every LP share twice.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory creates an X/X pool and deposits 100 + 100: the vault
+    ///    holds 200, both reserves read 200, she holds 100 LP
+    /// 2. The pool shows up in a front-end that lists every pool. Alice
+    ///    deposits 200 + 200 and receives 100 LP; the vault holds 600
+    /// 3. Mallory withdraws her 100 LP: 300 "A" and 300 "B", both paid
+    ///    from the one vault
+    /// 4. The vault is empty. Alice's 100 LP are worth nothing
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Every formula that treats the reserves as independent double
+    ///   counts the pool's funds
+    /// - Quotes from the pool (1 X = 1 X at any ratio) are meaningless
+    pub fn create_pool(ctx: Context<CreatePool>) -> Result<()> {
+        // ❌ token_a_mint may equal token_b_mint
+        let pool = &mut ctx.accounts.pool;
+        pool.mint_a = ctx.accounts.token_a_mint.key();
+        pool.mint_b = ctx.accounts.token_b_mint.key();
+        pool.vault_a = ctx.accounts.vault_a.key();
+        pool.vault_b = ctx.accounts.vault_b.key();
+        pool.bump = ctx.bumps.pool;
+        Ok(())
+    }
+
+    /// Add both tokens for LP shares, at the current reserve ratio
+    pub fn deposit(ctx: Context<Deposit>, amount_a: u64, amount_b: u64) -> Result<()> {
+        let reserve_a = ctx.accounts.vault_a.amount;
+        let reserve_b = ctx.accounts.vault_b.amount;
+        let lp_supply = ctx.accounts.pool.lp_supply;
+
+        let lp_out = if lp_supply == 0 {
+            amount_a
+        } else {
+            pro_rata(amount_a, lp_supply, reserve_a)?.min(pro_rata(amount_b, lp_supply, reserve_b)?)
+        };
+        require!(lp_out > 0, ErrorCode::ZeroLiquidity);
+
+        for (user_token, vault, amount) in [
+            (&ctx.accounts.user_token_a, &ctx.accounts.vault_a, amount_a),
+            (&ctx.accounts.user_token_b, &ctx.accounts.vault_b, amount_b),
+        ] {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: user_token.to_account_info(),
+                        to: vault.to_account_info(),
+                        authority: ctx.accounts.owner.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+
+        ctx.accounts.pool.lp_supply = lp_supply
+            .checked_add(lp_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let position = &mut ctx.accounts.position;
+        position.pool = ctx.accounts.pool.key();
+        position.owner = ctx.accounts.owner.key();
+        position.lp_balance = position
+            .lp_balance
+            .checked_add(lp_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Burn LP shares for a pro-rata amount of both reserves
+    pub fn withdraw(ctx: Context<Withdraw>, lp_amount: u64) -> Result<()> {
+        require!(
+            ctx.accounts.position.lp_balance >= lp_amount,
+            ErrorCode::InsufficientLp
+        );
+        let pool = &ctx.accounts.pool;
+        let out_a = pro_rata(ctx.accounts.vault_a.amount, lp_amount, pool.lp_supply)?;
+        let out_b = pro_rata(ctx.accounts.vault_b.amount, lp_amount, pool.lp_supply)?;
+
+        let seeds = &[b"pool".as_ref(), pool.mint_a.as_ref(), pool.mint_b.as_ref(), &[pool.bump]];
+        for (vault, user_token, amount) in [
+            (&ctx.accounts.vault_a, &ctx.accounts.user_token_a, out_a),
+            (&ctx.accounts.vault_b, &ctx.accounts.user_token_b, out_b),
+        ] {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: user_token.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    &[&seeds[..]],
+                ),
+                amount,
+            )?;
+        }
+
+        ctx.accounts.pool.lp_supply -= lp_amount;
+        ctx.accounts.position.lp_balance -= lp_amount;
+        Ok(())
+    }
+}
+
+/// `amount * numerator / denominator`
+fn pro_rata(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    require!(denominator > 0, ErrorCode::ZeroLiquidity);
+    let out = (amount as u128)
+        .checked_mul(numerator as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / denominator as u128;
+    u64::try_from(out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreatePool<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Pool::LEN,
+        seeds = [b"pool", token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    pub token_a_mint: Account<'info, Mint>,
+    pub token_b_mint: Account<'info, Mint>,
+
+    /// Created beforehand with the idempotent ATA instruction
+    #[account(associated_token::mint = token_a_mint, associated_token::authority = pool)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(associated_token::mint = token_b_mint, associated_token::authority = pool)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + LpPosition::LEN,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_a, token::authority = owner)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_b, token::authority = owner)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_a)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_b)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub lp_supply: u64,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const LEN: usize = 32 + // mint_a
+        32 + // mint_b
+        32 + // vault_a
+        32 + // vault_b
+        8 + // lp_supply
+        1; // bump
+}
+
+#[account]
+pub struct LpPosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub lp_balance: u64,
+}
+
+impl LpPosition {
+    pub const LEN: usize = 32 + // pool
+        32 + // owner
+        8; // lp_balance
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Deposit too small or pool empty")]
+    ZeroLiquidity,
+
+    #[msg("Insufficient LP balance")]
+    InsufficientLp,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur19719719719719719719719719719719719719");
+
+/// Tokens paid to each newly registered user
+pub const SIGNUP_BONUS: u64 = 100;
+
+#[program]
+pub mod secure_fake_system_program {
+    use super::*;
+
+    /// SECURE: System Program Pinned By `Program<'info, System>`
+    ///
+    /// `system_program` is typed `Program<'info, System>`, so Anchor
+    /// rejects any account whose key is not the System Program before the
+    /// handler runs. The receipt is created through Anchor's
+    /// `system_program::create_account`, which always targets the real
+    /// System Program. If the receipt already exists the create fails and
+    /// no bonus is paid.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `Program<'info, System>` checks the program ID
+    /// 2. CPI built by the Anchor helper, not from a caller-supplied ID
+    /// 3. Bonus paid only after the receipt is created
+    pub fn register(ctx: Context<Register>) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        let lamports = Rent::get()?.minimum_balance(0);
+
+        // ✅ Always the real System Program
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.receipt.to_account_info(),
+                },
+                &[&[b"registered", user.as_ref(), &[ctx.bumps.receipt]]],
+            ),
+            lamports,
+            0,
+            &crate::ID,
+        )?;
+
+        let seeds = &[b"treasury".as_ref(), &[ctx.accounts.treasury.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.treasury_vault.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.treasury.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            SIGNUP_BONUS,
+        )?;
+
+        ctx.accounts.treasury.registered_users += 1;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Register<'info> {
+    /// CHECK: Empty receipt; its existence marks the user as registered
+    #[account(mut, seeds = [b"registered", user.key().as_ref()], bump)]
+    pub receipt: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump, has_one = treasury_vault)]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = treasury_vault.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    // ✅ Key must equal the System Program ID
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Treasury {
+    pub treasury_vault: Pubkey,
+    pub registered_users: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln19719719719719719719719719719719719719");
+
+/// Tokens paid to each newly registered user
+pub const SIGNUP_BONUS: u64 = 100;
+
+#[program]
+pub mod vulnerable_fake_system_program {
+    use super::*;
+
+    /// VULNERABILITY: System Program Account Not Validated
+    ///
+    /// Registration creates an empty PDA receipt at
+    /// `[b"registered", user]` and pays a signup bonus. The receipt is the
+    /// only thing stopping a second registration: creating it again would
+    /// fail. The create instruction is sent to whatever program was passed
+    /// as `system_program`, an unchecked `AccountInfo`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory deploys a program that returns `Ok(())` for any input
+    /// 2. She calls `register` with it as `system_program`
+    /// 3. The "create_account" CPI succeeds; no receipt is created
+    /// 4. The bonus is paid, and the next call finds no receipt either
+    /// 5. She repeats until the treasury is empty
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A successful CPI only means the callee returned `Ok`
+    /// - `system_instruction::create_account` targets the real System
+    ///   Program; it is overriding `program_id` with the passed account
+    ///   that hands the choice to the caller
+    pub fn register(ctx: Context<Register>) -> Result<()> {
+        let user = ctx.accounts.user.key();
+        let lamports = Rent::get()?.minimum_balance(0);
+
+        let mut ix = system_instruction::create_account(
+            &user,
+            &ctx.accounts.receipt.key(),
+            lamports,
+            0,
+            &crate::ID,
+        );
+        // ❌ Instruction sent to the caller-supplied program
+        ix.program_id = ctx.accounts.system_program.key();
+        invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.receipt.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[b"registered", user.as_ref(), &[ctx.bumps.receipt]]],
+        )?;
+
+        let seeds = &[b"treasury".as_ref(), &[ctx.accounts.treasury.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.treasury_vault.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.treasury.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            SIGNUP_BONUS,
+        )?;
+
+        ctx.accounts.treasury.registered_users += 1;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Register<'info> {
+    /// CHECK: Empty receipt; its existence marks the user as registered
+    #[account(mut, seeds = [b"registered", user.key().as_ref()], bump)]
+    pub receipt: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump, has_one = treasury_vault)]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = treasury_vault.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: ❌ Any executable account is accepted
+    pub system_program: AccountInfo<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Treasury {
+    pub treasury_vault: Pubkey,
+    pub registered_users: u64,
+    pub bump: u8,
+}
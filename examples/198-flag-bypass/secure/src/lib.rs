@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("Secur19819819819819819819819819819819819819");
+
+/// The audited router withdrawals are normally sent through
+pub const APPROVED_ROUTER: Pubkey = pubkey!("Router1111111111111111111111111111111111111");
+
+#[program]
+pub mod secure_flag_bypass {
+    use super::*;
+
+    /// Grant or revoke the fast path for a vetted integrator
+    pub fn set_fast_path(ctx: Context<SetFastPath>, use_fast_path: bool) -> Result<()> {
+        // ✅ Only the admin decides who skips router validation
+        ctx.accounts.user_state.use_fast_path = use_fast_path;
+        Ok(())
+    }
+
+    /// SECURE: Fast Path Granted By The Admin Only
+    ///
+    /// The branch is unchanged, but `use_fast_path` can no longer be
+    /// written by the user. `set_fast_path` requires the config admin's
+    /// signature, and no user-facing instruction touches the field.
+    /// Everyone else's withdrawals go through `APPROVED_ROUTER`.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `set_fast_path` gated by `has_one = admin` on the config
+    /// 2. Users have no instruction that writes `use_fast_path`
+    /// 3. Router check applied whenever the flag is unset
+    ///
+    /// NOTE:
+    /// A fast-path account can still route through any program with the
+    /// vault's signature. Granting it is a trust decision about that
+    /// integrator, which is why it belongs to the admin.
+    pub fn route_withdrawal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RouteWithdrawal<'info>>,
+        amount: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let user_state = &mut ctx.accounts.user_state;
+        user_state.balance = user_state
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+
+        // ✅ Flag was set by the admin, not the caller
+        if !user_state.use_fast_path {
+            require_keys_eq!(
+                ctx.accounts.router_program.key(),
+                APPROVED_ROUTER,
+                ErrorCode::UnapprovedRouter
+            );
+        }
+
+        invoke_router(&ctx, amount, data)
+    }
+}
+
+/// Invoke the router with the vault PDA signing; the router receives the
+/// amount and pays the user out
+fn invoke_router<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, RouteWithdrawal<'info>>,
+    amount: u64,
+    data: Vec<u8>,
+) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer || a.key() == vault_key,
+            is_writable: a.is_writable,
+        })
+        .collect();
+
+    let mut ix_data = amount.to_le_bytes().to_vec();
+    ix_data.extend(data);
+    let ix = Instruction {
+        program_id: ctx.accounts.router_program.key(),
+        accounts,
+        data: ix_data,
+    };
+
+    let seeds: &[&[u8]] = &[b"vault", &[ctx.bumps.vault]];
+    invoke_signed(&ix, ctx.remaining_accounts, &[seeds])?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetFastPath<'info> {
+    #[account(seeds = [b"config"], bump, has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub user_state: Account<'info, UserState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RouteWithdrawal<'info> {
+    #[account(mut, seeds = [b"user", owner.key().as_ref()], bump, has_one = owner)]
+    pub user_state: Account<'info, UserState>,
+
+    /// CHECK: PDA that owns the vault token accounts
+    #[account(seeds = [b"vault"], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against APPROVED_ROUTER, unless on the fast path
+    pub router_program: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+    // remaining_accounts: passed through to the router
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+}
+
+#[account]
+pub struct UserState {
+    pub owner: Pubkey,
+    pub balance: u64,
+    /// Skip router validation; set only through `set_fast_path`
+    pub use_fast_path: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient balance")]
+    InsufficientBalance,
+
+    #[msg("Router program is not approved")]
+    UnapprovedRouter,
+}
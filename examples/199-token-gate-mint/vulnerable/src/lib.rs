@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+declare_id!("Vuln19919919919919919919919919919919919919");
+
+/// The DAO's governance token
+pub const GOVERNANCE_TOKEN_MINT: Pubkey = pubkey!("Gov1111111111111111111111111111111111111111");
+
+/// Longest description stored with a proposal
+pub const MAX_DESCRIPTION_LEN: usize = 256;
+
+#[program]
+pub mod vulnerable_token_gate_mint {
+    use super::*;
+
+    /// VULNERABILITY: Token Gate Checks Balance But Not Mint
+    ///
+    /// Only governance token holders may create proposals. The gate
+    /// checks that the proposer owns a token account with `amount >= 1`,
+    /// but never which mint that account holds. One unit of any SPL token
+    /// passes.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory mints herself 1 unit of a worthless token
+    /// 2. She calls `create_proposal` with that token account
+    /// 3. Owner and balance checks pass; the proposal is created
+    /// 4. She floods the DAO with spam or malicious proposals, each one
+    ///    needing voters' attention to defeat
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The gate restricts nothing; anyone can mint a token
+    /// - The same mistake on a voting or claim gate hands out votes or
+    ///   rewards to non-holders
+    pub fn create_proposal(ctx: Context<CreateProposal>, description: String) -> Result<()> {
+        require!(description.len() <= MAX_DESCRIPTION_LEN, ErrorCode::DescriptionTooLong);
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.description = description;
+        proposal.created_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(init, payer = proposer, space = 8 + Proposal::LEN)]
+    pub proposal: Account<'info, Proposal>,
+
+    // ❌ Any mint with a balance of at least 1
+    #[account(
+        token::authority = proposer,
+        constraint = proposer_token.amount >= 1 @ ErrorCode::NotATokenHolder,
+    )]
+    pub proposer_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Proposal {
+    pub proposer: Pubkey,
+    pub description: String,
+    pub created_at: i64,
+}
+
+impl Proposal {
+    pub const LEN: usize = 32 + // proposer
+        4 + MAX_DESCRIPTION_LEN + // description
+        8; // created_at
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Proposer must hold the governance token")]
+    NotATokenHolder,
+
+    #[msg("Description too long")]
+    DescriptionTooLong,
+}
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+declare_id!("Secur2oo2oo2oo2oo2oo2oo2oo2oo2oo2oo2oo2oo2o");
+
+/// Highest transfer fee the vault tolerates on a deposit (5%)
+pub const MAX_TRANSFER_FEE_BPS: u64 = 500;
+
+#[program]
+pub mod secure_fot_deposit {
+    use super::*;
+
+    /// SECURE: Deposit Credited At The Vault's Balance Change
+    ///
+    /// The vault's token balance is read before the transfer and again
+    /// after it, and the user is credited the difference. Whatever fee the
+    /// mint withheld is borne by the depositor. A delta below what
+    /// `MAX_TRANSFER_FEE_BPS` allows, or above `amount`, fails the deposit.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `received = post_balance - pre_balance`, after `reload()`
+    /// 2. `received >= amount * (10000 - MAX_TRANSFER_FEE_BPS) / 10000`
+    /// 3. `received <= amount`
+    /// 4. `deposited += received`, never `amount`
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        let pre_balance = ctx.accounts.vault_token.amount;
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.vault_token.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        // ✅ Credit what actually arrived
+        ctx.accounts.vault_token.reload()?;
+        let received = ctx
+            .accounts
+            .vault_token
+            .amount
+            .checked_sub(pre_balance)
+            .ok_or(ErrorCode::UnexpectedBalanceChange)?;
+
+        let min_received = (amount as u128) * (10_000 - MAX_TRANSFER_FEE_BPS) as u128 / 10_000;
+        require!(
+            received as u128 >= min_received && received <= amount,
+            ErrorCode::UnexpectedBalanceChange
+        );
+
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.owner = ctx.accounts.owner.key();
+        deposit.deposited = deposit
+            .deposited
+            .checked_add(received)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Withdraw up to the recorded deposit
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.deposited = deposit
+            .deposited
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientDeposit)?;
+
+        let seeds = &[b"vault".as_ref(), &[ctx.accounts.vault.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token, has_one = mint)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + UserDeposit::LEN,
+        seeds = [b"deposit", owner.key().as_ref()],
+        bump,
+    )]
+    pub deposit: Account<'info, UserDeposit>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub vault_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint, token::authority = owner)]
+    pub user_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token, has_one = mint)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, seeds = [b"deposit", owner.key().as_ref()], bump, has_one = owner)]
+    pub deposit: Account<'info, UserDeposit>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub vault_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint)]
+    pub user_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub mint: Pubkey,
+    pub vault_token: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct UserDeposit {
+    pub owner: Pubkey,
+    pub deposited: u64,
+}
+
+impl UserDeposit {
+    pub const LEN: usize = 32 + // owner
+        8; // deposited
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient deposit")]
+    InsufficientDeposit,
+
+    #[msg("Vault received an unexpected amount")]
+    UnexpectedBalanceChange,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
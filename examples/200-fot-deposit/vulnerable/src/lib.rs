@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+declare_id!("Vuln20020020020020020020020020020020020020");
+
+#[program]
+pub mod vulnerable_fot_deposit {
+    use super::*;
+
+    /// VULNERABILITY: Deposit Credited At The Requested Amount
+    ///
+    /// The vault accepts Token-2022 mints, including ones with the
+    /// `TransferFeeConfig` extension. On those, the mint withholds a fee
+    /// from every transfer and the destination receives less than
+    /// `amount`. `deposit` credits the user with `amount` anyway.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The mint charges a 2% transfer fee; the vault holds 10,000 from
+    ///    other depositors
+    /// 2. Mallory deposits 10,000: the vault receives 9,800, she is
+    ///    credited 10,000
+    /// 3. She withdraws 10,000. The extra 200 comes out of other users'
+    ///    deposits
+    /// 4. Each round trip moves 2% of the deposit from everyone else to
+    ///    her, until the last depositors cannot withdraw
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Recorded deposits exceed what the vault holds after every deposit
+    /// - The issuer can switch the fee on or raise it after listing
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.vault_token.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        // ❌ Credits the parameter, not what arrived
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.owner = ctx.accounts.owner.key();
+        deposit.deposited = deposit
+            .deposited
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Withdraw up to the recorded deposit
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.deposited = deposit
+            .deposited
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientDeposit)?;
+
+        let seeds = &[b"vault".as_ref(), &[ctx.accounts.vault.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token, has_one = mint)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + UserDeposit::LEN,
+        seeds = [b"deposit", owner.key().as_ref()],
+        bump,
+    )]
+    pub deposit: Account<'info, UserDeposit>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub vault_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint, token::authority = owner)]
+    pub user_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token, has_one = mint)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, seeds = [b"deposit", owner.key().as_ref()], bump, has_one = owner)]
+    pub deposit: Account<'info, UserDeposit>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub vault_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint)]
+    pub user_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub mint: Pubkey,
+    pub vault_token: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct UserDeposit {
+    pub owner: Pubkey,
+    pub deposited: u64,
+}
+
+impl UserDeposit {
+    pub const LEN: usize = 32 + // owner
+        8; // deposited
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient deposit")]
+    InsufficientDeposit,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, accessor, CloseAccount, Mint, Token, TokenAccount};
+
+declare_id!("Secur2o12o12o12o12o12o12o12o12o12o12o12o12o");
+
+/// The DEX every leg is routed through
+pub const DEX_PROGRAM_ID: Pubkey = pubkey!("Dex1111111111111111111111111111111111111111");
+
+#[program]
+pub mod secure_intermediate_account_owner {
+    use super::*;
+
+    /// Leg one: swap the user's A for B, parked in an intermediate account.
+    /// Each leg is its own instruction so long routes fit the account limit.
+    pub fn start_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StartSwap<'info>>,
+        swap_id: u64,
+        amount_in: u64,
+    ) -> Result<()> {
+        let before = ctx.accounts.intermediate.amount;
+        let intermediate = ctx.accounts.intermediate.to_account_info();
+
+        // ✅ B delivered to this swap's own router-owned account
+        dex_swap(
+            &ctx.accounts.dex_program,
+            &ctx.accounts.user_token_a.to_account_info(),
+            &intermediate,
+            &ctx.accounts.user.to_account_info(),
+            ctx.remaining_accounts,
+            amount_in,
+            &[],
+        )?;
+
+        let swap_state = &mut ctx.accounts.swap_state;
+        swap_state.user = ctx.accounts.user.key();
+        swap_state.swap_id = swap_id;
+        swap_state.mint_b = ctx.accounts.mint_b.key();
+        swap_state.amount_b = accessor::amount(&intermediate)?
+            .checked_sub(before)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// SECURE: Intermediate Account Owned By A Per-Swap PDA
+    ///
+    /// Each swap gets its own intermediate account: the associated token
+    /// account of the PDA `[b"intermediate", swap_id]`, created in leg one.
+    /// Leg two can only spend from the account derived from the same
+    /// `swap_id`, and signs with that swap's seeds alone. No signature
+    /// covers more than one swap's B.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Intermediate account derived from `swap_id`, not supplied freely
+    /// 2. Its authority is a PDA unique to the swap
+    /// 3. Leg two spends that account's whole balance, then closes it
+    /// 4. `has_one = user` - only the swap's owner can finish it
+    pub fn finish_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinishSwap<'info>>,
+        swap_id: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        let user_token_c = ctx.accounts.user_token_c.to_account_info();
+        let before = accessor::amount(&user_token_c)?;
+
+        // ✅ Only this swap's PDA can sign, only for this swap's account
+        let swap_id_bytes = swap_id.to_le_bytes();
+        let seeds: &[&[u8]] = &[
+            b"intermediate",
+            swap_id_bytes.as_ref(),
+            &[ctx.bumps.intermediate_authority],
+        ];
+        let intermediate = ctx.accounts.intermediate.to_account_info();
+        let authority = ctx.accounts.intermediate_authority.to_account_info();
+        dex_swap(
+            &ctx.accounts.dex_program,
+            &intermediate,
+            &user_token_c,
+            &authority,
+            ctx.remaining_accounts,
+            ctx.accounts.intermediate.amount,
+            &[seeds],
+        )?;
+
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: intermediate,
+                destination: ctx.accounts.user.to_account_info(),
+                authority,
+            },
+            &[seeds],
+        ))?;
+
+        let amount_out = accessor::amount(&user_token_c)?
+            .checked_sub(before)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(amount_out >= min_out, ErrorCode::SlippageExceeded);
+        Ok(())
+    }
+}
+
+/// Swap `amount` from `source` into `destination` through the DEX.
+/// `pool_accounts` are passed through unchanged.
+pub fn dex_swap<'info>(
+    dex_program: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    pool_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut accounts = vec![
+        AccountMeta::new(source.key(), false),
+        AccountMeta::new(destination.key(), false),
+        AccountMeta::new_readonly(authority.key(), true),
+    ];
+    accounts.extend(pool_accounts.iter().map(|a| AccountMeta {
+        pubkey: a.key(),
+        is_signer: false,
+        is_writable: a.is_writable,
+    }));
+    let ix = Instruction {
+        program_id: dex_program.key(),
+        accounts,
+        data: amount.to_le_bytes().to_vec(),
+    };
+
+    let mut infos = vec![source.clone(), destination.clone(), authority.clone()];
+    infos.extend_from_slice(pool_accounts);
+    invoke_signed(&ix, &infos, signer_seeds)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(swap_id: u64)]
+pub struct StartSwap<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = 8 + SwapState::LEN,
+        seeds = [b"swap", swap_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub swap_state: Account<'info, SwapState>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    pub mint_b: Account<'info, Mint>,
+
+    /// CHECK: Per-swap PDA; signs only for this swap's intermediate account
+    #[account(seeds = [b"intermediate", swap_id.to_le_bytes().as_ref()], bump)]
+    pub intermediate_authority: UncheckedAccount<'info>,
+
+    // ✅ Created here, owned by the swap's PDA
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = mint_b,
+        associated_token::authority = intermediate_authority,
+    )]
+    pub intermediate: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Pinned to the DEX program ID
+    #[account(address = DEX_PROGRAM_ID)]
+    pub dex_program: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: DEX pool accounts for the A -> B leg
+}
+
+#[derive(Accounts)]
+#[instruction(swap_id: u64)]
+pub struct FinishSwap<'info> {
+    #[account(
+        mut,
+        seeds = [b"swap", swap_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = user,
+        close = user,
+    )]
+    pub swap_state: Account<'info, SwapState>,
+
+    /// CHECK: Per-swap PDA, re-derived from `swap_id`
+    #[account(seeds = [b"intermediate", swap_id.to_le_bytes().as_ref()], bump)]
+    pub intermediate_authority: UncheckedAccount<'info>,
+
+    // ✅ Must be the account leg one created for this swap
+    #[account(
+        mut,
+        associated_token::mint = swap_state.mint_b,
+        associated_token::authority = intermediate_authority,
+    )]
+    pub intermediate: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_c: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Pinned to the DEX program ID
+    #[account(address = DEX_PROGRAM_ID)]
+    pub dex_program: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: DEX pool accounts for the B -> C leg
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct SwapState {
+    pub user: Pubkey,
+    pub swap_id: u64,
+    pub mint_b: Pubkey,
+    /// B received in leg one
+    pub amount_b: u64,
+}
+
+impl SwapState {
+    pub const LEN: usize = 32 + // user
+        8 + // swap_id
+        32 + // mint_b
+        8; // amount_b
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Output below minimum")]
+    SlippageExceeded,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
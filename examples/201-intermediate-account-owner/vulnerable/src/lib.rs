@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{accessor, Token, TokenAccount};
+
+declare_id!("Vuln20120120120120120120120120120120120120");
+
+/// The DEX every leg is routed through
+pub const DEX_PROGRAM_ID: Pubkey = pubkey!("Dex1111111111111111111111111111111111111111");
+
+#[program]
+pub mod vulnerable_intermediate_account_owner {
+    use super::*;
+
+    /// Leg one: swap the user's A for B, parked in an intermediate account.
+    /// Each leg is its own instruction so long routes fit the account limit.
+    pub fn start_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StartSwap<'info>>,
+        swap_id: u64,
+        amount_in: u64,
+    ) -> Result<()> {
+        let intermediate = &ctx.accounts.intermediate;
+        let before = accessor::amount(intermediate)?;
+
+        // ❌ B delivered to whatever account the caller passed
+        dex_swap(
+            &ctx.accounts.dex_program,
+            &ctx.accounts.user_token_a.to_account_info(),
+            intermediate,
+            &ctx.accounts.user.to_account_info(),
+            ctx.remaining_accounts,
+            amount_in,
+            &[],
+        )?;
+
+        let swap_state = &mut ctx.accounts.swap_state;
+        swap_state.user = ctx.accounts.user.key();
+        swap_state.swap_id = swap_id;
+        swap_state.amount_b = accessor::amount(intermediate)?
+            .checked_sub(before)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Intermediate Accounts Supplied By The User
+    ///
+    /// Between legs the router holds token B in intermediate accounts, all
+    /// owned by one router authority PDA. Both legs take the intermediate
+    /// account as a plain `AccountInfo`. Leg one never checks where B was
+    /// delivered; leg two spends `amount_b` from whichever account is
+    /// passed, signing as the router authority.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The router holds 50,000 B in flight for other users' swaps
+    /// 2. Mallory calls `start_swap` with her own B account as
+    ///    `intermediate`: 10,000 A buys her 10,000 B, recorded as `amount_b`
+    /// 3. She calls `finish_swap` with another user's intermediate account
+    /// 4. The router signs for it and swaps 10,000 of their B into C for
+    ///    Mallory
+    /// 5. She keeps the B and the C; the other user's swap can no longer
+    ///    complete
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The router authority signs for every parked balance, so any of
+    ///   them can be spent by any swap
+    /// - Nothing ties leg two's input to leg one's output
+    pub fn finish_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinishSwap<'info>>,
+        _swap_id: u64,
+        min_out: u64,
+    ) -> Result<()> {
+        let user_token_c = ctx.accounts.user_token_c.to_account_info();
+        let before = accessor::amount(&user_token_c)?;
+
+        // ❌ Spent from any account the router authority controls
+        let seeds: &[&[u8]] = &[b"router", &[ctx.bumps.router_authority]];
+        dex_swap(
+            &ctx.accounts.dex_program,
+            &ctx.accounts.intermediate,
+            &user_token_c,
+            &ctx.accounts.router_authority,
+            ctx.remaining_accounts,
+            ctx.accounts.swap_state.amount_b,
+            &[seeds],
+        )?;
+
+        let amount_out = accessor::amount(&user_token_c)?
+            .checked_sub(before)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(amount_out >= min_out, ErrorCode::SlippageExceeded);
+        Ok(())
+    }
+}
+
+/// Swap `amount` from `source` into `destination` through the DEX.
+/// `pool_accounts` are passed through unchanged.
+pub fn dex_swap<'info>(
+    dex_program: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    pool_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut accounts = vec![
+        AccountMeta::new(source.key(), false),
+        AccountMeta::new(destination.key(), false),
+        AccountMeta::new_readonly(authority.key(), true),
+    ];
+    accounts.extend(pool_accounts.iter().map(|a| AccountMeta {
+        pubkey: a.key(),
+        is_signer: false,
+        is_writable: a.is_writable,
+    }));
+    let ix = Instruction {
+        program_id: dex_program.key(),
+        accounts,
+        data: amount.to_le_bytes().to_vec(),
+    };
+
+    let mut infos = vec![source.clone(), destination.clone(), authority.clone()];
+    infos.extend_from_slice(pool_accounts);
+    invoke_signed(&ix, &infos, signer_seeds)?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(swap_id: u64)]
+pub struct StartSwap<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = 8 + SwapState::LEN,
+        seeds = [b"swap", swap_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub swap_state: Account<'info, SwapState>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    /// CHECK: ❌ Should be router-owned; never checked
+    #[account(mut)]
+    pub intermediate: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Pinned to the DEX program ID
+    #[account(address = DEX_PROGRAM_ID)]
+    pub dex_program: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: DEX pool accounts for the A -> B leg
+}
+
+#[derive(Accounts)]
+#[instruction(swap_id: u64)]
+pub struct FinishSwap<'info> {
+    #[account(
+        mut,
+        seeds = [b"swap", swap_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = user,
+        close = user,
+    )]
+    pub swap_state: Account<'info, SwapState>,
+
+    /// CHECK: ❌ Any account the router authority can sign for
+    #[account(mut)]
+    pub intermediate: AccountInfo<'info>,
+
+    /// CHECK: PDA that owns every intermediate account
+    #[account(seeds = [b"router"], bump)]
+    pub router_authority: AccountInfo<'info>,
+
+    #[account(mut, token::authority = user)]
+    pub user_token_c: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Pinned to the DEX program ID
+    #[account(address = DEX_PROGRAM_ID)]
+    pub dex_program: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: DEX pool accounts for the B -> C leg
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct SwapState {
+    pub user: Pubkey,
+    pub swap_id: u64,
+    /// B received in leg one, spent in leg two
+    pub amount_b: u64,
+}
+
+impl SwapState {
+    pub const LEN: usize = 32 + // user
+        8 + // swap_id
+        8; // amount_b
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Output below minimum")]
+    SlippageExceeded,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
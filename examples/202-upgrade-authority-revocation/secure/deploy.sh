@@ -0,0 +1,47 @@
+#!/usr/bin/env bash
+#
+# SECURE: Upgrade Authority Revoked After Testing
+#
+# The program is deployed upgradeable under a dedicated deploy key, so a
+# bug found on the live deployment can still be fixed. Once the test suite
+# passes against it, the upgrade authority is set to None. From then on
+# the loader rejects every upgrade, and verify_immutable confirms it from
+# ProgramData rather than trusting the script's exit status.
+#
+# SECURITY MEASURES:
+# 1. Dedicated deploy keypair, never the everyday CLI wallet
+# 2. Full test suite run against the deployed program
+# 3. BpfLoaderUpgradeable::SetAuthority with no new authority
+#    (`set-upgrade-authority --final`)
+# 4. verify_immutable reads ProgramData and fails unless the authority
+#    is None
+set -euo pipefail
+
+PROGRAM_SO=${PROGRAM_SO:-target/deploy/protocol.so}
+PROGRAM_KEYPAIR=${PROGRAM_KEYPAIR:-target/deploy/protocol-keypair.json}
+DEPLOY_KEYPAIR=${DEPLOY_KEYPAIR:?set DEPLOY_KEYPAIR to a dedicated deploy key}
+RPC_URL=${RPC_URL:-https://api.mainnet-beta.solana.com}
+
+PROGRAM_ID=$(solana address -k "$PROGRAM_KEYPAIR")
+
+# 1. Deploy upgradeable, authority = the deploy key
+anchor build --verifiable
+solana program deploy "$PROGRAM_SO" \
+    --program-id "$PROGRAM_KEYPAIR" \
+    --upgrade-authority "$DEPLOY_KEYPAIR" \
+    --keypair "$DEPLOY_KEYPAIR" \
+    --url "$RPC_URL"
+
+# 2. Test against the live deployment before giving up the ability to fix it
+anchor test --skip-build --skip-deploy --provider.cluster "$RPC_URL"
+
+# 3. ✅ SetAuthority with None - irreversible
+solana program set-upgrade-authority "$PROGRAM_ID" \
+    --final \
+    --upgrade-authority "$DEPLOY_KEYPAIR" \
+    --keypair "$DEPLOY_KEYPAIR" \
+    --url "$RPC_URL"
+
+# 4. ✅ Check the result on chain
+cargo run --quiet --manifest-path "$(dirname "$0")/../verify_immutable/Cargo.toml" -- \
+    "$PROGRAM_ID" "$RPC_URL"
@@ -0,0 +1,47 @@
+#[tokio::test]
+async fn test_hot_wallet_upgrade_exploit() {
+    println!("\n=== EXPLOIT: Stolen Hot Wallet Upgrades The Program ===\n");
+
+    let hot_wallet = Keypair::new();
+    let program_id = deploy_upgradeable(PROTOCOL_SO, &hot_wallet).await;
+    let vault = create_vault(&program_id, 1_000_000).await;
+
+    println!("1. Upgrade authority after deploy");
+    assert_eq!(get_upgrade_authority(&program_id).await, Some(hot_wallet.pubkey()));
+    println!("   {}", hot_wallet.pubkey());
+
+    println!("\n2. Attacker with the hot wallet key deploys a drainer build");
+    // Vulnerable: authority was never revoked
+    upgrade(&program_id, DRAINER_SO, &hot_wallet).await.unwrap();
+
+    println!("\n3. Drainer's withdraw, same program ID and vault");
+    let attacker = Keypair::new();
+    withdraw_all(&program_id, &vault, &attacker).await.unwrap();
+    assert_eq!(get_vault_balance(&vault).await, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 1,000,000 drained through replaced code");
+}
+
+#[tokio::test]
+async fn test_revoked_authority_blocks_upgrade() {
+    println!("\n=== SECURITY: Upgrade Authority Revoked ===\n");
+
+    let deploy_key = Keypair::new();
+    let program_id = deploy_upgradeable(PROTOCOL_SO, &deploy_key).await;
+
+    println!("1. set_upgrade_authority(None)");
+    set_upgrade_authority(&program_id, &deploy_key, None).await.unwrap();
+    assert_eq!(get_upgrade_authority(&program_id).await, None);
+    println!("   ✓ ProgramData.upgrade_authority_address = None");
+
+    println!("\n2. Upgrade with the former authority");
+    let result = upgrade(&program_id, DRAINER_SO, &deploy_key).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Incorrect authority provided");
+
+    println!("\n3. verify_immutable");
+    let status = run_verify_immutable(&program_id).await;
+    assert!(status.success());
+    println!("   ✓ Exit code 0");
+}
@@ -0,0 +1,210 @@
+//! Upgrade authority check
+//!
+//! Reads a program account, follows it to its `ProgramData` account and
+//! reports whether anyone can still upgrade it. Exits non-zero unless
+//! `upgrade_authority_address` is `None`.
+//!
+//! Usage:
+//!
+//! ```text
+//! verify_immutable <PROGRAM_ID> [RPC_URL]
+//! ```
+//!
+//! `RPC_URL` defaults to mainnet-beta.
+
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::account::Account;
+use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{bpf_loader, bpf_loader_deprecated};
+
+const DEFAULT_RPC_URL: &str = "https://api.mainnet-beta.solana.com";
+
+/// What the loader says about who can replace a program's code
+#[derive(Debug, PartialEq)]
+enum Upgradeability {
+    /// Deployed with the upgradeable loader and the authority revoked
+    Revoked,
+    /// Deployed with a loader that has no upgrade instruction
+    NonUpgradeableLoader,
+    /// Anyone holding this key can deploy new code
+    Upgradeable(Pubkey),
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(program_id) = args.next() else {
+        eprintln!("usage: verify_immutable <PROGRAM_ID> [RPC_URL]");
+        return ExitCode::from(2);
+    };
+    let rpc_url = args.next().unwrap_or_else(|| DEFAULT_RPC_URL.to_string());
+
+    match check(&program_id, &rpc_url) {
+        Ok(Upgradeability::Upgradeable(authority)) => {
+            println!("✗ {program_id} is UPGRADEABLE by {authority}");
+            ExitCode::FAILURE
+        }
+        Ok(Upgradeability::Revoked) => {
+            println!("✓ {program_id} is immutable: upgrade authority revoked");
+            ExitCode::SUCCESS
+        }
+        Ok(Upgradeability::NonUpgradeableLoader) => {
+            println!("✓ {program_id} is immutable: deployed with a non-upgradeable loader");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn check(program_id: &str, rpc_url: &str) -> Result<Upgradeability, String> {
+    let program_id =
+        Pubkey::from_str(program_id).map_err(|e| format!("invalid program id: {e}"))?;
+    let client = RpcClient::new(rpc_url.to_string());
+
+    let program = client
+        .get_account(&program_id)
+        .map_err(|e| format!("fetching {program_id}: {e}"))?;
+    let Some(program_data_address) = program_data_address(&program_id, &program)? else {
+        return Ok(Upgradeability::NonUpgradeableLoader);
+    };
+
+    let program_data = client
+        .get_account(&program_data_address)
+        .map_err(|e| format!("fetching ProgramData {program_data_address}: {e}"))?;
+    upgradeability(&program_data_address, &program_data)
+}
+
+/// `ProgramData` address for an upgradeable program, `None` for programs
+/// owned by one of the older, non-upgradeable loaders
+fn program_data_address(program_id: &Pubkey, program: &Account) -> Result<Option<Pubkey>, String> {
+    if !program.executable {
+        return Err(format!("{program_id} is not an executable account"));
+    }
+    if program.owner == bpf_loader::id() || program.owner == bpf_loader_deprecated::id() {
+        return Ok(None);
+    }
+    if program.owner != bpf_loader_upgradeable::id() {
+        return Err(format!(
+            "{program_id} is owned by unknown loader {}",
+            program.owner
+        ));
+    }
+
+    match program.deserialize_data::<UpgradeableLoaderState>() {
+        Ok(UpgradeableLoaderState::Program {
+            programdata_address,
+        }) => {
+            // The loader derives it this way; anything else is not its ProgramData
+            let (expected, _) =
+                Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+            if programdata_address != expected {
+                return Err(format!(
+                    "{program_id} points at unexpected ProgramData {programdata_address}"
+                ));
+            }
+            Ok(Some(programdata_address))
+        }
+        Ok(other) => Err(format!("{program_id} holds {other:?}, not a Program")),
+        Err(e) => Err(format!("decoding {program_id}: {e}")),
+    }
+}
+
+fn upgradeability(address: &Pubkey, program_data: &Account) -> Result<Upgradeability, String> {
+    if program_data.owner != bpf_loader_upgradeable::id() {
+        return Err(format!("{address} is not owned by the upgradeable loader"));
+    }
+    match program_data.deserialize_data::<UpgradeableLoaderState>() {
+        Ok(UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address,
+            ..
+        }) => Ok(match upgrade_authority_address {
+            None => Upgradeability::Revoked,
+            Some(authority) => Upgradeability::Upgradeable(authority),
+        }),
+        Ok(other) => Err(format!("{address} holds {other:?}, not ProgramData")),
+        Err(e) => Err(format!("decoding {address}: {e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_data_account(upgrade_authority_address: Option<Pubkey>) -> Account {
+        let state = UpgradeableLoaderState::ProgramData {
+            slot: 42,
+            upgrade_authority_address,
+        };
+        let mut data = bincode_bytes(&state);
+        data.extend_from_slice(&[0u8; 64]); // program bytes follow the header
+        Account {
+            lamports: 1,
+            data,
+            owner: bpf_loader_upgradeable::id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    fn bincode_bytes(state: &UpgradeableLoaderState) -> Vec<u8> {
+        let mut account = Account::new(
+            1,
+            UpgradeableLoaderState::size_of_programdata_metadata(),
+            &Pubkey::default(),
+        );
+        account.serialize_data(state).unwrap();
+        account.data
+    }
+
+    #[test]
+    fn authority_reported_until_revoked() {
+        let address = Pubkey::new_unique();
+        let hot_wallet = Pubkey::new_unique();
+
+        assert_eq!(
+            upgradeability(&address, &program_data_account(Some(hot_wallet))),
+            Ok(Upgradeability::Upgradeable(hot_wallet))
+        );
+        assert_eq!(
+            upgradeability(&address, &program_data_account(None)),
+            Ok(Upgradeability::Revoked)
+        );
+    }
+
+    #[test]
+    fn program_data_must_be_the_loader_pda() {
+        let program_id = Pubkey::new_unique();
+        let (expected, _) =
+            Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+
+        let mut program = Account::new(
+            1,
+            UpgradeableLoaderState::size_of_program(),
+            &bpf_loader_upgradeable::id(),
+        );
+        program.executable = true;
+
+        program
+            .serialize_data(&UpgradeableLoaderState::Program {
+                programdata_address: expected,
+            })
+            .unwrap();
+        assert_eq!(
+            program_data_address(&program_id, &program),
+            Ok(Some(expected))
+        );
+
+        program
+            .serialize_data(&UpgradeableLoaderState::Program {
+                programdata_address: Pubkey::new_unique(),
+            })
+            .unwrap();
+        assert!(program_data_address(&program_id, &program).is_err());
+    }
+}
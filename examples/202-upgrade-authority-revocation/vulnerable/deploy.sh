@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+#
+# VULNERABILITY: "Immutable" Program Left Upgradeable By A Hot Wallet
+#
+# The protocol is announced as immutable, but the deploy below never
+# revokes the upgrade authority. `solana program deploy` makes the
+# deploying keypair the upgrade authority, and that keypair is the
+# operator's everyday CLI wallet.
+#
+# ATTACK SCENARIO:
+# 1. The operator's laptop is compromised and ~/.config/solana/id.json
+#    is copied
+# 2. The attacker builds a version of the program whose withdraw skips
+#    every check
+# 3. `solana program deploy --program-id <ID>` with the stolen key
+#    replaces the code in place - same program ID, same PDAs, same vaults
+# 4. One withdraw per vault empties the protocol
+#
+# WHY THIS IS DANGEROUS:
+# - Every account check in the program is only as strong as the key that
+#   can replace the program
+# - Users and integrators trusted the "immutable" claim without reading
+#   ProgramData
+set -euo pipefail
+
+PROGRAM_SO=${PROGRAM_SO:-target/deploy/protocol.so}
+RPC_URL=${RPC_URL:-https://api.mainnet-beta.solana.com}
+
+anchor build
+
+# ❌ Upgrade authority defaults to the hot CLI wallet
+solana program deploy "$PROGRAM_SO" --url "$RPC_URL"
+
+# ❌ No set-upgrade-authority --final; nothing checks the result
+echo "Deployed. Protocol is immutable."
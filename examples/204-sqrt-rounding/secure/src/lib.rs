@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use num_integer::Roots;
+
+declare_id!("Secur2o42o42o42o42o42o42o42o42o42o42o42o42o");
+
+#[program]
+pub mod secure_sqrt_rounding {
+    use super::*;
+
+    /// SECURE: Library Square Root, Checked Against Its Definition
+    ///
+    /// The root comes from `num_integer::Roots`, a widely used and tested
+    /// implementation, and is then checked against what a floor square
+    /// root means: `r² <= n < (r + 1)²`. If the library or a future
+    /// replacement ever returns anything else, the deposit fails instead
+    /// of minting the wrong amount. The floor always rounds against the
+    /// depositor.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. No hand-rolled Newton iteration
+    /// 2. `r * r <= n` - never rounds up
+    /// 3. `(r + 1) * (r + 1) > n` - never rounds down by more than the
+    ///    fractional part
+    /// 4. Overflow of `(r + 1)²` treated as greater than `n`
+    pub fn deposit(ctx: Context<Deposit>, amount_a: u64, amount_b: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        if pool.lp_supply > 0 {
+            require!(
+                (amount_a as u128) * (pool.reserve_b as u128)
+                    == (amount_b as u128) * (pool.reserve_a as u128),
+                ErrorCode::RatioMismatch
+            );
+        }
+
+        // ✅ Floor square root, verified
+        let liquidity = checked_sqrt((amount_a as u128) * (amount_b as u128))?;
+        require!(liquidity > 0, ErrorCode::ZeroLiquidity);
+
+        for (user_token, vault, amount) in [
+            (&ctx.accounts.user_token_a, &ctx.accounts.vault_a, amount_a),
+            (&ctx.accounts.user_token_b, &ctx.accounts.vault_b, amount_b),
+        ] {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: user_token.to_account_info(),
+                        to: vault.to_account_info(),
+                        authority: ctx.accounts.owner.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+
+        let pool = &mut ctx.accounts.pool;
+        pool.reserve_a = pool
+            .reserve_a
+            .checked_add(amount_a)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.reserve_b = pool
+            .reserve_b
+            .checked_add(amount_b)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.lp_supply = pool
+            .lp_supply
+            .checked_add(liquidity)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let position = &mut ctx.accounts.position;
+        position.lp_balance = position
+            .lp_balance
+            .checked_add(liquidity)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Floor of the square root of `n`, verified before it is used
+pub fn checked_sqrt(n: u128) -> Result<u64> {
+    let root = n.sqrt();
+    let squared = root.checked_mul(root).ok_or(ErrorCode::SqrtError)?;
+    let next_squared_exceeds = (root + 1).checked_mul(root + 1).is_none_or(|sq| sq > n);
+    require!(squared <= n && next_squared_exceeds, ErrorCode::SqrtError);
+    u64::try_from(root).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_a.mint, token::authority = owner)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_b.mint, token::authority = owner)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub lp_supply: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct LpPosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub lp_balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Deposit does not match the pool ratio")]
+    RatioMismatch,
+
+    #[msg("Deposit too small to mint liquidity")]
+    ZeroLiquidity,
+
+    #[msg("Square root failed its invariant check")]
+    SqrtError,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
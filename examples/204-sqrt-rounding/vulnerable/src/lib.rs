@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln20420420420420420420420420420420420420");
+
+#[program]
+pub mod vulnerable_sqrt_rounding {
+    use super::*;
+
+    /// VULNERABILITY: Integer Square Root Rounds Up For `k² - 1`
+    ///
+    /// Each deposit mints `sqrt(amount_a * amount_b)` LP units, computed
+    /// with a hand-written Newton's method. The loop stops when the next
+    /// iterate stops decreasing, but returns that iterate rather than the
+    /// previous one. For `n = k² - 1` the iterates oscillate between
+    /// `k - 1` and `k`, and the function returns `k` - one more than the
+    /// floor. Every other input is correct, so ordinary tests pass.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Pool at 1,000,000 A / 2,000,000 B; an LP unit is worth ~0.71 A
+    ///    plus ~1.41 B
+    /// 2. Mallory deposits 2 A + 4 B: `2 * 4 = 8 = 3² - 1`
+    /// 3. `sqrt(8)` returns 3 instead of 2; the fair amount is 2.83
+    /// 4. She repeats 1,000 times in a loop of instructions: 3,000 LP
+    ///    for deposits worth ~2,828
+    /// 5. At the pool's pro-rata rate those units redeem for ~2,121 A +
+    ///    ~4,242 B, against 2,000 A + 4,000 B paid in
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Rounding in the depositor's favor is paid by every other LP
+    /// - The attacker picks the inputs, so a bug on rare values is hit on
+    ///   every call
+    /// - The gain is per base unit: worth pursuing on low-decimal tokens
+    ///   or high-value assets
+    pub fn deposit(ctx: Context<Deposit>, amount_a: u64, amount_b: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        if pool.lp_supply > 0 {
+            require!(
+                (amount_a as u128) * (pool.reserve_b as u128)
+                    == (amount_b as u128) * (pool.reserve_a as u128),
+                ErrorCode::RatioMismatch
+            );
+        }
+
+        // ❌ Off by one when amount_a * amount_b = k² - 1
+        let liquidity = sqrt((amount_a as u128) * (amount_b as u128));
+        require!(liquidity > 0, ErrorCode::ZeroLiquidity);
+
+        for (user_token, vault, amount) in [
+            (&ctx.accounts.user_token_a, &ctx.accounts.vault_a, amount_a),
+            (&ctx.accounts.user_token_b, &ctx.accounts.vault_b, amount_b),
+        ] {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: user_token.to_account_info(),
+                        to: vault.to_account_info(),
+                        authority: ctx.accounts.owner.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+
+        let pool = &mut ctx.accounts.pool;
+        pool.reserve_a = pool
+            .reserve_a
+            .checked_add(amount_a)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.reserve_b = pool
+            .reserve_b
+            .checked_add(amount_b)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.lp_supply = pool
+            .lp_supply
+            .checked_add(liquidity)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let position = &mut ctx.accounts.position;
+        position.lp_balance = position
+            .lp_balance
+            .checked_add(liquidity)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Integer square root by Newton's method
+pub fn sqrt(n: u128) -> u64 {
+    if n < 2 {
+        return n as u64;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    // ❌ Should return x, the last decreasing iterate
+    y as u64
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = owner)]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_a.mint, token::authority = owner)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_b.mint, token::authority = owner)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub lp_supply: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct LpPosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub lp_balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Deposit does not match the pool ratio")]
+    RatioMismatch,
+
+    #[msg("Deposit too small to mint liquidity")]
+    ZeroLiquidity,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
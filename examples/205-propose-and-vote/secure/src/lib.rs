@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur2o52o52o52o52o52o52o52o52o52o52o52o52o");
+
+/// Votes in favor needed before a proposal can execute
+pub const QUORUM: u64 = 1_000_000;
+
+/// Slots between a proposal's creation and the start of voting (~2 days)
+pub const VOTING_DELAY_SLOTS: u64 = 432_000;
+
+#[program]
+pub mod secure_propose_and_vote {
+    use super::*;
+
+    /// Propose paying `amount` lamports from the treasury to `recipient`
+    pub fn create_proposal(ctx: Context<CreateProposal>, recipient: Pubkey, amount: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.recipient = recipient;
+        proposal.amount = amount;
+        // ✅ Voting opens only after the delay
+        proposal.voting_starts_at = Clock::get()?
+            .slot
+            .checked_add(VOTING_DELAY_SLOTS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// SECURE: Voting Opens `VOTING_DELAY_SLOTS` After Creation
+    ///
+    /// `create_proposal` sets `voting_starts_at` about two days out, and
+    /// `cast_vote` fails before that slot. Proposing, voting and executing
+    /// can no longer share a transaction. In the meantime delegators can
+    /// see the proposal and withdraw their delegation, which `cast_vote`
+    /// reads at the time of the vote.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `proposal.voting_starts_at = clock.slot + VOTING_DELAY_SLOTS`
+    /// 2. `clock.slot >= proposal.voting_starts_at` required to vote
+    /// 3. No vote, and so no quorum, before the delay has passed
+    pub fn cast_vote(ctx: Context<CastVote>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.executed, ErrorCode::AlreadyExecuted);
+
+        // ✅ Everyone has had the delay to react
+        require!(
+            Clock::get()?.slot >= proposal.voting_starts_at,
+            ErrorCode::VotingNotStarted
+        );
+
+        proposal.votes_for = proposal
+            .votes_for
+            .checked_add(ctx.accounts.voter_weight.voting_power)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        ctx.accounts.vote_record.voter = ctx.accounts.voter.key();
+        Ok(())
+    }
+
+    /// Pay out a proposal that has reached quorum
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.executed, ErrorCode::AlreadyExecuted);
+        require!(proposal.votes_for >= QUORUM, ErrorCode::QuorumNotReached);
+        proposal.executed = true;
+
+        ctx.accounts.treasury.sub_lamports(proposal.amount)?;
+        ctx.accounts.recipient.add_lamports(proposal.amount)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(init, payer = proposer, space = 8 + Proposal::LEN)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// Own tokens plus everything currently delegated to `voter`
+    #[account(seeds = [b"voter_weight", voter.key().as_ref()], bump, has_one = voter)]
+    pub voter_weight: Account<'info, VoterWeight>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, has_one = recipient)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut, seeds = [b"treasury"], bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Matched against `proposal.recipient`
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Proposal {
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub voting_starts_at: u64,
+    pub votes_for: u64,
+    pub executed: bool,
+}
+
+impl Proposal {
+    pub const LEN: usize = 32 + // proposer
+        32 + // recipient
+        8 + // amount
+        8 + // voting_starts_at
+        8 + // votes_for
+        1; // executed
+}
+
+#[account]
+pub struct VoterWeight {
+    pub voter: Pubkey,
+    pub voting_power: u64,
+}
+
+#[account]
+pub struct VoteRecord {
+    pub voter: Pubkey,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 32; // voter
+}
+
+#[account]
+pub struct Treasury {}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Proposal already executed")]
+    AlreadyExecuted,
+
+    #[msg("Voting has not started")]
+    VotingNotStarted,
+
+    #[msg("Quorum not reached")]
+    QuorumNotReached,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
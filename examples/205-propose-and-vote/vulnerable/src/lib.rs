@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln20520520520520520520520520520520520520");
+
+/// Votes in favor needed before a proposal can execute
+pub const QUORUM: u64 = 1_000_000;
+
+#[program]
+pub mod vulnerable_propose_and_vote {
+    use super::*;
+
+    /// Propose paying `amount` lamports from the treasury to `recipient`
+    pub fn create_proposal(ctx: Context<CreateProposal>, recipient: Pubkey, amount: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.recipient = recipient;
+        proposal.amount = amount;
+        // ❌ Open for votes from the moment it exists
+        proposal.created_slot = Clock::get()?.slot;
+        Ok(())
+    }
+
+    /// VULNERABILITY: No Delay Between Proposal And Voting
+    ///
+    /// A proposal accepts votes in the same slot - even the same
+    /// transaction - that creates it, and executes as soon as
+    /// `votes_for` reaches `QUORUM`. Voting power is read from each
+    /// voter's current delegation. Nobody else gets a chance to see the
+    /// proposal before it has passed.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Token holders have delegated 1,200,000 votes to Mallory, a
+    ///    long-standing delegate
+    /// 2. One transaction: `create_proposal(mallory, treasury balance)`,
+    ///    `cast_vote`, `execute_proposal`
+    /// 3. Quorum met by her delegated votes alone; the treasury is paid out
+    /// 4. Delegators learn of the proposal from the block explorer
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Delegators cannot revoke, and other holders cannot vote, against
+    ///   a proposal they never had time to see
+    /// - Any holder of quorum-sized voting power, even briefly via a
+    ///   flash-borrowed delegation, controls the treasury
+    pub fn cast_vote(ctx: Context<CastVote>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.executed, ErrorCode::AlreadyExecuted);
+
+        // ❌ No check on when voting opens
+        proposal.votes_for = proposal
+            .votes_for
+            .checked_add(ctx.accounts.voter_weight.voting_power)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        ctx.accounts.vote_record.voter = ctx.accounts.voter.key();
+        Ok(())
+    }
+
+    /// Pay out a proposal that has reached quorum
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.executed, ErrorCode::AlreadyExecuted);
+        require!(proposal.votes_for >= QUORUM, ErrorCode::QuorumNotReached);
+        proposal.executed = true;
+
+        ctx.accounts.treasury.sub_lamports(proposal.amount)?;
+        ctx.accounts.recipient.add_lamports(proposal.amount)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(init, payer = proposer, space = 8 + Proposal::LEN)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// Own tokens plus everything currently delegated to `voter`
+    #[account(seeds = [b"voter_weight", voter.key().as_ref()], bump, has_one = voter)]
+    pub voter_weight: Account<'info, VoterWeight>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, has_one = recipient)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut, seeds = [b"treasury"], bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Matched against `proposal.recipient`
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Proposal {
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub created_slot: u64,
+    pub votes_for: u64,
+    pub executed: bool,
+}
+
+impl Proposal {
+    pub const LEN: usize = 32 + // proposer
+        32 + // recipient
+        8 + // amount
+        8 + // created_slot
+        8 + // votes_for
+        1; // executed
+}
+
+#[account]
+pub struct VoterWeight {
+    pub voter: Pubkey,
+    pub voting_power: u64,
+}
+
+#[account]
+pub struct VoteRecord {
+    pub voter: Pubkey,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 32; // voter
+}
+
+#[account]
+pub struct Treasury {}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Proposal already executed")]
+    AlreadyExecuted,
+
+    #[msg("Quorum not reached")]
+    QuorumNotReached,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
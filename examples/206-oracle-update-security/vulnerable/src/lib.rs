@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln20620620620620620620620620620620620620");
+
+/// Feed prices are lamports per collateral token, 6 decimals
+pub const PRICE_PRECISION: u128 = 1_000_000;
+
+/// Loan-to-value limit (75%)
+pub const MAX_LTV_BPS: u128 = 7_500;
+
+#[program]
+pub mod vulnerable_oracle_update_security {
+    use super::*;
+
+    /// VULNERABILITY: Oracle Replaced Instantly By One Key
+    ///
+    /// `update_oracle` writes any address into `config.oracle` as soon as
+    /// the admin signs. Nothing about the new account is checked and the
+    /// change applies to the very next borrow. The whole market's pricing
+    /// is exactly as safe as one hot key.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory phishes the admin key
+    /// 2. She deploys a feed program she controls reporting 1,000x the
+    ///    collateral price
+    /// 3. One transaction: `update_oracle(attacker_oracle)`, then `borrow`
+    ///    against a small deposit until the vault is empty
+    /// 4. Depositors see the change only after the vault is drained
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Every position is valued through the oracle; replacing it is
+    ///   equivalent to rewriting every balance
+    /// - No window for users, monitoring or a multisig to react
+    pub fn update_oracle(ctx: Context<UpdateOracle>, new_oracle: Pubkey) -> Result<()> {
+        // ❌ Applied immediately, no checks on the new feed
+        ctx.accounts.config.oracle = new_oracle;
+        Ok(())
+    }
+
+    /// Borrow lamports against deposited collateral
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        let price = read_price(&ctx.accounts.oracle)?;
+        let position = &mut ctx.accounts.position;
+
+        let debt = position
+            .debt
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(
+            debt as u128 <= max_borrow(position.collateral, price)?,
+            ErrorCode::InsufficientCollateral
+        );
+        position.debt = debt;
+
+        ctx.accounts.vault.sub_lamports(amount)?;
+        ctx.accounts.borrower.add_lamports(amount)?;
+        Ok(())
+    }
+}
+
+/// Price is stored after the 8-byte discriminator
+pub fn read_price(oracle: &AccountInfo) -> Result<u64> {
+    let data = oracle.try_borrow_data()?;
+    let feed = PriceFeed::deserialize(&mut &data[8..])?;
+    require!(feed.price > 0, ErrorCode::InvalidPrice);
+    Ok(feed.price)
+}
+
+pub fn max_borrow(collateral: u64, price: u64) -> Result<u128> {
+    let value = (collateral as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / PRICE_PRECISION;
+    Ok(value * MAX_LTV_BPS / 10_000)
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateOracle<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump, has_one = admin)]
+    pub config: Account<'info, MarketConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(seeds = [b"config"], bump = config.bump, has_one = oracle)]
+    pub config: Account<'info, MarketConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"position", borrower.key().as_ref()],
+        bump,
+        constraint = position.owner == borrower.key(),
+    )]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: Matched against `config.oracle`
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct MarketConfig {
+    pub admin: Pubkey,
+    pub oracle: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub collateral: u64,
+    pub debt: u64,
+}
+
+#[account]
+pub struct Vault {
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceFeed {
+    pub price: u64,
+    pub last_update_ts: i64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Borrow exceeds collateral value")]
+    InsufficientCollateral,
+
+    #[msg("Invalid oracle price")]
+    InvalidPrice,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur2o72o72o72o72o72o72o72o72o72o72o72o72o");
+
+#[program]
+pub mod secure_staking_owner_signer {
+    use super::*;
+
+    /// Lock `amount` tokens in the pool vault
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.staker_token.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.staker.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let stake = &mut ctx.accounts.stake;
+        stake.staker = ctx.accounts.staker.key();
+        stake.amount = stake
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        stake.bump = ctx.bumps.stake;
+        Ok(())
+    }
+
+    /// SECURE: Stake Owner Must Sign
+    ///
+    /// `staker` is a `Signer` and `has_one = staker` ties it to the
+    /// stake position, so the key check is backed by a signature. The
+    /// destination must also be a token account the staker owns.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `staker: Signer` - the transaction carries the owner's signature
+    /// 2. `has_one = staker` on the stake position
+    /// 3. Stake PDA derived from the signer's key
+    /// 4. `token::authority = staker` on the destination
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        let stake = &mut ctx.accounts.stake;
+        stake.amount = stake
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStake)?;
+
+        let seeds = &[b"pool".as_ref(), &[ctx.accounts.pool.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + StakePosition::LEN,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump,
+    )]
+    pub stake: Account<'info, StakePosition>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint, token::authority = staker)]
+    pub staker_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump = stake.bump,
+        has_one = staker @ ErrorCode::NotStakeOwner,
+    )]
+    pub stake: Account<'info, StakePosition>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    // ✅ Tokens can only go back to the staker
+    #[account(mut, token::mint = vault.mint, token::authority = staker)]
+    pub destination: Account<'info, TokenAccount>,
+
+    // ✅ Owner's signature required
+    pub staker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct StakePool {
+    pub vault: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct StakePosition {
+    pub staker: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl StakePosition {
+    pub const LEN: usize = 32 + // staker
+        8 + // amount
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer does not own this stake")]
+    NotStakeOwner,
+
+    #[msg("Not enough staked")]
+    InsufficientStake,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
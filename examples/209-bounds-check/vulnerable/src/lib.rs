@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+declare_id!("Vuln20920920920920920920920920920920920920");
+
+#[program]
+pub mod vulnerable_bounds_check {
+    use super::*;
+
+    /// Create a raw data store of `size` bytes (write instructions omitted)
+    pub fn create_store(_ctx: Context<CreateStore>, size: u32) -> Result<()> {
+        msg!("Created store of {} bytes", size);
+        Ok(())
+    }
+
+    /// VULNERABILITY: Unchecked Offset And Length On Account Data
+    ///
+    /// `read_data` returns `data[offset..offset + length]` for any
+    /// caller-supplied `offset` and `length`. Neither the sum nor the end
+    /// of the range is checked. Rust does not read past the buffer; the
+    /// checks it does apply - slice bounds, and `overflow-checks` in the
+    /// release profile - end the instruction with a panic instead of an
+    /// error, and take the caller's transaction down with it.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A settlement program reads each order's payload from this store
+    ///    via CPI, using the `(offset, length)` stored in the order
+    /// 2. Mallory places an order with `offset = u32::MAX - 15`,
+    ///    `length = 32`
+    /// 3. Every batch that includes her order panics in `read_data`
+    /// 4. The settlement program cannot catch a panic in a CPI, so no
+    ///    order in the batch settles until hers is removed by hand
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A panic is indistinguishable from any other crash: integrators
+    ///   get "program failed to complete", not `OutOfBounds`
+    /// - Without `overflow-checks` the sum wraps silently, and the same
+    ///   unchecked arithmetic in `unsafe` or `copy_nonoverlapping` code
+    ///   would read or write outside the account
+    pub fn read_data(ctx: Context<ReadData>, offset: u32, length: u32) -> Result<()> {
+        let data = ctx.accounts.store.try_borrow_data()?;
+
+        // ❌ No overflow check on the sum, no bound against data.len()
+        let slice = &data[offset as usize..(offset + length) as usize];
+
+        set_return_data(slice);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(size: u32)]
+pub struct CreateStore<'info> {
+    /// CHECK: Raw bytes, no Anchor layout
+    #[account(init, payer = authority, space = size as usize)]
+    pub store: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReadData<'info> {
+    /// CHECK: Raw bytes owned by this program
+    #[account(owner = crate::ID)]
+    pub store: UncheckedAccount<'info>,
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur21o21o21o21o21o21o21o21o21o21o21o21o21");
+
+/// Allowed drift of `reserve_a * reserve_b` from `k_last`, as a divisor of
+/// `k_last` (0.1%)
+pub const K_TOLERANCE_DIVISOR: u128 = 1_000;
+
+#[program]
+pub mod secure_invariant_equality {
+    use super::*;
+
+    /// SECURE: Invariant Compared Within A Tolerance
+    ///
+    /// The post-swap product may exceed `k_last` by up to 0.1% of `k_last`,
+    /// but never fall below it. Rounding the output down only ever grows the
+    /// product, by less than the new input reserve - 1,000 on a k of 10^12 in
+    /// the example above - while an accounting bug or a manipulated reserve
+    /// moves it by far more.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `reserve_a * reserve_b >= k_last`, so k can never shrink
+    /// 2. `reserve_a * reserve_b - k_last <= TOLERANCE`
+    /// 3. `TOLERANCE = k_last / K_TOLERANCE_DIVISOR`
+    /// 4. Product computed in `u128`, so it cannot overflow
+    /// 5. `k_last` updated after each swap; only upward drift is tolerated,
+    ///    so accumulated drift only ever favours the pool
+    ///
+    /// WHY RELATIVE TO k:
+    /// The drift is a quantity in reserve-product units. A tolerance of
+    /// `max(reserve_a, reserve_b) / 1000` would be a thousandth of the
+    /// largest possible rounding error and reject most valid swaps.
+    pub fn swap(ctx: Context<Swap>, a_to_b: bool, amount_in: u64, min_out: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let (reserve_in, reserve_out) = if a_to_b {
+            (pool.reserve_a, pool.reserve_b)
+        } else {
+            (pool.reserve_b, pool.reserve_a)
+        };
+
+        let amount_out = swap_output(amount_in, reserve_in, reserve_out)?;
+        require!(amount_out >= min_out, ErrorCode::SlippageExceeded);
+
+        execute_swap(ctx, a_to_b, amount_in, amount_out)
+    }
+}
+
+pub fn swap_output(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+    let out = (reserve_out as u128)
+        .checked_mul(amount_in as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / (reserve_in as u128 + amount_in as u128);
+    u64::try_from(out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+fn execute_swap(ctx: Context<Swap>, a_to_b: bool, amount_in: u64, amount_out: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let (user_in, vault_in, vault_out, user_out) = if a_to_b {
+        (&accounts.user_token_a, &accounts.vault_a, &accounts.vault_b, &accounts.user_token_b)
+    } else {
+        (&accounts.user_token_b, &accounts.vault_b, &accounts.vault_a, &accounts.user_token_a)
+    };
+
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: user_in.to_account_info(),
+                to: vault_in.to_account_info(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        amount_in,
+    )?;
+
+    let seeds = &[b"pool".as_ref(), &[accounts.pool.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault_out.to_account_info(),
+                to: user_out.to_account_info(),
+                authority: accounts.pool.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount_out,
+    )?;
+
+    let pool = &mut accounts.pool;
+    if a_to_b {
+        pool.reserve_a += amount_in;
+        pool.reserve_b -= amount_out;
+    } else {
+        pool.reserve_b += amount_in;
+        pool.reserve_a -= amount_out;
+    }
+
+    // ✅ Upward rounding drift accepted, any decrease or large jump is not
+    let k = (pool.reserve_a as u128) * (pool.reserve_b as u128);
+    let tolerance = pool.k_last / K_TOLERANCE_DIVISOR;
+    require!(k >= pool.k_last, ErrorCode::InvariantViolated);
+    require!(k - pool.k_last <= tolerance, ErrorCode::InvariantViolated);
+
+    // Next swap is measured from here; k never decreases across swaps
+    pool.k_last = k;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_a.mint, token::authority = user)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_b.mint, token::authority = user)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    /// `reserve_a * reserve_b` after the last liquidity change
+    pub k_last: u128,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Pool invariant violated")]
+    InvariantViolated,
+
+    #[msg("Output below minimum")]
+    SlippageExceeded,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln21021021021021021021021021021021021021");
+
+#[program]
+pub mod vulnerable_invariant_equality {
+    use super::*;
+
+    /// VULNERABILITY: Exact Equality On A Rounded Invariant
+    ///
+    /// After each swap the pool requires
+    /// `reserve_a * reserve_b == k_last`. The output is rounded down, so
+    /// the new product is the old one plus the division's remainder -
+    /// equal only when the division happens to be exact. Every other swap
+    /// is rejected, though it leaves the pool better off.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Pool at 1,000,000 A / 1,000,000 B; Alice swaps 1,000 A
+    /// 2. `amount_out = 1,000,000,000 / 1,001,000 = 999` (rounded down)
+    /// 3. New product 1,001,000 * 999,001 exceeds `k_last` by 1,000
+    /// 4. `InvariantViolated`: the swap fails. Only amounts that divide
+    ///    exactly go through, and users cannot find them by hand
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The pool is effectively frozen: funds are safe but unusable, and
+    ///   anything relying on it for liquidations or routing fails
+    /// - Integer AMMs accumulate rounding by design; an invariant check
+    ///   must allow for it
+    pub fn swap(ctx: Context<Swap>, a_to_b: bool, amount_in: u64, min_out: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let (reserve_in, reserve_out) = if a_to_b {
+            (pool.reserve_a, pool.reserve_b)
+        } else {
+            (pool.reserve_b, pool.reserve_a)
+        };
+
+        let amount_out = swap_output(amount_in, reserve_in, reserve_out)?;
+        require!(amount_out >= min_out, ErrorCode::SlippageExceeded);
+
+        execute_swap(ctx, a_to_b, amount_in, amount_out)
+    }
+}
+
+pub fn swap_output(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+    let out = (reserve_out as u128)
+        .checked_mul(amount_in as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / (reserve_in as u128 + amount_in as u128);
+    u64::try_from(out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+fn execute_swap(ctx: Context<Swap>, a_to_b: bool, amount_in: u64, amount_out: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let (user_in, vault_in, vault_out, user_out) = if a_to_b {
+        (&accounts.user_token_a, &accounts.vault_a, &accounts.vault_b, &accounts.user_token_b)
+    } else {
+        (&accounts.user_token_b, &accounts.vault_b, &accounts.vault_a, &accounts.user_token_a)
+    };
+
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: user_in.to_account_info(),
+                to: vault_in.to_account_info(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        amount_in,
+    )?;
+
+    let seeds = &[b"pool".as_ref(), &[accounts.pool.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault_out.to_account_info(),
+                to: user_out.to_account_info(),
+                authority: accounts.pool.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount_out,
+    )?;
+
+    let pool = &mut accounts.pool;
+    if a_to_b {
+        pool.reserve_a += amount_in;
+        pool.reserve_b -= amount_out;
+    } else {
+        pool.reserve_b += amount_in;
+        pool.reserve_a -= amount_out;
+    }
+
+    // ❌ Rounding leaves the product a little above k_last
+    let k = (pool.reserve_a as u128) * (pool.reserve_b as u128);
+    require!(k == pool.k_last, ErrorCode::InvariantViolated);
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_a.mint, token::authority = user)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault_b.mint, token::authority = user)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    /// `reserve_a * reserve_b` after the last liquidity change
+    pub k_last: u128,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Pool invariant violated")]
+    InvariantViolated,
+
+    #[msg("Output below minimum")]
+    SlippageExceeded,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
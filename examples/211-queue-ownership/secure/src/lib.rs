@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("Secur21121121121121121121121121121121121121");
+
+/// Delay before a queued instruction may execute (48 hours)
+pub const EXECUTION_DELAY_SECS: i64 = 48 * 60 * 60;
+
+/// Largest number of entries a queue holds
+pub const MAX_ENTRIES: usize = 8;
+
+#[program]
+pub mod secure_queue_ownership {
+    use super::*;
+
+    /// Create an execution queue owned by the signer
+    pub fn create_queue(ctx: Context<CreateQueue>) -> Result<()> {
+        ctx.accounts.queue.authority = ctx.accounts.authority.key();
+        Ok(())
+    }
+
+    /// Queue an instruction for execution after `EXECUTION_DELAY_SECS`
+    pub fn enqueue(ctx: Context<Enqueue>, entry: QueuedInstruction) -> Result<()> {
+        let queue = &mut ctx.accounts.queue;
+        require!(queue.entries.len() < MAX_ENTRIES, ErrorCode::QueueFull);
+
+        let eta = Clock::get()?
+            .unix_timestamp
+            .checked_add(EXECUTION_DELAY_SECS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        queue.entries.push(QueuedInstruction { eta, ..entry });
+        Ok(())
+    }
+
+    /// SECURE: Only The DAO's Queue PDA Is Processed
+    ///
+    /// Queues are PDAs at `[b"queue", authority]`. `process_queue` takes
+    /// the DAO's authority from the `Dao` config and derives the queue
+    /// from it, so the only entries the treasury signs for are the ones
+    /// governance queued. Mallory can still create a queue at her own
+    /// address; the treasury never executes it.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `seeds = [b"queue", authority.key().as_ref()]` on the queue
+    /// 2. `has_one = authority` on the `Dao` config
+    /// 3. One queue per authority, created at its PDA
+    pub fn process_queue<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessQueue<'info>>,
+    ) -> Result<()> {
+        // ✅ Queue derived from the DAO's authority
+        let queue = &mut ctx.accounts.queue;
+        require!(!queue.entries.is_empty(), ErrorCode::QueueEmpty);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= queue.entries[0].eta, ErrorCode::NotReady);
+        let entry = queue.entries.remove(0);
+
+        execute_entry(&entry, &ctx.accounts.treasury, ctx.bumps.treasury, ctx.remaining_accounts)
+    }
+}
+
+/// Invoke `entry` with the treasury PDA as signer
+fn execute_entry<'info>(
+    entry: &QueuedInstruction,
+    treasury: &UncheckedAccount<'info>,
+    treasury_bump: u8,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let accounts = entry
+        .accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.pubkey,
+            is_signer: a.is_signer,
+            is_writable: a.is_writable,
+        })
+        .collect();
+    let ix = Instruction {
+        program_id: entry.program_id,
+        accounts,
+        data: entry.data.clone(),
+    };
+
+    let mut infos = remaining_accounts.to_vec();
+    infos.push(treasury.to_account_info());
+
+    invoke_signed(&ix, &infos, &[&[b"treasury", &[treasury_bump]]])?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateQueue<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Queue::space(MAX_ENTRIES),
+        seeds = [b"queue", authority.key().as_ref()],
+        bump,
+    )]
+    pub queue: Account<'info, Queue>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Enqueue<'info> {
+    #[account(mut, seeds = [b"queue", authority.key().as_ref()], bump, has_one = authority)]
+    pub queue: Account<'info, Queue>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessQueue<'info> {
+    #[account(seeds = [b"dao"], bump, has_one = authority)]
+    pub dao: Account<'info, Dao>,
+
+    /// CHECK: The DAO's governance authority, matched by `has_one`
+    pub authority: UncheckedAccount<'info>,
+
+    // ✅ The program's own queue for that authority
+    #[account(mut, seeds = [b"queue", authority.key().as_ref()], bump)]
+    pub queue: Account<'info, Queue>,
+
+    /// CHECK: PDA that owns the treasury's token accounts
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub cranker: Signer<'info>,
+    // remaining_accounts: accounts referenced by the front entry
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Dao {
+    /// Governance PDA that queues approved proposals
+    pub authority: Pubkey,
+}
+
+#[account]
+pub struct Queue {
+    pub authority: Pubkey,
+    pub entries: Vec<QueuedInstruction>,
+}
+
+impl Queue {
+    pub fn space(entries: usize) -> usize {
+        32 + // authority
+        4 + entries * QueuedInstruction::MAX_SIZE // entries
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct QueuedInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<QueuedAccountMeta>,
+    pub data: Vec<u8>,
+    /// Set by `enqueue`; the caller's value is ignored
+    pub eta: i64,
+}
+
+impl QueuedInstruction {
+    /// Up to 8 accounts and 64 bytes of data
+    pub const MAX_SIZE: usize = 32 + // program_id
+        4 + 8 * QueuedAccountMeta::SIZE + // accounts
+        4 + 64 + // data
+        8; // eta
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct QueuedAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl QueuedAccountMeta {
+    pub const SIZE: usize = 32 + // pubkey
+        1 + // is_signer
+        1; // is_writable
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Queue is full")]
+    QueueFull,
+
+    #[msg("Queue is empty")]
+    QueueEmpty,
+
+    #[msg("Entry is not ready to execute")]
+    NotReady,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+declare_id!("Vuln21121121121121121121121121121121121121");
+
+/// Delay before a queued instruction may execute (48 hours)
+pub const EXECUTION_DELAY_SECS: i64 = 48 * 60 * 60;
+
+/// Largest number of entries a queue holds
+pub const MAX_ENTRIES: usize = 8;
+
+#[program]
+pub mod vulnerable_queue_ownership {
+    use super::*;
+
+    /// Create an execution queue owned by the signer
+    pub fn create_queue(ctx: Context<CreateQueue>) -> Result<()> {
+        ctx.accounts.queue.authority = ctx.accounts.authority.key();
+        Ok(())
+    }
+
+    /// Queue an instruction for execution after `EXECUTION_DELAY_SECS`
+    pub fn enqueue(ctx: Context<Enqueue>, entry: QueuedInstruction) -> Result<()> {
+        let queue = &mut ctx.accounts.queue;
+        require!(queue.entries.len() < MAX_ENTRIES, ErrorCode::QueueFull);
+
+        let eta = Clock::get()?
+            .unix_timestamp
+            .checked_add(EXECUTION_DELAY_SECS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        queue.entries.push(QueuedInstruction { eta, ..entry });
+        Ok(())
+    }
+
+    /// VULNERABILITY: Queue Account Not Tied To The Protocol
+    ///
+    /// Anyone may crank `process_queue`, which pops the front entry and
+    /// executes it with the treasury PDA's signature. The queue is any
+    /// `Queue` account this program owns - and `create_queue` hands one
+    /// to whoever asks. The treasury signs whatever the caller queued.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The DAO's queue is populated only through governance votes
+    /// 2. Mallory calls `create_queue` and `enqueue`s an SPL Token
+    ///    transfer from the treasury to herself
+    /// 3. 48 hours later she calls `process_queue` with her queue
+    /// 4. The treasury PDA signs her transfer
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Owner and discriminator checks prove the account is a `Queue`,
+    ///   not that it is the DAO's queue
+    /// - The timelock and the vote both live on the DAO's queue; a
+    ///   different queue bypasses both
+    pub fn process_queue<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessQueue<'info>>,
+    ) -> Result<()> {
+        // ❌ Whichever queue the caller passed
+        let queue = &mut ctx.accounts.queue;
+        require!(!queue.entries.is_empty(), ErrorCode::QueueEmpty);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= queue.entries[0].eta, ErrorCode::NotReady);
+        let entry = queue.entries.remove(0);
+
+        execute_entry(&entry, &ctx.accounts.treasury, ctx.bumps.treasury, ctx.remaining_accounts)
+    }
+}
+
+/// Invoke `entry` with the treasury PDA as signer
+fn execute_entry<'info>(
+    entry: &QueuedInstruction,
+    treasury: &UncheckedAccount<'info>,
+    treasury_bump: u8,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let accounts = entry
+        .accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.pubkey,
+            is_signer: a.is_signer,
+            is_writable: a.is_writable,
+        })
+        .collect();
+    let ix = Instruction {
+        program_id: entry.program_id,
+        accounts,
+        data: entry.data.clone(),
+    };
+
+    let mut infos = remaining_accounts.to_vec();
+    infos.push(treasury.to_account_info());
+
+    invoke_signed(&ix, &infos, &[&[b"treasury", &[treasury_bump]]])?;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateQueue<'info> {
+    // ❌ Keypair account - any number of queues, created by anyone
+    #[account(init, payer = authority, space = 8 + Queue::space(MAX_ENTRIES))]
+    pub queue: Account<'info, Queue>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Enqueue<'info> {
+    #[account(mut, has_one = authority)]
+    pub queue: Account<'info, Queue>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessQueue<'info> {
+    #[account(mut)]
+    pub queue: Account<'info, Queue>,
+
+    /// CHECK: PDA that owns the treasury's token accounts
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub cranker: Signer<'info>,
+    // remaining_accounts: accounts referenced by the front entry
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Queue {
+    pub authority: Pubkey,
+    pub entries: Vec<QueuedInstruction>,
+}
+
+impl Queue {
+    pub fn space(entries: usize) -> usize {
+        32 + // authority
+        4 + entries * QueuedInstruction::MAX_SIZE // entries
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct QueuedInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<QueuedAccountMeta>,
+    pub data: Vec<u8>,
+    /// Set by `enqueue`; the caller's value is ignored
+    pub eta: i64,
+}
+
+impl QueuedInstruction {
+    /// Up to 8 accounts and 64 bytes of data
+    pub const MAX_SIZE: usize = 32 + // program_id
+        4 + 8 * QueuedAccountMeta::SIZE + // accounts
+        4 + 64 + // data
+        8; // eta
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct QueuedAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl QueuedAccountMeta {
+    pub const SIZE: usize = 32 + // pubkey
+        1 + // is_signer
+        1; // is_writable
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Queue is full")]
+    QueueFull,
+
+    #[msg("Queue is empty")]
+    QueueEmpty,
+
+    #[msg("Entry is not ready to execute")]
+    NotReady,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur21221221221221221221221221221221221221");
+
+#[program]
+pub mod secure_approve_race {
+    use super::*;
+
+    /// SECURE: Tokens Moved And Credited In One Instruction
+    ///
+    /// `deposit` transfers the tokens into the vault and credits the
+    /// user in the same instruction. There is no approval to revoke and
+    /// no window in which the credit exists without the tokens. The user
+    /// signs this instruction, so the transfer runs on their authority
+    /// directly - a delegate is not needed at all.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Transfer and credit are atomic: both happen or neither does
+    /// 2. Balance credited only after the transfer CPI succeeds
+    /// 3. No lingering delegation on the user's token account
+    /// 4. No keeper step, so nothing pending for anyone to front-run
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        // ✅ Tokens move first, on the user's own signature
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    to: ctx.accounts.vault_token.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.owner = ctx.accounts.user.key();
+        deposit.balance = deposit
+            .balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Withdraw from the deposit balance
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.balance = deposit
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+
+        let seeds = &[b"vault".as_ref(), &[ctx.accounts.vault.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + DepositRecord::LEN,
+        seeds = [b"deposit", user.key().as_ref()],
+        bump,
+    )]
+    pub deposit: Account<'info, DepositRecord>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, seeds = [b"deposit", user.key().as_ref()], bump)]
+    pub deposit: Account<'info, DepositRecord>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub mint: Pubkey,
+    pub vault_token: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct DepositRecord {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
+
+impl DepositRecord {
+    pub const LEN: usize = 32 + // owner
+        8; // balance
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient deposit balance")]
+    InsufficientBalance,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
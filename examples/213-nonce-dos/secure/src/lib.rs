@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use std::collections::BTreeMap;
+
+declare_id!("Secur21321321321321321321321321321321321321");
+
+/// Nonces older than this many slots are rejected and pruned (~60 seconds,
+/// the lifetime of a recent blockhash)
+pub const NONCE_EXPIRY_SLOTS: u64 = 150;
+
+#[program]
+pub mod secure_nonce_dos {
+    use super::*;
+
+    /// SECURE: Expiring Per-User Nonces
+    ///
+    /// The nonce is the slot of the client's recent blockhash. It must
+    /// lie within the last `NONCE_EXPIRY_SLOTS`, and used nonces are kept
+    /// in a slot-keyed `BTreeMap` on the user's own guard PDA. Each
+    /// submission prunes entries older than the expiry window - they can
+    /// no longer be replayed anyway - so a guard never holds more than
+    /// `NONCE_EXPIRY_SLOTS` entries and never fills up.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `current_slot - NONCE_EXPIRY_SLOTS < nonce_slot <= current_slot`
+    /// 2. Expired entries removed with `split_off` before the check
+    /// 3. Guard per user: Mallory can only fill her own
+    /// 4. Size bounded by the expiry window, not by usage
+    ///
+    /// NOTE:
+    /// One order per user per slot. Clients that need more per slot
+    /// should key by `(slot, sequence)` instead.
+    pub fn submit_order(ctx: Context<SubmitOrder>, nonce_slot: u64, amount: u64) -> Result<()> {
+        let current_slot = Clock::get()?.slot;
+        let oldest_valid = current_slot.saturating_sub(NONCE_EXPIRY_SLOTS) + 1;
+
+        // ✅ Only recent nonces are accepted
+        require!(
+            nonce_slot >= oldest_valid && nonce_slot <= current_slot,
+            ErrorCode::NonceExpired
+        );
+
+        // ✅ Drop everything that has expired
+        let guard = &mut ctx.accounts.replay_guard;
+        guard.used = guard.used.split_off(&oldest_valid);
+
+        require!(!guard.used.contains_key(&nonce_slot), ErrorCode::NonceReused);
+        guard.used.insert(nonce_slot, true);
+
+        emit!(OrderSubmitted {
+            user: ctx.accounts.user.key(),
+            amount,
+        });
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SubmitOrder<'info> {
+    // ✅ One guard per user
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + ReplayGuard::LEN,
+        seeds = [b"replay_guard", user.key().as_ref()],
+        bump,
+    )]
+    pub replay_guard: Account<'info, ReplayGuard>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct ReplayGuard {
+    /// Slots of nonces used within the expiry window
+    pub used: BTreeMap<u64, bool>,
+}
+
+impl ReplayGuard {
+    pub const LEN: usize = 4 + NONCE_EXPIRY_SLOTS as usize * (8 + 1); // used
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct OrderSubmitted {
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Nonce already used")]
+    NonceReused,
+
+    #[msg("Nonce is outside the expiry window")]
+    NonceExpired,
+}
@@ -0,0 +1,53 @@
+#[tokio::test]
+async fn test_replay_list_filled_exploit() {
+    println!("\n=== EXPLOIT: Shared Replay List Filled With Dummy Nonces ===\n");
+
+    let mallory = Keypair::new();
+    let alice = Keypair::new();
+    initialize_replay_guard().await;
+
+    println!("1. Mallory submits 1,000 orders with random nonces");
+    for _ in 0..MAX_PROCESSED {
+        submit_order(&mallory, rand::random::<[u8; 32]>(), 1).await.unwrap();
+    }
+
+    println!("\n2. Alice submits an order with a fresh blockhash");
+    let result = submit_order(&alice, get_latest_blockhash().await.to_bytes(), 500).await;
+    // Vulnerable: the list never shrinks
+    assert!(result.is_err());
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Order submission closed to everyone: ReplayGuardFull");
+}
+
+#[tokio::test]
+async fn test_expiring_per_user_nonces() {
+    println!("\n=== SECURITY: Per-User Nonces Pruned After Expiry ===\n");
+
+    let mallory = Keypair::new();
+    let alice = Keypair::new();
+
+    println!("1. Mallory submits an order every slot for 1,000 slots");
+    for _ in 0..1_000 {
+        submit_order(&mallory, get_slot().await, 1).await.unwrap();
+        warp_to_slot(get_slot().await + 1).await;
+    }
+    let guard = get_replay_guard(&mallory.pubkey()).await;
+    assert!(guard.used.len() <= NONCE_EXPIRY_SLOTS as usize);
+    println!("   ✓ Her guard holds at most {} entries", NONCE_EXPIRY_SLOTS);
+
+    println!("\n2. Alice submits an order");
+    let slot = get_slot().await;
+    submit_order(&alice, slot, 500).await.unwrap();
+    println!("   ✓ Unaffected by Mallory's orders");
+
+    println!("\n3. Alice's client retries the same order");
+    let result = submit_order(&alice, slot, 500).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: NonceReused");
+
+    println!("\n4. Nonce from 200 slots ago");
+    let result = submit_order(&alice, slot - 200, 500).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: NonceExpired");
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln21321321321321321321321321321321321321");
+
+/// Hashes the replay guard account has room for
+pub const MAX_PROCESSED: usize = 1_000;
+
+#[program]
+pub mod vulnerable_nonce_dos {
+    use super::*;
+
+    /// VULNERABILITY: Replay List That Only Grows
+    ///
+    /// Clients retry order submissions, so each order carries a nonce -
+    /// the recent blockhash the client built it with - and the program
+    /// rejects nonces it has seen. Seen nonces go into one shared
+    /// `processed_hashes` list that is never pruned. The account has room
+    /// for `MAX_PROCESSED` entries; once it is full, every order fails.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory submits 1,000 tiny orders, each with a random 32-byte
+    ///    "nonce" - nothing checks it is a real blockhash
+    /// 2. At ~5,000 lamports per transaction the list is full for a few
+    ///    SOL
+    /// 3. Every later `submit_order` fails with `ReplayGuardFull`
+    /// 4. The order book is closed to everyone until an upgrade
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Shared, bounded storage that any user can grow is a DoS lever
+    /// - Nonces never expire, so nothing is ever freed
+    pub fn submit_order(ctx: Context<SubmitOrder>, nonce: [u8; 32], amount: u64) -> Result<()> {
+        let guard = &mut ctx.accounts.replay_guard;
+        require!(!guard.processed_hashes.contains(&nonce), ErrorCode::NonceReused);
+
+        // ❌ Appended forever, by anyone, into one account
+        require!(guard.processed_hashes.len() < MAX_PROCESSED, ErrorCode::ReplayGuardFull);
+        guard.processed_hashes.push(nonce);
+
+        emit!(OrderSubmitted {
+            user: ctx.accounts.user.key(),
+            amount,
+        });
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SubmitOrder<'info> {
+    // ❌ One list shared by all users
+    #[account(mut, seeds = [b"replay_guard"], bump)]
+    pub replay_guard: Account<'info, ReplayGuard>,
+
+    pub user: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct ReplayGuard {
+    pub processed_hashes: Vec<[u8; 32]>,
+}
+
+impl ReplayGuard {
+    pub const LEN: usize = 4 + MAX_PROCESSED * 32; // processed_hashes
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct OrderSubmitted {
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Nonce already used")]
+    NonceReused,
+
+    #[msg("Replay guard is full")]
+    ReplayGuardFull,
+}
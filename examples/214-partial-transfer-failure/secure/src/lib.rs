@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur21421421421421421421421421421421421421");
+
+/// Most recipients one split may pay
+pub const MAX_RECIPIENTS: usize = 10;
+
+#[program]
+pub mod secure_partial_transfer_failure {
+    use super::*;
+
+    /// SECURE: Shares Computed And Checked Before Any Transfer
+    ///
+    /// `compute_shares` gives each recipient `amount / n` and hands the
+    /// remainder out one unit at a time to the first `amount % n`. The
+    /// shares are summed with checked arithmetic and must equal `amount`
+    /// exactly before the first transfer is made. The vault pays out
+    /// precisely what was debited from the escrow.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. All shares computed up front
+    /// 2. `sum(shares) == amount` required before any transfer
+    /// 3. Remainder distributed, never rounded up
+    /// 4. Escrow debited by the same `amount` that is paid out
+    pub fn split_payment<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SplitPayment<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        let recipients = ctx.remaining_accounts;
+        require!(
+            !recipients.is_empty() && recipients.len() <= MAX_RECIPIENTS,
+            ErrorCode::InvalidRecipientCount
+        );
+
+        let escrow = &mut ctx.accounts.escrow;
+        require!(amount <= escrow.balance, ErrorCode::InsufficientBalance);
+        escrow.balance -= amount;
+
+        // ✅ Every transfer is known and checked before the first one runs
+        let shares = compute_shares(amount, recipients.len())?;
+        for (recipient, share) in recipients.iter().zip(shares) {
+            transfer_from_vault(ctx.accounts, recipient, share)?;
+        }
+        Ok(())
+    }
+}
+
+/// Split `amount` into `n` shares that differ by at most one and sum to
+/// exactly `amount`
+pub fn compute_shares(amount: u64, n: usize) -> Result<Vec<u64>> {
+    let base = amount / n as u64;
+    let remainder = (amount % n as u64) as usize;
+    let shares: Vec<u64> = (0..n)
+        .map(|i| if i < remainder { base + 1 } else { base })
+        .collect();
+
+    let total = shares
+        .iter()
+        .try_fold(0u64, |acc, share| acc.checked_add(*share))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(total == amount, ErrorCode::SharesMismatch);
+    Ok(shares)
+}
+
+fn transfer_from_vault<'info>(
+    accounts: &SplitPayment<'info>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[b"vault".as_ref(), &[accounts.vault.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.vault_token.to_account_info(),
+                to: recipient.clone(),
+                authority: accounts.vault.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SplitPayment<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"escrow", user.key().as_ref()], bump)]
+    pub escrow: Account<'info, Escrow>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: recipient token accounts
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub vault_token: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Escrow {
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Recipient count out of range")]
+    InvalidRecipientCount,
+
+    #[msg("Insufficient escrow balance")]
+    InsufficientBalance,
+
+    #[msg("Shares do not sum to the amount")]
+    SharesMismatch,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
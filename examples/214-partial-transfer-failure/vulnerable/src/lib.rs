@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln21421421421421421421421421421421421421");
+
+/// Most recipients one split may pay
+pub const MAX_RECIPIENTS: usize = 10;
+
+#[program]
+pub mod vulnerable_partial_transfer_failure {
+    use super::*;
+
+    /// VULNERABILITY: Shares Transferred Without Checking Their Sum
+    ///
+    /// `split_payment` pays `amount` from the user's escrow balance to
+    /// the token accounts in `remaining_accounts`. It checks
+    /// `amount <= escrow.balance`, then sends each recipient
+    /// `amount.div_ceil(n)`, rounded up so nobody is short-changed. The
+    /// shares add up to more than `amount` whenever the division is
+    /// inexact, and the difference comes out of the shared vault.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory escrows 2 tokens
+    /// 2. She calls `split_payment(2)` with three of her own accounts
+    /// 3. Check passes: 2 <= 2. Each account receives `ceil(2 / 3) = 1`
+    /// 4. 3 tokens leave the vault, 2 are debited: +1 per call, and the
+    ///    gain grows with the recipient count
+    /// 5. When the vault runs dry, the last transfer fails and the
+    ///    honest user's split reverts
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - The checked amount and the transferred amounts are different
+    ///   numbers; only one of them was validated
+    /// - Rounding error is paid by the other users of the vault
+    pub fn split_payment<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SplitPayment<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        let recipients = ctx.remaining_accounts;
+        require!(
+            !recipients.is_empty() && recipients.len() <= MAX_RECIPIENTS,
+            ErrorCode::InvalidRecipientCount
+        );
+
+        let escrow = &mut ctx.accounts.escrow;
+        require!(amount <= escrow.balance, ErrorCode::InsufficientBalance);
+        escrow.balance -= amount;
+
+        // ❌ Sum of shares never compared with `amount`
+        let share = amount.div_ceil(recipients.len() as u64);
+        for recipient in recipients {
+            transfer_from_vault(ctx.accounts, recipient, share)?;
+        }
+        Ok(())
+    }
+}
+
+fn transfer_from_vault<'info>(
+    accounts: &SplitPayment<'info>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[b"vault".as_ref(), &[accounts.vault.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.vault_token.to_account_info(),
+                to: recipient.clone(),
+                authority: accounts.vault.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        amount,
+    )
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SplitPayment<'info> {
+    #[account(seeds = [b"vault"], bump = vault.bump, has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"escrow", user.key().as_ref()], bump)]
+    pub escrow: Account<'info, Escrow>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: recipient token accounts
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub vault_token: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Escrow {
+    pub balance: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Recipient count out of range")]
+    InvalidRecipientCount,
+
+    #[msg("Insufficient escrow balance")]
+    InsufficientBalance,
+}
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("Secur21521521521521521521521521521521521521");
+
+/// Market prices are USD with 6 decimals
+pub const PRICE_PRECISION: u128 = 1_000_000;
+
+/// Loan-to-value limit (75%)
+pub const MAX_LTV_BPS: u128 = 7_500;
+
+#[program]
+pub mod secure_circular_lending {
+    use super::*;
+
+    /// SECURE: Collateral And Debt Must Be Different Assets
+    ///
+    /// `open_position` rejects a position whose collateral mint equals its
+    /// debt mint. Both mints are fixed on the position at creation, and
+    /// `deposit` and `borrow` derive their markets from them, so a
+    /// position can never be switched to a same-asset pair later.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `collateral_mint != debt_mint` in `open_position`
+    /// 2. Markets for `deposit` / `borrow` derived from the stored mints
+    ///
+    /// NOTE:
+    /// Looping through two assets (borrow Y against X, swap Y to X,
+    /// deposit) is still possible and is bounded by the LTV and by swap
+    /// costs; rewards programs often exclude borrowed-against deposits
+    /// for that reason.
+    pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+        // ✅ No borrowing an asset against itself
+        require!(
+            ctx.accounts.collateral_mint.key() != ctx.accounts.debt_mint.key(),
+            ErrorCode::CircularLending
+        );
+
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.collateral_mint = ctx.accounts.collateral_mint.key();
+        position.debt_mint = ctx.accounts.debt_mint.key();
+        Ok(())
+    }
+
+    /// Add collateral to a position
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner_token.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let position = &mut ctx.accounts.position;
+        position.collateral = position
+            .collateral
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let market = &mut ctx.accounts.market;
+        market.total_deposits = market
+            .total_deposits
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Borrow the debt asset against the position's collateral
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        let debt = position
+            .debt
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let collateral_value = value(position.collateral, ctx.accounts.collateral_market.price)?;
+        let debt_value = value(debt, ctx.accounts.debt_market.price)?;
+        require!(
+            debt_value * 10_000 <= collateral_value * MAX_LTV_BPS,
+            ErrorCode::InsufficientCollateral
+        );
+        position.debt = debt;
+
+        let mint = ctx.accounts.debt_market.mint;
+        let seeds = &[b"market".as_ref(), mint.as_ref(), &[ctx.accounts.debt_market.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.debt_vault.to_account_info(),
+                    to: ctx.accounts.owner_token.to_account_info(),
+                    authority: ctx.accounts.debt_market.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+/// USD value of `amount` at `price`
+pub fn value(amount: u64, price: u64) -> Result<u128> {
+    Ok((amount as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / PRICE_PRECISION)
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Position::LEN,
+        seeds = [b"position", owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(seeds = [b"market", collateral_mint.key().as_ref()], bump = collateral_market.bump)]
+    pub collateral_market: Account<'info, Market>,
+
+    #[account(seeds = [b"market", debt_mint.key().as_ref()], bump = debt_market.bump)]
+    pub debt_market: Account<'info, Market>,
+
+    pub collateral_mint: Account<'info, Mint>,
+    pub debt_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, seeds = [b"position", owner.key().as_ref()], bump)]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        mut,
+        seeds = [b"market", position.collateral_mint.as_ref()],
+        bump = market.bump,
+        has_one = vault @ ErrorCode::WrongVault,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = position.collateral_mint, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(mut, seeds = [b"position", owner.key().as_ref()], bump)]
+    pub position: Account<'info, Position>,
+
+    #[account(seeds = [b"market", position.collateral_mint.as_ref()], bump = collateral_market.bump)]
+    pub collateral_market: Account<'info, Market>,
+
+    #[account(seeds = [b"market", position.debt_mint.as_ref()], bump = debt_market.bump)]
+    pub debt_market: Account<'info, Market>,
+
+    #[account(mut, address = debt_market.vault @ ErrorCode::WrongVault)]
+    pub debt_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = position.debt_mint, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Market {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    /// Updated by the oracle crank
+    pub price: u64,
+    pub total_deposits: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub debt_mint: Pubkey,
+    pub collateral: u64,
+    pub debt: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        32 + // collateral_mint
+        32 + // debt_mint
+        8 + // collateral
+        8; // debt
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Collateral and debt cannot be the same asset")]
+    CircularLending,
+
+    #[msg("Borrow exceeds collateral value")]
+    InsufficientCollateral,
+
+    #[msg("Vault does not belong to the market")]
+    WrongVault,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
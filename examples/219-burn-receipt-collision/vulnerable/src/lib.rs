@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+declare_id!("Vuln21921921921921921921921921921921921921");
+
+#[program]
+pub mod vulnerable_burn_receipt_collision {
+    use super::*;
+
+    /// VULNERABILITY: Burn Receipts Keyed By Transaction Hash Alone
+    ///
+    /// Guardians observe burns on the bridge's source chains and post a
+    /// `BurnReceipt` for each at `[b"burn", tx_hash]`; the receipt's
+    /// existence is the replay guard. The PDA itself cannot be collided -
+    /// finding two seeds with the same SHA-256-derived address is
+    /// infeasible. The key can: a transaction hash is unique on one
+    /// chain, not across chains. A legacy (pre-EIP-155) EVM transaction
+    /// carries no chain id, so the same signed bytes - with the same
+    /// hash - can be replayed on every EVM chain the bridge supports.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice burns 100,000 tokens on chain A with a legacy-signed
+    ///    transaction; she also holds the token on chain B
+    /// 2. Mallory rebroadcasts Alice's signed transaction on chain B,
+    ///    where it burns another 100,000 of Alice's tokens
+    /// 3. Chain B finalizes first; its receipt takes `[b"burn", tx_hash]`
+    /// 4. The receipt for chain A fails: the address is already in use
+    /// 5. Alice burned 200,000 tokens and can mint 100,000 back
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A replay key must identify the event across every source the
+    ///   bridge accepts, not only within one chain
+    /// - Whoever lands a receipt first decides which burn counts
+    pub fn post_burn_receipt(
+        ctx: Context<PostBurnReceipt>,
+        chain_id: u64,
+        tx_hash: [u8; 32],
+        amount: u64,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        // ❌ Receipt address depends on tx_hash only
+        let receipt = &mut ctx.accounts.burn_receipt;
+        receipt.chain_id = chain_id;
+        receipt.tx_hash = tx_hash;
+        receipt.amount = amount;
+        receipt.recipient = recipient;
+        Ok(())
+    }
+
+    /// Mint the wrapped tokens for a posted burn
+    pub fn mint_from_receipt(ctx: Context<MintFromReceipt>) -> Result<()> {
+        let receipt = &mut ctx.accounts.burn_receipt;
+        require!(!receipt.claimed, ErrorCode::AlreadyClaimed);
+        receipt.claimed = true;
+
+        let seeds = &[b"bridge".as_ref(), &[ctx.accounts.bridge.bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.wrapped_mint.to_account_info(),
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: ctx.accounts.bridge.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            receipt.amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, tx_hash: [u8; 32])]
+pub struct PostBurnReceipt<'info> {
+    #[account(seeds = [b"bridge"], bump = bridge.bump, has_one = guardian)]
+    pub bridge: Account<'info, Bridge>,
+
+    #[account(
+        init,
+        payer = guardian,
+        space = 8 + BurnReceipt::LEN,
+        seeds = [b"burn", tx_hash.as_ref()],
+        bump,
+    )]
+    pub burn_receipt: Account<'info, BurnReceipt>,
+
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintFromReceipt<'info> {
+    #[account(seeds = [b"bridge"], bump = bridge.bump, has_one = wrapped_mint)]
+    pub bridge: Account<'info, Bridge>,
+
+    #[account(mut)]
+    pub burn_receipt: Account<'info, BurnReceipt>,
+
+    #[account(mut)]
+    pub wrapped_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = wrapped_mint, token::authority = burn_receipt.recipient)]
+    pub recipient_token: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Bridge {
+    pub guardian: Pubkey,
+    pub wrapped_mint: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct BurnReceipt {
+    pub chain_id: u64,
+    pub tx_hash: [u8; 32],
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub claimed: bool,
+}
+
+impl BurnReceipt {
+    pub const LEN: usize = 8 + // chain_id
+        32 + // tx_hash
+        8 + // amount
+        32 + // recipient
+        1; // claimed
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Receipt already claimed")]
+    AlreadyClaimed,
+}
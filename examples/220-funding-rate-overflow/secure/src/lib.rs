@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur22o22o22o22o22o22o22o22o22o22o22o22o22");
+
+/// Funding rates are fractions of notional scaled by 1e18 (WAD)
+pub const FUNDING_PRECISION: i128 = 1_000_000_000_000_000_000;
+
+/// Largest funding rate per epoch, either direction (1%)
+pub const MAX_FUNDING_RATE: i64 = 10_000_000_000_000_000;
+
+/// Magnitude at which the accumulator is settled and reset
+pub const FUNDING_CAP: i64 = i64::MAX / 2;
+
+#[program]
+pub mod secure_funding_rate_overflow {
+    use super::*;
+
+    /// SECURE: Checked, Capped Accumulator With Settlement Generations
+    ///
+    /// The rate is added with `checked_add`. When the cumulative value
+    /// would pass `FUNDING_CAP` in either direction, the market emits
+    /// `FundingSettled`, stores the final value as `settled_funding`,
+    /// bumps `funding_generation` and restarts the accumulator from zero.
+    /// Positions opened in the previous generation settle against the
+    /// stored value plus the new accumulator, so no funding is lost
+    /// across the reset.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `checked_add` - never a silent wrap
+    /// 2. `|funding_rate_cumulative| <= i64::MAX / 2`, leaving headroom
+    ///    for the differences `settle_funding` takes
+    /// 3. Reset recorded on-chain and in an event
+    /// 4. Position deltas computed in `i128`
+    ///
+    /// LIMITATION:
+    /// Only the previous generation's final value is kept. A position
+    /// must settle at least once per generation - at the rate clamp,
+    /// roughly every 460 epochs - or `settle_funding` rejects it as
+    /// stale; keepers settle idle positions well before that.
+    pub fn update_funding(ctx: Context<UpdateFunding>, rate: i64) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+        let market = &mut ctx.accounts.market;
+        require!(epoch > market.last_funding_epoch, ErrorCode::AlreadyUpdated);
+        require!(rate.abs() <= MAX_FUNDING_RATE, ErrorCode::RateOutOfRange);
+
+        let next = market
+            .funding_rate_cumulative
+            .checked_add(rate)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        if next.unsigned_abs() > FUNDING_CAP as u64 {
+            // ✅ Close out this generation and restart from zero
+            emit!(FundingSettled {
+                generation: market.funding_generation,
+                settled_funding: market.funding_rate_cumulative,
+                epoch,
+            });
+            market.settled_funding = market.funding_rate_cumulative;
+            market.funding_generation = market
+                .funding_generation
+                .checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            market.funding_rate_cumulative = rate;
+        } else {
+            market.funding_rate_cumulative = next;
+        }
+        market.last_funding_epoch = epoch;
+        Ok(())
+    }
+
+    /// Apply funding accrued since the position's last settlement
+    pub fn settle_funding(ctx: Context<SettleFunding>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let position = &mut ctx.accounts.position;
+
+        // ✅ Accrued funding, carried across at most one reset
+        let current = market.funding_rate_cumulative as i128;
+        let delta = if position.entry_generation == market.funding_generation {
+            current - position.entry_funding as i128
+        } else if position.entry_generation + 1 == market.funding_generation {
+            market.settled_funding as i128 - position.entry_funding as i128 + current
+        } else {
+            return err!(ErrorCode::PositionTooStale);
+        };
+        let payment = funding_payment(position.size, delta)?;
+        position.collateral = position
+            .collateral
+            .checked_sub(payment)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        position.entry_funding = market.funding_rate_cumulative;
+        position.entry_generation = market.funding_generation;
+        Ok(())
+    }
+}
+
+/// Funding owed by a position of signed notional `size` over `delta`
+pub fn funding_payment(size: i64, delta: i128) -> Result<i64> {
+    let payment = (size as i128)
+        .checked_mul(delta)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / FUNDING_PRECISION;
+    i64::try_from(payment).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateFunding<'info> {
+    #[account(mut, has_one = funding_authority)]
+    pub market: Account<'info, Market>,
+
+    pub funding_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleFunding<'info> {
+    pub market: Account<'info, Market>,
+
+    #[account(mut, has_one = market)]
+    pub position: Account<'info, Position>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Market {
+    pub funding_authority: Pubkey,
+    /// Sum of every epoch's funding rate, WAD scaled
+    pub funding_rate_cumulative: i64,
+    /// Incremented each time the accumulator is reset
+    pub funding_generation: u64,
+    /// Final `funding_rate_cumulative` of the previous generation
+    pub settled_funding: i64,
+    pub last_funding_epoch: u64,
+}
+
+#[account]
+pub struct Position {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    /// Signed notional: positive long, negative short
+    pub size: i64,
+    pub collateral: i64,
+    /// `funding_rate_cumulative` at the last settlement
+    pub entry_funding: i64,
+    pub entry_generation: u64,
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct FundingSettled {
+    pub generation: u64,
+    pub settled_funding: i64,
+    pub epoch: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Funding already updated this epoch")]
+    AlreadyUpdated,
+
+    #[msg("Funding rate out of range")]
+    RateOutOfRange,
+
+    #[msg("Position has not settled since before the last funding reset")]
+    PositionTooStale,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln22022022022022022022022022022022022022");
+
+/// Funding rates are fractions of notional scaled by 1e18 (WAD)
+pub const FUNDING_PRECISION: i128 = 1_000_000_000_000_000_000;
+
+/// Largest funding rate per epoch, either direction (1%)
+pub const MAX_FUNDING_RATE: i64 = 10_000_000_000_000_000;
+
+#[program]
+pub mod vulnerable_funding_rate_overflow {
+    use super::*;
+
+    /// VULNERABILITY: Cumulative Funding Grows Without Bound
+    ///
+    /// The funding crank adds each epoch's rate to
+    /// `funding_rate_cumulative: i64` with a plain `+=`. Rates are WAD
+    /// scaled, so a rate held near the 1% clamp adds 1e16 per epoch and
+    /// `i64::MAX` (~9.2e18) is reached after ~920 epochs - about five
+    /// years, sooner in a market where one side pays persistently. The
+    /// release profile has `overflow-checks = true`, so the add panics;
+    /// without it the value would wrap negative.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A long-lived market pays positive funding epoch after epoch
+    /// 2. Traders holding the premium at the clamp speed it up
+    /// 3. The epoch the sum passes `i64::MAX`, `update_funding` panics
+    /// 4. Funding freezes; every open position's PnL is computed against
+    ///    a stale accumulator until the program is upgraded
+    /// 5. Without overflow checks: the accumulator wraps to ~-9.2e18 and
+    ///    every long is "owed" enormous funding by every short
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Monotonic accumulators only ever approach their limit
+    /// - The failure arrives years after launch, long after audits
+    pub fn update_funding(ctx: Context<UpdateFunding>, rate: i64) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+        let market = &mut ctx.accounts.market;
+        require!(epoch > market.last_funding_epoch, ErrorCode::AlreadyUpdated);
+        require!(rate.abs() <= MAX_FUNDING_RATE, ErrorCode::RateOutOfRange);
+
+        // ❌ Unbounded accumulation
+        market.funding_rate_cumulative += rate;
+        market.last_funding_epoch = epoch;
+        Ok(())
+    }
+
+    /// Apply funding accrued since the position's last settlement
+    pub fn settle_funding(ctx: Context<SettleFunding>) -> Result<()> {
+        let market = &ctx.accounts.market;
+        let position = &mut ctx.accounts.position;
+
+        let delta = market.funding_rate_cumulative - position.entry_funding;
+        let payment = funding_payment(position.size, delta)?;
+        position.collateral = position
+            .collateral
+            .checked_sub(payment)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        position.entry_funding = market.funding_rate_cumulative;
+        Ok(())
+    }
+}
+
+/// Funding owed by a position of signed notional `size` over `delta`
+pub fn funding_payment(size: i64, delta: i64) -> Result<i64> {
+    let payment = (size as i128)
+        .checked_mul(delta as i128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / FUNDING_PRECISION;
+    i64::try_from(payment).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateFunding<'info> {
+    #[account(mut, has_one = funding_authority)]
+    pub market: Account<'info, Market>,
+
+    pub funding_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleFunding<'info> {
+    pub market: Account<'info, Market>,
+
+    #[account(mut, has_one = market)]
+    pub position: Account<'info, Position>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Market {
+    pub funding_authority: Pubkey,
+    /// Sum of every epoch's funding rate, WAD scaled
+    pub funding_rate_cumulative: i64,
+    pub last_funding_epoch: u64,
+}
+
+#[account]
+pub struct Position {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    /// Signed notional: positive long, negative short
+    pub size: i64,
+    pub collateral: i64,
+    /// `funding_rate_cumulative` at the last settlement
+    pub entry_funding: i64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Funding already updated this epoch")]
+    AlreadyUpdated,
+
+    #[msg("Funding rate out of range")]
+    RateOutOfRange,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
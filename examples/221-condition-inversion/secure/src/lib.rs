@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+declare_id!("Secur22122122122122122122122122122122122122");
+
+#[program]
+pub mod secure_condition_inversion {
+    use super::*;
+
+    /// Buy a bond at the series' discount price; pays `face_value` at
+    /// maturity
+    pub fn purchase_bond(ctx: Context<PurchaseBond>, face_value: u64) -> Result<()> {
+        let series = &ctx.accounts.series;
+        let price = (face_value as u128)
+            .checked_mul(series.price_bps as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / 10_000;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.holder.to_account_info(),
+                    to: ctx.accounts.series.to_account_info(),
+                },
+            ),
+            price as u64,
+        )?;
+
+        let bond = &mut ctx.accounts.bond;
+        bond.series = ctx.accounts.series.key();
+        bond.holder = ctx.accounts.holder.key();
+        bond.face_value = face_value;
+        bond.maturity_timestamp = series.maturity_timestamp;
+        Ok(())
+    }
+
+    /// SECURE: Maturity Named, Then Required
+    ///
+    /// The condition is computed into `is_matured` first and `require!`
+    /// checks that flag, so the guard reads as the rule it enforces:
+    /// redemption requires a matured bond. Tests cover both sides of the
+    /// boundary.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `is_matured = clock.unix_timestamp >= maturity`
+    /// 2. `require!(is_matured, BondNotMatured)`
+    /// 3. Maturity copied from the series at purchase, not caller-set
+    pub fn redeem(ctx: Context<Redeem>) -> Result<()> {
+        let clock = Clock::get()?;
+        let bond = &ctx.accounts.bond;
+
+        // ✅ The condition that must hold, named for what it means
+        let is_matured = clock.unix_timestamp >= bond.maturity_timestamp;
+        require!(is_matured, ErrorCode::BondNotMatured);
+
+        ctx.accounts.series.sub_lamports(bond.face_value)?;
+        ctx.accounts.holder.add_lamports(bond.face_value)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PurchaseBond<'info> {
+    #[account(mut, seeds = [b"series", series.issuer.as_ref()], bump = series.bump)]
+    pub series: Account<'info, BondSeries>,
+
+    #[account(init, payer = holder, space = 8 + Bond::LEN)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    #[account(mut, seeds = [b"series", series.issuer.as_ref()], bump = series.bump)]
+    pub series: Account<'info, BondSeries>,
+
+    #[account(mut, has_one = series, has_one = holder, close = holder)]
+    pub bond: Account<'info, Bond>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct BondSeries {
+    pub issuer: Pubkey,
+    /// Purchase price as a fraction of face value, in basis points
+    pub price_bps: u16,
+    pub maturity_timestamp: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Bond {
+    pub series: Pubkey,
+    pub holder: Pubkey,
+    pub face_value: u64,
+    pub maturity_timestamp: i64,
+}
+
+impl Bond {
+    pub const LEN: usize = 32 + // series
+        32 + // holder
+        8 + // face_value
+        8; // maturity_timestamp
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Bond has not matured")]
+    BondNotMatured,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
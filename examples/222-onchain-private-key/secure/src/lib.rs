@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur22222222222222222222222222222222222223");
+
+#[program]
+pub mod secure_onchain_private_key {
+    use super::*;
+
+    /// Record the vault; its token account must already be owned by the
+    /// vault authority PDA
+    pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.admin = ctx.accounts.admin.key();
+        vault.vault_token = ctx.accounts.vault_token.key();
+        // ✅ Only the bump is stored - there is no secret to store
+        vault.authority_bump = ctx.bumps.vault_authority;
+        Ok(())
+    }
+
+    /// SECURE: Vault Authority Is a PDA
+    ///
+    /// The vault token account is owned by a program derived address,
+    /// `[b"vault_authority", vault]`. A PDA lies off the ed25519 curve,
+    /// so no private key for it exists anywhere. The only way to sign as
+    /// it is for this program to pass its seeds to `invoke_signed`, and
+    /// the runtime accepts those seeds only from the program that
+    /// derived the address.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. No key material in account data or instruction data - the
+    ///    seeds and bump are public and meant to be
+    /// 2. Transfers out of the vault happen only through this
+    ///    instruction, after its checks pass
+    /// 3. `token::authority = vault_authority` ties the token account to
+    ///    the PDA derived from this vault
+    ///
+    /// WHY PDAS:
+    /// Knowing a PDA's seeds grants nothing: signing needs both the seeds
+    /// and the program's identity, which the runtime checks itself. A
+    /// keypair's authority is its secret, and the chain cannot keep one.
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let vault_key = ctx.accounts.vault.key();
+        let seeds = &[
+            b"vault_authority".as_ref(),
+            vault_key.as_ref(),
+            &[ctx.accounts.vault.authority_bump],
+        ];
+
+        // ✅ Program signs for its PDA via invoke_signed
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeVault<'info> {
+    #[account(init, payer = admin, space = 8 + Vault::LEN)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: PDA used only as a signer; never read or written
+    #[account(seeds = [b"vault_authority", vault.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(token::authority = vault_authority)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(has_one = admin, has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: PDA used only as a signer; never read or written
+    #[account(seeds = [b"vault_authority", vault.key().as_ref()], bump = vault.authority_bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(mut, token::authority = vault_authority)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub admin: Pubkey,
+    pub vault_token: Pubkey,
+    pub authority_bump: u8,
+}
+
+impl Vault {
+    pub const LEN: usize = 32 + // admin
+        32 + // vault_token
+        1; // authority_bump
+}
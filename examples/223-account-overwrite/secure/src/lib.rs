@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur22322322322322322322322322322322322322");
+
+#[program]
+pub mod secure_account_overwrite {
+    use super::*;
+
+    /// SECURE: Position Closed With Anchor's `close` Constraint
+    ///
+    /// `close = owner` runs when the instruction exits: it moves every
+    /// lamport to the owner, hands the account back to the System Program
+    /// and shrinks its data to zero bytes. Sending lamports back in the
+    /// same transaction no longer revives a position - it funds an empty,
+    /// system-owned account, which `Account<Position>` rejects.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Ownership reassigned to the System Program, so this program's
+    ///    checks fail on the address until it is initialized again
+    /// 2. Data resized to zero - the discriminator and `amount` are gone
+    /// 3. Done by Anchor after the handler, so nothing can write the
+    ///    struct back afterwards
+    ///
+    /// NOTE:
+    /// Recreating the address with `create_account` is not a way around
+    /// this: the position is a PDA, and only this program can sign for
+    /// it, through `init`, which starts from a zeroed `Position`.
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        let amount = ctx.accounts.position.amount;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.total_staked = pool
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStake)?;
+
+        let bump = pool.bump;
+        let seeds = &[b"pool".as_ref(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.owner_token.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        msg!("Closed position, returned {}", amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [b"position", owner.key().as_ref()],
+        bump = position.bump,
+        has_one = owner,
+        // ✅ Lamports drained, owner reassigned and data cleared on exit
+        close = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault: Pubkey,
+    pub total_staked: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient stake in pool")]
+    InsufficientStake,
+}
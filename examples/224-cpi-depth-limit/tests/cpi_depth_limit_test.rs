@@ -0,0 +1,60 @@
+#[tokio::test]
+async fn test_withdraw_through_multisig_exploit() {
+    println!("\n=== EXPLOIT: Withdrawal Exceeds The CPI Depth Limit ===\n");
+
+    let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let multisig = create_multisig(&members, 2).await;
+    let vault = create_vault().await;
+
+    println!("1. multisig -> router -> vault -> token: deposit 1,000,000");
+    execute_via_multisig(&multisig, &members[..2], router_deposit_ix(&vault, &multisig, 1_000_000))
+        .await
+        .unwrap();
+    assert_eq!(get_position(&vault, &multisig.pda).await.shares, 1_000_000);
+
+    println!("\n2. multisig -> router -> vault -> strategy -> lending -> token");
+    let result = execute_via_multisig(
+        &multisig,
+        &members[..2],
+        router_withdraw_ix(&vault, &multisig, 1_000_000),
+    )
+    .await;
+
+    // Vulnerable: the token CPI would run at stack height 6
+    assert!(result.is_err());
+    assert!(format!("{:?}", result).contains("CallDepth"));
+    assert_eq!(get_position(&vault, &multisig.pda).await.shares, 1_000_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Treasury deposit can never be withdrawn by its multisig");
+}
+
+#[tokio::test]
+async fn test_flat_withdrawal_path() {
+    println!("\n=== SECURITY: Flat Withdrawal Path With Depth Check ===\n");
+
+    let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let multisig = create_multisig(&members, 2).await;
+    let vault = create_vault_with_buffer(2_000_000).await;
+    execute_via_multisig(&multisig, &members[..2], router_deposit_ix(&vault, &multisig, 1_000_000))
+        .await
+        .unwrap();
+    let (root, proof) = post_strategy_root(&vault, 0).await;
+    assert_eq!(get_vault(&vault).await.strategy_root, root);
+
+    println!("1. multisig -> router -> vault -> token: withdraw");
+    execute_via_multisig(
+        &multisig,
+        &members[..2],
+        router_withdraw_ix(&vault, &multisig, 1_000_000, 0, proof.clone()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(get_position(&vault, &multisig.pda).await.shares, 0);
+    println!("   ✓ Token CPI ran at stack height 4");
+
+    println!("\n2. Same call wrapped in two more programs");
+    let result = call_at_stack_height(5, withdraw_ix(&vault, &multisig, 1, 0, proof)).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: CallTooDeep");
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+declare_id!("Vuln22422422422422422422422422422422422422");
+
+/// Anchor discriminator of the strategy program's `redeem` instruction
+pub const STRATEGY_REDEEM_DISCRIMINATOR: [u8; 8] = [184, 12, 86, 149, 70, 196, 97, 225];
+
+#[program]
+pub mod vulnerable_cpi_depth_limit {
+    use super::*;
+
+    /// VULNERABILITY: Withdrawal Path Deeper Than Callers Can Reach
+    ///
+    /// `withdraw` asks the strategy program to redeem, the strategy asks
+    /// the lending market, and the lending market asks the token program
+    /// to pay out. That is three nested CPIs below the vault. The runtime
+    /// allows an instruction stack at most 5 high - the top-level
+    /// instruction plus 4 levels of CPI - so the path only works when the
+    /// vault is called directly or from one wrapper.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. A DAO treasury is held by a multisig program, which executes
+    ///    approved transactions through CPI
+    /// 2. It deposits through a router: multisig -> router -> vault ->
+    ///    token, well within the limit
+    /// 3. To withdraw: multisig -> router -> vault -> strategy ->
+    ///    lending -> token is six levels, and the token CPI fails with
+    ///    `CallDepth`
+    /// 4. Nothing the DAO can sign gets its funds out; anyone who wraps
+    ///    a liquidation or settlement the same way hits the same wall
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Deposits are shallow and withdrawals are deep, so funds go in
+    ///   from places they cannot come back out to
+    /// - The depth a call needs depends on who calls it, which tests that
+    ///   call the vault directly never exercise
+    /// - The failure surfaces in another program, three levels down
+    pub fn withdraw(ctx: Context<Withdraw>, shares: u64) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let position = &mut ctx.accounts.position;
+        position.shares = position
+            .shares
+            .checked_sub(shares)
+            .ok_or(ErrorCode::InsufficientShares)?;
+        vault.total_shares = vault
+            .total_shares
+            .checked_sub(shares)
+            .ok_or(ErrorCode::InsufficientShares)?;
+
+        let mut data = STRATEGY_REDEEM_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&shares.to_le_bytes());
+        let accounts: Vec<AccountMeta> = ctx
+            .remaining_accounts
+            .iter()
+            .map(|a| {
+                if a.is_writable {
+                    AccountMeta::new(*a.key, a.is_signer)
+                } else {
+                    AccountMeta::new_readonly(*a.key, a.is_signer)
+                }
+            })
+            .collect();
+
+        // ❌ Strategy -> lending -> token: three more levels from here
+        invoke(
+            &Instruction {
+                program_id: ctx.accounts.strategy_program.key(),
+                accounts,
+                data,
+            },
+            ctx.remaining_accounts,
+        )?;
+
+        msg!("Withdrew {} shares", shares);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = strategy_program)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut, has_one = owner, has_one = vault)]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: Pinned by `has_one` on the vault
+    #[account(executable)]
+    pub strategy_program: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Vault {
+    pub strategy_program: Pubkey,
+    pub total_shares: u64,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub shares: u64,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient shares")]
+    InsufficientShares,
+}
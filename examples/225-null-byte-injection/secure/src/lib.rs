@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur22522522522522522522522522522522522522");
+
+/// Longest username; also the most bytes a single PDA seed may hold
+pub const MAX_USERNAME_LEN: usize = 32;
+
+#[program]
+pub mod secure_null_byte_injection {
+    use super::*;
+
+    /// SECURE: Username Restricted To Printable ASCII
+    ///
+    /// The bytes that make a name unique are now the bytes every viewer
+    /// shows. With no NUL and nothing outside ASCII, there is no byte a
+    /// display layer can cut at and no character that renders as another.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `!username.contains('\0')` - nothing for C-style readers to
+    ///    truncate at
+    /// 2. `username.is_ascii()` - rules out Unicode look-alikes
+    /// 3. No ASCII control characters, which also render inconsistently
+    /// 4. Checked before `msg!` and before the name is stored
+    ///
+    /// NOTE:
+    /// Rust itself never truncates at NUL - `"admin\0pay" == "admin"` is
+    /// false on-chain. The risk is the gap between this program and
+    /// everything that reads its output.
+    pub fn register(ctx: Context<Register>, username: String) -> Result<()> {
+        require!(
+            !username.is_empty() && username.len() <= MAX_USERNAME_LEN,
+            ErrorCode::InvalidUsernameLength
+        );
+
+        // ✅ Only bytes that every reader displays the same way
+        require!(!username.contains('\0'), ErrorCode::NullByteInUsername);
+        require!(username.is_ascii(), ErrorCode::NonAsciiUsername);
+        require!(
+            !username.bytes().any(|b| b.is_ascii_control()),
+            ErrorCode::ControlCharacterInUsername
+        );
+
+        let record = &mut ctx.accounts.record;
+        record.owner = ctx.accounts.owner.key();
+        record.username = username;
+
+        msg!("Registered {}", record.username);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(username: String)]
+pub struct Register<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + UsernameRecord::LEN,
+        seeds = [b"username", username.as_bytes()],
+        bump,
+    )]
+    pub record: Account<'info, UsernameRecord>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct UsernameRecord {
+    pub owner: Pubkey,
+    pub username: String,
+}
+
+impl UsernameRecord {
+    pub const LEN: usize = 32 + // owner
+        4 + MAX_USERNAME_LEN; // username
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Username must be 1 to 32 bytes")]
+    InvalidUsernameLength,
+
+    #[msg("Username contains a null byte")]
+    NullByteInUsername,
+
+    #[msg("Username must be ASCII")]
+    NonAsciiUsername,
+
+    #[msg("Username contains a control character")]
+    ControlCharacterInUsername,
+}
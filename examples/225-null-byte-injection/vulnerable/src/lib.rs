@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln22522522522522522522522522522522522522");
+
+/// Longest username; also the most bytes a single PDA seed may hold
+pub const MAX_USERNAME_LEN: usize = 32;
+
+#[program]
+pub mod vulnerable_null_byte_injection {
+    use super::*;
+
+    /// VULNERABILITY: Username Stored Without Sanitizing Its Bytes
+    ///
+    /// Names are unique because each one gets a PDA seeded by its bytes.
+    /// Inside the program that holds: `"admin\0pay"` and `"admin"` are
+    /// different strings and different PDAs. Outside it, many of the
+    /// tools that display names - C-backed log processors, explorers,
+    /// databases that strip or reject NUL - read up to the first `\0`
+    /// and show both as `admin`.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The project's treasury registered `admin`
+    /// 2. Mallory registers `"admin\0pay"`, a fresh PDA, so `init` succeeds
+    /// 3. `msg!` and the indexer render her name as `admin`
+    /// 4. Users resolving `admin` in a wallet that reads the indexer send
+    ///    funds to Mallory's owner key
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Uniqueness is enforced on bytes, but people compare what they see
+    /// - Any off-chain `== "admin"` check after truncation matches both
+    /// - Non-ASCII look-alikes (Cyrillic `а` for Latin `a`) work the same
+    ///   way without a NUL at all
+    pub fn register(ctx: Context<Register>, username: String) -> Result<()> {
+        require!(
+            !username.is_empty() && username.len() <= MAX_USERNAME_LEN,
+            ErrorCode::InvalidUsernameLength
+        );
+
+        // ❌ Any UTF-8 accepted, including '\0' and look-alike characters
+        let record = &mut ctx.accounts.record;
+        record.owner = ctx.accounts.owner.key();
+        record.username = username;
+
+        msg!("Registered {}", record.username);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(username: String)]
+pub struct Register<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + UsernameRecord::LEN,
+        seeds = [b"username", username.as_bytes()],
+        bump,
+    )]
+    pub record: Account<'info, UsernameRecord>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct UsernameRecord {
+    pub owner: Pubkey,
+    pub username: String,
+}
+
+impl UsernameRecord {
+    pub const LEN: usize = 32 + // owner
+        4 + MAX_USERNAME_LEN; // username
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Username must be 1 to 32 bytes")]
+    InvalidUsernameLength,
+}
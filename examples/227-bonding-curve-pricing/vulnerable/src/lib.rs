@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount};
+
+declare_id!("Vuln22722722722722722722722722722722722722");
+
+#[program]
+pub mod vulnerable_bonding_curve_pricing {
+    use super::*;
+
+    /// Buy `amount` tokens at the current price, `reserve / supply`
+    pub fn buy(ctx: Context<Buy>, amount: u64) -> Result<()> {
+        let curve = &ctx.accounts.curve;
+        let cost = u64::try_from(
+            (amount as u128)
+                .checked_mul(curve.reserve as u128)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                .div_ceil(curve.supply as u128),
+        )
+        .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.trader.to_account_info(),
+                    to: ctx.accounts.curve.to_account_info(),
+                },
+            ),
+            cost,
+        )?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[b"curve".as_ref(), mint_key.as_ref(), &[curve.bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.trader_tokens.to_account_info(),
+                    authority: ctx.accounts.curve.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        let curve = &mut ctx.accounts.curve;
+        curve.reserve = curve.reserve.checked_add(cost).ok_or(ErrorCode::ArithmeticOverflow)?;
+        curve.supply = curve.supply.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Sell Priced From Post-Trade Supply
+    ///
+    /// Buys are priced at `reserve / supply` before the trade. Sells
+    /// divide by the supply *after* the sold tokens are burned,
+    /// `reserve / (supply - amount)`, which is higher the more is sold.
+    /// Selling half the supply pays double the fair price; selling
+    /// nearly all of it pays out the entire reserve.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. The curve holds 100 SOL against 1,000,000 tokens (0.0001 SOL)
+    /// 2. Mallory buys 1,000,000 tokens for 100 SOL; now 200 SOL against
+    ///    2,000,000 tokens - still 0.0001 SOL each
+    /// 3. She sells the same 1,000,000 tokens, priced at
+    ///    200 / (2,000,000 - 1,000,000) = 0.0002 SOL each
+    /// 4. She receives 200 SOL: her 100 back plus every other holder's
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - Buy and sell use different states, so a round trip is profitable
+    /// - The overpayment grows with the sale size, so capital (or a flash
+    ///   loan) is all it takes to empty the reserve
+    pub fn sell(ctx: Context<Sell>, amount: u64) -> Result<()> {
+        let curve = &ctx.accounts.curve;
+        let supply_after = curve
+            .supply
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientSupply)?;
+        require!(supply_after > 0, ErrorCode::InsufficientSupply);
+
+        // ❌ Price read from the state the sale itself produces
+        let payout = u64::try_from(
+            (amount as u128)
+                .checked_mul(curve.reserve as u128)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                / supply_after as u128,
+        )
+        .map_err(|_| error!(ErrorCode::ArithmeticOverflow))?;
+        let payout = payout.min(curve.reserve);
+
+        settle_sell(ctx, amount, payout)
+    }
+}
+
+fn settle_sell(ctx: Context<Sell>, amount: u64, payout: u64) -> Result<()> {
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.trader_tokens.to_account_info(),
+                authority: ctx.accounts.trader.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let curve_info = ctx.accounts.curve.to_account_info();
+    let trader_info = ctx.accounts.trader.to_account_info();
+    **curve_info.try_borrow_mut_lamports()? -= payout;
+    **trader_info.try_borrow_mut_lamports()? += payout;
+
+    let curve = &mut ctx.accounts.curve;
+    curve.reserve -= payout;
+    curve.supply -= amount;
+    Ok(())
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Buy<'info> {
+    #[account(mut, seeds = [b"curve", mint.key().as_ref()], bump = curve.bump, has_one = mint)]
+    pub curve: Account<'info, Curve>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = mint, token::authority = trader)]
+    pub trader_tokens: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Sell<'info> {
+    #[account(mut, seeds = [b"curve", mint.key().as_ref()], bump = curve.bump, has_one = mint)]
+    pub curve: Account<'info, Curve>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = mint, token::authority = trader)]
+    pub trader_tokens: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub trader: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Curve {
+    pub mint: Pubkey,
+    /// Lamports backing the supply, excluding the account's rent
+    pub reserve: u64,
+    pub supply: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Sale exceeds circulating supply")]
+    InsufficientSupply,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+declare_id!("Secur22822822822822822822822822822822822822");
+
+#[program]
+pub mod secure_expired_order_fill {
+    use super::*;
+
+    /// SECURE: Fill Rejected After the Deadline
+    ///
+    /// `fill_order` compares the clock to `expires_at` before anything
+    /// moves, so the maker's price is only available for as long as they
+    /// offered it. Expired orders can be unwound by anyone with
+    /// `cancel_expired_order`, which sends the escrow back to the maker.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `clock.unix_timestamp <= order.expires_at` checked first
+    /// 2. Permissionless cleanup, so expired funds do not depend on the
+    ///    maker coming back
+    /// 3. Cleanup pays only the maker: their token account and their rent
+    pub fn fill_order(ctx: Context<FillOrder>, base_amount: u64) -> Result<()> {
+        let order = &ctx.accounts.order;
+
+        // ✅ The maker's price lapses at expires_at
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp <= order.expires_at, ErrorCode::OrderExpired);
+
+        require!(base_amount <= order.remaining, ErrorCode::FillExceedsOrder);
+        let quote_amount = base_amount
+            .checked_mul(order.price)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.taker_quote.to_account_info(),
+                    to: ctx.accounts.maker_quote.to_account_info(),
+                    authority: ctx.accounts.taker.to_account_info(),
+                },
+            ),
+            quote_amount,
+        )?;
+
+        let maker = order.maker;
+        let order_id = order.order_id.to_le_bytes();
+        let seeds = &[b"order".as_ref(), maker.as_ref(), &order_id, &[order.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    to: ctx.accounts.taker_base.to_account_info(),
+                    authority: ctx.accounts.order.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            base_amount,
+        )?;
+
+        let order = &mut ctx.accounts.order;
+        order.remaining -= base_amount;
+        msg!("Filled {} at price {}", base_amount, order.price);
+        Ok(())
+    }
+
+    /// Return an expired order's escrow to its maker and close the order.
+    /// Anyone may call this.
+    pub fn cancel_expired_order(ctx: Context<CancelExpiredOrder>) -> Result<()> {
+        let order = &ctx.accounts.order;
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp > order.expires_at, ErrorCode::OrderNotExpired);
+
+        let maker = order.maker;
+        let order_id = order.order_id.to_le_bytes();
+        let seeds = &[b"order".as_ref(), maker.as_ref(), &order_id, &[order.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    to: ctx.accounts.maker_base.to_account_info(),
+                    authority: ctx.accounts.order.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            ctx.accounts.escrow.amount,
+        )?;
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.escrow.to_account_info(),
+                destination: ctx.accounts.maker.to_account_info(),
+                authority: ctx.accounts.order.to_account_info(),
+            },
+            &[&seeds[..]],
+        ))?;
+
+        msg!("Expired order {} returned to maker", order.order_id);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FillOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"order", order.maker.as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        has_one = escrow,
+    )]
+    pub order: Account<'info, Order>,
+
+    #[account(mut)]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = order.quote_mint, token::authority = order.maker)]
+    pub maker_quote: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = escrow.mint, token::authority = taker)]
+    pub taker_base: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = order.quote_mint, token::authority = taker)]
+    pub taker_quote: Account<'info, TokenAccount>,
+
+    pub taker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelExpiredOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"order", maker.key().as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        has_one = maker,
+        has_one = escrow,
+        close = maker,
+    )]
+    pub order: Account<'info, Order>,
+
+    #[account(mut)]
+    pub escrow: Account<'info, TokenAccount>,
+
+    // ✅ Funds can only go back to the maker
+    #[account(mut, token::mint = escrow.mint, token::authority = maker)]
+    pub maker_base: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub maker: SystemAccount<'info>,
+
+    pub caller: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Order {
+    pub maker: Pubkey,
+    pub order_id: u64,
+    /// Base tokens held for this order, authority = the order PDA
+    pub escrow: Pubkey,
+    pub quote_mint: Pubkey,
+    /// Quote units per base unit
+    pub price: u64,
+    pub remaining: u64,
+    /// Last second the order may be filled
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fill amount exceeds remaining order size")]
+    FillExceedsOrder,
+
+    #[msg("Order has expired")]
+    OrderExpired,
+
+    #[msg("Order has not expired")]
+    OrderNotExpired,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,44 @@
+#[tokio::test]
+async fn test_fill_expired_order_exploit() {
+    println!("\n=== EXPLOIT: Expired Order Filled At A Stale Price ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+
+    println!("1. Alice offers 1,000 SOL at 150 USDC, valid for one hour");
+    let order = place_order(&alice, 1_000, 150, ONE_HOUR).await.unwrap();
+
+    println!("\n2. A week passes; SOL trades at 200 USDC");
+    warp_forward_seconds(7 * ONE_DAY).await;
+
+    println!("\n3. Mallory fills the whole order");
+    // Vulnerable: expires_at is never compared to the clock
+    fill_order(&order, &mallory, 1_000).await.unwrap();
+
+    assert_eq!(get_order(&order).await.remaining, 0);
+    assert_eq!(get_token_balance(&mallory_base(&mallory)).await, 1_000);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ 200,000 USDC of SOL bought for 150,000 USDC");
+}
+
+#[tokio::test]
+async fn test_expiry_enforced() {
+    println!("\n=== SECURITY: Fill Rejected After the Deadline ===\n");
+
+    let alice = Keypair::new();
+    let mallory = Keypair::new();
+    let order = place_order(&alice, 1_000, 150, ONE_HOUR).await.unwrap();
+
+    println!("1. Mallory fills after expiry");
+    warp_forward_seconds(7 * ONE_DAY).await;
+    let result = fill_order(&order, &mallory, 1_000).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: OrderExpired");
+
+    println!("\n2. Mallory calls cancel_expired_order");
+    cancel_expired_order(&order, &mallory).await.unwrap();
+    assert_eq!(get_token_balance(&alice_base(&alice)).await, 1_000);
+    assert!(get_account(&order).await.is_none());
+    println!("   ✓ 1,000 SOL returned to Alice; order closed");
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln22822822822822822822822822822822822822");
+
+#[program]
+pub mod vulnerable_expired_order_fill {
+    use super::*;
+
+    /// VULNERABILITY: Fill Ignores the Order's Deadline
+    ///
+    /// A maker escrows base tokens in an order PDA at a fixed `price` and
+    /// sets `expires_at`, meaning "this price is good until then". The
+    /// field is stored and shown in every UI, but `fill_order` never
+    /// compares it to the clock, so the order stays fillable at its old
+    /// price forever.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice offers 1,000 SOL at 150 USDC each, valid for one hour
+    /// 2. The hour passes; Alice assumes the order is dead and leaves it
+    /// 3. A week later SOL trades at 200 USDC
+    /// 4. Mallory fills the whole order: 150,000 USDC for 200,000 USDC
+    ///    worth of SOL, and sells the SOL at market
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - An expiry is part of the price the maker agreed to
+    /// - Stale orders only become attractive when the market has moved
+    ///   against the maker, so they are filled exactly when it hurts
+    pub fn fill_order(ctx: Context<FillOrder>, base_amount: u64) -> Result<()> {
+        let order = &ctx.accounts.order;
+
+        // ❌ No check of order.expires_at against the clock
+        require!(base_amount <= order.remaining, ErrorCode::FillExceedsOrder);
+        let quote_amount = base_amount
+            .checked_mul(order.price)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.taker_quote.to_account_info(),
+                    to: ctx.accounts.maker_quote.to_account_info(),
+                    authority: ctx.accounts.taker.to_account_info(),
+                },
+            ),
+            quote_amount,
+        )?;
+
+        let maker = order.maker;
+        let order_id = order.order_id.to_le_bytes();
+        let seeds = &[b"order".as_ref(), maker.as_ref(), &order_id, &[order.bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    to: ctx.accounts.taker_base.to_account_info(),
+                    authority: ctx.accounts.order.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            base_amount,
+        )?;
+
+        let order = &mut ctx.accounts.order;
+        order.remaining -= base_amount;
+        msg!("Filled {} at price {}", base_amount, order.price);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FillOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"order", order.maker.as_ref(), &order.order_id.to_le_bytes()],
+        bump = order.bump,
+        has_one = escrow,
+    )]
+    pub order: Account<'info, Order>,
+
+    #[account(mut)]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = order.quote_mint, token::authority = order.maker)]
+    pub maker_quote: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = escrow.mint, token::authority = taker)]
+    pub taker_base: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = order.quote_mint, token::authority = taker)]
+    pub taker_quote: Account<'info, TokenAccount>,
+
+    pub taker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Order {
+    pub maker: Pubkey,
+    pub order_id: u64,
+    /// Base tokens held for this order, authority = the order PDA
+    pub escrow: Pubkey,
+    pub quote_mint: Pubkey,
+    /// Quote units per base unit
+    pub price: u64,
+    pub remaining: u64,
+    /// ❌ Stored, never checked
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fill amount exceeds remaining order size")]
+    FillExceedsOrder,
+
+    #[msg("Arithmetic overflow occurred")]
+    ArithmeticOverflow,
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Secur22922922922922922922922922922922922922");
+
+#[program]
+pub mod secure_rescue_authorization {
+    use super::*;
+
+    /// SECURE: Rescue Pays Only the Depositor, With Their Consent
+    ///
+    /// The rescue path keeps the admin in the loop - it is for when the
+    /// normal withdrawal is broken - but the admin no longer chooses where
+    /// the funds go. The recipient must belong to the position's
+    /// depositor, and the depositor signs too.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. `has_one = depositor` on the position, and the recipient is a
+    ///    token account whose owner is that depositor
+    /// 2. Both admin and depositor are `Signer`s
+    /// 3. Recipient mint must match the vault
+    ///
+    /// WHY BOTH SIGNATURES:
+    /// The admin signature keeps the rescue path closed until the team
+    /// has declared an emergency; the depositor's means a leaked admin
+    /// key cannot move anyone's funds, even to their own address.
+    pub fn rescue_tokens(ctx: Context<RescueTokens>) -> Result<()> {
+        let amount = ctx.accounts.position.amount;
+        let bump = ctx.accounts.pool.bump;
+        let seeds = &[b"pool".as_ref(), &[bump]];
+
+        // ✅ Recipient constrained to the depositor's own token account
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        ctx.accounts.position.amount = 0;
+        msg!("Rescued {} from {}", amount, ctx.accounts.position.depositor);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RescueTokens<'info> {
+    #[account(seeds = [b"pool"], bump = pool.bump, has_one = admin, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool, has_one = depositor)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = vault.mint, token::authority = depositor)]
+    pub recipient: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+
+    pub depositor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub pool: Pubkey,
+    /// Who deposited, and so who the funds belong to
+    pub depositor: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,49 @@
+#[tokio::test]
+async fn test_admin_rescues_to_self_exploit() {
+    println!("\n=== EXPLOIT: Admin Rescues Deposits To Their Own Account ===\n");
+
+    let admin = Keypair::new();
+    let alice = Keypair::new();
+    let pool = create_pool(&admin).await;
+    let position = deposit(&pool, &alice, 50_000).await.unwrap();
+
+    println!("1. Alice has 50,000 USDC in the pool");
+    let admin_token = create_token_account(&admin, &usdc_mint()).await;
+
+    println!("\n2. Admin calls rescue_tokens with their own account");
+    // Vulnerable: recipient is unchecked
+    rescue_tokens(&pool, &position, &admin_token, &admin).await.unwrap();
+
+    assert_eq!(get_token_balance(&admin_token).await, 50_000);
+    assert_eq!(get_position(&position).await.amount, 0);
+
+    println!("\n   EXPLOIT SUCCESSFUL!");
+    println!("   ✗ Alice's 50,000 USDC paid to the admin");
+}
+
+#[tokio::test]
+async fn test_rescue_to_depositor_only() {
+    println!("\n=== SECURITY: Rescue Pays Only the Depositor ===\n");
+
+    let admin = Keypair::new();
+    let alice = Keypair::new();
+    let pool = create_pool(&admin).await;
+    let position = deposit(&pool, &alice, 50_000).await.unwrap();
+    let admin_token = create_token_account(&admin, &usdc_mint()).await;
+    let alice_token = create_token_account(&alice, &usdc_mint()).await;
+
+    println!("1. Admin rescues to their own account");
+    let result = rescue_tokens(&pool, &position, &admin_token, &[&admin, &alice]).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: ConstraintTokenOwner");
+
+    println!("\n2. Admin rescues without Alice's signature");
+    let result = rescue_tokens(&pool, &position, &alice_token, &[&admin]).await;
+    assert!(result.is_err());
+    println!("   ✓ Error: missing depositor signature");
+
+    println!("\n3. Admin and Alice both sign");
+    rescue_tokens(&pool, &position, &alice_token, &[&admin, &alice]).await.unwrap();
+    assert_eq!(get_token_balance(&alice_token).await, 50_000);
+    println!("   ✓ 50,000 USDC returned to Alice");
+}
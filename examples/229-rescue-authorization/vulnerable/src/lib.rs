@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("Vuln22922922922922922922922922922922922922");
+
+#[program]
+pub mod vulnerable_rescue_authorization {
+    use super::*;
+
+    /// VULNERABILITY: Rescued Tokens Sent Wherever the Admin Says
+    ///
+    /// `rescue_tokens` exists for the day the normal withdrawal path has
+    /// a bug: the admin moves a depositor's balance out of the pool's
+    /// PDA-owned vault. The destination is an unchecked `recipient`, so
+    /// "rescue" means "send to any account the admin names" - including
+    /// their own.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Alice has 50,000 USDC in the pool
+    /// 2. The admin key is compromised, or its holder turns malicious
+    /// 3. They call `rescue_tokens` on Alice's position with their own
+    ///    token account as `recipient`
+    /// 4. The pool debits Alice's position and pays the admin; repeated
+    ///    per position, it empties the vault
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - An emergency function is a withdrawal path with fewer checks
+    /// - Depositors' funds are only as safe as one key, which the
+    ///   protocol's non-custodial design was meant to avoid
+    pub fn rescue_tokens(ctx: Context<RescueTokens>) -> Result<()> {
+        let amount = ctx.accounts.position.amount;
+        let bump = ctx.accounts.pool.bump;
+        let seeds = &[b"pool".as_ref(), &[bump]];
+
+        // ❌ Recipient is whatever account the admin passes in
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        )?;
+
+        ctx.accounts.position.amount = 0;
+        msg!("Rescued {} from {}", amount, ctx.accounts.position.depositor);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RescueTokens<'info> {
+    #[account(seeds = [b"pool"], bump = pool.bump, has_one = admin, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut, has_one = pool)]
+    pub position: Account<'info, Position>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Any token account
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Pool {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+pub struct Position {
+    pub pool: Pubkey,
+    /// Who deposited, and so who the funds belong to
+    pub depositor: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Secur23o23o23o23o23o23o23o23o23o23o23o23o23");
+
+/// Length of one reward cycle (~20 days at ~2 days per epoch)
+pub const CYCLE_EPOCHS: u64 = 10;
+
+#[program]
+pub mod secure_epoch_cycle_nonce {
+    use super::*;
+
+    /// Create the staker's claim record
+    pub fn register(ctx: Context<Register>) -> Result<()> {
+        let record = &mut ctx.accounts.record;
+        record.staker = ctx.accounts.staker.key();
+        record.last_cycle = None;
+        record.bump = ctx.bumps.record;
+        Ok(())
+    }
+
+    /// SECURE: Cycle Identified By Absolute Number
+    ///
+    /// The cycle is `epoch / CYCLE_EPOCHS`: every epoch of a cycle maps to
+    /// the same number, and no two cycles share one. The record keeps the
+    /// absolute cycle of the last claim and a new claim must be in a
+    /// strictly later one, so a claim can never become fresh again.
+    ///
+    /// SECURITY MEASURES:
+    /// 1. Cycle number derived by division from the absolute epoch
+    /// 2. `cycle > last_cycle` - monotonic, not merely different
+    /// 3. `None` before the first claim instead of a sentinel value that
+    ///    a real cycle number could one day equal
+    pub fn claim_cycle_reward(ctx: Context<ClaimCycleReward>) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+
+        // ✅ Same for a whole cycle, never repeated across cycles
+        let cycle = epoch / CYCLE_EPOCHS;
+        let record = &mut ctx.accounts.record;
+        if let Some(last_cycle) = record.last_cycle {
+            require!(cycle > last_cycle, ErrorCode::AlreadyClaimed);
+        }
+        record.last_cycle = Some(cycle);
+
+        let reward = ctx.accounts.distributor.reward_per_cycle;
+        let distributor_info = ctx.accounts.distributor.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(distributor_info.data_len());
+        require!(
+            distributor_info.lamports().saturating_sub(reward) >= rent_floor,
+            ErrorCode::DistributorEmpty
+        );
+        **distributor_info.try_borrow_mut_lamports()? -= reward;
+        **ctx.accounts.staker.try_borrow_mut_lamports()? += reward;
+
+        msg!("Claimed {} for cycle {}", reward, cycle);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Register<'info> {
+    #[account(
+        init,
+        payer = staker,
+        space = 8 + ClaimRecord::LEN,
+        seeds = [b"claim", staker.key().as_ref()],
+        bump,
+    )]
+    pub record: Account<'info, ClaimRecord>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimCycleReward<'info> {
+    #[account(mut, seeds = [b"distributor"], bump = distributor.bump)]
+    pub distributor: Account<'info, Distributor>,
+
+    #[account(
+        mut,
+        seeds = [b"claim", staker.key().as_ref()],
+        bump = record.bump,
+        has_one = staker,
+    )]
+    pub record: Account<'info, ClaimRecord>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Distributor {
+    pub reward_per_cycle: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct ClaimRecord {
+    pub staker: Pubkey,
+    /// `epoch / CYCLE_EPOCHS` at the last claim
+    pub last_cycle: Option<u64>,
+    pub bump: u8,
+}
+
+impl ClaimRecord {
+    pub const LEN: usize = 32 + // staker
+        1 + 8 + // last_cycle
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Reward already claimed this cycle")]
+    AlreadyClaimed,
+
+    #[msg("Distributor has no rewards left")]
+    DistributorEmpty,
+}
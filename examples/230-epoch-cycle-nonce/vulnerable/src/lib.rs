@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Vuln23023023023023023023023023023023023023");
+
+/// Length of one reward cycle (~20 days at ~2 days per epoch)
+pub const CYCLE_EPOCHS: u64 = 10;
+
+#[program]
+pub mod vulnerable_epoch_cycle_nonce {
+    use super::*;
+
+    /// Create the staker's claim record
+    pub fn register(ctx: Context<Register>) -> Result<()> {
+        let record = &mut ctx.accounts.record;
+        record.staker = ctx.accounts.staker.key();
+        record.last_cycle = u64::MAX;
+        record.bump = ctx.bumps.record;
+        Ok(())
+    }
+
+    /// VULNERABILITY: Cycle Identified By `epoch % CYCLE_EPOCHS`
+    ///
+    /// Each staker may claim once per 10-epoch cycle. The cycle is taken
+    /// to be `epoch % CYCLE_EPOCHS` and compared with the one recorded at
+    /// the last claim. That expression is the position *within* a cycle,
+    /// not which cycle it is: it changes every epoch, and it comes back
+    /// to the same value every 10 epochs. A claim made in epoch 0 is
+    /// forgotten by epoch 1, and its index is "fresh" again in epoch 10.
+    ///
+    /// ATTACK SCENARIO:
+    /// 1. Mallory claims in epoch 500 (index 0)
+    /// 2. In epoch 501 the index is 1, which differs from 0: she claims
+    ///    again, and again in every epoch after
+    /// 3. Over one cycle she collects 10 rewards instead of 1
+    /// 4. The distributor runs dry long before the honest stakers' turn
+    ///
+    /// WHY THIS IS DANGEROUS:
+    /// - A modular value is a position in a repeating pattern, so it
+    ///   cannot say whether something happened in this pattern or an
+    ///   earlier one
+    /// - Every once-per-period guard built on it resets early
+    pub fn claim_cycle_reward(ctx: Context<ClaimCycleReward>) -> Result<()> {
+        let epoch = Clock::get()?.epoch;
+
+        // ❌ Repeats every CYCLE_EPOCHS epochs and changes every epoch
+        let cycle = epoch % CYCLE_EPOCHS;
+        let record = &mut ctx.accounts.record;
+        require!(record.last_cycle != cycle, ErrorCode::AlreadyClaimed);
+        record.last_cycle = cycle;
+
+        let reward = ctx.accounts.distributor.reward_per_cycle;
+        let distributor_info = ctx.accounts.distributor.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(distributor_info.data_len());
+        require!(
+            distributor_info.lamports().saturating_sub(reward) >= rent_floor,
+            ErrorCode::DistributorEmpty
+        );
+        **distributor_info.try_borrow_mut_lamports()? -= reward;
+        **ctx.accounts.staker.try_borrow_mut_lamports()? += reward;
+
+        msg!("Claimed {} for cycle {}", reward, cycle);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT VALIDATION STRUCTURES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Register<'info> {
+    #[account(
+        init,
+        payer = staker,
+        space = 8 + ClaimRecord::LEN,
+        seeds = [b"claim", staker.key().as_ref()],
+        bump,
+    )]
+    pub record: Account<'info, ClaimRecord>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimCycleReward<'info> {
+    #[account(mut, seeds = [b"distributor"], bump = distributor.bump)]
+    pub distributor: Account<'info, Distributor>,
+
+    #[account(
+        mut,
+        seeds = [b"claim", staker.key().as_ref()],
+        bump = record.bump,
+        has_one = staker,
+    )]
+    pub record: Account<'info, ClaimRecord>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+}
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+#[account]
+pub struct Distributor {
+    pub reward_per_cycle: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct ClaimRecord {
+    pub staker: Pubkey,
+    /// `epoch % CYCLE_EPOCHS` at the last claim; `u64::MAX` before any
+    pub last_cycle: u64,
+    pub bump: u8,
+}
+
+impl ClaimRecord {
+    pub const LEN: usize = 32 + // staker
+        8 + // last_cycle
+        1; // bump
+}
+
+// ============================================================================
+// ERROR CODES
+// ============================================================================
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Reward already claimed this cycle")]
+    AlreadyClaimed,
+
+    #[msg("Distributor has no rewards left")]
+    DistributorEmpty,
+}
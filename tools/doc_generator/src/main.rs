@@ -0,0 +1,722 @@
+//! Security documentation generator
+//!
+//! Parses `examples/*/{vulnerable,secure}/src/lib.rs` with `syn`, pulls the
+//! structured doc comments off every instruction handler and writes:
+//!
+//! - `SECURITY.md` - one section per example: the vulnerability, its attack
+//!   scenario and the measures the secure version takes
+//! - `attack_tree.dot` - a Graphviz attack tree grouping the examples by
+//!   vulnerability class and showing how the classes feed into each other
+//!
+//! Usage (from the repository root):
+//!
+//! ```text
+//! cargo run -p doc-generator -- [EXAMPLES_DIR] [OUTPUT_DIR]
+//! ```
+//!
+//! Both arguments default to `examples` and `.`.
+
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use quote::ToTokens;
+use syn::{Attribute, Expr, ImplItem, Item, Lit, Meta, Signature};
+
+const VULNERABILITY_MARKER: &str = "VULNERABILITY:";
+const SECURE_MARKER: &str = "SECURE:";
+const ATTACK_SCENARIO: &str = "ATTACK SCENARIO";
+const SECURITY_MEASURES: &str = "SECURITY MEASURES";
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let examples_dir = PathBuf::from(args.next().unwrap_or_else(|| "examples".into()));
+    let output_dir = PathBuf::from(args.next().unwrap_or_else(|| ".".into()));
+
+    let examples = load_examples(&examples_dir)?;
+    if examples.is_empty() {
+        return Err(format!("no examples found under {}", examples_dir.display()).into());
+    }
+
+    fs::create_dir_all(&output_dir)?;
+    let security_md = output_dir.join("SECURITY.md");
+    fs::write(&security_md, render_security_md(&examples))?;
+    println!("Wrote {}", security_md.display());
+
+    let attack_tree = output_dir.join("attack_tree.dot");
+    fs::write(&attack_tree, render_attack_tree(&examples))?;
+    println!("Wrote {}", attack_tree.display());
+
+    Ok(())
+}
+
+// ============================================================================
+// EXTRACTION
+// ============================================================================
+
+/// One example directory, e.g. `examples/180-per-trade-limit`
+struct Example {
+    number: u32,
+    slug: String,
+    vulnerabilities: Vec<DocBlock>,
+    fixes: Vec<DocBlock>,
+}
+
+impl Example {
+    fn title(&self) -> String {
+        match self.vulnerabilities.first() {
+            Some(block) => block.title.clone(),
+            None => self.slug.replace('-', " "),
+        }
+    }
+
+    fn class(&self) -> Class {
+        Class::classify(&format!("{} {}", self.slug.replace('-', " "), self.title()))
+    }
+}
+
+/// A `VULNERABILITY:` or `SECURE:` doc comment on one handler
+struct DocBlock {
+    signature: String,
+    title: String,
+    summary: String,
+    sections: Vec<Section>,
+}
+
+impl DocBlock {
+    fn section(&self, heading: &str) -> Option<&Section> {
+        self.sections
+            .iter()
+            .find(|section| section.heading == heading)
+    }
+}
+
+/// An upper-case `HEADING:` and the list items under it
+struct Section {
+    heading: String,
+    items: Vec<String>,
+}
+
+fn load_examples(examples_dir: &Path) -> Result<Vec<Example>> {
+    let mut examples = Vec::new();
+
+    for entry in fs::read_dir(examples_dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        let Some((number, slug)) = name
+            .split_once('-')
+            .and_then(|(number, slug)| Some((number.parse::<u32>().ok()?, slug.to_string())))
+        else {
+            continue;
+        };
+
+        let vulnerable_lib = path.join("vulnerable/src/lib.rs");
+        let secure_lib = path.join("secure/src/lib.rs");
+        if !vulnerable_lib.exists() && !secure_lib.exists() {
+            continue;
+        }
+
+        let vulnerabilities = extract_blocks(&vulnerable_lib, VULNERABILITY_MARKER)?;
+        let fixes = extract_blocks(&secure_lib, SECURE_MARKER)?;
+        if vulnerabilities.is_empty() && fixes.is_empty() {
+            eprintln!("warning: no documented handlers in {}", path.display());
+            continue;
+        }
+
+        examples.push(Example {
+            number,
+            slug,
+            vulnerabilities,
+            fixes,
+        });
+    }
+
+    examples.sort_by_key(|example| example.number);
+    Ok(examples)
+}
+
+/// Parses `path` and returns every function doc block starting with `marker`
+fn extract_blocks(path: &Path, marker: &str) -> Result<Vec<DocBlock>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let source = fs::read_to_string(path)?;
+    let file = syn::parse_file(&source).map_err(|err| format!("{}: {}", path.display(), err))?;
+
+    let mut functions = Vec::new();
+    collect_functions(&file.items, &mut functions);
+
+    Ok(functions
+        .into_iter()
+        .filter_map(|(signature, docs)| parse_doc_block(signature, &docs, marker))
+        .collect())
+}
+
+/// Walks free functions, inline modules (the `#[program]` module) and impls
+fn collect_functions<'a>(items: &'a [Item], out: &mut Vec<(&'a Signature, Vec<String>)>) {
+    for item in items {
+        match item {
+            Item::Fn(function) => out.push((&function.sig, doc_lines(&function.attrs))),
+            Item::Mod(module) => {
+                if let Some((_, items)) = &module.content {
+                    collect_functions(items, out);
+                }
+            }
+            Item::Impl(block) => {
+                for item in &block.items {
+                    if let ImplItem::Fn(function) = item {
+                        out.push((&function.sig, doc_lines(&function.attrs)));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `/// text` lowers to `#[doc = " text"]`; strip the single leading space
+fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(value) => Some(value.value()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+fn parse_doc_block(signature: &Signature, lines: &[String], marker: &str) -> Option<DocBlock> {
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with(marker))?;
+    let title = lines[start].trim_start()[marker.len()..].trim().to_string();
+
+    let mut summary = Vec::new();
+    let mut sections: Vec<Section> = Vec::new();
+
+    for line in &lines[start + 1..] {
+        if let Some(heading) = section_heading(line) {
+            sections.push(Section {
+                heading,
+                items: Vec::new(),
+            });
+            continue;
+        }
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        match sections.last_mut() {
+            None => summary.push(trimmed.to_string()),
+            Some(section) => {
+                // Indented lines continue the previous list item
+                let continues_item = line.starts_with(' ') && !is_list_item(trimmed);
+                match section.items.last_mut() {
+                    Some(item) if continues_item => {
+                        item.push(' ');
+                        item.push_str(trimmed);
+                    }
+                    _ => section.items.push(trimmed.to_string()),
+                }
+            }
+        }
+    }
+
+    Some(DocBlock {
+        signature: render_signature(signature),
+        title,
+        summary: summary.join(" "),
+        sections,
+    })
+}
+
+/// `ATTACK SCENARIO:` -> `ATTACK SCENARIO`; ordinary prose returns `None`
+fn section_heading(line: &str) -> Option<String> {
+    let heading = line.trim().strip_suffix(':')?;
+    let is_heading = !heading.is_empty()
+        && heading.chars().any(|c| c.is_ascii_uppercase())
+        && heading
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || " '-/()".contains(c));
+    is_heading.then(|| heading.to_string())
+}
+
+fn is_list_item(line: &str) -> bool {
+    line.starts_with("- ")
+        || line.split_once(". ").is_some_and(|(number, _)| {
+            !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+        })
+}
+
+/// Prints the signature through `quote`, then undoes its token spacing
+fn render_signature(signature: &Signature) -> String {
+    let raw = signature.to_token_stream().to_string();
+    [
+        (" ,", ","),
+        (" :", ":"),
+        (" (", "("),
+        ("< ", "<"),
+        (" <", "<"),
+        (" >", ">"),
+        ("( ", "("),
+        (" )", ")"),
+        ("& ", "&"),
+        ("' ", "'"),
+    ]
+    .iter()
+    .fold(raw, |acc, (from, to)| acc.replace(from, to))
+    .replace("->", " -> ")
+    .replace("  ", " ")
+}
+
+// ============================================================================
+// CLASSIFICATION
+// ============================================================================
+
+/// Coarse vulnerability classes used as the inner nodes of the attack tree
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Class {
+    AccountValidation,
+    AccessControl,
+    Arithmetic,
+    Cpi,
+    StateOrdering,
+    Economic,
+}
+
+impl Class {
+    const ALL: [Class; 6] = [
+        Class::AccountValidation,
+        Class::AccessControl,
+        Class::Arithmetic,
+        Class::Cpi,
+        Class::StateOrdering,
+        Class::Economic,
+    ];
+
+    /// Checked in this order; the first class with a matching keyword wins
+    const KEYWORDS: [(Class, &'static [&'static str]); 5] = [
+        (
+            Class::Arithmetic,
+            &[
+                "overflow",
+                "underflow",
+                "wrap",
+                "rounding",
+                "rounds",
+                "precision",
+                "dust",
+                "cast",
+                "arithmetic",
+                "multipl",
+                "zero shares",
+            ],
+        ),
+        (
+            Class::Cpi,
+            &[
+                "cpi",
+                "invoke",
+                "callback",
+                "cross-program",
+                "programdata",
+                "router",
+                "flash",
+            ],
+        ),
+        (
+            Class::AccessControl,
+            &[
+                "signer",
+                "authority",
+                "admin",
+                "permission",
+                "quorum",
+                "timelock",
+                "emergency",
+                "exemption",
+                "allowlist",
+                "config",
+                "ownership",
+                "revocation",
+                "migration",
+                "metadata",
+                "recipient changed",
+            ],
+        ),
+        (
+            Class::AccountValidation,
+            &[
+                "owner",
+                "discriminator",
+                "pda",
+                "seed",
+                "cosplay",
+                "raw account",
+                "reinitializ",
+                "duplicate",
+                "distinctness",
+                "type tag",
+                "destination account",
+                "close",
+                "keyed by",
+                "realloc",
+            ],
+        ),
+        (
+            Class::StateOrdering,
+            &[
+                "replay",
+                "nonce",
+                "status",
+                "race",
+                "batch",
+                "ordering",
+                "stale",
+                "before",
+                "withdrawable",
+                "counter",
+                "elapsed",
+                "swallowed",
+                "reclaim",
+            ],
+        ),
+    ];
+
+    /// Classifies on the example slug and vulnerability title. Keywords
+    /// match at the start of a word, so "pda" does not hit "updates".
+    fn classify(text: &str) -> Class {
+        let text = text.to_lowercase();
+        let at_word_start = |keyword: &str| {
+            text.match_indices(keyword).any(|(index, _)| {
+                !text[..index]
+                    .chars()
+                    .next_back()
+                    .is_some_and(char::is_alphanumeric)
+            })
+        };
+        Self::KEYWORDS
+            .iter()
+            .find(|(_, keywords)| keywords.iter().any(|keyword| at_word_start(keyword)))
+            .map_or(Class::Economic, |(class, _)| *class)
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Class::AccountValidation => "Account Validation",
+            Class::AccessControl => "Access Control",
+            Class::Arithmetic => "Arithmetic",
+            Class::Cpi => "Cross-Program Invocation",
+            Class::StateOrdering => "State & Ordering",
+            Class::Economic => "Economic / Pricing",
+        }
+    }
+
+    fn node_id(self) -> &'static str {
+        match self {
+            Class::AccountValidation => "class_account_validation",
+            Class::AccessControl => "class_access_control",
+            Class::Arithmetic => "class_arithmetic",
+            Class::Cpi => "class_cpi",
+            Class::StateOrdering => "class_state_ordering",
+            Class::Economic => "class_economic",
+        }
+    }
+}
+
+/// How a foothold in one class is typically turned into the next
+const CHAINS: [(Class, Class, &str); 6] = [
+    (
+        Class::AccountValidation,
+        Class::AccessControl,
+        "forged account carries\nattacker's authority",
+    ),
+    (
+        Class::AccessControl,
+        Class::Economic,
+        "attacker-set parameters\n(fees, prices, limits)",
+    ),
+    (
+        Class::Cpi,
+        Class::StateOrdering,
+        "failed or re-entered CPI\nleaves stale state",
+    ),
+    (
+        Class::Arithmetic,
+        Class::Economic,
+        "wrapped value inflates\nbalances or shares",
+    ),
+    (
+        Class::StateOrdering,
+        Class::Economic,
+        "replayed action\nrepeats a payout",
+    ),
+    (
+        Class::AccountValidation,
+        Class::Cpi,
+        "spoofed program or\naccount in a CPI",
+    ),
+];
+
+// ============================================================================
+// RENDERING
+// ============================================================================
+
+fn render_security_md(examples: &[Example]) -> String {
+    let mut out = String::new();
+    out.push_str("# Security Reference\n\n");
+    out.push_str("> Generated by `tools/doc_generator` from the doc comments in `examples/`.\n");
+    out.push_str("> Do not edit by hand - run `cargo run -p doc-generator` instead.\n\n");
+
+    out.push_str("## Index\n\n| # | Vulnerability | Class |\n|---|---|---|\n");
+    for example in examples {
+        let _ = writeln!(
+            out,
+            "| [{:03}](#{}) | {} | {} |",
+            example.number,
+            anchor(example),
+            example.title(),
+            example.class().label()
+        );
+    }
+
+    for example in examples {
+        let _ = write!(
+            out,
+            "\n## {:03} - {}\n\nSource: `examples/{:02}-{}/`\n",
+            example.number,
+            example.title(),
+            example.number,
+            example.slug
+        );
+
+        for block in &example.vulnerabilities {
+            render_block(&mut out, "Vulnerability", block, ATTACK_SCENARIO);
+        }
+        for block in &example.fixes {
+            render_block(&mut out, "Fix", block, SECURITY_MEASURES);
+        }
+    }
+
+    out
+}
+
+fn render_block(out: &mut String, kind: &str, block: &DocBlock, heading: &str) {
+    let _ = write!(
+        out,
+        "\n### {}: {}\n\n`{}`\n",
+        kind, block.title, block.signature
+    );
+    if !block.summary.is_empty() {
+        let _ = write!(out, "\n{}\n", block.summary);
+    }
+    if let Some(section) = block.section(heading) {
+        let _ = write!(out, "\n**{}**\n\n", title_case(heading));
+        for item in &section.items {
+            let _ = writeln!(out, "{}", item);
+        }
+    }
+}
+
+fn render_attack_tree(examples: &[Example]) -> String {
+    let mut out = String::new();
+    out.push_str("// Generated by tools/doc_generator - do not edit by hand\n");
+    out.push_str("digraph attack_tree {\n");
+    out.push_str("    rankdir=RL;\n");
+    out.push_str("    node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\", fillcolor=\"#fff2cc\"];\n");
+    out.push_str("    edge [fontname=\"Helvetica\", fontsize=10];\n\n");
+    out.push_str("    goal [label=\"Drain or corrupt\\nprotocol funds\", shape=doubleoctagon, fillcolor=\"#f4cccc\"];\n\n");
+
+    let mut present: Vec<Class> = examples.iter().map(Example::class).collect();
+    present.sort();
+    present.dedup();
+
+    for class in Class::ALL.iter().filter(|class| present.contains(class)) {
+        let _ = writeln!(
+            out,
+            "    {} [label=\"{}\", shape=ellipse, fillcolor=\"#cfe2f3\"];",
+            class.node_id(),
+            class.label()
+        );
+        let _ = writeln!(out, "    {} -> goal;", class.node_id());
+    }
+
+    out.push_str("\n    // How one class of bug is escalated into another\n");
+    for (from, to, label) in CHAINS
+        .iter()
+        .filter(|(from, to, _)| present.contains(from) && present.contains(to))
+    {
+        let _ = writeln!(
+            out,
+            "    {} -> {} [style=dashed, color=\"#cc0000\", label=\"{}\"];",
+            from.node_id(),
+            to.node_id(),
+            escape(label)
+        );
+    }
+
+    out.push('\n');
+    for example in examples {
+        let node = format!("ex_{}", example.number);
+        let _ = writeln!(
+            out,
+            "    {} [label=\"{:03}\\n{}\"];",
+            node,
+            example.number,
+            escape(&example.title())
+        );
+        let _ = writeln!(out, "    {} -> {};", node, example.class().node_id());
+
+        if let Some(scenario) = example
+            .vulnerabilities
+            .first()
+            .and_then(|b| b.section(ATTACK_SCENARIO))
+        {
+            let _ =
+                writeln!(
+                out,
+                "    {}_steps [shape=note, fillcolor=\"#ffffff\", fontsize=9, label=\"{}\\l\"];",
+                node,
+                scenario.items.iter().map(|item| escape(item)).collect::<Vec<_>>().join("\\l")
+            );
+            let _ = writeln!(
+                out,
+                "    {}_steps -> {} [arrowhead=none, style=dotted];",
+                node, node
+            );
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// GitHub's heading anchor for `## NNN - Title`
+fn anchor(example: &Example) -> String {
+    format!("{:03} - {}", example.number, example.title())
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+fn title_case(heading: &str) -> String {
+    let lower = heading.to_lowercase();
+    let mut chars = lower.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+        #[program]
+        pub mod vulnerable_example {
+            use super::*;
+
+            /// VULNERABILITY: Missing Signer Check
+            ///
+            /// Anyone can withdraw.
+            ///
+            /// ATTACK SCENARIO:
+            /// 1. Mallory passes Alice's key
+            /// 2. Funds move to Mallory, and the
+            ///    vault is emptied
+            ///
+            /// WHY THIS IS DANGEROUS:
+            /// - Total loss
+            pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+                Ok(())
+            }
+
+            /// Not a documented vulnerability
+            pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+                Ok(())
+            }
+        }
+    "#;
+
+    fn blocks(source: &str, marker: &str) -> Vec<DocBlock> {
+        let file = syn::parse_file(source).unwrap();
+        let mut functions = Vec::new();
+        collect_functions(&file.items, &mut functions);
+        functions
+            .into_iter()
+            .filter_map(|(signature, docs)| parse_doc_block(signature, &docs, marker))
+            .collect()
+    }
+
+    #[test]
+    fn extracts_sections_from_program_module() {
+        let blocks = blocks(SOURCE, VULNERABILITY_MARKER);
+        assert_eq!(blocks.len(), 1);
+
+        let block = &blocks[0];
+        assert_eq!(block.title, "Missing Signer Check");
+        assert_eq!(block.summary, "Anyone can withdraw.");
+        assert_eq!(
+            block.signature,
+            "fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()>"
+        );
+
+        let scenario = block.section(ATTACK_SCENARIO).unwrap();
+        assert_eq!(
+            scenario.items,
+            [
+                "1. Mallory passes Alice's key",
+                "2. Funds move to Mallory, and the vault is emptied"
+            ]
+        );
+        assert_eq!(
+            block.section("WHY THIS IS DANGEROUS").unwrap().items,
+            ["- Total loss"]
+        );
+    }
+
+    #[test]
+    fn classifies_by_keyword_priority() {
+        assert!(Class::classify("Missing Signer Check") == Class::AccessControl);
+        assert!(Class::classify("Multiplier Overflow in reward math") == Class::Arithmetic);
+        assert!(Class::classify("TWAP Over The Last N Updates") == Class::Economic);
+        assert!(Class::classify("Price Impact Limited Per Trade Only") == Class::Economic);
+    }
+}